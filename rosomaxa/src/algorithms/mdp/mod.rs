@@ -48,6 +48,12 @@ pub trait LearningStrategy<S: State> {
 pub trait PolicyStrategy<S: State> {
     /// Selects an action from the estimated actions.
     fn select(&self, estimates: &ActionEstimates<S>) -> Option<S::Action>;
+
+    /// Selects an action from the estimated actions using given exploration rate instead of the
+    /// strategy's own one. Strategies without exploration ignore it.
+    fn select_with_exploration(&self, estimates: &ActionEstimates<S>, _exploration: f64) -> Option<S::Action> {
+        self.select(estimates)
+    }
 }
 
 type ActionEstimate<S> = (<S as State>::Action, f64);
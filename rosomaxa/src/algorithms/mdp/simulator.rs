@@ -3,16 +3,62 @@
 mod simulator_test;
 
 use super::*;
-use crate::utils::{parallel_into_collect, CollectGroupBy, Parallelism};
+use crate::utils::{compare_floats, parallel_into_collect, CollectGroupBy, Parallelism};
 
 /// A type which keeps track of all state-action estimates.
 pub type StateEstimates<S> = HashMap<S, ActionEstimates<S>>;
 
+/// A recorded trajectory: a sequence of states, actions taken in them and received rewards.
+pub type Trajectory<S> = Vec<(S, <S as State>::Action, f64)>;
+
+/// An exploration schedule: returns exploration rate (e.g. epsilon) for given episode index.
+pub type ExplorationSchedule = Box<dyn Fn(usize) -> f64 + Send + Sync>;
+
+/// Keeps track of how many times state-action estimates were updated by an agent within episode.
+type VisitCounts<S> = HashMap<S, HashMap<<S as State>::Action, usize>>;
+
+/// Specifies how action estimates learned by multiple agents within episode are merged together.
+#[derive(Clone, Debug, Default)]
+pub enum MergeStrategy {
+    /// Applies the reducer passed to episode run methods to estimates of all agents.
+    #[default]
+    Reduce,
+
+    /// Uses an average of agents' estimates weighted by amount of their updates within episode.
+    /// Estimates which are not updated by any agent are kept unchanged.
+    VisitWeighted,
+
+    /// Moves prior estimate towards the visit weighted average using given rate.
+    Blend {
+        /// A rate in range (0, 1]: the higher the value, the less prior estimate is kept.
+        rate: f64,
+    },
+}
+
+impl MergeStrategy {
+    fn merge(&self, prior: Option<f64>, values: &[(f64, usize)], reduce: impl FnOnce() -> f64) -> f64 {
+        let visits = values.iter().map(|(_, visits)| *visits).sum::<usize>();
+        let weighted = || values.iter().map(|(value, visits)| value * *visits as f64).sum::<f64>() / visits as f64;
+
+        match (self, prior) {
+            (MergeStrategy::Reduce, _) => reduce(),
+            (_, Some(prior)) if visits == 0 => prior,
+            (_, None) if visits == 0 => reduce(),
+            (MergeStrategy::VisitWeighted, _) => weighted(),
+            (MergeStrategy::Blend { rate }, Some(prior)) => prior + rate * (weighted() - prior),
+            (MergeStrategy::Blend { .. }, None) => weighted(),
+        }
+    }
+}
+
 /// A simulator to train agent with multiple episodes.
 pub struct Simulator<S: State> {
     q: StateEstimates<S>,
     learning_strategy: Box<dyn LearningStrategy<S> + Send + Sync>,
     policy_strategy: Box<dyn PolicyStrategy<S> + Send + Sync>,
+    exploration_schedule: Option<ExplorationSchedule>,
+    merge_strategy: MergeStrategy,
+    episode: usize,
 }
 
 impl<S: State> Simulator<S> {
@@ -21,7 +67,14 @@ impl<S: State> Simulator<S> {
         learning_strategy: Box<dyn LearningStrategy<S> + Send + Sync>,
         policy_strategy: Box<dyn PolicyStrategy<S> + Send + Sync>,
     ) -> Self {
-        Self { q: Default::default(), learning_strategy, policy_strategy }
+        Self {
+            q: Default::default(),
+            learning_strategy,
+            policy_strategy,
+            exploration_schedule: None,
+            merge_strategy: MergeStrategy::default(),
+            episode: 0,
+        }
     }
 
     /// Return a learned optimal policy for given state.
@@ -34,6 +87,26 @@ impl<S: State> Simulator<S> {
         })
     }
 
+    /// Gets greedy policy learned so far: an action with max estimate for each known state.
+    /// Ties are broken in favor of the smallest action, so the result is deterministic.
+    pub fn get_greedy_policy(&self) -> HashMap<S, <S as State>::Action>
+    where
+        <S as State>::Action: Ord,
+    {
+        self.q
+            .iter()
+            .filter_map(|(state, estimates)| {
+                estimates
+                    .data()
+                    .iter()
+                    .max_by(|(a_action, a_value), (b_action, b_value)| {
+                        compare_floats(**a_value, **b_value).then_with(|| b_action.cmp(a_action))
+                    })
+                    .map(|(action, _)| (state.clone(), action.clone()))
+            })
+            .collect()
+    }
+
     /// Gets state estimates.
     pub fn get_state_estimates(&self) -> &StateEstimates<S> {
         &self.q
@@ -44,6 +117,33 @@ impl<S: State> Simulator<S> {
         self.q.insert(state, estimates);
     }
 
+    /// Imports recorded (e.g. expert) trajectories to initialize action estimates before learning.
+    /// Rewards received for the same state-action pair are aggregated by `reducer` and used as its
+    /// estimate. Other actions of the state, returned by the `agent`, keep their estimates.
+    pub fn import_trajectories(
+        &mut self,
+        agent: &dyn Agent<S>,
+        trajectories: Vec<Trajectory<S>>,
+        reducer: impl Fn(&S, &[f64]) -> f64,
+    ) {
+        trajectories
+            .into_iter()
+            .flat_map(|trajectory| trajectory.into_iter())
+            .map(|(state, action, reward)| (state, (action, reward)))
+            .collect_group_by()
+            .into_iter()
+            .for_each(|(state, action_rewards)| {
+                let mut estimates = self.q.remove(&state).unwrap_or_else(|| agent.get_actions(&state));
+
+                action_rewards.into_iter().collect_group_by().into_iter().for_each(|(action, rewards)| {
+                    estimates.insert(action, reducer(&state, rewards.as_slice()));
+                });
+                estimates.recalculate_min_max();
+
+                self.q.insert(state, estimates);
+            });
+    }
+
     /// Sets a new learning strategy.
     pub fn set_learning_strategy(&mut self, learning_strategy: Box<dyn LearningStrategy<S> + Send + Sync>) {
         self.learning_strategy = learning_strategy;
@@ -54,19 +154,38 @@ impl<S: State> Simulator<S> {
         self.policy_strategy = policy_strategy;
     }
 
+    /// Sets a new strategy to merge estimates learned by agents within episode.
+    pub fn set_merge_strategy(&mut self, merge_strategy: MergeStrategy) {
+        self.merge_strategy = merge_strategy;
+    }
+
+    /// Sets exploration schedule which is consulted by policy strategy on each episode instead of
+    /// its own exploration rate. Episodes are counted from zero, `run_episodes` counts as one episode.
+    pub fn set_exploration_schedule(&mut self, exploration_schedule: ExplorationSchedule) {
+        self.exploration_schedule = Some(exploration_schedule);
+    }
+
+    /// Returns index of the next episode.
+    pub fn get_episode(&self) -> usize {
+        self.episode
+    }
+
     /// Runs single episode for each of the given agents in parallel.
     pub fn run_episode<A>(&mut self, mut agent: A, reducer: impl Fn(&S, &[f64]) -> f64) -> A
     where
         A: Agent<S> + Send + Sync,
     {
+        let exploration = self.get_exploration();
         let qs = Self::run_episode_isolated(
             &mut agent,
             self.learning_strategy.as_ref(),
             self.policy_strategy.as_ref(),
+            exploration,
             &self.q,
         );
 
         self.merge_state_estimates(vec![qs], reducer);
+        self.episode += 1;
 
         agent
     }
@@ -81,6 +200,7 @@ impl<S: State> Simulator<S> {
     where
         A: Agent<S> + Send + Sync,
     {
+        let exploration = self.get_exploration();
         let (agents, qs): (Vec<_>, Vec<_>) =
             parallel_into_collect(agents.into_iter().enumerate().collect(), |(idx, mut agent)| {
                 parallelism.thread_pool_execute(idx, || {
@@ -88,6 +208,7 @@ impl<S: State> Simulator<S> {
                         &mut agent,
                         self.learning_strategy.as_ref(),
                         self.policy_strategy.as_ref(),
+                        exploration,
                         &self.q,
                     );
                     (agent, qs)
@@ -97,28 +218,39 @@ impl<S: State> Simulator<S> {
             .unzip();
 
         self.merge_state_estimates(qs, reducer);
+        self.episode += 1;
 
         agents
     }
 
+    fn get_exploration(&self) -> Option<f64> {
+        self.exploration_schedule.as_ref().map(|schedule| (schedule)(self.episode))
+    }
+
     /// Runs episode for given agent without changing any state of a simulator.
     fn run_episode_isolated<A>(
         agent: &mut A,
         learning_strategy: &(dyn LearningStrategy<S> + Send + Sync),
         policy_strategy: &(dyn PolicyStrategy<S> + Send + Sync),
+        exploration: Option<f64>,
         q: &StateEstimates<S>,
-    ) -> StateEstimates<S>
+    ) -> (StateEstimates<S>, VisitCounts<S>)
     where
         A: Agent<S> + Send + Sync,
     {
         let mut q_new = StateEstimates::new();
+        let mut visits = VisitCounts::<S>::new();
 
         loop {
             let old_state = agent.get_state().clone();
             Self::ensure_actions(&mut q_new, q, &old_state, agent);
             let old_estimates = q_new.get(&old_state).unwrap();
 
-            let action = if let Some(action) = policy_strategy.select(old_estimates) { action } else { return q_new };
+            let action = match exploration {
+                Some(exploration) => policy_strategy.select_with_exploration(old_estimates, exploration),
+                None => policy_strategy.select(old_estimates),
+            };
+            let action = if let Some(action) = action { action } else { return (q_new, visits) };
 
             agent.take_action(&action);
             let old_value = *old_estimates.data().get(&action).unwrap();
@@ -134,15 +266,49 @@ impl<S: State> Simulator<S> {
                 estimates.insert(action.clone(), new_value);
                 estimates.recalculate_min_max();
             });
+            *visits.entry(old_state).or_default().entry(action).or_insert(0) += 1;
         }
     }
 
-    fn merge_state_estimates(&mut self, qs: Vec<StateEstimates<S>>, reducer: impl Fn(&S, &[f64]) -> f64) {
+    fn merge_state_estimates(
+        &mut self,
+        qs: Vec<(StateEstimates<S>, VisitCounts<S>)>,
+        reducer: impl Fn(&S, &[f64]) -> f64,
+    ) {
+        let merge_strategy = &self.merge_strategy;
+        let q = &mut self.q;
+
+        // NOTE attach visit counts to each estimate to let merge strategy weight them
+        let qs = qs
+            .into_iter()
+            .map(|(q_new, visits)| {
+                q_new
+                    .into_iter()
+                    .map(|(state, estimates)| {
+                        let state_visits = visits.get(&state);
+                        let estimates = HashMap::<S::Action, f64>::from(estimates)
+                            .into_iter()
+                            .map(|(action, value)| {
+                                let action_visits = state_visits.and_then(|v| v.get(&action)).copied().unwrap_or(0);
+                                (action, (value, action_visits))
+                            })
+                            .collect::<HashMap<_, _>>();
+
+                        (state, estimates)
+                    })
+                    .collect::<HashMap<_, _>>()
+            })
+            .collect();
+
         merge_vec_maps(qs, |(state, values)| {
-            let action_values = self.q.entry(state.clone()).or_insert_with(ActionEstimates::default);
-            let vec_map = values.into_iter().map(|estimates| estimates.into()).collect();
-            merge_vec_maps(vec_map, |(action, values)| {
-                action_values.insert(action, reducer(&state, values.as_slice()));
+            let action_values = q.entry(state.clone()).or_default();
+            merge_vec_maps(values, |(action, values)| {
+                let prior = action_values.data().get(&action).copied();
+                let value = merge_strategy.merge(prior, values.as_slice(), || {
+                    let values = values.iter().map(|(value, _)| *value).collect::<Vec<_>>();
+                    reducer(&state, values.as_slice())
+                });
+                action_values.insert(action, value);
             });
             action_values.recalculate_min_max();
         });
@@ -57,11 +57,15 @@ impl EpsilonGreedy {
 
 impl<S: State> PolicyStrategy<S> for EpsilonGreedy {
     fn select(&self, estimates: &ActionEstimates<S>) -> Option<S::Action> {
+        self.select_with_exploration(estimates, self.epsilon)
+    }
+
+    fn select_with_exploration(&self, estimates: &ActionEstimates<S>, exploration: f64) -> Option<S::Action> {
         if estimates.data().is_empty() {
             return None;
         }
 
-        if self.random.is_hit(self.epsilon) {
+        if self.random.is_hit(exploration) {
             estimates.random(self.random.as_ref())
         } else {
             estimates.data().iter().max_by(|(_, x), (_, y)| compare_floats(**x, **y)).map(|(a, _)| a.clone())
@@ -94,11 +98,15 @@ impl EpsilonWeighted {
 
 impl<S: State> PolicyStrategy<S> for EpsilonWeighted {
     fn select(&self, estimates: &ActionEstimates<S>) -> Option<S::Action> {
+        self.select_with_exploration(estimates, self.epsilon)
+    }
+
+    fn select_with_exploration(&self, estimates: &ActionEstimates<S>, exploration: f64) -> Option<S::Action> {
         if estimates.data().is_empty() {
             return None;
         }
 
-        if self.random.is_hit(self.epsilon) {
+        if self.random.is_hit(exploration) {
             estimates.random(self.random.as_ref())
         } else {
             estimates.weighted(self.random.as_ref())
@@ -1,6 +1,7 @@
 use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
+use crate::utils::Random;
 use std::hash::Hash;
 use std::sync::Arc;
 
@@ -36,8 +37,8 @@ pub trait InitialOperator {
     /// A heuristic solution type.
     type Solution: HeuristicSolution;
 
-    /// Creates an initial solution from scratch.
-    fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution;
+    /// Creates an initial solution from scratch using given random generator.
+    fn create(&self, heuristic_ctx: &Self::Context, random: Arc<dyn Random + Send + Sync>) -> Self::Solution;
 }
 
 /// A collection of initial operators.
@@ -84,11 +85,15 @@ where
 {
     max_generations: Option<usize>,
     max_time: Option<usize>,
+    max_time_reserve: Option<usize>,
     min_cv: Option<(String, usize, f64, bool, K)>,
+    max_stagnation: Option<(usize, K)>,
+    min_improvement: Option<(usize, f64, K)>,
     target_proximity: Option<(Vec<f64>, f64)>,
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
     context: Option<C>,
     termination: Option<Box<dyn Termination<Context = C, Objective = O>>>,
+    extra_terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>>,
     strategy: Option<Box<dyn EvolutionStrategy<Context = C, Objective = O, Solution = S>>>,
 
     search_operators: Option<HeuristicSearchOperators<C, O, S>>,
@@ -111,11 +116,15 @@ where
         Self {
             max_generations: None,
             max_time: None,
+            max_time_reserve: None,
             min_cv: None,
+            max_stagnation: None,
+            min_improvement: None,
             target_proximity: None,
             heuristic: None,
             context: None,
             termination: None,
+            extra_terminations: vec![],
             strategy: None,
             search_operators: None,
             diversify_operators: None,
@@ -145,12 +154,33 @@ where
         self
     }
 
+    /// Sets reserve time in seconds which is subtracted from max running time limit, so evolution
+    /// stops earlier and there is time left to return the best known solution before the deadline.
+    /// Default is None.
+    pub fn with_max_time_reserve(mut self, reserve: Option<usize>) -> Self {
+        self.max_time_reserve = reserve;
+        self
+    }
+
     /// Sets variation coefficient termination criteria. Default is None.
     pub fn with_min_cv(mut self, min_cv: Option<(String, usize, f64, bool)>, key: K) -> Self {
         self.min_cv = min_cv.map(|min_cv| (min_cv.0, min_cv.1, min_cv.2, min_cv.3, key));
         self
     }
 
+    /// Sets max amount of generations without population change as termination criteria. Default is None.
+    pub fn with_max_stagnation(mut self, limit: Option<usize>, key: K) -> Self {
+        self.max_stagnation = limit.map(|limit| (limit, key));
+        self
+    }
+
+    /// Sets amount of generations and min improvement of the best fitness within them as termination criteria.
+    /// Default is None.
+    pub fn with_min_improvement(mut self, min_improvement: Option<(usize, f64)>, key: K) -> Self {
+        self.min_improvement = min_improvement.map(|(generations, epsilon)| (generations, epsilon, key));
+        self
+    }
+
     /// Sets target fitness and distance threshold as termination criteria.
     pub fn with_target_proximity(mut self, target_proximity: Option<(Vec<f64>, f64)>) -> Self {
         self.target_proximity = target_proximity;
@@ -200,6 +230,16 @@ where
         self
     }
 
+    /// Adds an extra termination criteria which is used together with the configured ones:
+    /// evolution stops when any of them is met.
+    pub fn add_termination(
+        mut self,
+        termination: Box<dyn Termination<Context = C, Objective = O> + Send + Sync>,
+    ) -> Self {
+        self.extra_terminations.push(termination);
+        self
+    }
+
     /// Sets a different heuristic replacing initial.
     pub fn with_heuristic(
         mut self,
@@ -231,21 +271,26 @@ where
     }
 
     /// Gets termination criterias.
-    #[allow(clippy::type_complexity)]
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
     fn get_termination(
         logger: &InfoLogger,
         max_generations: Option<usize>,
-        max_time: Option<usize>,
+        max_time: Option<(usize, Option<usize>)>,
         min_cv: Option<(String, usize, f64, bool, K)>,
+        max_stagnation: Option<(usize, K)>,
+        min_improvement: Option<(usize, f64, K)>,
         target_proximity: Option<(Vec<f64>, f64)>,
+        extra_terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>>,
     ) -> Result<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>, String> {
-        let terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
+        let mut terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
             max_generations,
             max_time,
             &min_cv,
+            &max_stagnation,
+            &min_improvement,
             &target_proximity,
         ) {
-            (None, None, None, None) => {
+            (None, None, None, None, None, None) => {
                 (logger)("configured to use default max-generations (3000) and max-time (300secs)");
                 vec![Box::new(MaxGeneration::new(3000)), Box::new(MaxTime::new(300.))]
             }
@@ -257,9 +302,16 @@ where
                     terminations.push(Box::new(MaxGeneration::new(limit)))
                 }
 
-                if let Some(limit) = max_time {
-                    (logger)(format!("configured to use max-time: {limit}s").as_str());
-                    terminations.push(Box::new(MaxTime::new(limit as f64)));
+                match max_time {
+                    Some((limit, Some(reserve))) => {
+                        (logger)(format!("configured to use max-time: {limit}s with reserve: {reserve}s").as_str());
+                        terminations.push(Box::new(MaxTime::new_with_reserve(limit as f64, reserve as f64)));
+                    }
+                    Some((limit, None)) => {
+                        (logger)(format!("configured to use max-time: {limit}s").as_str());
+                        terminations.push(Box::new(MaxTime::new(limit as f64)));
+                    }
+                    None => {}
                 }
 
                 if let Some((interval_type, value, threshold, is_global, key)) = min_cv.clone() {
@@ -284,6 +336,19 @@ where
                     terminations.push(variation)
                 }
 
+                if let Some((limit, key)) = max_stagnation.clone() {
+                    (logger)(format!("configured to use max-stagnation: {limit}").as_str());
+                    terminations.push(Box::new(MaxStagnation::<C, O, S, K>::new(limit, key)));
+                }
+
+                if let Some((generations, epsilon, key)) = min_improvement.clone() {
+                    (logger)(
+                        format!("configured to use min-improvement: {epsilon} within {generations} generations")
+                            .as_str(),
+                    );
+                    terminations.push(Box::new(MinImprovement::<C, O, S, K>::new(generations, epsilon, key)));
+                }
+
                 if let Some((target_fitness, distance_threshold)) = target_proximity.clone() {
                     (logger)(
                             format!(
@@ -298,6 +363,11 @@ where
             }
         };
 
+        if !extra_terminations.is_empty() {
+            (logger)(format!("configured to use {} extra termination(s)", extra_terminations.len()).as_str());
+            terminations.extend(extra_terminations);
+        }
+
         Ok(Box::new(CompositeTermination::new(terminations)))
     }
 
@@ -305,8 +375,16 @@ where
     pub fn build(self) -> Result<EvolutionConfig<C, O, S>, String> {
         let context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
         let logger = context.environment().logger.clone();
-        let termination =
-            Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
+        let termination = Self::get_termination(
+            &logger,
+            self.max_generations,
+            self.max_time.map(|limit| (limit, self.max_time_reserve)),
+            self.min_cv,
+            self.max_stagnation,
+            self.min_improvement,
+            self.target_proximity,
+            self.extra_terminations,
+        )?;
 
         Ok(EvolutionConfig {
             initial: self.initial,
@@ -1,6 +1,11 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/simulator_test.rs"]
+mod simulator_test;
+
 use crate::evolution::EvolutionResult;
 use crate::prelude::*;
 use crate::utils::Timer;
+use std::sync::Arc;
 
 /// An entity which simulates evolution process.
 pub struct EvolutionSimulator<C, O, S>
@@ -68,11 +73,9 @@ where
                 return Err(());
             }
 
-            let operator_idx =
-                if idx < config.initial.operators.len() { idx } else { random.weighted(weights.as_slice()) };
-
             // TODO consider initial quota limit
-            let solution = config.initial.operators[operator_idx].0.create(&heuristic_ctx);
+            let solution =
+                create_initial_solution(&config.initial.operators, weights.as_slice(), &heuristic_ctx, &random, idx);
             heuristic_ctx.on_initial(solution, item_time);
 
             Ok(())
@@ -90,3 +93,23 @@ where
         })
     }
 }
+
+/// Creates an initial solution with the given index. When random generator is seeded, a generator
+/// derived for the index is used, so the solution does not depend on construction order of others.
+fn create_initial_solution<C, O, S>(
+    operators: &InitialOperators<C, O, S>,
+    weights: &[usize],
+    heuristic_ctx: &C,
+    random: &Arc<dyn Random + Send + Sync>,
+    idx: usize,
+) -> S
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let random = random.derive(idx as u64).unwrap_or_else(|| random.clone());
+    let operator_idx = if idx < operators.len() { idx } else { random.weighted(weights) };
+
+    operators[operator_idx].0.create(heuristic_ctx, random)
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::population::Greedy;
-use crate::utils::Timer;
-use crate::DynHeuristicPopulation;
+use crate::utils::{Environment, Timer};
+use crate::{get_default_selection_size, DynHeuristicPopulation};
 use std::marker::PhantomData;
 use std::sync::{Arc, RwLock};
 use tokio::runtime::Builder;
@@ -24,6 +24,20 @@ pub struct AsyncParams {
     pub selection_size: usize,
 }
 
+impl AsyncParams {
+    /// Creates a new instance of `AsyncParams` with given amount of actors. Channel buffer is scaled with
+    /// the amount of actors.
+    pub fn new(actors_size: usize, selection_size: usize) -> Self {
+        let actors_size = actors_size.max(1);
+        Self { actors_size, channel_buffer: actors_size, selection_size }
+    }
+
+    /// Creates a new instance of `AsyncParams` using environment defaults: one actor per available CPU.
+    pub fn new_with_environment(environment: &Environment) -> Self {
+        Self::new(environment.parallelism.available_cpus(), get_default_selection_size(environment))
+    }
+}
+
 /// An asynchronous simple evolution algorithm which maintains a single population and improves it iteratively.
 pub struct AsyncIterative<H, C, O, S> {
     params: AsyncParams,
@@ -52,6 +66,9 @@ where
         let (host_sender, mut host_receiver) = mpsc::channel(self.params.channel_buffer);
         let runtime = Builder::new_current_thread().build().unwrap();
 
+        let mut processed_solutions = Vec::with_capacity(self.params.selection_size);
+        let mut generation_time = Timer::start();
+
         runtime.block_on(async {
             let actors = (0..self.params.actors_size)
                 .map(|_| {
@@ -61,8 +78,6 @@ where
                 .collect::<Vec<_>>();
 
             let mut selected_solutions = Vec::with_capacity(self.params.selection_size);
-            let mut processed_solutions = Vec::with_capacity(self.params.selection_size);
-            let mut generation_time = Timer::start();
             let mut actors = HeuristicActors::new(actors, host_sender);
 
             loop {
@@ -98,7 +113,9 @@ where
                     processed_solutions.extend(solutions.into_iter());
                 }
 
-                if processed_solutions.len() == self.params.selection_size {
+                // NOTE each search can return more than one solution (e.g. diversified ones in exploration phase),
+                // so amount of processed solutions can exceed selection size
+                if processed_solutions.len() >= self.params.selection_size {
                     let termination_estimate = termination.estimate(&heuristic_ctx);
                     heuristic_ctx.on_generation(
                         processed_solutions.drain(0..).collect(),
@@ -110,6 +127,12 @@ where
             }
         });
 
+        // NOTE do not discard solutions which are received, but not yet added to population
+        if !processed_solutions.is_empty() {
+            let termination_estimate = termination.estimate(&heuristic_ctx);
+            heuristic_ctx.on_generation(processed_solutions, termination_estimate, generation_time);
+        }
+
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
 
         let solutions =
@@ -22,6 +22,8 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Diversity of the final population, if it is estimated.
+    pub diversity: Option<f64>,
 }
 
 /// Represents information about generation.
@@ -38,6 +40,8 @@ pub struct TelemetryGeneration {
     pub is_improvement: bool,
     /// Population state.
     pub population: TelemetryPopulation,
+    /// Fitness spread: a mean pairwise relative distance between individuals' fitness values.
+    pub fitness_spread: Option<f64>,
 }
 
 /// Keeps essential information about particular individual in population.
@@ -56,6 +60,9 @@ pub struct TelemetryPopulation {
     pub individuals: Vec<TelemetryIndividual>,
 }
 
+/// A callback which receives generation snapshot each time generation is reported.
+pub type TelemetryObserver = Box<dyn Fn(&TelemetryGeneration) + Send + Sync>;
+
 /// Specifies a telemetry mode.
 #[derive(Clone)]
 pub enum TelemetryMode {
@@ -105,6 +112,7 @@ where
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    observer: Option<TelemetryObserver>,
     _marker: (PhantomData<O>, PhantomData<S>),
 }
 
@@ -117,16 +125,23 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![], diversity: None },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            observer: None,
             _marker: Default::default(),
         }
     }
 
+    /// Sets observer which is called with generation snapshot on each generation independently
+    /// from telemetry mode.
+    pub fn set_generation_observer(&mut self, observer: TelemetryObserver) {
+        self.observer = Some(observer);
+    }
+
     /// Reports initial solution statistics.
     pub fn on_initial(&mut self, solution: &S, item_time: Timer) {
         match &self.mode {
@@ -170,8 +185,10 @@ where
             termination_estimate,
         };
 
+        let should_observe = self.observer.is_some();
         let (log_best, log_population, track_population, should_dump_population) = match &self.mode {
-            TelemetryMode::None => return,
+            TelemetryMode::None if !should_observe => return,
+            TelemetryMode::None => (None, None, None, false),
             TelemetryMode::OnlyLogging { log_best, log_population, dump_population, .. } => {
                 (Some(log_best), Some(log_population), None, *dump_population)
             }
@@ -181,41 +198,53 @@ where
             }
         };
 
+        let is_due = |frequency: Option<&usize>| frequency.map_or(false, |frequency| generation % *frequency == 0);
+
         if let Some((best_individual, rank)) = population.ranked().next() {
-            let should_log_best = generation % *log_best.unwrap_or(&usize::MAX) == 0;
-            let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
-            let should_track_population = generation % *track_population.unwrap_or(&usize::MAX) == 0;
+            let should_log_population = is_due(log_population);
+            let should_track_population = is_due(track_population);
 
-            if should_log_best {
+            if is_due(log_best) {
                 self.log_individual(
                     &self.get_individual_metrics(objective, population, best_individual, rank),
                     Some((generation, generation_time)),
                 )
             }
 
-            self.on_population(
+            // NOTE generation snapshot is created once and shared between observer and metrics
+            let snapshot = self.on_population(
                 objective,
                 population,
                 should_log_population,
-                should_track_population,
+                should_track_population || should_observe,
                 should_dump_population,
             );
+
+            if let Some(snapshot) = snapshot {
+                if let Some(observer) = &self.observer {
+                    observer(&snapshot);
+                }
+
+                if should_track_population {
+                    self.metrics.evolution.push(snapshot);
+                }
+            }
         } else {
             self.log("no progress yet");
         }
     }
 
-    /// Reports population state.
+    /// Reports population state and returns its snapshot if requested.
     fn on_population(
-        &mut self,
+        &self,
         objective: &O,
         population: &DynHeuristicPopulation<O, S>,
         should_log_population: bool,
-        should_track_population: bool,
+        should_create_generation: bool,
         should_dump_population: bool,
-    ) {
-        if !should_log_population && !should_track_population {
-            return;
+    ) -> Option<TelemetryGeneration> {
+        if !should_log_population && !should_create_generation {
+            return None;
         }
 
         let generation = self.statistics.generation;
@@ -240,10 +269,7 @@ where
             );
         }
 
-        let individuals = population
-            .ranked()
-            .map(|(insertion_ctx, rank)| self.get_individual_metrics(objective, population, insertion_ctx, rank))
-            .collect::<Vec<_>>();
+        let individuals = self.get_population_metrics(objective, population);
 
         if should_log_population {
             individuals.iter().for_each(|metrics| self.log_individual(metrics, None));
@@ -254,15 +280,10 @@ where
             }
         }
 
-        if should_track_population {
-            self.metrics.evolution.push(TelemetryGeneration {
-                number: generation,
-                timestamp: self.time.elapsed_secs_as_f64(),
-                i_all_ratio: self.improvement_tracker.i_all_ratio,
-                i_1000_ratio: self.improvement_tracker.i_1000_ratio,
-                is_improvement: self.improvement_tracker.is_last_improved,
-                population: TelemetryPopulation { individuals },
-            });
+        if should_create_generation {
+            Some(self.create_generation(individuals))
+        } else {
+            None
         }
     }
 
@@ -277,7 +298,11 @@ where
             _ => return,
         };
 
-        self.on_population(objective, population, should_log_population, should_track_population, false);
+        if let Some(generation) =
+            self.on_population(objective, population, should_log_population, should_track_population, false)
+        {
+            self.metrics.evolution.push(generation);
+        }
 
         let elapsed = self.time.elapsed_secs() as usize;
         let speed = generations as f64 / self.time.elapsed_secs_as_f64();
@@ -310,6 +335,29 @@ where
         &self.statistics
     }
 
+    fn create_generation(&self, individuals: Vec<TelemetryIndividual>) -> TelemetryGeneration {
+        TelemetryGeneration {
+            number: self.statistics.generation,
+            timestamp: self.time.elapsed_secs_as_f64(),
+            i_all_ratio: self.improvement_tracker.i_all_ratio,
+            i_1000_ratio: self.improvement_tracker.i_1000_ratio,
+            is_improvement: self.improvement_tracker.is_last_improved,
+            fitness_spread: get_fitness_spread(individuals.as_slice()),
+            population: TelemetryPopulation { individuals },
+        }
+    }
+
+    fn get_population_metrics(
+        &self,
+        objective: &O,
+        population: &DynHeuristicPopulation<O, S>,
+    ) -> Vec<TelemetryIndividual> {
+        population
+            .ranked()
+            .map(|(solution, rank)| self.get_individual_metrics(objective, population, solution, rank))
+            .collect()
+    }
+
     fn get_individual_metrics(
         &self,
         objective: &O,
@@ -468,6 +516,23 @@ where
     fitness_change
 }
 
+/// Returns a mean pairwise relative distance between fitness values of given individuals.
+fn get_fitness_spread(individuals: &[TelemetryIndividual]) -> Option<f64> {
+    if individuals.is_empty() {
+        return None;
+    }
+
+    let (total, pairs) = individuals
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, a)| individuals.iter().skip(idx + 1).map(move |b| (a, b)))
+        .fold((0., 0_usize), |(total, pairs), (a, b)| {
+            (total + relative_distance(a.fitness.iter().cloned(), b.fitness.iter().cloned()), pairs + 1)
+        });
+
+    Some(if pairs > 0 { total / pairs as f64 } else { 0. })
+}
+
 fn format_fitness(fitness: impl Iterator<Item = f64>) -> String {
     fitness.map(|v| format!("{v:.3}")).collect::<Vec<_>>().join(", ")
 }
@@ -13,7 +13,9 @@ use crate::utils::Noise;
 use crate::*;
 use std::any::Any;
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::iter::once;
 use std::ops::Range;
 use std::sync::Arc;
@@ -196,6 +198,13 @@ impl HeuristicSolution for VectorSolution {
             order: self.order.clone(),
         }
     }
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.data.iter().for_each(|value| value.to_bits().hash(&mut hasher));
+
+        hasher.finish()
+    }
 }
 
 impl DominanceOrdered for VectorSolution {
@@ -244,7 +253,7 @@ impl InitialOperator for VectorInitialOperator {
     type Objective = VectorObjective;
     type Solution = VectorSolution;
 
-    fn create(&self, context: &Self::Context) -> Self::Solution {
+    fn create(&self, context: &Self::Context, _: Arc<dyn Random + Send + Sync>) -> Self::Solution {
         Self::Solution::new(self.data.clone(), context.inner_context.objective.clone())
     }
 }
@@ -59,11 +59,12 @@ pub mod utils;
 
 use crate::algorithms::math::RemedianUsize;
 use crate::algorithms::nsga2::MultiObjective;
-use crate::evolution::{Telemetry, TelemetryMetrics, TelemetryMode};
+use crate::evolution::{Telemetry, TelemetryMetrics, TelemetryMode, TelemetryObserver};
 use crate::population::*;
 use crate::utils::Environment;
 use crate::utils::Timer;
-use std::hash::Hash;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 /// Represents solution in population defined as actual solution.
@@ -72,6 +73,14 @@ pub trait HeuristicSolution: Send + Sync {
     fn fitness<'a>(&'a self) -> Box<dyn Iterator<Item = f64> + 'a>;
     /// Creates a deep copy of the solution.
     fn deep_copy(&self) -> Self;
+    /// Returns a fingerprint of the solution structure which is used to distinguish solutions
+    /// with the same fitness. By default, it is derived from fitness values only.
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.fitness().for_each(|value| value.to_bits().hash(&mut hasher));
+
+        hasher.finish()
+    }
 }
 
 /// Represents a heuristic objective function.
@@ -183,6 +192,11 @@ where
     pub fn add_solution(&mut self, solution: S) {
         self.population.add(solution);
     }
+
+    /// Sets observer which receives generation snapshots during evolution.
+    pub fn set_generation_observer(&mut self, observer: TelemetryObserver) {
+        self.telemetry.set_generation_observer(observer);
+    }
 }
 
 impl<O, S> HeuristicContext for TelemetryHeuristicContext<O, S>
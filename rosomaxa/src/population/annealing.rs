@@ -0,0 +1,179 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/annealing_test.rs"]
+mod annealing_test;
+
+use super::*;
+use rand::prelude::*;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::{empty, repeat};
+use std::sync::Arc;
+
+/// Specifies simulated annealing population configuration.
+#[derive(Clone, Debug)]
+pub struct AnnealingConfig {
+    /// Selection size.
+    pub selection_size: usize,
+    /// Initial temperature. The higher it is, the more likely worse solutions are accepted.
+    pub initial_temperature: f64,
+    /// A cooling rate in (0, 1) range: temperature is multiplied by it on each generation.
+    pub cooling_rate: f64,
+    /// A seed used to decide whether worse solution is accepted. Randomized when omitted.
+    pub seed: Option<u64>,
+}
+
+impl AnnealingConfig {
+    /// Creates an instance of `AnnealingConfig` using default parameter values.
+    pub fn new_with_defaults(selection_size: usize) -> Self {
+        Self { selection_size, initial_temperature: 0.01, cooling_rate: 0.995, seed: None }
+    }
+}
+
+/// A population which keeps track of the best known individual and of the current one. The current
+/// individual is replaced by a better one always, and by a worse one with probability `exp(-delta / t)`,
+/// where `delta` is a relative fitness difference and `t` is a temperature decaying each generation.
+/// That allows to escape local optima at the beginning of the search.
+pub struct Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    objective: Arc<O>,
+    config: AnnealingConfig,
+    temperature: f64,
+    rng: SmallRng,
+    current: Option<S>,
+    best_known: Option<S>,
+}
+
+impl<O, S> HeuristicPopulation for Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Objective = O;
+    type Individual = S;
+
+    fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
+        #[allow(clippy::unnecessary_fold)]
+        individuals.into_iter().fold(false, |acc, individual| self.add(individual) || acc)
+    }
+
+    fn add(&mut self, individual: Self::Individual) -> bool {
+        let is_best_known = self
+            .best_known
+            .as_ref()
+            .map_or(true, |best_known| self.objective.total_order(best_known, &individual) == Ordering::Greater);
+
+        if is_best_known {
+            self.best_known = Some(individual.deep_copy());
+        }
+
+        let is_accepted = match &self.current {
+            Some(current) => match self.objective.total_order(current, &individual) {
+                Ordering::Greater => true,
+                Ordering::Equal => false,
+                Ordering::Less => {
+                    let probability = (-get_relative_delta(current, &individual) / self.temperature).exp();
+                    self.rng.gen_bool(probability.clamp(0., 1.))
+                }
+            },
+            None => true,
+        };
+
+        if is_accepted {
+            self.current = Some(individual);
+        }
+
+        is_best_known
+    }
+
+    fn on_generation(&mut self, statistics: &HeuristicStatistics) {
+        self.temperature = (self.config.initial_temperature
+            * self.config.cooling_rate.powi(statistics.generation as i32))
+        .max(f64::EPSILON);
+    }
+
+    fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> Ordering {
+        self.objective.total_order(a, b)
+    }
+
+    fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        if let Some(current) = self.current.as_ref() {
+            Box::new(repeat(current).take(self.config.selection_size))
+        } else {
+            Box::new(empty())
+        }
+    }
+
+    fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a Self::Individual, usize)> + 'a> {
+        Box::new(self.best_known.iter().map(|individual| (individual, 0)))
+    }
+
+    fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        Box::new(self.best_known.iter())
+    }
+
+    fn size(&self) -> usize {
+        usize::from(self.best_known.is_some())
+    }
+
+    fn selection_phase(&self) -> SelectionPhase {
+        SelectionPhase::Exploration
+    }
+}
+
+impl<O, S> Display for Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let format_fitness = |individual: Option<&S>| {
+            individual.map_or_else(String::new, |individual| {
+                individual.fitness().map(|v| format!("{v:.7}")).collect::<Vec<_>>().join(",")
+            })
+        };
+
+        write!(
+            f,
+            "[{}],[{}],t={:.7}",
+            format_fitness(self.best_known.as_ref()),
+            format_fitness(self.current.as_ref()),
+            self.temperature
+        )
+    }
+}
+
+impl<O, S> Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Annealing`.
+    pub fn new(objective: Arc<O>, config: AnnealingConfig) -> Result<Self, String> {
+        if config.initial_temperature <= 0. {
+            return Err("initial temperature should be positive".to_string());
+        }
+
+        if config.cooling_rate <= 0. || config.cooling_rate >= 1. {
+            return Err("cooling rate should be in (0, 1) range".to_string());
+        }
+
+        let rng = match config.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_rng(thread_rng()).map_err(|err| format!("cannot create rng: {err}"))?,
+        };
+
+        Ok(Self { objective, temperature: config.initial_temperature, config, rng, current: None, best_known: None })
+    }
+}
+
+/// Returns relative difference of the first fitness value which differs between two individuals.
+fn get_relative_delta<S: HeuristicSolution>(current: &S, candidate: &S) -> f64 {
+    current
+        .fitness()
+        .zip(candidate.fitness())
+        .find(|(current, candidate)| compare_floats(*current, *candidate) != Ordering::Equal)
+        .map_or(0., |(current, candidate)| (candidate - current).abs() / current.abs().max(f64::EPSILON))
+}
@@ -7,6 +7,7 @@ use crate::algorithms::nsga2::select_and_rank;
 use crate::utils::Random;
 use crate::{HeuristicSpeed, HeuristicStatistics};
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::{Formatter, Write};
 use std::iter::{empty, once};
 use std::ops::RangeBounds;
@@ -33,6 +34,26 @@ where
     individuals: Vec<S>,
     speed: Option<HeuristicSpeed>,
     dedup_fn: DedupFn<O, S>,
+    trimming: TrimmingStrategy,
+}
+
+/// Specifies how population is trimmed when its size exceeds max population size.
+#[derive(Clone, Debug, Default)]
+pub enum TrimmingStrategy {
+    /// Keeps the best individuals according to dominance order.
+    #[default]
+    Dominance,
+
+    /// Keeps the best individuals according to dominance order, but reserves given share of max
+    /// population size for individuals which are the most distant in objective space from already kept ones.
+    DominanceWithDiversity {
+        /// A share of max population size reserved for diverse individuals, in range [0, 1].
+        diversity_ratio: f64,
+    },
+
+    /// Keeps individuals as NSGA-II does: non-dominated fronts are kept as a whole while they fit,
+    /// the front which does not fit is trimmed using crowding distance.
+    CrowdingDistance,
 }
 
 /// Keeps track of dominance order in the population for certain individual.
@@ -176,7 +197,22 @@ where
         dedup_fn: DedupFn<O, S>,
     ) -> Self {
         assert!(max_population_size > 0);
-        Self { objective, random, selection_size, max_population_size, individuals: vec![], speed: None, dedup_fn }
+        Self {
+            objective,
+            random,
+            selection_size,
+            max_population_size,
+            individuals: vec![],
+            speed: None,
+            dedup_fn,
+            trimming: TrimmingStrategy::default(),
+        }
+    }
+
+    /// Sets a strategy used to trim population when it exceeds max population size.
+    pub fn with_trimming_strategy(mut self, trimming: TrimmingStrategy) -> Self {
+        self.trimming = trimming;
+        self
     }
 
     /// Shuffles objective function.
@@ -210,9 +246,36 @@ where
     }
 
     fn ensure_max_population_size(&mut self) {
-        if self.individuals.len() > self.max_population_size {
-            self.individuals.truncate(self.max_population_size);
+        if self.individuals.len() <= self.max_population_size {
+            return;
         }
+
+        let kept = match &self.trimming {
+            TrimmingStrategy::Dominance => {
+                self.individuals.truncate(self.max_population_size);
+                return;
+            }
+            TrimmingStrategy::DominanceWithDiversity { diversity_ratio } => {
+                let diverse_size = (self.max_population_size as f64 * diversity_ratio.clamp(0., 1.)).round() as usize;
+                let elite_size = (self.max_population_size - diverse_size).max(1);
+
+                select_diverse(self.individuals.as_slice(), elite_size, self.max_population_size)
+            }
+            TrimmingStrategy::CrowdingDistance => {
+                select_and_rank(self.individuals.as_slice(), self.max_population_size, self.objective.as_ref())
+                    .into_iter()
+                    .map(|acc| acc.index)
+                    .collect()
+            }
+        };
+
+        // NOTE keep dominance order of retained individuals
+        let mut idx = 0;
+        self.individuals.retain(|_| {
+            let is_kept = kept.contains(&idx);
+            idx += 1;
+            is_kept
+        });
     }
 
     fn is_improved(&self, best_known_fitness: Option<Vec<f64>>) -> bool {
@@ -231,6 +294,55 @@ where
     }
 }
 
+/// Selects `elite_size` first individuals and adds the rest up to `max_size` one by one choosing
+/// the one with the largest distance in normalized objective space to already selected ones.
+fn select_diverse<S: HeuristicSolution>(individuals: &[S], elite_size: usize, max_size: usize) -> HashSet<usize> {
+    let fitness = individuals.iter().map(|individual| individual.fitness().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let dimension = fitness.first().map_or(0, |values| values.len());
+    let ranges = (0..dimension)
+        .map(|idx| {
+            let (min, max) = fitness
+                .iter()
+                .filter_map(|values| values.get(idx))
+                .fold((f64::MAX, f64::MIN), |(min, max), &value| (min.min(value), max.max(value)));
+            if max > min {
+                max - min
+            } else {
+                1.
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let get_distance = |a: usize, b: usize| {
+        fitness[a]
+            .iter()
+            .zip(fitness[b].iter())
+            .zip(ranges.iter())
+            .map(|((a, b), range)| ((a - b) / range).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    };
+
+    let mut selected = (0..elite_size.min(individuals.len())).collect::<Vec<_>>();
+    let mut candidates = (selected.len()..individuals.len()).collect::<Vec<_>>();
+
+    while selected.len() < max_size && !candidates.is_empty() {
+        let (candidate_idx, _) = candidates
+            .iter()
+            .enumerate()
+            .map(|(candidate_idx, &individual_idx)| {
+                let distance = selected.iter().map(|&idx| get_distance(idx, individual_idx)).fold(f64::MAX, f64::min);
+                (candidate_idx, distance)
+            })
+            .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .unwrap();
+
+        selected.push(candidates.remove(candidate_idx));
+    }
+
+    selected.into_iter().collect()
+}
+
 impl<O, S> Display for Elitism<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
@@ -1,5 +1,9 @@
 //! Specifies population types.
 
+mod annealing;
+pub use self::annealing::Annealing;
+pub use self::annealing::AnnealingConfig;
+
 mod elitism;
 pub use self::elitism::DominanceOrder;
 pub use self::elitism::DominanceOrdered;
@@ -16,6 +16,7 @@ pub use crate::evolution::HeuristicSolutionProcessing;
 pub use crate::evolution::InitialOperators;
 pub use crate::evolution::TelemetryMode;
 
+pub use crate::population::AnnealingConfig;
 pub use crate::population::HeuristicPopulation;
 pub use crate::population::RosomaxaConfig;
 pub use crate::population::SelectionPhase;
@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/max_stagnation_test.rs"]
+mod max_stagnation_test;
+
+use super::*;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A termination criteria which detects structural stagnation: it terminates when population
+/// membership has not changed for the given amount of generations.
+///
+/// Population membership is represented by a set of solution fingerprints, see
+/// `HeuristicSolution::fingerprint`.
+pub struct MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    limit: usize,
+    key: K,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+/// Keeps fingerprints of the last seen population and amount of generations without changes.
+type StagnationState = (Vec<u64>, usize);
+
+impl<C, O, S, K> MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    /// Creates a new instance of `MaxStagnation`.
+    pub fn new(limit: usize, key: K) -> Self {
+        assert_ne!(limit, 0);
+        Self { limit, key, _marker: (Default::default(), Default::default(), Default::default()) }
+    }
+
+    fn update_and_check(&self, heuristic_ctx: &mut C, fingerprints: Vec<u64>) -> bool {
+        let (last_fingerprints, generations) =
+            heuristic_ctx.state_mut::<StagnationState, _>(self.key.clone(), StagnationState::default);
+
+        if !last_fingerprints.is_empty() && *last_fingerprints == fingerprints {
+            *generations += 1;
+        } else {
+            *last_fingerprints = fingerprints;
+            *generations = 0;
+        }
+
+        *generations >= self.limit
+    }
+}
+
+impl<C, O, S, K> Termination for MaxStagnation<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        let fingerprints = get_fingerprints(heuristic_ctx);
+
+        self.update_and_check(heuristic_ctx, fingerprints)
+    }
+
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
+        let generations =
+            heuristic_ctx.get_state::<StagnationState>(&self.key).map(|(_, generations)| *generations).unwrap_or(0);
+
+        (generations as f64 / self.limit as f64).min(1.)
+    }
+}
+
+/// Returns sorted fingerprints of all solutions in population.
+fn get_fingerprints<C, O, S>(heuristic_ctx: &C) -> Vec<u64>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let mut fingerprints = heuristic_ctx.ranked().map(|(solution, _)| solution.fingerprint()).collect::<Vec<_>>();
+
+    fingerprints.sort_unstable();
+
+    fingerprints
+}
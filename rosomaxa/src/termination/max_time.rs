@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/max_time_test.rs"]
+mod max_time_test;
+
 use super::*;
 use crate::utils::Timer;
 use std::marker::PhantomData;
@@ -28,6 +32,12 @@ where
             _marker: (Default::default(), Default::default(), Default::default()),
         }
     }
+
+    /// Creates a new instance of `MaxTime` which terminates earlier by reserve time, so there is
+    /// enough time left to process the best known solution before the actual deadline.
+    pub fn new_with_reserve(limit_in_secs: f64, reserve_in_secs: f64) -> Self {
+        Self::new((limit_in_secs - reserve_in_secs).max(0.))
+    }
 }
 
 impl<C, O, S> Termination for MaxTime<C, O, S>
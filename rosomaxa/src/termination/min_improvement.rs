@@ -0,0 +1,81 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/min_improvement_test.rs"]
+mod min_improvement_test;
+
+use super::*;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A termination criteria which detects convergence: it terminates when fitness of the best known
+/// solution has not been improved by more than `epsilon` during the given amount of generations.
+///
+/// Fitness is considered as improved when at least one of its values is decreased by more than `epsilon`.
+pub struct MinImprovement<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    generations: usize,
+    epsilon: f64,
+    key: K,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+/// Keeps best fitness of the last generations.
+type ImprovementState = VecDeque<Vec<f64>>;
+
+impl<C, O, S, K> MinImprovement<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    /// Creates a new instance of `MinImprovement`.
+    pub fn new(generations: usize, epsilon: f64, key: K) -> Self {
+        assert_ne!(generations, 0);
+        assert!(epsilon >= 0.);
+        Self { generations, epsilon, key, _marker: (Default::default(), Default::default(), Default::default()) }
+    }
+
+    fn update_and_check(&self, heuristic_ctx: &mut C, fitness: Vec<f64>) -> bool {
+        let history = heuristic_ctx.state_mut::<ImprovementState, _>(self.key.clone(), ImprovementState::default);
+
+        history.push_back(fitness);
+        if history.len() > self.generations + 1 {
+            history.pop_front();
+        }
+
+        match (history.len() > self.generations, history.front(), history.back()) {
+            (true, Some(oldest), Some(latest)) => {
+                !oldest.iter().zip(latest.iter()).any(|(oldest, latest)| oldest - latest > self.epsilon)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C, O, S, K> Termination for MinImprovement<C, O, S, K>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+    K: Hash + Eq + Clone,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        let fitness =
+            heuristic_ctx.ranked().next().map(|(best, _)| heuristic_ctx.objective().fitness(best).collect::<Vec<_>>());
+
+        fitness.map_or(false, |fitness| self.update_and_check(heuristic_ctx, fitness))
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
@@ -18,6 +18,12 @@ pub trait Termination {
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64;
 }
 
+mod max_stagnation;
+pub use self::max_stagnation::MaxStagnation;
+
+mod min_improvement;
+pub use self::min_improvement::MinImprovement;
+
 mod min_variation;
 pub use self::min_variation::MinVariation;
 
@@ -51,6 +51,15 @@ impl Environment {
     ) -> Self {
         Self { random, quota, parallelism, logger, is_experimental }
     }
+
+    /// Replaces random generator with the one created from the given seed, so evolution produces the same
+    /// results for identical inputs. Please note, that data parallelism (e.g. multiple thread pools or
+    /// parallel search over population) can still introduce non-determinism as the shared generator is
+    /// accessed in non-deterministic order. Use a single thread to get fully reproducible runs. Also, heuristics
+    /// which take into account elapsed time (e.g. time quota or dynamic selective) are not deterministic.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { random: Arc::new(DefaultRandom::new_with_seed(seed)), ..self }
+    }
 }
 
 impl Default for Environment {
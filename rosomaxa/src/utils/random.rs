@@ -5,6 +5,7 @@ mod random_test;
 use rand::prelude::*;
 use rand::Error;
 use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -27,11 +28,27 @@ pub trait Random {
 
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
+
+    /// Returns a new random generator seeded with a sub-seed derived from the seed of this one and
+    /// the given index, so it produces the same sequence of values regardless of how this generator
+    /// was used before. Returns `None` when generator is not seeded.
+    fn derive(&self, _index: u64) -> Option<Arc<dyn Random + Send + Sync>> {
+        None
+    }
 }
 
 /// A default random implementation.
 #[derive(Default)]
-pub struct DefaultRandom {}
+pub struct DefaultRandom {
+    seeded: Option<(u64, Arc<Mutex<SmallRng>>)>,
+}
+
+impl DefaultRandom {
+    /// Creates an instance of `DefaultRandom` which produces the same sequence of values for the same seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { seeded: Some((seed, Arc::new(Mutex::new(SmallRng::seed_from_u64(seed))))) }
+    }
+}
 
 impl Random for DefaultRandom {
     fn uniform_int(&self, min: i32, max: i32) -> i32 {
@@ -71,7 +88,18 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen::new_randomized()
+        match &self.seeded {
+            Some((_, rng)) => RandomGen { source: RandomSource::Seeded(rng.clone()) },
+            None => RandomGen::new_randomized(),
+        }
+    }
+
+    fn derive(&self, index: u64) -> Option<Arc<dyn Random + Send + Sync>> {
+        self.seeded.as_ref().map::<Arc<dyn Random + Send + Sync>, _>(|(seed, _)| {
+            // NOTE mix seed and index to avoid correlation between sequences of neighbour indices
+            let sub_seed = SmallRng::seed_from_u64(seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15)).next_u64();
+            Arc::new(DefaultRandom::new_with_seed(sub_seed))
+        })
     }
 }
 
@@ -86,53 +114,52 @@ thread_local! {
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    source: RandomSource,
+}
+
+#[derive(Clone, Debug)]
+enum RandomSource {
+    Randomized,
+    Repeatable,
+    Seeded(Arc<Mutex<SmallRng>>),
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { source: RandomSource::Repeatable }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { source: RandomSource::Randomized }
+    }
+
+    fn with_rng<T>(&mut self, func: impl FnOnce(&mut SmallRng) -> T) -> T {
+        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
+        match &self.source {
+            RandomSource::Randomized => RANDOMIZED_RNG.with(|t| func(&mut t.borrow_mut())),
+            RandomSource::Repeatable => REPEATABLE_RNG.with(|t| func(&mut t.borrow_mut())),
+            RandomSource::Seeded(rng) => func(&mut rng.lock().expect("cannot lock seeded rng")),
+        }
     }
 }
 
 impl RngCore for RandomGen {
     fn next_u32(&mut self) -> u32 {
-        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
-        }
+        self.with_rng(|rng| rng.next_u32())
     }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
@@ -11,7 +11,7 @@ enum GridState {
     Terminal,
 }
 
-#[derive(PartialEq, Eq, Hash, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
 enum GridAction {
     Move { dx: i32, dy: i32 },
 }
@@ -188,3 +188,147 @@ fn can_run_grid_episodes_impl(
         assert_eq!((dx, dy), (e_dx, e_dy));
     }
 }
+
+#[test]
+fn can_import_expert_trajectories() {
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let expert_action = GridAction::Move { dx: 0, dy: 1 };
+    let agent = create_agent(state.clone(), Arc::new(RwLock::new(vec![])));
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.2, 0.01)), Box::<Greedy>::default());
+    let trajectories = vec![
+        vec![(state.clone(), expert_action.clone(), 4.), (GridState::OnGrid { x: 0, y: 1 }, expert_action.clone(), 4.)],
+        vec![(state.clone(), expert_action.clone(), 6.)],
+    ];
+
+    simulator.import_trajectories(&agent, trajectories, |_, values| values.iter().sum::<f64>() / values.len() as f64);
+
+    assert_eq!(simulator.get_state_estimates().len(), 2);
+    assert_eq!(simulator.get_state_estimates().get(&state).unwrap().data().len(), 4);
+    assert_eq!(simulator.get_optimal_policy(&state), Some((expert_action.clone(), 5.)));
+
+    let actions_taken = run_simulator(&mut simulator, 1, 1, false, |counter| create_agent(state.clone(), counter));
+    assert_eq!(actions_taken[0][0].first(), Some(&expert_action));
+}
+
+struct RecordingPolicy {
+    explorations: Arc<RwLock<Vec<f64>>>,
+}
+
+impl PolicyStrategy<GridState> for RecordingPolicy {
+    fn select(&self, estimates: &ActionEstimates<GridState>) -> Option<GridAction> {
+        Greedy.select(estimates)
+    }
+
+    fn select_with_exploration(&self, estimates: &ActionEstimates<GridState>, exploration: f64) -> Option<GridAction> {
+        self.explorations.write().unwrap().push(exploration);
+        self.select(estimates)
+    }
+}
+
+#[test]
+fn can_pass_scheduled_exploration_to_policy() {
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let explorations = Arc::new(RwLock::new(vec![]));
+    let policy_strategy = Box::new(RecordingPolicy { explorations: explorations.clone() });
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.2, 0.01)), policy_strategy);
+    simulator.set_exploration_schedule(Box::new(|episode| 1. / (episode + 1) as f64));
+
+    run_simulator(&mut simulator, 4, 2, false, |counter| create_agent(state.clone(), counter));
+
+    let mut explorations = explorations.read().unwrap().clone();
+    explorations.dedup();
+    assert_eq!(explorations, vec![1., 1. / 2., 1. / 3., 1. / 4.]);
+    assert_eq!(simulator.get_episode(), 4);
+}
+
+#[test]
+fn can_decay_exploration_with_schedule() {
+    let repeat_count = 1000;
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let policy_strategy = Box::new(EpsilonGreedy::new(1., create_test_random()));
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.2, 0.01)), policy_strategy);
+    simulator
+        .set_exploration_schedule(Box::new(move |episode| (1. - 2. * episode as f64 / repeat_count as f64).max(0.)));
+
+    let actions_taken =
+        run_simulator(&mut simulator, repeat_count, 1, false, |counter| create_agent(state.clone(), counter));
+
+    // NOTE exploration is off in the second half, so agent follows the learned optimal path
+    actions_taken.iter().rev().take(100).for_each(|agents_actions| {
+        assert_eq!(agents_actions[0].len(), 6);
+    });
+    let early_actions = actions_taken.iter().take(100).map(|agents_actions| agents_actions[0].len()).sum::<usize>();
+    assert!(early_actions > 600);
+}
+
+parameterized_test! {can_merge_estimates_with_strategy, (merge_strategy, expected), {
+    can_merge_estimates_with_strategy_impl(merge_strategy, expected);
+}}
+
+can_merge_estimates_with_strategy! {
+    case01_reduce: (MergeStrategy::Reduce, (5., 2., 3.)),
+    case02_visit_weighted: (MergeStrategy::VisitWeighted, (8., 2., 3.)),
+    case03_blend: (MergeStrategy::Blend { rate: 0.5 }, (4.5, 2., 3.)),
+}
+
+fn can_merge_estimates_with_strategy_impl(merge_strategy: MergeStrategy, expected: (f64, f64, f64)) {
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let new_state = GridState::OnGrid { x: 1, y: 0 };
+    let (visited, unvisited) = (GridAction::Move { dx: 1, dy: 0 }, GridAction::Move { dx: 0, dy: 1 });
+    let create_estimates = |estimates: Vec<(&GridState, f64, f64)>| {
+        estimates
+            .into_iter()
+            .map(|(state, visited_value, unvisited_value)| {
+                let estimates = vec![(visited.clone(), visited_value), (unvisited.clone(), unvisited_value)];
+                (state.clone(), ActionEstimates::from(estimates.into_iter().collect::<HashMap<_, _>>()))
+            })
+            .collect::<StateEstimates<_>>()
+    };
+    let create_visits = |visits: Vec<(&GridState, usize)>| {
+        visits
+            .into_iter()
+            .map(|(state, visits)| (state.clone(), vec![(visited.clone(), visits)].into_iter().collect()))
+            .collect::<VisitCounts<_>>()
+    };
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.2, 0.01)), Box::<Greedy>::default());
+    simulator.set_merge_strategy(merge_strategy);
+    simulator.q = create_estimates(vec![(&state, 1., 2.)]);
+    let qs = vec![
+        (create_estimates(vec![(&state, 9., 2.), (&new_state, 3., 3.)]), create_visits(vec![(&state, 3)])),
+        (create_estimates(vec![(&state, 1., 2.)]), create_visits(vec![])),
+        (create_estimates(vec![(&state, 5., 2.), (&new_state, 3., 3.)]), create_visits(vec![(&state, 1)])),
+    ];
+
+    simulator.merge_state_estimates(qs, |_, values| values.iter().sum::<f64>() / values.len() as f64);
+
+    let estimates = simulator.get_state_estimates();
+    assert_eq!(estimates.get(&state).unwrap().data().get(&visited).copied(), Some(expected.0));
+    assert_eq!(estimates.get(&state).unwrap().data().get(&unvisited).copied(), Some(expected.1));
+    assert_eq!(estimates.get(&new_state).unwrap().data().get(&unvisited).copied(), Some(expected.2));
+}
+
+#[test]
+fn can_get_greedy_policy() {
+    let (right, up, left) =
+        (GridAction::Move { dx: 1, dy: 0 }, GridAction::Move { dx: 0, dy: 1 }, GridAction::Move { dx: -1, dy: 0 });
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.2, 0.01)), Box::<Greedy>::default());
+    let create_estimates = |estimates: Vec<(&GridAction, f64)>| {
+        let mut estimates = ActionEstimates::from(
+            estimates.into_iter().map(|(action, value)| (action.clone(), value)).collect::<HashMap<_, _>>(),
+        );
+        estimates.recalculate_min_max();
+        estimates
+    };
+    simulator.set_action_estimates(GridState::OnGrid { x: 0, y: 0 }, create_estimates(vec![(&right, 1.), (&up, 3.)]));
+    simulator.set_action_estimates(GridState::OnGrid { x: 0, y: 1 }, create_estimates(vec![(&right, -2.), (&up, -1.)]));
+    simulator.set_action_estimates(GridState::OnGrid { x: 1, y: 1 }, create_estimates(vec![(&right, 2.), (&left, 2.)]));
+    simulator.set_action_estimates(GridState::Terminal, create_estimates(vec![]));
+
+    let policy = simulator.get_greedy_policy();
+
+    assert_eq!(policy.len(), 3);
+    assert_eq!(policy.get(&GridState::OnGrid { x: 0, y: 0 }), Some(&up));
+    assert_eq!(policy.get(&GridState::OnGrid { x: 0, y: 1 }), Some(&up));
+    assert_eq!(policy.get(&GridState::OnGrid { x: 1, y: 1 }), Some(&left));
+    assert_eq!(policy.get(&GridState::Terminal), None);
+}
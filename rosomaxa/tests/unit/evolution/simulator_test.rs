@@ -0,0 +1,45 @@
+use super::*;
+use crate::evolution::InitialOperator;
+use crate::example::*;
+use crate::helpers::example::*;
+use crate::utils::DefaultRandom;
+
+struct RandomInitialOperator {
+    id: f64,
+}
+
+impl InitialOperator for RandomInitialOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn create(&self, _: &Self::Context, random: Arc<dyn Random + Send + Sync>) -> Self::Solution {
+        let data = vec![self.id, random.uniform_real(-1., 1.), random.uniform_real(-1., 1.)];
+        VectorSolution::new(data, create_example_objective())
+    }
+}
+
+fn create_initial_data(seed: u64, indices: Vec<usize>) -> Vec<(usize, Vec<f64>)> {
+    let operators: InitialOperators<VectorContext, VectorObjective, VectorSolution> =
+        vec![(Box::new(RandomInitialOperator { id: 0. }), 1), (Box::new(RandomInitialOperator { id: 1. }), 1)];
+    let heuristic_ctx = create_default_heuristic_context();
+    let random: Arc<dyn Random + Send + Sync> = Arc::new(DefaultRandom::new_with_seed(seed));
+
+    let mut data = indices
+        .into_iter()
+        .map(|idx| (idx, create_initial_solution(&operators, &[1, 1], &heuristic_ctx, &random, idx).data))
+        .collect::<Vec<_>>();
+    data.sort_by_key(|(idx, _)| *idx);
+
+    data
+}
+
+#[test]
+fn can_create_the_same_initial_solutions_with_the_same_seed() {
+    let expected = create_initial_data(42, (0..8).collect());
+
+    assert_eq!(create_initial_data(42, (0..8).collect()), expected);
+    assert_eq!(create_initial_data(42, (0..8).rev().collect()), expected);
+    assert_eq!(std::thread::spawn(|| create_initial_data(42, vec![5, 1, 7, 0, 3, 2, 6, 4])).join().unwrap(), expected);
+    assert_ne!(create_initial_data(43, (0..8).collect()), expected);
+}
@@ -1,10 +1,11 @@
 use super::*;
 use crate::example::*;
 use crate::helpers::example::create_example_objective;
+use crate::population::Elitism;
 use crate::utils::compare_floats;
 use crate::{get_default_population, get_default_selection_size};
 use std::cmp::Ordering;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 fn compare_statistic(statistics: &HeuristicStatistics, expected: (usize, f64, f64)) {
     assert_eq!(statistics.generation, expected.0);
@@ -41,3 +42,75 @@ fn can_update_statistic() {
     telemetry.on_generation(objective, population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_observe_generations() {
+    let generations = 5;
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let mut population = get_default_population(objective.clone(), environment, selection_size);
+    population.add(VectorSolution::new(vec![1., 1.], objective.clone()));
+    let snapshots = Arc::new(RwLock::new(vec![]));
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.set_generation_observer(Box::new({
+        let snapshots = snapshots.clone();
+        move |generation| snapshots.write().unwrap().push((generation.number, generation.population.individuals.len()))
+    }));
+
+    (0..generations)
+        .for_each(|_| telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), false));
+
+    assert_eq!(snapshots.read().unwrap().clone(), (0..generations).map(|number| (number, 1)).collect::<Vec<_>>());
+    assert!(telemetry.take_metrics().is_none());
+}
+
+#[test]
+fn can_observe_tracked_generations() {
+    let generations = 5;
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let mut population = get_default_population(objective.clone(), environment, selection_size);
+    population.add(VectorSolution::new(vec![1., 1.], objective.clone()));
+    let snapshots = Arc::new(RwLock::new(vec![]));
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 2 });
+    telemetry.set_generation_observer(Box::new({
+        let snapshots = snapshots.clone();
+        move |generation| snapshots.write().unwrap().push(generation.number)
+    }));
+
+    (0..generations)
+        .for_each(|_| telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), false));
+
+    assert_eq!(snapshots.read().unwrap().clone(), (0..generations).collect::<Vec<_>>());
+    let metrics = telemetry.take_metrics().expect("no metrics");
+    assert_eq!(metrics.evolution.iter().map(|generation| generation.number).collect::<Vec<_>>(), vec![0, 2, 4]);
+}
+
+#[test]
+fn can_track_fitness_spread() {
+    let objective = create_example_objective();
+    let mut population = Elitism::new(objective.clone(), Environment::default().random, 4, 1);
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1 });
+    let create_solution = |data: Vec<f64>| VectorSolution::new(data, objective.clone());
+    population.add_all(
+        vec![vec![0., 0.], vec![2., 2.], vec![-1., -1.], vec![-2., -2.]].into_iter().map(create_solution).collect(),
+    );
+
+    // NOTE each new solution is better than the worst one, so population converges around the same fitness
+    let spread = (0..4)
+        .map(|idx| {
+            if idx > 0 {
+                population.add(create_solution(vec![0.001 * idx as f64, 0.]));
+            }
+            telemetry.on_generation(objective.as_ref(), &population, 0., Timer::start(), true);
+
+            telemetry.metrics.evolution.last().and_then(|generation| generation.fitness_spread).expect("no spread")
+        })
+        .collect::<Vec<_>>();
+
+    assert!(spread[0] > 0.5);
+    assert!(spread.windows(2).all(|pair| pair[0] > pair[1]));
+    assert!(spread[3] < 0.01);
+}
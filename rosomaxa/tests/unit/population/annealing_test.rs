@@ -0,0 +1,84 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::create_example_objective;
+
+fn create_annealing(seed: u64) -> (Arc<VectorObjective>, Annealing<VectorObjective, VectorSolution>) {
+    let objective = create_example_objective();
+    let config = AnnealingConfig { initial_temperature: 0.01, cooling_rate: 0.95, seed: Some(seed), selection_size: 1 };
+    let population = Annealing::new(objective.clone(), config).unwrap();
+
+    (objective, population)
+}
+
+fn get_current_fitness(population: &Annealing<VectorObjective, VectorSolution>) -> f64 {
+    population.select().next().unwrap().fitness()
+}
+
+fn get_best_fitness(population: &Annealing<VectorObjective, VectorSolution>) -> f64 {
+    population.ranked().next().unwrap().0.fitness()
+}
+
+#[test]
+fn can_keep_best_solution_and_accept_better_one() {
+    let (objective, mut population) = create_annealing(0);
+
+    assert!(population.add(VectorSolution::new(vec![-1., -1.], objective.clone())));
+    assert!(population.add(VectorSolution::new(vec![2., 2.], objective)));
+
+    assert_eq!(population.size(), 1);
+    assert_eq!(get_best_fitness(&population), 401.);
+    assert_eq!(get_current_fitness(&population), 401.);
+}
+
+parameterized_test! {can_accept_worse_solutions_depending_on_temperature, (generation, expected_range), {
+    can_accept_worse_solutions_depending_on_temperature_impl(generation, expected_range);
+}}
+
+can_accept_worse_solutions_depending_on_temperature! {
+    case01_early: (0, 30..70),
+    case02_late: (100, 0..1),
+}
+
+fn can_accept_worse_solutions_depending_on_temperature_impl(generation: usize, expected_range: std::ops::Range<usize>) {
+    let accepted = (0..100)
+        .filter(|seed| {
+            let (objective, mut population) = create_annealing(*seed);
+            population.add(VectorSolution::new(vec![2., 2.], objective.clone()));
+            population.on_generation(&HeuristicStatistics { generation, ..HeuristicStatistics::default() });
+
+            let is_best_known = population.add(VectorSolution::new(vec![-1., -1.], objective));
+
+            assert!(!is_best_known);
+            assert_eq!(get_best_fitness(&population), 401.);
+
+            get_current_fitness(&population) == 404.
+        })
+        .count();
+
+    assert!(expected_range.contains(&accepted), "accepted: {accepted}");
+}
+
+parameterized_test! {can_validate_config, (initial_temperature, cooling_rate, is_ok), {
+    let config = AnnealingConfig { initial_temperature, cooling_rate, seed: None, selection_size: 1 };
+
+    let result = Annealing::<VectorObjective, VectorSolution>::new(create_example_objective(), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}}
+
+can_validate_config! {
+    case01_valid: (0.1, 0.9, true),
+    case02_zero_temperature: (0., 0.9, false),
+    case03_zero_cooling: (0.1, 0., false),
+    case04_no_cooling: (0.1, 1., false),
+}
+
+#[test]
+fn can_format_population() {
+    let (objective, mut population) = create_annealing(0);
+    population.add(VectorSolution::new(vec![-1., -1.], objective));
+
+    let formatted = format!("{population}");
+
+    assert_eq!(formatted, "[404.0000000],[404.0000000],t=0.0100000");
+}
@@ -117,3 +117,91 @@ fn can_handle_empty() {
 
     assert!(population.select().next().is_none());
 }
+
+struct BiObjective;
+
+struct BiSolution {
+    data: Vec<f64>,
+    order: DominanceOrder,
+}
+
+impl MultiObjective for BiObjective {
+    type Solution = BiSolution;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        match (compare_floats(a.data[0], b.data[0]), compare_floats(a.data[1], b.data[1])) {
+            (Ordering::Less, Ordering::Greater) | (Ordering::Greater, Ordering::Less) => Ordering::Equal,
+            (Ordering::Equal, order) | (order, _) => order,
+        }
+    }
+
+    fn fitness<'a>(&'a self, solution: &'a Self::Solution) -> Box<dyn Iterator<Item = f64> + 'a> {
+        Box::new(solution.data.iter().cloned())
+    }
+
+    fn get_order(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<Ordering, String> {
+        Ok(compare_floats(a.data[idx], b.data[idx]))
+    }
+
+    fn get_distance(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<f64, String> {
+        Ok(a.data[idx] - b.data[idx])
+    }
+
+    fn size(&self) -> usize {
+        2
+    }
+}
+
+impl HeuristicObjective for BiObjective {}
+
+impl Shuffled for BiObjective {
+    fn get_shuffled(&self, _: &(dyn Random + Send + Sync)) -> Self {
+        Self
+    }
+}
+
+impl HeuristicSolution for BiSolution {
+    fn fitness<'a>(&'a self) -> Box<dyn Iterator<Item = f64> + 'a> {
+        Box::new(self.data.iter().cloned())
+    }
+
+    fn deep_copy(&self) -> Self {
+        Self { data: self.data.clone(), order: self.order.clone() }
+    }
+}
+
+impl DominanceOrdered for BiSolution {
+    fn get_order(&self) -> &DominanceOrder {
+        &self.order
+    }
+
+    fn set_order(&mut self, order: DominanceOrder) {
+        self.order = order
+    }
+}
+
+parameterized_test! {can_trim_population_using_strategy, (trimming, expected_spread), {
+    can_trim_population_using_strategy_impl(trimming, expected_spread);
+}}
+
+can_trim_population_using_strategy! {
+    case01_dominance: (TrimmingStrategy::Dominance, 3.),
+    case02_dominance_with_diversity: (TrimmingStrategy::DominanceWithDiversity { diversity_ratio: 0.5 }, 10.),
+    case03_crowding_distance: (TrimmingStrategy::CrowdingDistance, 10.),
+}
+
+fn can_trim_population_using_strategy_impl(trimming: TrimmingStrategy, expected_spread: f64) {
+    let mut population =
+        Elitism::new(Arc::new(BiObjective), Environment::default().random, 4, 1).with_trimming_strategy(trimming);
+    // NOTE all solutions are on the same pareto front
+    let solutions = (0..=10)
+        .map(|idx| BiSolution { data: vec![idx as f64, 10. - idx as f64], order: DominanceOrder::default() })
+        .collect();
+
+    population.add_all(solutions);
+
+    let values = population.all().map(|solution| solution.data[0]).collect::<Vec<_>>();
+    let spread = values.iter().cloned().fold(f64::MIN, f64::max) - values.iter().cloned().fold(f64::MAX, f64::min);
+    assert_eq!(population.size(), 4);
+    assert_eq!(spread, expected_spread);
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::example::{VectorContext, VectorSolution};
+use crate::helpers::example::*;
+use crate::Timer;
+
+parameterized_test! {can_detect_termination, (limit, change_at, expected), {
+    can_detect_termination_impl(limit, change_at, expected);
+}}
+
+can_detect_termination! {
+    case_01_frozen: (3, None, vec![false, false, false, true, true]),
+    case_02_changed: (3, Some(2), vec![false, false, false, false, false]),
+    case_03_changed_early: (2, Some(1), vec![false, false, false, true, true]),
+}
+
+fn can_detect_termination_impl(limit: usize, change_at: Option<usize>, expected: Vec<bool>) {
+    let mut context = create_heuristic_context_with_solutions(vec![vec![2., 2.], vec![3., 3.]]);
+    let termination = MaxStagnation::<_, _, _, _>::new(limit, 0);
+
+    let result = (0..expected.len())
+        .map(|generation| {
+            let offspring = if change_at == Some(generation) {
+                vec![VectorSolution::new(vec![1., 1.], create_example_objective())]
+            } else {
+                vec![]
+            };
+            context.on_generation(offspring, 0.1, Timer::start());
+
+            termination.is_termination(&mut context)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_estimate_termination() {
+    let mut context = create_heuristic_context_with_solutions(vec![vec![2., 2.]]);
+    let termination = MaxStagnation::<_, _, _, _>::new(4, 0);
+
+    assert_eq!(termination.estimate(&context), 0.);
+    (0..3).for_each(|_| {
+        termination.is_termination(&mut context);
+    });
+
+    assert_eq!(termination.estimate(&context), 0.5);
+}
+
+#[test]
+fn can_distinguish_solutions_with_same_fitness() {
+    // NOTE both [0, 0] and [2, 4] have the same fitness value of rosenbrock function
+    let context_a = create_heuristic_context_with_solutions(vec![vec![0., 0.]]);
+    let context_b = create_heuristic_context_with_solutions(vec![vec![2., 4.]]);
+    let get_fitness =
+        |context: &VectorContext| context.ranked().flat_map(|(s, _)| HeuristicSolution::fitness(s)).collect::<Vec<_>>();
+    assert_eq!(get_fitness(&context_a), get_fitness(&context_b));
+
+    assert_ne!(get_fingerprints(&context_a), get_fingerprints(&context_b));
+}
@@ -0,0 +1,26 @@
+use super::*;
+use crate::helpers::example::*;
+use std::thread::sleep;
+use std::time::Duration;
+
+#[test]
+fn can_terminate_earlier_with_reserve() {
+    let mut context = create_default_heuristic_context();
+    let termination = MaxTime::<_, _, _>::new_with_reserve(0.5, 0.3);
+
+    assert!(!termination.is_termination(&mut context));
+    sleep(Duration::from_millis(250));
+
+    assert!(termination.is_termination(&mut context));
+    assert_eq!(termination.estimate(&context), 1.);
+}
+
+#[test]
+fn can_use_zero_limit_when_reserve_is_bigger() {
+    let mut context = create_default_heuristic_context();
+    let termination = MaxTime::<_, _, _>::new_with_reserve(0.1, 0.2);
+
+    sleep(Duration::from_millis(1));
+
+    assert!(termination.is_termination(&mut context));
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::example::VectorSolution;
+use crate::helpers::example::*;
+use crate::Timer;
+
+parameterized_test! {can_detect_termination, (generations, epsilon, improvements, expected), {
+    can_detect_termination_impl(generations, epsilon, improvements, expected);
+}}
+
+can_detect_termination! {
+    case_01_no_improvement: (3, 0., vec![], vec![false, false, false, true, true]),
+    case_02_improvement: (3, 0., vec![(2, vec![1., 1.])], vec![false, false, false, false, false]),
+    case_03_improvement_early: (2, 0., vec![(1, vec![1., 1.])], vec![false, false, false, true, true]),
+    case_04_small_improvement: (3, 10., vec![(2, vec![1.999, 1.999])], vec![false, false, false, true, true]),
+    case_05_big_improvement: (3, 10., vec![(2, vec![1., 1.])], vec![false, false, false, false, false]),
+}
+
+fn can_detect_termination_impl(
+    generations: usize,
+    epsilon: f64,
+    improvements: Vec<(usize, Vec<f64>)>,
+    expected: Vec<bool>,
+) {
+    let mut context = create_heuristic_context_with_solutions(vec![vec![2., 2.], vec![3., 3.]]);
+    let termination = MinImprovement::<_, _, _, _>::new(generations, epsilon, 0);
+
+    let result = (0..expected.len())
+        .map(|generation| {
+            let offspring = improvements
+                .iter()
+                .filter(|(at, _)| *at == generation)
+                .map(|(_, data)| VectorSolution::new(data.clone(), create_example_objective()))
+                .collect();
+            context.on_generation(offspring, 0.1, Timer::start());
+
+            termination.is_termination(&mut context)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
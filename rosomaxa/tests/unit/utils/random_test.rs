@@ -20,3 +20,25 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+fn get_sequence(random: &(dyn Random + Send + Sync)) -> Vec<i32> {
+    (0..10).map(|_| random.uniform_int(0, 1000)).collect()
+}
+
+#[test]
+fn can_repeat_sequence_with_the_same_seed() {
+    assert_eq!(get_sequence(&DefaultRandom::new_with_seed(42)), get_sequence(&DefaultRandom::new_with_seed(42)));
+    assert_ne!(get_sequence(&DefaultRandom::new_with_seed(42)), get_sequence(&DefaultRandom::new_with_seed(43)));
+}
+
+#[test]
+fn can_derive_random_independently_of_usage() {
+    let random = DefaultRandom::new_with_seed(42);
+    let expected = get_sequence(random.derive(1).unwrap().as_ref());
+
+    get_sequence(&random);
+
+    assert_eq!(get_sequence(random.derive(1).unwrap().as_ref()), expected);
+    assert_ne!(get_sequence(random.derive(2).unwrap().as_ref()), expected);
+    assert!(DefaultRandom::default().derive(1).is_none());
+}
@@ -530,11 +530,8 @@ fn get_async_evolution(
 ) -> Result<TargetEvolutionStrategy, String> {
     use vrp_core::rosomaxa::evolution::strategies::{AsyncIterative, AsyncParams};
 
-    let selection_size = get_default_selection_size(environment.as_ref());
-    let actors_size = (selection_size * 2).max(2);
-
     Ok(Box::new(AsyncIterative::new(
-        AsyncParams { actors_size, channel_buffer: 4, selection_size },
+        AsyncParams::new_with_environment(environment.as_ref()),
         1,
         problem.goal.clone(),
         Box::new({
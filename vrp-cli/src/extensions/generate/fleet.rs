@@ -32,12 +32,17 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 shifts: get_random_item(shifts.as_slice(), &rnd).expect("cannot find any shifts").clone(),
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
+                skill_validity: None,
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                lifo: None,
+                count: None,
+                temperature: None,
+                service_scale: None,
             }
         })
         .collect();
 
-    Fleet { vehicles, profiles, resources: None }
+    Fleet { vehicles, profiles, resources: None, gates: None }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -37,6 +37,7 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            blackouts: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -45,6 +46,7 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
                     order: task.order,
+                    target_time: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -68,11 +70,20 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                customer_id: job_proto.customer_id.clone(),
+                max_detour_time: job_proto.max_detour_time,
+                max_wait_time: job_proto.max_wait_time,
+                area: job_proto.area.clone(),
+                visit: None,
+                temperature: None,
+                tier: None,
+                cleaning: None,
+                priority: None,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, cordon: None, strict_groups: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -70,9 +70,11 @@ mod actual {
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                blackouts: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
+            target_time: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -101,6 +103,15 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                customer_id: None,
+                max_detour_time: None,
+                max_wait_time: None,
+                area: None,
+                visit: None,
+                temperature: None,
+                tier: None,
+                cleaning: None,
+                priority: None,
             })
             .collect();
 
@@ -117,7 +128,13 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        distance_tiers: None,
+                        stop: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
@@ -128,10 +145,18 @@ mod actual {
                         dispatch: None,
                         breaks: None,
                         reloads: None,
+                        job_types: None,
+                        overtime_penalty: None,
+                        break_separation: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
+                    skill_validity: None,
                     limits: None,
+                    lifo: None,
+                    count: None,
+                    temperature: None,
+                    service_scale: None,
                 }
             })
             .collect();
@@ -158,11 +183,15 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, cordon: None, strict_groups: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, min_travel: None })
+                    .collect(),
                 resources: None,
+                gates: None,
             },
             objectives: None,
         })
@@ -90,6 +90,21 @@ pub enum PopulationType {
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<f64>,
     },
+
+    /// A population which accepts worse individuals with probability decaying over time
+    /// (simulated annealing acceptance criterion).
+    #[serde(rename(deserialize = "annealing"))]
+    #[serde(rename_all = "camelCase")]
+    Annealing {
+        /// Selection size. Default is number of cpus.
+        selection_size: Option<usize>,
+        /// Initial temperature. Default is 0.01.
+        initial_temperature: Option<f64>,
+        /// A cooling rate applied each generation. Default is 0.995.
+        cooling_rate: Option<f64>,
+        /// A seed used by acceptance criterion. Randomized when omitted.
+        seed: Option<u64>,
+    },
 }
 
 /// An initial solution configuration.
@@ -323,6 +338,9 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "k-opt"))]
+    KOpt { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -336,6 +354,8 @@ pub struct NoiseConfig {
 #[serde(rename_all = "camelCase")]
 pub struct TerminationConfig {
     pub max_time: Option<usize>,
+    /// A time in seconds reserved before max time to return the best known solution.
+    pub max_time_reserve: Option<usize>,
     pub max_generations: Option<usize>,
     pub variation: Option<VariationConfig>,
 }
@@ -390,6 +410,10 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies a seed of random generator to make runs reproducible. Please note, that parallelism
+    /// can still introduce non-determinism. Randomized when omitted.
+    pub seed: Option<u64>,
 }
 
 /// Data parallelism configuration.
@@ -513,6 +537,21 @@ fn configure_from_evolution(
 
                     Box::new(RosomaxaPopulation::new(problem.goal.clone(), environment.clone(), config)?)
                 }
+                PopulationType::Annealing { selection_size, initial_temperature, cooling_rate, seed } => {
+                    let mut config = AnnealingConfig::new_with_defaults(default_selection_size);
+                    if let Some(selection_size) = selection_size {
+                        config.selection_size = *selection_size;
+                    }
+                    if let Some(initial_temperature) = initial_temperature {
+                        config.initial_temperature = *initial_temperature;
+                    }
+                    if let Some(cooling_rate) = cooling_rate {
+                        config.cooling_rate = *cooling_rate;
+                    }
+                    config.seed = *seed;
+
+                    Box::new(AnnealingPopulation::new(problem.goal.clone(), config)?)
+                }
             };
 
             builder = builder.with_context(RefinementContext::new(problem, population, telemetry_mode, environment));
@@ -558,10 +597,14 @@ fn configure_from_termination(
     termination_config: &Option<TerminationConfig>,
 ) -> ProblemConfigBuilder {
     if let Some(config) = termination_config {
-        builder = builder.with_max_time(config.max_time).with_max_generations(config.max_generations).with_min_cv(
-            config.variation.as_ref().map(|v| (v.interval_type.clone(), v.value, v.cv, v.is_global)),
-            "min_cv".to_string(),
-        );
+        builder = builder
+            .with_max_time(config.max_time)
+            .with_max_time_reserve(config.max_time_reserve)
+            .with_max_generations(config.max_generations)
+            .with_min_cv(
+                config.variation.as_ref().map(|v| (v.interval_type.clone(), v.value, v.cv, v.is_global)),
+                "min_cv".to_string(),
+            );
     }
 
     builder
@@ -715,6 +758,7 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::KOpt { weight } => (Arc::new(ExchangeKOpt::default()), *weight),
         })
         .collect::<Vec<_>>();
 
@@ -786,6 +830,10 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if let Some(seed) = environment_config.as_ref().and_then(|c| c.seed) {
+        environment = environment.with_seed(seed);
+    }
+
     Arc::new(environment)
 }
 
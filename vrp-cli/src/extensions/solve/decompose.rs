@@ -0,0 +1,235 @@
+//! Provides the way to solve large problem by decomposing it into regions.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/decompose_test.rs"]
+mod decompose_test;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::create_default_config_builder;
+use vrp_pragmatic::format::problem::{Fleet, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{create_solution, Solution};
+use vrp_pragmatic::format::CoordIndex;
+
+/// Solves the problem by partitioning its jobs using their `area` property. Each partition is solved
+/// separately with time budget proportional to its size and results are merged into one solution.
+///
+/// Jobs without area form their own partition. Vehicles shared across regions are distributed
+/// between partitions, so each vehicle is used only in one of them. Jobs of a relation are moved to
+/// the partition of relation's vehicle, so relations are kept within one partition.
+pub fn solve_by_areas(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    environment: Arc<Environment>,
+    max_time: usize,
+) -> Result<Solution, String> {
+    let areas = get_areas(&problem);
+    let vehicle_areas = get_vehicle_areas(&problem, areas.len())?;
+    let relation_areas = get_relation_areas(&problem, &vehicle_areas);
+    let total_jobs = problem.plan.jobs.len().max(1);
+
+    areas
+        .iter()
+        .enumerate()
+        .map(|(area_idx, (area, _))| {
+            create_sub_problem(&problem, area.as_ref(), area_idx, &vehicle_areas, &relation_areas)
+        })
+        .filter(|sub_problem| !sub_problem.plan.jobs.is_empty())
+        .map(|sub_problem| {
+            let sub_matrices = matrices.as_ref().map(|matrices| create_sub_matrices(&problem, &sub_problem, matrices));
+            let max_time = (max_time * sub_problem.plan.jobs.len() / total_jobs).max(1);
+
+            solve_sub_problem(sub_problem, sub_matrices, environment.clone(), max_time)
+        })
+        .try_fold(None, |acc: Option<Solution>, solution| -> Result<_, String> {
+            let solution = solution?;
+            Ok(Some(match acc {
+                Some(acc) => merge_solutions(acc, solution),
+                None => solution,
+            }))
+        })?
+        .ok_or_else(|| "cannot decompose problem without jobs".to_string())
+}
+
+/// Returns areas with amount of their jobs sorted by the amount in descending order.
+fn get_areas(problem: &Problem) -> Vec<(Option<String>, usize)> {
+    let mut areas = problem
+        .plan
+        .jobs
+        .iter()
+        .fold(HashMap::<Option<String>, usize>::new(), |mut acc, job| {
+            *acc.entry(job.area.clone()).or_insert(0) += 1;
+            acc
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    areas.sort_by(|(a_area, a_size), (b_area, b_size)| b_size.cmp(a_size).then_with(|| a_area.cmp(b_area)));
+
+    areas
+}
+
+/// Assigns each vehicle id to one of areas in round-robin fashion.
+fn get_vehicle_areas(problem: &Problem, areas: usize) -> Result<HashMap<String, usize>, String> {
+    let vehicle_areas = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter())
+        .enumerate()
+        .map(|(idx, vehicle_id)| (vehicle_id.clone(), idx % areas.max(1)))
+        .collect::<HashMap<_, _>>();
+
+    if vehicle_areas.len() < areas {
+        return Err(format!(
+            "cannot decompose problem: amount of vehicles ({}) is less than amount of areas ({})",
+            vehicle_areas.len(),
+            areas
+        ));
+    }
+
+    Ok(vehicle_areas)
+}
+
+/// Assigns jobs used in relations to the area of relation's vehicle. When a job is used in several
+/// relations, the first one wins.
+fn get_relation_areas(problem: &Problem, vehicle_areas: &HashMap<String, usize>) -> HashMap<String, usize> {
+    let job_ids = problem.plan.jobs.iter().map(|job| &job.id).collect::<HashSet<_>>();
+
+    problem.plan.relations.iter().flat_map(|relations| relations.iter()).fold(HashMap::new(), |mut acc, relation| {
+        if let Some(area_idx) = vehicle_areas.get(&relation.vehicle_id) {
+            relation.jobs.iter().filter(|job_id| job_ids.contains(job_id)).for_each(|job_id| {
+                acc.entry(job_id.clone()).or_insert(*area_idx);
+            });
+        }
+        acc
+    })
+}
+
+fn create_sub_problem(
+    problem: &Problem,
+    area: Option<&String>,
+    area_idx: usize,
+    vehicle_areas: &HashMap<String, usize>,
+    relation_areas: &HashMap<String, usize>,
+) -> Problem {
+    let is_area_vehicle = |vehicle_id: &String| vehicle_areas.get(vehicle_id).map_or(false, |idx| *idx == area_idx);
+
+    let mut sub_problem = problem.clone();
+
+    sub_problem.plan.jobs.retain(|job| {
+        relation_areas.get(&job.id).map_or(job.area.as_ref() == area, |relation_area| *relation_area == area_idx)
+    });
+
+    // NOTE keep only plan jobs of the sub problem in relations, other ids are reserved ones (e.g. break)
+    let job_ids = problem.plan.jobs.iter().map(|job| &job.id).collect::<HashSet<_>>();
+    let sub_job_ids = sub_problem.plan.jobs.iter().map(|job| &job.id).collect::<HashSet<_>>();
+    sub_problem.plan.relations = problem.plan.relations.as_ref().map(|relations| {
+        relations
+            .iter()
+            .filter(|relation| is_area_vehicle(&relation.vehicle_id))
+            .filter_map(|relation| {
+                let mut relation = relation.clone();
+                relation.jobs.retain(|job_id| sub_job_ids.contains(job_id) || !job_ids.contains(job_id));
+
+                if relation.jobs.iter().any(|job_id| sub_job_ids.contains(job_id)) {
+                    Some(relation)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    sub_problem.fleet = Fleet {
+        vehicles: problem
+            .fleet
+            .vehicles
+            .iter()
+            .filter_map(|vehicle| {
+                let mut vehicle = vehicle.clone();
+                vehicle.vehicle_ids.retain(is_area_vehicle);
+
+                if vehicle.vehicle_ids.is_empty() {
+                    None
+                } else {
+                    Some(vehicle)
+                }
+            })
+            .collect(),
+        ..problem.fleet.clone()
+    };
+
+    sub_problem
+}
+
+/// Creates routing matrices for the sub problem. Matrices are kept as is when location indices are used.
+fn create_sub_matrices(problem: &Problem, sub_problem: &Problem, matrices: &[Matrix]) -> Vec<Matrix> {
+    let coord_index = CoordIndex::new(problem);
+    let sub_coord_index = CoordIndex::new(sub_problem);
+
+    if coord_index.get_used_types().1 {
+        return matrices.to_vec();
+    }
+
+    let (size, sub_size) = (coord_index.unique().len(), sub_coord_index.unique().len());
+    let indices = sub_coord_index
+        .unique()
+        .iter()
+        .map(|location| coord_index.get_by_loc(location).expect("sub problem location should be present"))
+        .collect::<Vec<_>>();
+
+    let map_values = |values: &[i64]| {
+        (0..sub_size * sub_size).map(|idx| values[indices[idx / sub_size] * size + indices[idx % sub_size]]).collect()
+    };
+
+    matrices
+        .iter()
+        .map(|matrix| Matrix {
+            profile: matrix.profile.clone(),
+            timestamp: matrix.timestamp.clone(),
+            travel_times: map_values(matrix.travel_times.as_slice()),
+            distances: map_values(matrix.distances.as_slice()),
+            error_codes: matrix.error_codes.as_ref().map(|error_codes| map_values(error_codes.as_slice())),
+        })
+        .collect()
+}
+
+fn solve_sub_problem(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    environment: Arc<Environment>,
+    max_time: usize,
+) -> Result<Solution, String> {
+    let problem = Arc::new(
+        if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
+            .map_err(|err| err.to_string())?,
+    );
+
+    let (solution, _, _) = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_time(Some(max_time))
+        .build()
+        .map(|config| Solver::new(problem.clone(), config))
+        .and_then(|solver| solver.solve())?;
+
+    Ok(create_solution(problem.as_ref(), &solution, &Default::default()))
+}
+
+fn merge_solutions(left: Solution, right: Solution) -> Solution {
+    Solution {
+        statistic: left.statistic + right.statistic,
+        tours: left.tours.into_iter().chain(right.tours).collect(),
+        unassigned: merge_options(left.unassigned, right.unassigned),
+        violations: merge_options(left.violations, right.violations),
+        extras: None,
+    }
+}
+
+fn merge_options<T>(left: Option<Vec<T>>, right: Option<Vec<T>>) -> Option<Vec<T>> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(left.into_iter().chain(right).collect()),
+        (left, right) => left.or(right),
+    }
+}
@@ -12,19 +12,34 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        customer_id: None,
+        max_detour_time: None,
+        max_wait_time: None,
+        area: None,
+        visit: None,
+        temperature: None,
+        tier: None,
+        cleaning: None,
+        priority: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, order: None }
+    JobTask { places: vec![], demand: None, order: None, target_time: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        blackouts: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, cordon: None, strict_groups: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,7 +47,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., distance_tiers: None, stop: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -43,15 +58,23 @@ pub fn create_test_vehicle_type() -> VehicleType {
             dispatch: None,
             breaks: None,
             reloads: None,
+            job_types: None,
+            overtime_penalty: None,
+            break_separation: None,
         }],
         capacity: vec![10],
         skills: None,
+        skill_validity: None,
         limits: None,
+        lifo: None,
+        count: None,
+        temperature: None,
+        service_scale: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, min_travel: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -8,8 +8,9 @@ fn can_generate_fleet_of_specific_size() {
         plan: create_empty_plan(),
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None, min_travel: None }],
             resources: None,
+            gates: None,
         },
         objectives: None,
     };
@@ -97,7 +97,7 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 5);
                 }
                 _ => unreachable!(),
             }
@@ -107,10 +107,12 @@ fn can_read_full_config() {
 
     let termination = config.termination.expect("no termination config");
     assert_eq!(termination.max_time, Some(300));
+    assert_eq!(termination.max_time_reserve, Some(5));
     assert_eq!(termination.max_generations, Some(3000));
 
     let environment = config.environment.expect("no environment config");
     assert_eq!(environment.is_experimental, Some(false));
+    assert_eq!(environment.seed, Some(42));
 
     let parallelism = environment.parallelism.expect("no parallelism config");
     assert_eq!(parallelism.num_thread_pools, 6);
@@ -136,7 +138,12 @@ fn can_configure_telemetry_metrics() {
     let config = Config {
         evolution: None,
         hyper: None,
-        termination: Some(TerminationConfig { max_time: None, max_generations: Some(100), variation: None }),
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_time_reserve: None,
+            max_generations: Some(100),
+            variation: None,
+        }),
         environment: None,
         telemetry: Some(TelemetryConfig {
             progress: None,
@@ -161,3 +168,35 @@ fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_configure_annealing_population() {
+    let config = Config {
+        evolution: Some(EvolutionConfig {
+            initial: None,
+            population: Some(PopulationType::Annealing {
+                selection_size: Some(1),
+                initial_temperature: Some(0.1),
+                cooling_rate: Some(0.9),
+                seed: Some(42),
+            }),
+        }),
+        hyper: None,
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_time_reserve: None,
+            max_generations: Some(10),
+            variation: None,
+        }),
+        environment: None,
+        telemetry: None,
+    };
+
+    let (solution, _, _) = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::helpers::generate::*;
+use std::collections::HashSet;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::get_unique_locations;
+
+fn create_area_job(id: &str, lat: f64, lng: f64, area: &str) -> Job {
+    Job { id: id.to_string(), area: Some(area.to_string()), ..create_test_job(lat, lng) }
+}
+
+fn create_test_problem(vehicle_ids: &[&str]) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_area_job("job1", 0.001, 0.001, "north"),
+                create_area_job("job2", 0.002, 0.001, "north"),
+                create_area_job("job3", -0.001, 0.001, "south"),
+                create_area_job("job4", -0.002, 0.001, "south"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.iter().map(|id| id.to_string()).collect(),
+                ..create_test_vehicle_type()
+            }],
+            profiles: vec![create_test_vehicle_profile()],
+            resources: None,
+            gates: None,
+        },
+        objectives: None,
+    }
+}
+
+fn create_test_matrix(problem: &Problem) -> Matrix {
+    let locations = get_unique_locations(problem);
+    let values = locations
+        .iter()
+        .flat_map(|from| {
+            locations.iter().map(move |to| match (from, to) {
+                (
+                    Location::Coordinate { lat: from_lat, lng: from_lng },
+                    Location::Coordinate { lat: to_lat, lng: to_lng },
+                ) => (((from_lat - to_lat).abs() + (from_lng - to_lng).abs()) * 100_000.).round() as i64,
+                _ => unreachable!(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: values.clone(),
+        distances: values,
+        error_codes: None,
+    }
+}
+
+parameterized_test! {can_solve_problem_by_areas, use_matrix, {
+    can_solve_problem_by_areas_impl(use_matrix);
+}}
+
+can_solve_problem_by_areas! {
+    case01_approximation: false,
+    case02_routing_matrix: true,
+}
+
+fn can_solve_problem_by_areas_impl(use_matrix: bool) {
+    let problem = create_test_problem(&["vehicle_1", "vehicle_2", "vehicle_3"]);
+    let matrices = if use_matrix { Some(vec![create_test_matrix(&problem)]) } else { None };
+
+    let solution = solve_by_areas(problem, matrices, Arc::new(Environment::default()), 2)
+        .unwrap_or_else(|err| panic!("cannot solve: '{err}'"));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .map(|activity| activity.job_id.as_str())
+                .filter(|job_id| job_id.starts_with("job"))
+                .collect::<Vec<_>>();
+            job_ids.sort();
+            job_ids
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(tours, vec![vec!["job1", "job2"], vec!["job3", "job4"]]);
+    let vehicle_ids = solution.tours.iter().map(|tour| tour.vehicle_id.as_str()).collect::<HashSet<_>>();
+    assert_eq!(vehicle_ids.len(), 2);
+}
+
+#[test]
+fn can_keep_relation_with_jobs_from_different_areas() {
+    let mut problem = create_test_problem(&["vehicle_1", "vehicle_2", "vehicle_3"]);
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Any,
+        jobs: vec!["departure".to_string(), "job1".to_string(), "job3".to_string()],
+        vehicle_id: "vehicle_1".to_string(),
+        shift_index: None,
+    }]);
+
+    let solution = solve_by_areas(problem, None, Arc::new(Environment::default()), 2)
+        .unwrap_or_else(|err| panic!("cannot solve: '{err}'"));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.vehicle_id == "vehicle_1").expect("no tour for vehicle_1");
+    let job_ids =
+        tour.stops.iter().flat_map(|stop| stop.activities().iter()).map(|a| a.job_id.as_str()).collect::<HashSet<_>>();
+    assert!(job_ids.contains("job1"));
+    assert!(job_ids.contains("job3"));
+}
+
+#[test]
+fn can_detect_not_enough_vehicles() {
+    let problem = create_test_problem(&["vehicle_1"]);
+
+    let result = solve_by_areas(problem, None, Arc::new(Environment::default()), 2);
+
+    assert_eq!(
+        result.err(),
+        Some("cannot decompose problem: amount of vehicles (1) is less than amount of areas (2)".to_string())
+    );
+}
@@ -103,7 +103,7 @@ pub(crate) fn get_jobs_dissimilarities(
         .map(|outer| {
             let dissimilarities = jobs
                 .iter()
-                .filter(|inner| outer != *inner)
+                .filter(|inner| outer != *inner && (config.filtering.pair_filter)(outer, inner))
                 .filter_map(|inner| {
                     let dissimilarities = get_dissimilarities(outer, inner, transport, config);
                     if dissimilarities.is_empty() {
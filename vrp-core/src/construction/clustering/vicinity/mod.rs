@@ -91,6 +91,9 @@ pub enum VisitPolicy {
     OpenContinuation,
 }
 
+/// A function type which checks whether the second job can be clustered with the first one.
+pub type PairFilterFn = Arc<dyn Fn(&Job, &Job) -> bool + Send + Sync>;
+
 /// Specifies filtering policy.
 #[derive(Clone)]
 pub struct FilterPolicy {
@@ -98,6 +101,8 @@ pub struct FilterPolicy {
     pub job_filter: Arc<dyn Fn(&Job) -> bool + Send + Sync>,
     /// Actor filter.
     pub actor_filter: Arc<dyn Fn(&Actor) -> bool + Send + Sync>,
+    /// Job pair filter: checks whether the candidate job can be clustered with the cluster center.
+    pub pair_filter: PairFilterFn,
 }
 
 /// Specifies service time policy.
@@ -36,8 +36,7 @@ pub trait MultiTrip {
 
     /// Returns marker intervals.
     fn get_marker_intervals<'a>(&self, route_ctx: &'a RouteContext) -> Option<&'a Vec<(usize, usize)>> {
-        self.get_state_code()
-            .and_then(|state_code| route_ctx.state().get_route_state::<Vec<(usize, usize)>>(state_code))
+        self.get_state_code().and_then(|state_code| route_ctx.marker_intervals(state_code))
     }
 
     /// Accepts insertion and promotes unassigned jobs with specific error code to unknown.
@@ -1,8 +1,15 @@
-use crate::construction::heuristics::RouteContext;
-use crate::models::common::{Distance, Schedule, Timestamp};
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/schedule_update_test.rs"]
+mod schedule_update_test;
+
+use crate::construction::heuristics::{ActivityContext, RouteContext};
+use crate::models::common::{Distance, Location, Schedule, Timestamp};
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
 use crate::models::StateKey;
 use crate::models::OP_START_MSG;
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
 
 /// Contains state keys ids used by route schedule updating logic.
 pub struct ScheduleStateKeys {
@@ -42,6 +49,50 @@ pub fn update_route_departure(
     update_route_schedule(route_ctx, activity, transport, state_keys);
 }
 
+/// Estimates service start of the target activity when it is inserted between prev and next ones.
+/// Also returns new service starts of the following activities which are shifted by the insertion:
+/// the shift is propagated till it is absorbed, e.g. by waiting time.
+pub fn estimate_service_starts<'a>(
+    route: &'a Route,
+    activity_ctx: &ActivityContext,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) -> (Timestamp, Vec<(&'a Activity, Timestamp)>) {
+    let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+    let estimate_arrival = |from: Location, departure: Timestamp, to: &Activity| {
+        departure + transport.duration(route, from, to.place.location, TravelTime::Departure(departure))
+    };
+
+    let target_arrival = estimate_arrival(prev.place.location, prev.schedule.departure, target);
+    let target_departure = activity.estimate_departure(route, target, target_arrival);
+
+    let (_, _, shifted) = route
+        .tour
+        .all_activities()
+        .skip(activity_ctx.index + 1)
+        .try_fold((target.place.location, target_departure, Vec::new()), |(location, departure, mut shifted), next| {
+            let arrival = estimate_arrival(location, departure, next);
+            let service_start = arrival.max(next.place.time.start);
+
+            if compare_floats(service_start, get_service_start(next)) == Ordering::Equal {
+                return Err((location, departure, shifted));
+            }
+
+            shifted.push((next, service_start));
+
+            Ok((next.place.location, activity.estimate_departure(route, next, arrival), shifted))
+        })
+        .unwrap_or_else(|result| result);
+
+    (target_arrival.max(target.place.time.start), shifted)
+}
+
+/// Returns service start of the activity according to its current schedule.
+pub fn get_service_start(activity: &Activity) -> Timestamp {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
 fn update_schedules(
     route_ctx: &mut RouteContext,
     activity: &(dyn ActivityCost + Send + Sync),
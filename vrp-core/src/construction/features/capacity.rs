@@ -6,7 +6,7 @@ mod capacity_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::problem::Single;
+use crate::models::problem::{Fleet, Single};
 use crate::models::solution::Activity;
 use rosomaxa::prelude::Objective;
 use std::iter::once;
@@ -21,8 +21,71 @@ pub fn create_capacity_limit_with_multi_trip_feature<T: LoadOps>(
 ) -> Result<Feature, String> {
     FeatureBuilder::default()
         .with_name(name)
-        .with_constraint(CapacityConstraint::new(code, multi_trip.clone()))
-        .with_objective(CapacityObjective::new(multi_trip.clone()))
+        .with_constraint(CapacityConstraint::new(code, multi_trip.clone(), None, Vec::default()))
+        .with_objective(CapacityObjective::new(multi_trip.clone(), None))
+        .with_state(CapacityState::new(code, multi_trip))
+        .build()
+}
+
+/// Creates capacity feature as a hard constraint which additionally rejects merging of jobs when their
+/// combined static delivery or pickup cannot fit into any vehicle of the fleet.
+pub fn create_capacity_limit_with_fleet_feature<T: LoadOps>(
+    name: &str,
+    code: ViolationCode,
+    fleet: &Fleet,
+) -> Result<Feature, String> {
+    let multi_trip = Arc::new(NoMultiTrip::<T>::default());
+    let capacities = fleet.vehicles.iter().filter_map(|vehicle| vehicle.dimens.get_capacity().copied()).collect();
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CapacityConstraint::new(code, multi_trip.clone(), None, capacities))
+        .with_state(CapacityState::new(code, multi_trip))
+        .build()
+}
+
+/// Specifies how much vehicle capacity can be exceeded and how overflow is penalized.
+#[derive(Clone, Debug)]
+pub struct OverflowPolicy {
+    /// A max allowed overflow as a ratio of vehicle capacity, e.g. 0.1 allows to overload vehicle by 10%.
+    pub max_ratio: f64,
+    /// A penalty per unit of overflow ratio.
+    pub penalty: Cost,
+}
+
+impl OverflowPolicy {
+    fn get_overflow(&self, load_ratio: f64) -> f64 {
+        (load_ratio - 1.).max(0.)
+    }
+}
+
+/// Creates capacity feature which allows to exceed vehicle capacity within limits of overflow policy
+/// and multi trip functionality. Capacity overflow is penalized by the feature's objective, so it is
+/// minimized when the objective is used in the goal.
+pub fn create_capacity_limit_with_overflow_feature<T: ScalableLoad>(
+    name: &str,
+    code: ViolationCode,
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    overflow: OverflowPolicy,
+) -> Result<Feature, String> {
+    if !(overflow.max_ratio.is_finite() && overflow.max_ratio >= 0.) {
+        return Err("overflow ratio should be a non-negative number".to_string());
+    }
+
+    if !(overflow.penalty.is_finite() && overflow.penalty >= 0.) {
+        return Err("overflow penalty should be a non-negative number".to_string());
+    }
+
+    let max_ratio = overflow.max_ratio;
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CapacityConstraint::new(
+            code,
+            multi_trip.clone(),
+            Some(Arc::new(move |capacity: &T| capacity.scale(1. + max_ratio))),
+            Vec::default(),
+        ))
+        .with_objective(CapacityObjective::new(multi_trip.clone(), Some(overflow)))
         .with_state(CapacityState::new(code, multi_trip))
         .build()
 }
@@ -32,14 +95,59 @@ pub fn create_capacity_limit_feature<T: LoadOps>(name: &str, code: ViolationCode
     let multi_trip = Arc::new(NoMultiTrip::<T>::default());
     FeatureBuilder::default()
         .with_name(name)
-        .with_constraint(CapacityConstraint::new(code, multi_trip.clone()))
+        .with_constraint(CapacityConstraint::new(code, multi_trip.clone(), None, Vec::default()))
         .with_state(CapacityState::new(code, multi_trip))
         .build()
 }
 
+/// A predicate which decides whether demand belongs to the vehicle compartment: a physically
+/// separated part of vehicle with its own capacity, e.g. refrigerated or ambient one.
+pub type CompartmentPredicate<T> = Arc<dyn Fn(&Demand<T>) -> bool + Send + Sync>;
+
+/// Creates capacity feature as a hard constraint which tracks load of each vehicle compartment
+/// separately: insertion is rejected when any compartment overflows, even if total load fits.
+/// Compartment capacities are read from vehicle dimensions (see `CompartmentCapacityDimension`)
+/// in the same order as predicates. Demand is loaded into the compartment of the first matching
+/// predicate, a job with demand which does not match any compartment cannot be assigned. Loads
+/// are reset at multi trip markers, e.g. reloads.
+pub fn create_capacity_limit_with_compartments_feature<T: LoadOps>(
+    name: &str,
+    code: ViolationCode,
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    compartments: Vec<CompartmentPredicate<T>>,
+) -> Result<Feature, String> {
+    if compartments.is_empty() {
+        return Err("at least one compartment has to be specified".to_string());
+    }
+
+    let compartments = Arc::new(compartments);
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompartmentConstraint {
+            code,
+            multi_trip: multi_trip.clone(),
+            compartments: compartments.clone(),
+        })
+        .with_state(CompartmentState { multi_trip, compartments, state_keys: vec![COMPARTMENT_CAPACITY_KEY] })
+        .build()
+}
+
+/// Keeps track of load at specific activity.
+#[derive(Clone, Default)]
+struct ActivityLoad<T: LoadOps> {
+    current: T,
+    max_past: T,
+    max_future: T,
+}
+
+/// A function which returns a hard capacity limit from vehicle capacity.
+type CapacityLimitFn<T> = Arc<dyn Fn(&T) -> T + Send + Sync>;
+
 struct CapacityConstraint<T: LoadOps> {
     code: ViolationCode,
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    capacity_limit_fn: Option<CapacityLimitFn<T>>,
+    capacities: Vec<T>,
 }
 
 impl<T: LoadOps> FeatureConstraint for CapacityConstraint<T> {
@@ -55,33 +163,26 @@ impl<T: LoadOps> FeatureConstraint for CapacityConstraint<T> {
             return Err(self.code);
         }
 
-        match (&source, &candidate) {
-            (Job::Single(s_source), Job::Single(s_candidate)) => {
-                let source_demand: Option<&Demand<T>> = s_source.dimens.get_demand();
-                let candidate_demand: Option<&Demand<T>> = s_candidate.dimens.get_demand();
-
-                match (source_demand, candidate_demand) {
-                    (None, None) | (Some(_), None) => Ok(source),
-                    _ => {
-                        let source_demand = source_demand.cloned().unwrap_or_default();
-                        let candidate_demand = candidate_demand.cloned().unwrap_or_default();
-                        let new_demand = source_demand + candidate_demand;
-
-                        let mut dimens = s_source.dimens.clone();
-                        dimens.set_demand(new_demand);
-
-                        Ok(Job::Single(Arc::new(Single { places: s_source.places.clone(), dimens })))
-                    }
-                }
-            }
-            _ => Err(self.code),
-        }
+        merge_demands::<T>(source, candidate, self.capacities.as_slice(), self.code)
     }
 }
 
 impl<T: LoadOps> CapacityConstraint<T> {
-    fn new(code: ViolationCode, multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>) -> Self {
-        Self { code, multi_trip }
+    fn new(
+        code: ViolationCode,
+        multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+        capacity_limit_fn: Option<CapacityLimitFn<T>>,
+        capacities: Vec<T>,
+    ) -> Self {
+        Self { code, multi_trip, capacity_limit_fn, capacities }
+    }
+
+    /// Returns a hard capacity limit: vehicle capacity extended by allowed overflow.
+    fn get_capacity(&self, route_ctx: &RouteContext) -> Option<T> {
+        route_ctx.route().actor.vehicle.dimens.get_capacity().map(|capacity: &T| match &self.capacity_limit_fn {
+            Some(capacity_limit_fn) => capacity_limit_fn(capacity),
+            None => *capacity,
+        })
     }
 
     fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
@@ -93,12 +194,25 @@ impl<T: LoadOps> CapacityConstraint<T> {
             };
         };
 
+        let capacity = self.get_capacity(route_ctx);
         let can_handle = match job {
-            Job::Single(job) => {
-                can_handle_demand_on_intervals(route_ctx, self.multi_trip.as_ref(), job.dimens.get_demand(), None)
-            }
+            Job::Single(job) => can_handle_demand_on_intervals(
+                route_ctx,
+                self.multi_trip.as_ref(),
+                capacity.as_ref(),
+                job.dimens.get_demand(),
+                None,
+                false,
+            ),
             Job::Multi(job) => job.jobs.iter().any(|job| {
-                can_handle_demand_on_intervals(route_ctx, self.multi_trip.as_ref(), job.dimens.get_demand(), None)
+                can_handle_demand_on_intervals(
+                    route_ctx,
+                    self.multi_trip.as_ref(),
+                    capacity.as_ref(),
+                    job.dimens.get_demand(),
+                    None,
+                    true,
+                )
             }),
         };
 
@@ -132,10 +246,18 @@ impl<T: LoadOps> CapacityConstraint<T> {
         };
 
         let demand = get_demand(activity_ctx.target);
+        let capacity = self.get_capacity(route_ctx);
 
         let violation = if activity_ctx.target.retrieve_job().map_or(false, |job| job.as_multi().is_some()) {
             // NOTE multi job has dynamic demand which can go in another interval
-            if can_handle_demand_on_intervals(route_ctx, self.multi_trip.as_ref(), demand, Some(activity_ctx.index)) {
+            if can_handle_demand_on_intervals(
+                route_ctx,
+                self.multi_trip.as_ref(),
+                capacity.as_ref(),
+                demand,
+                Some(activity_ctx.index),
+                true,
+            ) {
                 None
             } else {
                 Some(false)
@@ -144,9 +266,10 @@ impl<T: LoadOps> CapacityConstraint<T> {
             has_demand_violation(
                 route_ctx.state(),
                 activity_ctx.prev,
-                route_ctx.route().actor.vehicle.dimens.get_capacity(),
+                capacity.as_ref(),
                 demand,
                 !self.multi_trip.has_markers(route_ctx),
+                false,
             )
         };
 
@@ -154,13 +277,216 @@ impl<T: LoadOps> CapacityConstraint<T> {
     }
 }
 
+struct CompartmentConstraint<T: LoadOps> {
+    code: ViolationCode,
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    compartments: Arc<Vec<CompartmentPredicate<T>>>,
+}
+
+impl<T: LoadOps> FeatureConstraint for CompartmentConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let get_index =
+            |job: &Job| job.as_single().and_then(|single| single.dimens.get_demand()).map(|d| self.get_index(d));
+        let (source_index, candidate_index) = (get_index(&source), get_index(&candidate));
+
+        // NOTE compartment capacities are vehicle specific, so only compartment match is checked here
+        match (source_index, candidate_index) {
+            (Some(None), _) | (_, Some(None)) => Err(self.code),
+            (Some(source_index), Some(candidate_index)) if source_index != candidate_index => Err(self.code),
+            _ => merge_demands::<T>(source, candidate, &[], self.code),
+        }
+    }
+}
+
+impl<T: LoadOps> CompartmentConstraint<T> {
+    fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let demands: Vec<&Demand<T>> = match job {
+            Job::Single(single) => single.dimens.get_demand().into_iter().collect(),
+            Job::Multi(multi) => multi.jobs.iter().filter_map(|single| single.dimens.get_demand()).collect(),
+        };
+
+        if demands.iter().any(|demand| self.get_index(demand).is_none()) {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        let can_handle = match job {
+            Job::Single(_) => demands.iter().all(|demand| {
+                let tour = &route_ctx.route().tour;
+                get_compartment_intervals(route_ctx, self.multi_trip.as_ref()).into_iter().any(
+                    |(start_idx, end_idx)| {
+                        tour.get(start_idx)
+                            .into_iter()
+                            .chain(tour.get(end_idx))
+                            .any(|activity| self.get_violation(route_ctx, activity, demand, false).is_none())
+                    },
+                )
+            }),
+            Job::Multi(_) => true,
+        };
+
+        if can_handle {
+            ConstraintViolation::success()
+        } else {
+            ConstraintViolation::fail(self.code)
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let demand = get_demand(activity_ctx.target)?;
+        let is_multi = activity_ctx.target.retrieve_job().map_or(false, |job| job.as_multi().is_some());
+
+        self.get_violation(route_ctx, activity_ctx.prev, demand, is_multi).map(|stopped| ConstraintViolation {
+            code: self.code,
+            stopped: stopped && get_compartment_intervals(route_ctx, self.multi_trip.as_ref()).len() < 2,
+        })
+    }
+
+    fn get_violation(
+        &self,
+        route_ctx: &RouteContext,
+        pivot: &Activity,
+        demand: &Demand<T>,
+        is_multi: bool,
+    ) -> Option<bool> {
+        let index = match self.get_index(demand) {
+            Some(index) => index,
+            None => return Some(true),
+        };
+
+        let capacities: Option<&Vec<T>> = route_ctx.route().actor.vehicle.dimens.get_compartment_capacities();
+        let load = route_ctx
+            .state()
+            .get_activity_state::<Vec<ActivityLoad<T>>>(COMPARTMENT_CAPACITY_KEY, pivot)
+            .and_then(|loads| loads.get(index).cloned())
+            .unwrap_or_default();
+
+        has_load_violation(&load, capacities.and_then(|capacities| capacities.get(index)), demand, true, is_multi)
+    }
+
+    fn get_index(&self, demand: &Demand<T>) -> Option<usize> {
+        get_compartment_index(self.compartments.as_slice(), demand)
+    }
+}
+
+struct CompartmentState<T: LoadOps> {
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    compartments: Arc<Vec<CompartmentPredicate<T>>>,
+    state_keys: Vec<StateKey>,
+}
+
+impl<T: LoadOps> CompartmentState<T> {
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        let intervals = get_compartment_intervals(route_ctx, self.multi_trip.as_ref());
+        let (route, state) = route_ctx.as_mut();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        let compartment_loads = (0..self.compartments.len())
+            .map(|index| {
+                let get_demand = |activity: &Activity| {
+                    get_demand(activity)
+                        .filter(|demand| get_compartment_index(self.compartments.as_slice(), demand) == Some(index))
+                        .cloned()
+                };
+
+                // NOTE the same logic as for capacity feature: load is calculated within each reload interval
+                intervals.iter().fold(Vec::with_capacity(activities.len()), |mut loads, &(start_idx, end_idx)| {
+                    let interval = &activities[start_idx..=end_idx];
+                    let start_delivery = interval
+                        .iter()
+                        .filter_map(|activity| get_demand(activity))
+                        .fold(T::default(), |acc, demand| acc + demand.delivery.0);
+
+                    let start_len = loads.len();
+                    interval.iter().fold((start_delivery, T::default()), |(current, max), activity| {
+                        let change = get_demand(activity).map(|demand| demand.change()).unwrap_or_default();
+                        let current = current + change;
+                        let max = max.max_load(current);
+
+                        loads.push(ActivityLoad { current, max_past: max, max_future: T::default() });
+
+                        (current, max)
+                    });
+
+                    loads[start_len..].iter_mut().rev().fold(T::default(), |max, load| {
+                        load.max_future = max.max_load(load.current);
+                        load.max_future
+                    });
+
+                    loads
+                })
+            })
+            .collect::<Vec<_>>();
+
+        activities.iter().enumerate().for_each(|(activity_idx, activity)| {
+            let loads =
+                compartment_loads.iter().map(|loads| loads[activity_idx].clone()).collect::<Vec<ActivityLoad<T>>>();
+            state.put_activity_state(COMPARTMENT_CAPACITY_KEY, activity, loads);
+        });
+    }
+}
+
+impl<T: LoadOps> FeatureState for CompartmentState<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.recalculate_states(route_ctx);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.recalculate_states(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
 struct CapacityObjective<T: LoadOps> {
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+    overflow: Option<OverflowPolicy>,
 }
 
 impl<T: LoadOps> CapacityObjective<T> {
-    pub fn new(multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>) -> Self {
-        Self { multi_trip }
+    pub fn new(multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>, overflow: Option<OverflowPolicy>) -> Self {
+        Self { multi_trip, overflow }
+    }
+
+    fn estimate_overflow(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let (overflow, demand) = match (&self.overflow, get_demand::<T>(activity_ctx.target)) {
+            (Some(overflow), Some(demand)) => (overflow, demand),
+            _ => return 0.,
+        };
+
+        let capacity: Option<&T> = route_ctx.route().actor.vehicle.dimens.get_capacity();
+        let old_ratio = route_ctx.state().get_route_state::<f64>(MAX_LOAD_KEY).copied();
+
+        match (capacity, old_ratio) {
+            (Some(capacity), Some(old_ratio)) => {
+                let load = get_activity_load::<T>(route_ctx.state(), activity_ctx.prev);
+                let new_peak = (load.max_past + demand.delivery.0)
+                    .max_load(load.max_future + demand.pickup.0)
+                    .max_load(load.max_future + demand.change());
+                let new_ratio = new_peak.ratio(capacity).max(old_ratio);
+
+                (overflow.get_overflow(new_ratio) - overflow.get_overflow(old_ratio)) * overflow.penalty
+            }
+            _ => 0.,
+        }
     }
 
     fn estimate_job(&self, job: &Job) -> Cost {
@@ -176,13 +502,17 @@ impl<T: LoadOps> Objective for CapacityObjective<T> {
     type Solution = InsertionContext;
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution
-            .solution
-            .routes
-            .iter()
-            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
-            .map(|job| self.estimate_job(&job))
-            .sum()
+        let routes = &solution.solution.routes;
+        let markers =
+            routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).map(|job| self.estimate_job(&job)).sum();
+
+        self.overflow.as_ref().map_or(markers, |overflow| {
+            routes
+                .iter()
+                .filter_map(|route_ctx| route_ctx.state().get_route_state::<f64>(MAX_LOAD_KEY))
+                .map(|&ratio| overflow.get_overflow(ratio) * overflow.penalty)
+                .fold(markers, |acc, penalty| acc + penalty)
+        })
     }
 }
 
@@ -190,7 +520,7 @@ impl<T: LoadOps> FeatureObjective for CapacityObjective<T> {
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
             MoveContext::Route { job, .. } => self.estimate_job(job),
-            MoveContext::Activity { .. } => 0.,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_overflow(route_ctx, activity_ctx),
         }
     }
 }
@@ -310,64 +640,89 @@ fn has_demand_violation<T: LoadOps>(
     capacity: Option<&T>,
     demand: Option<&Demand<T>>,
     stopped: bool,
+    is_multi: bool,
 ) -> Option<bool> {
-    if let Some(demand) = demand {
-        if let Some(&capacity) = capacity {
-            let default = T::default();
-
-            // check how static delivery affect past max load
-            if demand.delivery.0.is_not_empty() {
-                let past = *state.get_activity_state(MAX_PAST_CAPACITY_KEY, pivot).unwrap_or(&default);
-                if !capacity.can_fit(&(past + demand.delivery.0)) {
-                    return Some(stopped);
-                }
-            }
+    demand.and_then(|demand| has_load_violation(&get_activity_load(state, pivot), capacity, demand, stopped, is_multi))
+}
 
-            // check how static pickup affect future max load
-            if demand.pickup.0.is_not_empty() {
-                let future = *state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot).unwrap_or(&default);
-                if !capacity.can_fit(&(future + demand.pickup.0)) {
-                    return Some(false);
-                }
-            }
+fn get_activity_load<T: LoadOps>(state: &RouteState, pivot: &Activity) -> ActivityLoad<T> {
+    let default = T::default();
+    let get_load = |key: StateKey| *state.get_activity_state(key, pivot).unwrap_or(&default);
 
-            // check dynamic load change
-            let change = demand.change();
-            if change.is_not_empty() {
-                let future = *state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot).unwrap_or(&default);
-                if !capacity.can_fit(&(future + change)) {
-                    return Some(false);
-                }
+    ActivityLoad {
+        current: get_load(CURRENT_CAPACITY_KEY),
+        max_past: get_load(MAX_PAST_CAPACITY_KEY),
+        max_future: get_load(MAX_FUTURE_CAPACITY_KEY),
+    }
+}
+
+fn has_load_violation<T: LoadOps>(
+    load: &ActivityLoad<T>,
+    capacity: Option<&T>,
+    demand: &Demand<T>,
+    stopped: bool,
+    is_multi: bool,
+) -> Option<bool> {
+    if let Some(&capacity) = capacity {
+        // check how static delivery affect past max load
+        if demand.delivery.0.is_not_empty() && !capacity.can_fit(&(load.max_past + demand.delivery.0)) {
+            return Some(stopped);
+        }
+
+        // check how static pickup affect future max load
+        if demand.pickup.0.is_not_empty() && !capacity.can_fit(&(load.max_future + demand.pickup.0)) {
+            return Some(false);
+        }
+
+        // check dynamic load change
+        let change = demand.change();
+        if change.is_not_empty() {
+            if !capacity.can_fit(&(load.current + change)) {
+                return Some(false);
+            }
 
-                let current = *state.get_activity_state(CURRENT_CAPACITY_KEY, pivot).unwrap_or(&default);
-                if !capacity.can_fit(&(current + change)) {
+            if is_multi {
+                // NOTE sub-jobs of multi job are inserted one by one and route state is recalculated in
+                // between, so dynamic pickup raises load on all activities till its delivery is inserted.
+                // That's why the peak of the segment carrying the load is checked on delivery insertion
+                if demand.delivery.1.is_not_empty() && !capacity.can_fit(&load.max_past) {
                     return Some(false);
                 }
+            } else if !capacity.can_fit(&(load.max_future + change)) {
+                return Some(false);
             }
-
-            None
-        } else {
-            Some(stopped)
         }
-    } else {
+
         None
+    } else {
+        Some(stopped)
     }
 }
 
 fn can_handle_demand_on_intervals<T: LoadOps>(
     route_ctx: &RouteContext,
     multi_trip: &(dyn MultiTrip<Constraint = T> + Send + Sync),
+    capacity: Option<&T>,
     demand: Option<&Demand<T>>,
     insert_idx: Option<usize>,
+    is_multi: bool,
 ) -> bool {
-    let has_demand_violation = |activity: &Activity| {
-        has_demand_violation(
-            route_ctx.state(),
-            activity,
-            route_ctx.route().actor.vehicle.dimens.get_capacity(),
-            demand,
-            true,
-        )
+    let has_demand_violation =
+        |activity: &Activity| has_demand_violation(route_ctx.state(), activity, capacity, demand, true, is_multi);
+
+    // NOTE dynamic delivery of multi job unloads what was picked up earlier, so intervals after it are not
+    // affected, but all preceding ones might carry the load and have to fit capacity
+    let is_dynamic_delivery = is_multi && demand.map_or(false, |demand| demand.delivery.1.is_not_empty());
+    let can_fit_interval = |start_idx: usize| {
+        let tour = &route_ctx.route().tour;
+        match (capacity, tour.get(start_idx)) {
+            (Some(capacity), Some(activity)) => route_ctx
+                .state()
+                .get_activity_state::<T>(MAX_FUTURE_CAPACITY_KEY, activity)
+                .into_iter()
+                .all(|max| capacity.can_fit(max)),
+            _ => true,
+        }
     };
 
     let has_demand_violation_on_borders = |start_idx: usize, end_idx: usize| {
@@ -379,8 +734,14 @@ fn can_handle_demand_on_intervals<T: LoadOps>(
         .get_marker_intervals(route_ctx)
         .map(|intervals| {
             if let Some(insert_idx) = insert_idx {
-                intervals.iter().filter(|(_, end_idx)| insert_idx <= *end_idx).all(|interval| {
-                    has_demand_violation(route_ctx.route().tour.get(insert_idx.max(interval.0)).unwrap()).is_none()
+                intervals.iter().all(|&(start_idx, end_idx)| {
+                    if insert_idx > end_idx {
+                        !is_dynamic_delivery || can_fit_interval(start_idx)
+                    } else if is_dynamic_delivery && insert_idx < start_idx {
+                        true
+                    } else {
+                        has_demand_violation(route_ctx.route().tour.get(insert_idx.max(start_idx)).unwrap()).is_none()
+                    }
                 })
             } else {
                 intervals.iter().any(|(start_idx, end_idx)| has_demand_violation_on_borders(*start_idx, *end_idx))
@@ -398,3 +759,57 @@ fn can_handle_demand_on_intervals<T: LoadOps>(
 fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
     activity.job.as_ref().and_then(|job| job.dimens.get_demand())
 }
+
+fn get_compartment_index<T: LoadOps>(compartments: &[CompartmentPredicate<T>], demand: &Demand<T>) -> Option<usize> {
+    compartments.iter().position(|predicate| predicate(demand))
+}
+
+/// Returns intervals between vehicle terminals and multi trip markers which cover the whole tour.
+fn get_compartment_intervals<T: LoadOps>(
+    route_ctx: &RouteContext,
+    multi_trip: &(dyn MultiTrip<Constraint = T> + Send + Sync),
+) -> Vec<(usize, usize)> {
+    route_intervals(route_ctx.route(), |activity| {
+        activity.job.as_ref().map_or(false, |job| multi_trip.is_marker_job(&Job::Single(job.clone())))
+    })
+}
+
+/// Merges demands of two single jobs: pickup and delivery parts are summed independently. When
+/// capacities are specified, merged static pickup and delivery have to fit at least one of them.
+fn merge_demands<T: LoadOps>(
+    source: Job,
+    candidate: Job,
+    capacities: &[T],
+    code: ViolationCode,
+) -> Result<Job, ViolationCode> {
+    match (&source, &candidate) {
+        (Job::Single(s_source), Job::Single(s_candidate)) => {
+            let source_demand: Option<&Demand<T>> = s_source.dimens.get_demand();
+            let candidate_demand: Option<&Demand<T>> = s_candidate.dimens.get_demand();
+
+            match (source_demand, candidate_demand) {
+                (None, None) | (Some(_), None) => Ok(source),
+                _ => {
+                    let source_demand = source_demand.cloned().unwrap_or_default();
+                    let candidate_demand = candidate_demand.cloned().unwrap_or_default();
+                    let new_demand = source_demand + candidate_demand;
+
+                    let can_fit = capacities.is_empty()
+                        || capacities.iter().any(|capacity| {
+                            capacity.can_fit(&new_demand.delivery.0) && capacity.can_fit(&new_demand.pickup.0)
+                        });
+
+                    if !can_fit {
+                        return Err(code);
+                    }
+
+                    let mut dimens = s_source.dimens.clone();
+                    dimens.set_demand(new_demand);
+
+                    Ok(Job::Single(Arc::new(Single { places: s_source.places.clone(), dimens })))
+                }
+            }
+        }
+        _ => Err(code),
+    }
+}
@@ -0,0 +1,296 @@
+//! A feature to model precedence between pickup and delivery jobs which can be served by different
+//! vehicles, e.g. in cross-docking scenario.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/cross_route_precedence_test.rs"]
+mod cross_route_precedence_test;
+
+use super::*;
+use crate::models::solution::Activity;
+use hashbrown::HashMap;
+use std::iter::successors;
+
+/// Creates a feature which enforces that delivery job is served only after its pickup job is
+/// completed, even when they are assigned to different routes.
+///
+/// `pairs` specifies a list of (pickup, delivery) jobs. A delivery cannot be assigned without its
+/// pickup and its service cannot start before pickup's departure. The rule is evaluated on insertion
+/// level and, as schedules of other routes can be changed later, enforced on solution level: violated
+/// deliveries are moved to the list of unassigned jobs.
+///
+/// A job can be a delivery in one pair and a pickup in another one, so pairs can form an ordered
+/// chain of visits, e.g. served by differently skilled crews. Each job can have only one pickup
+/// and one delivery partner, cycles are not allowed.
+pub fn create_cross_route_precedence_feature(
+    name: &str,
+    pairs: &[(Job, Job)],
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    code: ViolationCode,
+    state_key: StateKey,
+) -> Result<Feature, String> {
+    let partners = pairs.iter().try_fold(HashMap::<Job, Partners>::new(), |mut acc, (pickup, delivery)| {
+        if pickup == delivery {
+            return Err("Cross route precedence: pickup and delivery should be different jobs".to_string());
+        }
+
+        let is_unique = acc.entry(pickup.clone()).or_default().delivery.replace(delivery.clone()).is_none()
+            && acc.entry(delivery.clone()).or_default().pickup.replace(pickup.clone()).is_none();
+
+        if is_unique {
+            Ok(acc)
+        } else {
+            Err("Cross route precedence: a job can have only one pickup and one delivery partner".to_string())
+        }
+    })?;
+
+    let get_delivery = |job: &Job| partners.get(job).and_then(|partners| partners.delivery.as_ref());
+    let has_cycle =
+        partners.keys().any(|job| successors(get_delivery(job), |&delivery| get_delivery(delivery)).any(|d| d == job));
+
+    if has_cycle {
+        return Err("Cross route precedence: pairs should not form a cycle".to_string());
+    }
+
+    let partners = Arc::new(partners);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CrossRoutePrecedenceConstraint {
+            partners: partners.clone(),
+            transport,
+            activity,
+            code,
+            state_key,
+        })
+        .with_state(CrossRoutePrecedenceState { partners, code, state_keys: vec![state_key] })
+        .build()
+}
+
+/// Specifies partners of the job in precedence pairs.
+#[derive(Clone, Default)]
+struct Partners {
+    /// A pickup which has to be served before the job.
+    pickup: Option<Job>,
+    /// A delivery which has to be served after the job.
+    delivery: Option<Job>,
+}
+
+/// Keeps times of partner jobs assigned to other routes: service start and departure.
+type PartnerTimes = HashMap<Job, (Timestamp, Timestamp)>;
+
+struct CrossRoutePrecedenceConstraint {
+    partners: Arc<HashMap<Job, Partners>>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    code: ViolationCode,
+    state_key: StateKey,
+}
+
+impl CrossRoutePrecedenceConstraint {
+    fn evaluate_route(&self, solution_ctx: &SolutionContext, job: &Job) -> Option<ConstraintViolation> {
+        match self.partners.get(job).and_then(|partners| partners.pickup.as_ref()) {
+            Some(pickup) if !is_assigned(solution_ctx, pickup) => ConstraintViolation::fail(self.code),
+            _ => None,
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let partners = activity_ctx.target.retrieve_job().and_then(|job| self.partners.get(&job))?;
+
+        partners.pickup.as_ref().and_then(|pickup| self.evaluate_pickup(route_ctx, activity_ctx, pickup)).or_else(
+            || {
+                partners
+                    .delivery
+                    .as_ref()
+                    .and_then(|delivery| self.evaluate_delivery(route_ctx, activity_ctx, delivery))
+            },
+        )
+    }
+
+    /// Checks that the target activity is served after its pickup partner.
+    fn evaluate_pickup(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        pickup: &Job,
+    ) -> Option<ConstraintViolation> {
+        if let Some(pickup_idx) = route_ctx.route().tour.index(pickup) {
+            return if pickup_idx <= activity_ctx.index { None } else { ConstraintViolation::fail(self.code) };
+        }
+
+        let (_, departure) = self.get_partner_time(route_ctx, pickup)?;
+        let service_start = self
+            .estimate_arrival(route_ctx, activity_ctx.prev, activity_ctx.target)
+            .max(activity_ctx.target.place.time.start);
+
+        // NOTE later insertion positions can still be feasible
+        if service_start < departure {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that the target activity is served before its delivery partner.
+    fn evaluate_delivery(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        delivery: &Job,
+    ) -> Option<ConstraintViolation> {
+        if let Some(delivery_idx) = route_ctx.route().tour.index(delivery) {
+            return if delivery_idx > activity_ctx.index { None } else { ConstraintViolation::skip(self.code) };
+        }
+
+        let (service_start, _) = self.get_partner_time(route_ctx, delivery)?;
+        let arrival = self.estimate_arrival(route_ctx, activity_ctx.prev, activity_ctx.target);
+        let departure = self.activity.estimate_departure(route_ctx.route(), activity_ctx.target, arrival);
+
+        if departure > service_start {
+            ConstraintViolation::fail(self.code)
+        } else {
+            None
+        }
+    }
+
+    fn get_partner_time(&self, route_ctx: &RouteContext, partner: &Job) -> Option<(Timestamp, Timestamp)> {
+        route_ctx.state().get_route_state::<PartnerTimes>(self.state_key).and_then(|times| times.get(partner)).copied()
+    }
+
+    fn estimate_arrival(&self, route_ctx: &RouteContext, prev: &Activity, target: &Activity) -> Timestamp {
+        let departure = prev.schedule.departure;
+
+        departure
+            + self.transport.duration(
+                route_ctx.route(),
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            )
+    }
+}
+
+impl FeatureConstraint for CrossRoutePrecedenceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, job, .. } => self.evaluate_route(solution_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if self.partners.contains_key(&source) || self.partners.contains_key(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct CrossRoutePrecedenceState {
+    partners: Arc<HashMap<Job, Partners>>,
+    code: ViolationCode,
+    state_keys: Vec<StateKey>,
+}
+
+impl CrossRoutePrecedenceState {
+    /// Removes deliveries which are assigned without their pickups or served before pickups are completed.
+    /// As removal of a delivery can break its own delivery partner in the chain, it is repeated till
+    /// there are no violations.
+    fn remove_violated_deliveries(&self, solution_ctx: &mut SolutionContext) {
+        loop {
+            let times = get_assigned_times(solution_ctx, self.partners.as_ref());
+
+            let violated = times
+                .iter()
+                .filter_map(|(job, (_, (service_start, _)))| {
+                    let pickup = self.partners.get(job).and_then(|partners| partners.pickup.as_ref())?;
+                    match times.get(pickup) {
+                        Some((_, (_, departure))) if *departure <= *service_start => None,
+                        _ => Some(job.clone()),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if violated.is_empty() {
+                break;
+            }
+
+            violated.into_iter().for_each(|job| {
+                if let Some(route_ctx) =
+                    solution_ctx.routes.iter_mut().find(|route_ctx| route_ctx.route().tour.has_job(&job))
+                {
+                    route_ctx.route_mut().tour.remove(&job);
+                }
+
+                solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+            });
+        }
+    }
+
+    fn update_partner_times(&self, solution_ctx: &mut SolutionContext) {
+        let times = get_assigned_times(solution_ctx, self.partners.as_ref());
+
+        solution_ctx.routes.iter_mut().enumerate().for_each(|(route_idx, route_ctx)| {
+            let partner_times = times
+                .iter()
+                .filter(|(_, (idx, _))| *idx != route_idx)
+                .map(|(job, (_, times))| (job.clone(), *times))
+                .collect::<PartnerTimes>();
+
+            route_ctx.state_mut().put_route_state(self.state_keys[0], partner_times);
+        });
+    }
+}
+
+impl FeatureState for CrossRoutePrecedenceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        // NOTE insertion of any job can shift schedule of pair jobs in the same route
+        let has_pair_jobs = self.partners.contains_key(job)
+            || solution_ctx.routes[route_index].route().tour.jobs().any(|job| self.partners.contains_key(&job));
+
+        if has_pair_jobs {
+            self.update_partner_times(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_violated_deliveries(solution_ctx);
+        self.update_partner_times(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn is_assigned(solution_ctx: &SolutionContext, job: &Job) -> bool {
+    solution_ctx.routes.iter().any(|route_ctx| route_ctx.route().tour.has_job(job))
+}
+
+/// Returns route index, service start and departure times of assigned jobs from pairs.
+fn get_assigned_times(
+    solution_ctx: &SolutionContext,
+    partners: &HashMap<Job, Partners>,
+) -> HashMap<Job, (usize, (Timestamp, Timestamp))> {
+    solution_ctx
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| {
+            route_ctx.route().tour.all_activities().filter_map(move |activity| {
+                let job = activity.retrieve_job().filter(|job| partners.contains_key(job))?;
+                let service_start = activity.schedule.arrival.max(activity.place.time.start);
+
+                Some((job, (route_idx, (service_start, activity.schedule.departure))))
+            })
+        })
+        .collect()
+}
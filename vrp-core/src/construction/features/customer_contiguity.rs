@@ -0,0 +1,97 @@
+//! A feature to keep activities of the same customer together within the tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/customer_contiguity_test.rs"]
+mod customer_contiguity_test;
+
+use super::*;
+use crate::models::problem::Single;
+use crate::models::solution::Activity;
+use hashbrown::HashMap;
+use std::iter::once;
+
+/// Specifies a function which returns a customer id of the job, if it is set.
+pub type CustomerIdFn = Arc<dyn Fn(&Single) -> Option<&String> + Send + Sync>;
+
+/// Creates a feature which penalizes gaps between activities of the same customer within the tour.
+/// A gap is measured in sequence positions: each foreign activity served between two consecutive
+/// activities of the same customer adds `penalty` to the cost.
+pub fn create_customer_contiguity_feature(
+    name: &str,
+    customer_id_fn: CustomerIdFn,
+    penalty: Cost,
+) -> Result<Feature, String> {
+    if penalty < 0. {
+        return Err("Customer contiguity: penalty should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(CustomerContiguityObjective { customer_id_fn, penalty })
+        .build()
+}
+
+struct CustomerContiguityObjective {
+    customer_id_fn: CustomerIdFn,
+    penalty: Cost,
+}
+
+impl CustomerContiguityObjective {
+    fn get_customer_id<'a>(&self, activity: &'a Activity) -> Option<&'a String> {
+        activity.job.as_ref().and_then(|single| (self.customer_id_fn)(single.as_ref()))
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let target_id = self.get_customer_id(activity_ctx.target);
+        let tour = &route_ctx.route().tour;
+
+        // NOTE skip route scanning when the route has no customer ids and target has none
+        if target_id.is_none() && tour.all_activities().all(|activity| self.get_customer_id(activity).is_none()) {
+            return Cost::default();
+        }
+
+        let split = (activity_ctx.index + 1).min(tour.total());
+        let ids = tour.all_activities().map(|activity| self.get_customer_id(activity));
+
+        let old_gaps = count_gaps(ids.clone());
+        let new_gaps = count_gaps(ids.clone().take(split).chain(once(target_id)).chain(ids.skip(split)));
+
+        (new_gaps as Cost - old_gaps as Cost) * self.penalty
+    }
+}
+
+impl Objective for CustomerContiguityObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                count_gaps(route_ctx.route().tour.all_activities().map(|activity| self.get_customer_id(activity)))
+            })
+            .sum::<usize>() as Cost
+            * self.penalty
+    }
+}
+
+impl FeatureObjective for CustomerContiguityObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+/// Counts amount of foreign activities placed between consecutive activities of the same customer.
+fn count_gaps<'a>(ids: impl Iterator<Item = Option<&'a String>>) -> usize {
+    ids.enumerate()
+        .filter_map(|(idx, id)| id.map(|id| (idx, id)))
+        .fold((HashMap::<&String, usize>::default(), 0), |(mut last_seen, gaps), (idx, id)| {
+            let gaps = gaps + last_seen.insert(id, idx).map_or(0, |last| idx - last - 1);
+            (last_seen, gaps)
+        })
+        .1
+}
@@ -0,0 +1,102 @@
+//! A feature to control how departure times of the routes are spread.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/departure_spread_test.rs"]
+mod departure_spread_test;
+
+use super::*;
+
+/// Specifies how departure times of the routes should be spread.
+#[derive(Clone, Debug)]
+pub enum DepartureSpread {
+    /// Departures are staggered: consecutive departures should be at least `interval` apart.
+    Stagger {
+        /// A minimum time between two consecutive departures.
+        interval: Duration,
+    },
+    /// Departures are kept within a narrow band: the difference between the earliest and the latest
+    /// departure is minimized.
+    Band,
+}
+
+/// Creates a feature which penalizes departure times of the routes depending on the spread type:
+/// either departures too close to each other or departures far away from each other.
+pub fn create_departure_spread_feature(name: &str, spread: DepartureSpread) -> Result<Feature, String> {
+    if let DepartureSpread::Stagger { interval } = &spread {
+        if *interval < 0. {
+            return Err("Departure spread: interval should not be negative".to_string());
+        }
+    }
+
+    FeatureBuilder::default().with_name(name).with_objective(DepartureSpreadObjective { spread }).build()
+}
+
+/// Returns a penalty of the given departure times according to the spread type.
+fn get_departure_spread_penalty(spread: &DepartureSpread, departures: &[Timestamp]) -> Cost {
+    let mut departures = departures.to_vec();
+    departures.sort_by(|a, b| compare_floats(*a, *b));
+
+    match spread {
+        DepartureSpread::Stagger { interval } => {
+            departures.windows(2).map(|pair| (interval - (pair[1] - pair[0])).max(0.)).sum()
+        }
+        DepartureSpread::Band => match (departures.first(), departures.last()) {
+            (Some(first), Some(last)) => last - first,
+            _ => Cost::default(),
+        },
+    }
+}
+
+struct DepartureSpreadObjective {
+    spread: DepartureSpread,
+}
+
+impl DepartureSpreadObjective {
+    fn estimate_route(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> Cost {
+        // NOTE departure time is not changed by insertion into already used route, but a new route
+        // adds one more departure
+        if route_ctx.route().tour.has_jobs() {
+            return Cost::default();
+        }
+
+        let departure = match get_departure(route_ctx) {
+            Some(departure) => departure,
+            None => return Cost::default(),
+        };
+
+        let departures = get_departures(solution_ctx);
+        let old_penalty = get_departure_spread_penalty(&self.spread, departures.as_slice());
+        let new_penalty = get_departure_spread_penalty(
+            &self.spread,
+            departures.into_iter().chain(std::iter::once(departure)).collect::<Vec<_>>().as_slice(),
+        );
+
+        new_penalty - old_penalty
+    }
+}
+
+impl Objective for DepartureSpreadObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_departure_spread_penalty(&self.spread, get_departures(&solution.solution).as_slice())
+    }
+}
+
+impl FeatureObjective for DepartureSpreadObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } => self.estimate_route(solution_ctx, route_ctx),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+/// Returns departure times of the routes which serve jobs.
+fn get_departures(solution_ctx: &SolutionContext) -> Vec<Timestamp> {
+    solution_ctx.routes.iter().filter(|route_ctx| route_ctx.route().tour.has_jobs()).filter_map(get_departure).collect()
+}
+
+fn get_departure(route_ctx: &RouteContext) -> Option<Timestamp> {
+    route_ctx.route().tour.start().map(|start| start.schedule.departure)
+}
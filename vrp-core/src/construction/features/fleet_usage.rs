@@ -5,6 +5,13 @@
 mod fleet_usage_test;
 
 use super::*;
+use crate::models::problem::Actor;
+use hashbrown::HashSet;
+
+/// Specifies a function which returns vehicle type id of the actor.
+pub type VehicleTypeFn = Arc<dyn Fn(&Actor) -> Option<&String> + Send + Sync>;
+/// Specifies a function which returns overtime penalty of the actor, if its shift is an overtime one.
+pub type OvertimePenaltyFn = Arc<dyn Fn(&Actor) -> Option<Cost> + Send + Sync>;
 
 /// Creates a feature to minimize used fleet size (affects amount of tours in solution).
 pub fn create_minimize_tours_feature(name: &str) -> Result<Feature, String> {
@@ -52,6 +59,46 @@ pub fn create_minimize_arrival_time_feature(name: &str) -> Result<Feature, Strin
         .build()
 }
 
+/// Creates a feature to minimize amount of distinct vehicle types used in solution.
+/// Unlike minimizing tours, it does not penalize using more vehicles of already used type.
+pub fn create_minimize_vehicle_types_feature(name: &str, vehicle_type_fn: VehicleTypeFn) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(VehicleTypesObjective { vehicle_type_fn }).build()
+}
+
+/// Creates a feature to minimize deviation of used fleet size from the target one, e.g. from
+/// amount of tours used in the previous day's plan. It helps to keep fleet usage consistent
+/// across consecutive planning runs.
+pub fn create_minimize_tours_deviation_feature(name: &str, target: usize) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(ToursDeviationObjective { target }).build()
+}
+
+/// Creates a feature to minimize usage of overtime shifts: a penalty is applied for each used actor
+/// which shift is marked as an overtime one, so such shifts are used only when regular ones are saturated.
+pub fn create_minimize_overtime_feature(name: &str, overtime_penalty_fn: OvertimePenaltyFn) -> Result<Feature, String> {
+    let route_penalty_fn = overtime_penalty_fn.clone();
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(FleetUsageObjective {
+            route_estimate_fn: Box::new(move |route_ctx| {
+                if route_ctx.route().tour.job_count() == 0 {
+                    (route_penalty_fn)(route_ctx.route().actor.as_ref()).unwrap_or_default()
+                } else {
+                    Cost::default()
+                }
+            }),
+            solution_estimate_fn: Box::new(move |solution_ctx| {
+                solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|route_ctx| route_ctx.route().tour.job_count() > 0)
+                    .filter_map(|route_ctx| (overtime_penalty_fn)(route_ctx.route().actor.as_ref()))
+                    .sum()
+            }),
+        })
+        .build()
+}
+
 struct FleetUsageObjective {
     route_estimate_fn: Box<dyn Fn(&RouteContext) -> Cost + Send + Sync>,
     solution_estimate_fn: Box<dyn Fn(&SolutionContext) -> Cost + Send + Sync>,
@@ -73,3 +120,73 @@ impl FeatureObjective for FleetUsageObjective {
         }
     }
 }
+
+struct VehicleTypesObjective {
+    vehicle_type_fn: VehicleTypeFn,
+}
+
+impl VehicleTypesObjective {
+    fn get_used_types<'a>(&self, solution_ctx: &'a SolutionContext) -> HashSet<&'a String> {
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.job_count() > 0)
+            .filter_map(|route_ctx| (self.vehicle_type_fn)(route_ctx.route().actor.as_ref()))
+            .collect()
+    }
+}
+
+impl Objective for VehicleTypesObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_used_types(&solution.solution).len() as Cost
+    }
+}
+
+impl FeatureObjective for VehicleTypesObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } if route_ctx.route().tour.job_count() == 0 => {
+                (self.vehicle_type_fn)(route_ctx.route().actor.as_ref())
+                    .filter(|vehicle_type| !self.get_used_types(solution_ctx).contains(vehicle_type))
+                    .map_or(Cost::default(), |_| 1.)
+            }
+            _ => Cost::default(),
+        }
+    }
+}
+
+struct ToursDeviationObjective {
+    target: usize,
+}
+
+impl ToursDeviationObjective {
+    fn get_deviation(&self, used_tours: usize) -> Cost {
+        used_tours.abs_diff(self.target) as Cost
+    }
+}
+
+impl Objective for ToursDeviationObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_deviation(get_used_tours(&solution.solution))
+    }
+}
+
+impl FeatureObjective for ToursDeviationObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } if route_ctx.route().tour.job_count() == 0 => {
+                let used_tours = get_used_tours(solution_ctx);
+                self.get_deviation(used_tours + 1) - self.get_deviation(used_tours)
+            }
+            _ => Cost::default(),
+        }
+    }
+}
+
+fn get_used_tours(solution_ctx: &SolutionContext) -> usize {
+    solution_ctx.routes.iter().filter(|route_ctx| route_ctx.route().tour.job_count() > 0).count()
+}
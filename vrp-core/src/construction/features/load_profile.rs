@@ -0,0 +1,135 @@
+//! A feature to prefer deliver-down load profiles: the vehicle is loaded as much as possible at the
+//! beginning and its load only decreases after the peak.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/load_profile_test.rs"]
+mod load_profile_test;
+
+use super::*;
+use crate::models::common::{CapacityDimension, Demand, DemandDimension, LoadOps};
+use crate::models::solution::Activity;
+use std::marker::PhantomData;
+
+/// Creates a feature which penalizes load increases after the peak load within each tour (or each
+/// trip when vehicle is reloaded). Increase is measured as a ratio of vehicle capacity and multiplied
+/// by `penalty`. It relies on activity load state maintained by capacity feature.
+pub fn create_deliver_down_load_feature<T: LoadOps>(name: &str, penalty: Cost) -> Result<Feature, String> {
+    if penalty < 0. {
+        return Err("Deliver down load: penalty should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(DeliverDownLoadObjective::<T> { penalty, phantom: Default::default() })
+        .build()
+}
+
+struct DeliverDownLoadObjective<T: LoadOps> {
+    penalty: Cost,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> DeliverDownLoadObjective<T> {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let demand: Option<&Demand<T>> = activity_ctx.target.job.as_ref().and_then(|job| job.dimens.get_demand());
+        let capacity: Option<&T> = route_ctx.route().actor.vehicle.dimens.get_capacity();
+
+        let (demand, capacity) = match (demand, capacity) {
+            (Some(demand), Some(capacity)) => (demand, capacity),
+            _ => return Cost::default(),
+        };
+
+        let insert_idx = activity_ctx.index;
+        let (start_idx, end_idx) = get_intervals(route_ctx)
+            .into_iter()
+            .find(|(start_idx, end_idx)| *start_idx <= insert_idx && insert_idx <= *end_idx)
+            .unwrap_or((insert_idx, insert_idx));
+
+        let old_loads = get_loads::<T>(route_ctx, start_idx, end_idx).collect::<Vec<_>>();
+
+        // NOTE static delivery is loaded at the beginning of the trip, so the load is increased
+        // till the target activity, where it is replaced with pickup
+        let before_change = demand.delivery.0;
+        let after_change = demand.delivery.0 + demand.change();
+        let new_loads = old_loads.iter().enumerate().flat_map(|(idx, &load)| {
+            if start_idx + idx < insert_idx {
+                vec![load + before_change]
+            } else if start_idx + idx == insert_idx {
+                vec![load + before_change, load + after_change]
+            } else {
+                vec![load + after_change]
+            }
+        });
+
+        let new_penalty = get_load_increase(new_loads, capacity);
+        let old_penalty = get_load_increase(old_loads.into_iter(), capacity);
+
+        (new_penalty - old_penalty) * self.penalty
+    }
+
+    fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        route_ctx.route().actor.vehicle.dimens.get_capacity().map_or(Cost::default(), |capacity: &T| {
+            get_intervals(route_ctx)
+                .into_iter()
+                .map(|(start_idx, end_idx)| get_load_increase(get_loads(route_ctx, start_idx, end_idx), capacity))
+                .sum::<Cost>()
+                * self.penalty
+        })
+    }
+}
+
+impl<T: LoadOps> Objective for DeliverDownLoadObjective<T> {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_penalty(route_ctx)).sum()
+    }
+}
+
+impl<T: LoadOps> FeatureObjective for DeliverDownLoadObjective<T> {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+fn get_intervals(route_ctx: &RouteContext) -> Vec<(usize, usize)> {
+    route_ctx
+        .marker_intervals(RELOAD_INTERVALS_KEY)
+        .cloned()
+        .unwrap_or_else(|| vec![(0, route_ctx.route().tour.total().max(1) - 1)])
+}
+
+fn get_loads<T: LoadOps>(route_ctx: &RouteContext, start_idx: usize, end_idx: usize) -> impl Iterator<Item = T> + '_ {
+    route_ctx.route().tour.activities_slice(start_idx, end_idx).iter().map(|activity: &Activity| {
+        route_ctx.state().get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).copied().unwrap_or_default()
+    })
+}
+
+/// Returns a total load increase after the first peak as a ratio of vehicle capacity.
+fn get_load_increase<T: LoadOps>(loads: impl Iterator<Item = T>, capacity: &T) -> f64 {
+    let loads = loads.collect::<Vec<_>>();
+
+    let peak_idx = loads
+        .iter()
+        .enumerate()
+        .fold((0, f64::MIN), |(peak_idx, peak), (idx, load)| {
+            let ratio = load.ratio(capacity);
+            if ratio > peak {
+                (idx, ratio)
+            } else {
+                (peak_idx, peak)
+            }
+        })
+        .0;
+
+    loads
+        .iter()
+        .skip(peak_idx)
+        .zip(loads.iter().skip(peak_idx + 1))
+        .map(|(&prev, &next)| (next - prev).max_load(T::default()).ratio(capacity))
+        .filter(|increase| increase.is_finite())
+        .sum()
+}
@@ -11,18 +11,39 @@ use std::sync::Arc;
 mod capacity;
 pub use self::capacity::*;
 
+mod cross_route_precedence;
+pub use self::cross_route_precedence::*;
+
+mod customer_contiguity;
+pub use self::customer_contiguity::*;
+
+mod departure_spread;
+pub use self::departure_spread::*;
+
 mod fleet_usage;
 pub use self::fleet_usage::*;
 
+mod load_profile;
+pub use self::load_profile::*;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod profiling;
+pub use self::profiling::*;
+
+mod service_tier;
+pub use self::service_tier::*;
+
 mod shared_resource;
 pub use self::shared_resource::*;
 
+mod target_time;
+pub use self::target_time::*;
+
 mod total_value;
 pub use self::total_value::*;
 
@@ -35,9 +56,15 @@ pub use self::tour_limits::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod tour_overlap;
+pub use self::tour_overlap::*;
+
 mod transport;
 pub use self::transport::*;
 
+mod unique_stops;
+pub use self::unique_stops::*;
+
 mod work_balance;
 pub use self::work_balance::*;
 
@@ -51,6 +78,16 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks amount of open multi jobs after activity.
+pub const OPEN_MULTI_JOBS_KEY: i32 = 6;
+/// A key which tracks amount of activities served after their target time.
+pub const LATE_ACTIVITIES_KEY: i32 = 7;
+/// A key which tracks max lateness of activities in the tour.
+pub const MAX_LATENESS_KEY: i32 = 8;
+/// A key which tracks amount of tour legs crossing the cordon.
+pub const CORDON_CROSSINGS_KEY: i32 = 9;
+/// A key which tracks arrival at the first and departure from the last job activity in the tour.
+pub const SHIFT_SPAN_KEY: i32 = 10;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -62,6 +99,13 @@ pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
 /// A key which tracks max load in tour.
 pub const MAX_LOAD_KEY: i32 = 15;
+/// A key which tracks loads of vehicle compartments.
+pub const COMPARTMENT_CAPACITY_KEY: i32 = 16;
+
+/// A key which tracks fleet cost budget left for the tour.
+pub const REMAINING_BUDGET_KEY: i32 = 17;
+/// A key which tracks max lateness of activities in all other tours of the solution.
+pub const OTHERS_MAX_LATENESS_KEY: i32 = 18;
 
 /// A key for balancing max load.
 pub const BALANCE_MAX_LOAD_KEY: i32 = 20;
@@ -71,3 +115,7 @@ pub const BALANCE_ACTIVITY_KEY: i32 = 21;
 pub const BALANCE_DISTANCE_KEY: i32 = 22;
 /// A key for balancing duration.
 pub const BALANCE_DURATION_KEY: i32 = 23;
+/// A key for balancing stops.
+pub const BALANCE_STOP_KEY: i32 = 24;
+/// A key for balancing cost.
+pub const BALANCE_COST_KEY: i32 = 25;
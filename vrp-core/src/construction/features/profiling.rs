@@ -0,0 +1,97 @@
+//! Provides the way to measure time spent in evaluation of feature constraints.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/profiling_test.rs"]
+mod profiling_test;
+
+use super::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration as StdDuration, Instant};
+
+/// Specifies time spent in evaluation of a single feature constraint.
+#[derive(Clone, Debug)]
+pub struct ConstraintTiming {
+    /// A name of the feature.
+    pub name: String,
+    /// A total time spent in constraint evaluation.
+    pub total: StdDuration,
+    /// An amount of constraint evaluations.
+    pub calls: u64,
+}
+
+/// Collects time spent in evaluation of feature constraints.
+#[derive(Clone, Default)]
+pub struct ConstraintProfiler {
+    entries: Vec<Arc<ProfileEntry>>,
+}
+
+impl ConstraintProfiler {
+    /// Returns timings of all profiled constraints sorted by total time in descending order.
+    pub fn report(&self) -> Vec<ConstraintTiming> {
+        let mut timings = self
+            .entries
+            .iter()
+            .map(|entry| ConstraintTiming {
+                name: entry.name.clone(),
+                total: StdDuration::from_nanos(entry.nanos.load(Ordering::Relaxed)),
+                calls: entry.calls.load(Ordering::Relaxed),
+            })
+            .collect::<Vec<_>>();
+
+        timings.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+
+        timings
+    }
+}
+
+/// Wraps constraints of given features with time measuring decorators. Features without constraint
+/// are kept as is. Profiling is opt-in: when features are not wrapped, there is no overhead at all.
+pub fn create_profiled_features(features: Vec<Feature>) -> (Vec<Feature>, ConstraintProfiler) {
+    features.into_iter().fold((Vec::new(), ConstraintProfiler::default()), |(mut features, mut profiler), feature| {
+        let feature = match feature.constraint {
+            Some(constraint) => {
+                let entry = Arc::new(ProfileEntry {
+                    name: feature.name.clone(),
+                    nanos: AtomicU64::new(0),
+                    calls: AtomicU64::new(0),
+                });
+                profiler.entries.push(entry.clone());
+
+                Feature { constraint: Some(Arc::new(ProfiledConstraint { inner: constraint, entry })), ..feature }
+            }
+            None => feature,
+        };
+
+        features.push(feature);
+
+        (features, profiler)
+    })
+}
+
+struct ProfileEntry {
+    name: String,
+    nanos: AtomicU64,
+    calls: AtomicU64,
+}
+
+struct ProfiledConstraint {
+    inner: Arc<dyn FeatureConstraint + Send + Sync>,
+    entry: Arc<ProfileEntry>,
+}
+
+impl FeatureConstraint for ProfiledConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        let start = Instant::now();
+        let result = self.inner.evaluate(move_ctx);
+        let elapsed = start.elapsed().as_nanos() as u64;
+
+        self.entry.nanos.fetch_add(elapsed, Ordering::Relaxed);
+        self.entry.calls.fetch_add(1, Ordering::Relaxed);
+
+        result
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        self.inner.merge(source, candidate)
+    }
+}
@@ -0,0 +1,108 @@
+//! A feature to provide better service for jobs of higher service level tier.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/service_tier_test.rs"]
+mod service_tier_test;
+
+use super::*;
+use crate::construction::enablers::{estimate_service_starts, get_service_start};
+use crate::models::common::{Dimensions, Timestamp};
+use crate::models::problem::{ActivityCost, TransportCost};
+use crate::models::solution::{Activity, Route};
+
+/// Specifies service level parameters of a job tier.
+#[derive(Clone, Debug)]
+pub struct ServiceTier {
+    /// A penalty applied when job is not assigned.
+    pub penalty: Cost,
+    /// A penalty per time unit passed since tour start till job's service start.
+    pub earliness: Cost,
+}
+
+/// Specifies a function which returns a service tier of the job, if it is set.
+pub type ServiceTierFn = Arc<dyn Fn(&Dimensions) -> Option<ServiceTier> + Send + Sync>;
+
+/// Creates a feature which provides proportionally better service for jobs of higher tier: their
+/// unassignment is penalized stronger and they are preferred to be served earlier in the tour.
+pub fn create_service_tier_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    service_tier_fn: ServiceTierFn,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(ServiceTierObjective { transport, activity, service_tier_fn })
+        .build()
+}
+
+struct ServiceTierObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    service_tier_fn: ServiceTierFn,
+}
+
+impl ServiceTierObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let (service_start, shifted) =
+            estimate_service_starts(route, activity_ctx, self.activity.as_ref(), self.transport.as_ref());
+        let target_cost = self.get_earliness_cost(route, activity_ctx.target, service_start);
+
+        let shifted_cost = shifted
+            .into_iter()
+            .map(|(activity, service_start)| {
+                self.get_earliness_cost(route, activity, service_start)
+                    - self.get_earliness_cost(route, activity, get_service_start(activity))
+            })
+            .sum::<Cost>();
+
+        target_cost + shifted_cost
+    }
+
+    fn get_earliness_cost(&self, route: &Route, activity: &Activity, service_start: Timestamp) -> Cost {
+        activity.job.as_ref().and_then(|single| (self.service_tier_fn)(&single.dimens)).map_or(
+            Cost::default(),
+            |tier| {
+                let tour_start = route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+                (service_start - tour_start).max(0.) * tier.earliness
+            },
+        )
+    }
+
+    fn get_penalty(&self, job: &Job) -> Cost {
+        (self.service_tier_fn)(job.dimens()).map_or(Cost::default(), |tier| tier.penalty)
+    }
+}
+
+impl Objective for ServiceTierObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let unassigned = solution.solution.unassigned.keys().map(|job| self.get_penalty(job)).sum::<Cost>();
+
+        let earliness = solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                let route = route_ctx.route();
+                route
+                    .tour
+                    .all_activities()
+                    .map(move |activity| self.get_earliness_cost(route, activity, get_service_start(activity)))
+            })
+            .sum::<Cost>();
+
+        unassigned + earliness
+    }
+}
+
+impl FeatureObjective for ServiceTierObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { job, .. } => -self.get_penalty(job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
@@ -0,0 +1,491 @@
+//! A feature to model a preferred (target) service start time of the job activity.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/target_time_test.rs"]
+mod target_time_test;
+
+use super::*;
+use crate::construction::enablers::{estimate_service_starts, get_service_start};
+use crate::models::common::Timestamp;
+use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::cmp::Ordering;
+
+/// Specifies a function which returns a target service start time of the job, if it is set.
+pub type TargetTimeFn = Arc<dyn Fn(&Single) -> Option<Timestamp> + Send + Sync>;
+
+/// Specifies a function which returns a value (weight) of the job which owns given single.
+pub type JobValueFn = Arc<dyn Fn(&Single) -> Cost + Send + Sync>;
+
+/// Creates a feature which penalizes deviation of activity service start from its target time.
+/// Unlike time windows, target time is soft: it doesn't affect feasibility.
+///
+/// `earliness` and `lateness` are penalties per time unit of deviation before and after target time.
+pub fn create_target_time_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    earliness: Cost,
+    lateness: Cost,
+) -> Result<Feature, String> {
+    if earliness < 0. || lateness < 0. {
+        return Err("Target time: penalties should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(TargetTimeObjective { transport, activity, target_time_fn, earliness, lateness })
+        .build()
+}
+
+/// Creates a limit for amount of job activities served after their target time in a tour. Once
+/// the limit is reached, a job which would be served late has to be assigned to another tour.
+/// This is a hard constraint.
+pub fn create_max_late_activities_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxLateActivitiesConstraint {
+            code,
+            transport,
+            activity,
+            target_time_fn: target_time_fn.clone(),
+            limit_fn: limit_fn.clone(),
+        })
+        .with_state(MaxLateActivitiesState { target_time_fn, limit_fn, state_keys: vec![LATE_ACTIVITIES_KEY] })
+        .build()
+}
+
+/// Creates a feature which minimizes the maximum lateness: the worst delay of activity service start
+/// after its target time across all jobs. Unlike target deviation objective, which minimizes total
+/// lateness, it prefers plans where many jobs are slightly late over ones where a single job is very late.
+pub fn create_minimize_max_lateness_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MaxLatenessObjective { transport, activity, target_time_fn: target_time_fn.clone() })
+        .with_state(MaxLatenessState { target_time_fn, state_keys: vec![MAX_LATENESS_KEY, OTHERS_MAX_LATENESS_KEY] })
+        .build()
+}
+
+/// Creates a feature which minimizes cumulative lateness weighted by job value: a sum of delays of
+/// activity service start after its target time, each multiplied by the value of the job. As result,
+/// high-value jobs are preferred to be served on time at the expense of low-value ones.
+pub fn create_minimize_value_weighted_lateness_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    value_fn: JobValueFn,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(ValueWeightedLatenessObjective { transport, activity, target_time_fn, value_fn })
+        .build()
+}
+
+struct TargetTimeObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    earliness: Cost,
+    lateness: Cost,
+}
+
+impl TargetTimeObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let (service_start, shifted) =
+            estimate_service_starts(route_ctx.route(), activity_ctx, self.activity.as_ref(), self.transport.as_ref());
+        let target_cost = self.get_deviation_cost(activity_ctx.target, service_start);
+
+        let shifted_cost = shifted
+            .into_iter()
+            .map(|(activity, service_start)| {
+                self.get_deviation_cost(activity, service_start)
+                    - self.get_deviation_cost(activity, get_service_start(activity))
+            })
+            .sum::<Cost>();
+
+        target_cost + shifted_cost
+    }
+
+    fn get_deviation_cost(&self, activity: &Activity, service_start: Timestamp) -> Cost {
+        activity.job.as_ref().and_then(|single| (self.target_time_fn)(single)).map_or(Cost::default(), |target| {
+            if service_start < target {
+                (target - service_start) * self.earliness
+            } else {
+                (service_start - target) * self.lateness
+            }
+        })
+    }
+}
+
+impl Objective for TargetTimeObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| self.get_deviation_cost(activity, get_service_start(activity)))
+            .sum()
+    }
+}
+
+impl FeatureObjective for TargetTimeObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct MaxLatenessObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+}
+
+impl MaxLatenessObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let (service_start, shifted) =
+            estimate_service_starts(route_ctx.route(), activity_ctx, self.activity.as_ref(), self.transport.as_ref());
+        let target_lateness = get_lateness(&self.target_time_fn, activity_ctx.target, service_start);
+
+        let state = route_ctx.state();
+        let route_lateness = state.get_route_state::<Cost>(MAX_LATENESS_KEY).copied().unwrap_or_default();
+        let others_lateness = state.get_route_state::<Cost>(OTHERS_MAX_LATENESS_KEY).copied().unwrap_or_default();
+
+        let (old_shifted_lateness, new_shifted_lateness) =
+            shifted.iter().fold((Cost::default(), Cost::default()), |(old, new), &(activity, service_start)| {
+                (
+                    old.max(get_lateness(&self.target_time_fn, activity, get_service_start(activity))),
+                    new.max(get_lateness(&self.target_time_fn, activity, service_start)),
+                )
+            });
+
+        // NOTE when the route's max is reached only by shifted activities, the rest of the route has to be checked
+        let unshifted_lateness = if old_shifted_lateness < route_lateness {
+            route_lateness
+        } else {
+            let shifted_range = (activity_ctx.index + 1)..(activity_ctx.index + 1 + shifted.len());
+            route_ctx
+                .route()
+                .tour
+                .all_activities()
+                .enumerate()
+                .filter(|(idx, _)| !shifted_range.contains(idx))
+                .map(|(_, activity)| get_lateness(&self.target_time_fn, activity, get_service_start(activity)))
+                .fold(Cost::default(), |acc, lateness| acc.max(lateness))
+        };
+
+        let new_route_lateness = target_lateness.max(new_shifted_lateness).max(unshifted_lateness);
+
+        others_lateness.max(new_route_lateness) - others_lateness.max(route_lateness)
+    }
+}
+
+impl Objective for MaxLatenessObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| get_max_lateness(&self.target_time_fn, route_ctx.route()))
+            .fold(Cost::default(), |acc, lateness| acc.max(lateness))
+    }
+}
+
+impl FeatureObjective for MaxLatenessObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct ValueWeightedLatenessObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    value_fn: JobValueFn,
+}
+
+impl ValueWeightedLatenessObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let (service_start, shifted) =
+            estimate_service_starts(route_ctx.route(), activity_ctx, self.activity.as_ref(), self.transport.as_ref());
+        let target_cost = self.get_weighted_lateness(activity_ctx.target, service_start);
+
+        let shifted_cost = shifted
+            .into_iter()
+            .map(|(activity, service_start)| {
+                self.get_weighted_lateness(activity, service_start)
+                    - self.get_weighted_lateness(activity, get_service_start(activity))
+            })
+            .sum::<Cost>();
+
+        target_cost + shifted_cost
+    }
+
+    fn get_weighted_lateness(&self, activity: &Activity, service_start: Timestamp) -> Cost {
+        let lateness = get_lateness(&self.target_time_fn, activity, service_start);
+
+        if lateness > 0. {
+            activity.job.as_ref().map_or(Cost::default(), |single| lateness * (self.value_fn)(single))
+        } else {
+            Cost::default()
+        }
+    }
+}
+
+impl Objective for ValueWeightedLatenessObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| self.get_weighted_lateness(activity, get_service_start(activity)))
+            .sum()
+    }
+}
+
+impl FeatureObjective for ValueWeightedLatenessObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct MaxLatenessState {
+    target_time_fn: TargetTimeFn,
+    state_keys: Vec<StateKey>,
+}
+
+impl MaxLatenessState {
+    /// Stores for each tour the max lateness of all other tours, so the objective can be estimated
+    /// against the whole solution.
+    fn update_others_max_lateness(&self, solution_ctx: &mut SolutionContext) {
+        let put_lateness = |route_ctx: &mut RouteContext, lateness: Cost| {
+            let current = route_ctx.state().get_route_state::<Cost>(OTHERS_MAX_LATENESS_KEY).copied();
+            if current.map_or(true, |current| compare_floats(current, lateness) != Ordering::Equal) {
+                route_ctx.state_mut().put_route_state(OTHERS_MAX_LATENESS_KEY, lateness);
+            }
+        };
+
+        let lateness = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.state().get_route_state::<Cost>(MAX_LATENESS_KEY).copied().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        // NOTE keep the two largest values to exclude the tour's own lateness in constant time
+        let (first, second) = lateness.iter().fold((Cost::default(), Cost::default()), |(first, second), &value| {
+            if value > first {
+                (value, first)
+            } else {
+                (first, second.max(value))
+            }
+        });
+
+        solution_ctx.routes.iter_mut().zip(lateness.iter()).for_each(|(route_ctx, &value)| {
+            put_lateness(route_ctx, if value < first { first } else { second });
+        });
+
+        // NOTE routes available in registry are not used yet, so all used tours are others for them
+        solution_ctx.registry.update_route_states(|state| state.put_route_state(OTHERS_MAX_LATENESS_KEY, first));
+    }
+}
+
+impl FeatureState for MaxLatenessState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_others_max_lateness(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let max_lateness = get_max_lateness(&self.target_time_fn, route_ctx.route());
+        route_ctx.state_mut().put_route_state(MAX_LATENESS_KEY, max_lateness);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        self.update_others_max_lateness(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+struct MaxLateActivitiesConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    target_time_fn: TargetTimeFn,
+    limit_fn: ActivitySizeResolver,
+}
+
+impl MaxLateActivitiesConstraint {
+    /// Estimates change of late activities amount caused by insertion of the target activity.
+    fn estimate_late_change(&self, route: &Route, activity_ctx: &ActivityContext) -> i32 {
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arrival =
+            estimate_arrival(self.transport.as_ref(), route, target, prev.place.location, prev.schedule.departure);
+        let service_start = arrival.max(target.place.time.start);
+        let target_change = is_late(&self.target_time_fn, target, service_start) as i32;
+
+        // NOTE propagate the schedule shift till it is absorbed by waiting time
+        let (_, _, next_change) = route
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .try_fold(
+                (target.place.location, self.activity.estimate_departure(route, target, arrival), 0),
+                |(location, departure, change), activity| {
+                    let arrival = estimate_arrival(self.transport.as_ref(), route, activity, location, departure);
+                    let service_start = arrival.max(activity.place.time.start);
+                    let old_service_start = get_service_start(activity);
+
+                    if service_start <= old_service_start {
+                        return Err((location, departure, change));
+                    }
+
+                    let change = change + is_late(&self.target_time_fn, activity, service_start) as i32
+                        - is_late(&self.target_time_fn, activity, old_service_start) as i32;
+
+                    Ok((activity.place.location, self.activity.estimate_departure(route, activity, arrival), change))
+                },
+            )
+            .unwrap_or_else(|result| result);
+
+        target_change + next_change
+    }
+}
+
+impl FeatureConstraint for MaxLateActivitiesConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+                let change = self.estimate_late_change(route_ctx.route(), activity_ctx);
+
+                if change <= 0 {
+                    return None;
+                }
+
+                let late_activities =
+                    route_ctx.state().get_route_state::<usize>(LATE_ACTIVITIES_KEY).copied().unwrap_or_default();
+
+                if late_activities + change as usize > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct MaxLateActivitiesState {
+    target_time_fn: TargetTimeFn,
+    limit_fn: ActivitySizeResolver,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for MaxLateActivitiesState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let late_activities = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .filter(|activity| is_late(&self.target_time_fn, activity, get_service_start(activity)))
+            .count();
+
+        route_ctx.state_mut().put_route_state(LATE_ACTIVITIES_KEY, late_activities);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn estimate_arrival(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    activity: &Activity,
+    from: Location,
+    departure: Timestamp,
+) -> Timestamp {
+    departure + transport.duration(route, from, activity.place.location, TravelTime::Departure(departure))
+}
+
+fn is_late(target_time_fn: &TargetTimeFn, activity: &Activity, service_start: Timestamp) -> bool {
+    activity.job.as_ref().and_then(|single| (target_time_fn)(single)).map_or(false, |target| service_start > target)
+}
+
+fn get_lateness(target_time_fn: &TargetTimeFn, activity: &Activity, service_start: Timestamp) -> Cost {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| (target_time_fn)(single))
+        .map_or(Cost::default(), |target| (service_start - target).max(0.))
+}
+
+fn get_max_lateness(target_time_fn: &TargetTimeFn, route: &Route) -> Cost {
+    route
+        .tour
+        .all_activities()
+        .map(|activity| get_lateness(target_time_fn, activity, get_service_start(activity)))
+        .fold(Cost::default(), |acc, lateness| acc.max(lateness))
+}
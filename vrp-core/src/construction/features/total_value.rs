@@ -17,6 +17,10 @@ pub type SimpleValueFn = Arc<dyn Fn(&Job) -> f64 + Send + Sync>;
 pub type JobReadValueFn = Either<SimpleValueFn, ActorValueFn>;
 /// Specifies a job write value.
 pub type JobWriteValueFn = Arc<dyn Fn(Job, f64) -> Job + Send + Sync>;
+/// Specifies a job priority tier function: lower tier means higher priority.
+pub type JobTierFn = Arc<dyn Fn(&Job) -> Option<usize> + Send + Sync>;
+/// Specifies a function which maps job priority tier to its value.
+pub type TierValueFn = Arc<dyn Fn(usize) -> f64 + Send + Sync>;
 /// A job value estimation function.
 type EstimateValueFn = Arc<dyn Fn(&RouteContext, &Job) -> f64 + Send + Sync>;
 
@@ -45,6 +49,13 @@ pub fn create_maximize_total_job_value_feature(
         .build()
 }
 
+/// Creates a job value function which derives job value from its priority tier, e.g. `base^(max_tier - tier)`.
+/// Jobs without priority tier have zero value. Can be used as a `JobReadValueFn` of
+/// `create_maximize_total_job_value_feature`.
+pub fn create_priority_tier_value_fn(job_tier_fn: JobTierFn, tier_value_fn: TierValueFn) -> SimpleValueFn {
+    Arc::new(move |job| (job_tier_fn)(job).map_or(0., |tier| (tier_value_fn)(tier)))
+}
+
 struct MaximizeTotalValueObjective {
     estimate_value_fn: EstimateValueFn,
 }
@@ -5,14 +5,29 @@
 mod tour_limits_test;
 
 use super::*;
+use crate::construction::enablers::get_service_start;
+use crate::construction::heuristics::{get_route_cost, get_route_stop_cost, get_total_route_cost};
 use crate::models::common::{Distance, Duration, Timestamp};
-use crate::models::problem::{Actor, TransportCost, TravelTime};
+use crate::models::problem::{ActivityCost, Actor, Single, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
+use crate::utils::CollectGroupBy;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
 
 /// A function which returns activity size limit for given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function which checks whether actor's vehicle is loaded in LIFO (last-in, first-out) order.
+pub type LifoFn = Arc<dyn Fn(&Actor) -> bool + Send + Sync>;
+/// A function to resolve max detour duration of the job.
+pub type DetourLimitFn = Arc<dyn Fn(&Single) -> Option<Duration> + Send + Sync>;
+/// A function to resolve max waiting time of the job.
+pub type WaitLimitFn = Arc<dyn Fn(&Single) -> Option<Duration> + Send + Sync>;
+/// A function which checks whether location is inside of the cordon.
+pub type CordonFn = Arc<dyn Fn(Location) -> bool + Send + Sync>;
+/// A function which checks whether job interrupts the tour, e.g. it is a break or a reload.
+pub type InterruptionFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -50,6 +65,152 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a limit for total route cost which includes fixed, distance and time costs.
+/// This is a hard constraint.
+pub fn create_route_cost_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_cost_limit_fn: TravelLimitFn<Cost>,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RouteCostLimitConstraint { code, transport, route_cost_limit_fn })
+        .build()
+}
+
+/// Creates a limit for total cost of all tours (a fleet cost budget) which includes fixed, distance,
+/// time and stop costs. This is a hard constraint.
+/// NOTE: a budget left for the tour is refreshed on solution level, so it is not checked for tours
+/// which state has been just reset, e.g. within multi job insertion.
+pub fn create_fleet_cost_budget_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    budget: Cost,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(FleetCostBudgetConstraint { code, transport })
+        .with_state(FleetCostBudgetState { budget, state_keys: vec![REMAINING_BUDGET_KEY] })
+        .build()
+}
+
+/// Creates a LIFO (last-in, first-out) loading limit for multi jobs: a multi job can be finished only
+/// when all multi jobs started after it are already finished, so, their intervals in the tour are nested.
+/// This is a hard constraint.
+pub fn create_lifo_multi_jobs_feature(name: &str, code: ViolationCode, lifo_fn: LifoFn) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(LifoMultiJobsConstraint { code, lifo_fn }).build()
+}
+
+/// Creates a limit for route duration increase caused by insertion of job activity. It is checked
+/// only when the job is inserted: later insertions of other jobs are not restricted.
+/// This is a hard constraint.
+pub fn create_detour_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    detour_limit_fn: DetourLimitFn,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(DetourLimitConstraint { code, transport, detour_limit_fn })
+        .build()
+}
+
+/// Creates a limit for waiting time at job activity location till its time window opens. It is checked
+/// only when the job is inserted: later insertions of other jobs are not restricted.
+/// This is a hard constraint.
+pub fn create_wait_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    wait_limit_fn: WaitLimitFn,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(WaitLimitConstraint { code, transport, wait_limit_fn })
+        .build()
+}
+
+/// Creates an objective to minimize total detour: a sum of extra distance caused by each job
+/// activity in comparison to direct travel between its neighbours in the tour. Unlike total
+/// distance minimization, it does not penalize the travel to the first job, so it prefers tightly
+/// nested routes. This is a soft constraint.
+pub fn create_minimize_total_detour_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(TotalDetourObjective { transport }).build()
+}
+
+/// Creates a limit for amount of simultaneously open multi jobs in a tour. A multi job is open
+/// between its first and last activities, e.g. it is picked up, but not yet delivered.
+/// This is a hard constraint.
+pub fn create_open_multi_jobs_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(OpenMultiJobsLimitConstraint { code, limit_fn: limit_fn.clone() })
+        .with_state(OpenMultiJobsLimitState { limit_fn, state_keys: vec![OPEN_MULTI_JOBS_KEY] })
+        .build()
+}
+
+/// Creates a limit for amount of legs crossing the cordon (e.g. a toll zone boundary) in a tour:
+/// a leg crosses the cordon when only one of its ends is inside of it.
+/// This is a hard constraint.
+pub fn create_cordon_crossing_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    cordon_fn: CordonFn,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CordonCrossingLimitConstraint {
+            code,
+            cordon_fn: cordon_fn.clone(),
+            limit_fn: limit_fn.clone(),
+        })
+        .with_state(CordonCrossingLimitState { cordon_fn, limit_fn, state_keys: vec![CORDON_CROSSINGS_KEY] })
+        .build()
+}
+
+/// Creates a limit for elapsed time span of the tour: time between arrival at its first job activity
+/// and departure from its last one. Unlike tour duration limit, it ignores travel from and to depot.
+/// This is a hard constraint.
+pub fn create_shift_span_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    limit_fn: TravelLimitFn<Duration>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ShiftSpanLimitConstraint { code, transport, activity, limit_fn: limit_fn.clone() })
+        .with_state(ShiftSpanLimitState { limit_fn, state_keys: vec![SHIFT_SPAN_KEY] })
+        .build()
+}
+
+/// Creates a limit for total amount of interruptions in a tour, such as breaks and reloads,
+/// regardless of their type.
+/// This is a hard constraint.
+pub fn create_interruption_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    interruption_fn: InterruptionFn,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(InterruptionLimitConstraint { code, interruption_fn, limit_fn })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -83,64 +244,237 @@ impl FeatureConstraint for ActivityLimitConstraint {
     }
 }
 
-struct TravelLimitConstraint {
-    distance_code: ViolationCode,
-    duration_code: ViolationCode,
-    transport: Arc<dyn TransportCost + Send + Sync>,
-    tour_distance_limit_fn: TravelLimitFn<Distance>,
-    tour_duration_limit_fn: TravelLimitFn<Duration>,
+struct OpenMultiJobsLimitConstraint {
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+}
+
+impl FeatureConstraint for OpenMultiJobsLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+                let job = activity_ctx.target.retrieve_job().filter(|job| job.as_multi().is_some())?;
+
+                let tour = &route_ctx.route().tour;
+                let index = activity_ctx.index;
+
+                let mut job_indices = tour
+                    .all_activities()
+                    .enumerate()
+                    .filter(|(_, activity)| activity.has_same_job(&job))
+                    .map(|(idx, _)| idx);
+                let first = job_indices.next();
+                let last = job_indices.next_back().or(first);
+
+                // NOTE the job becomes open (or stays open longer) between its existing activities and the target
+                let (start, end) = match (first, last) {
+                    (Some(_), Some(last)) if index >= last => (last, index),
+                    (Some(first), Some(_)) if index < first => (index, first - 1),
+                    (Some(_), Some(_)) => return None,
+                    _ => (index, index),
+                };
+
+                let max_open_jobs = tour
+                    .activities_slice(start, end)
+                    .iter()
+                    .filter_map(|activity| route_ctx.state().get_activity_state::<usize>(OPEN_MULTI_JOBS_KEY, activity))
+                    .max()
+                    .copied()
+                    .unwrap_or_default();
+
+                if max_open_jobs + 1 > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
 }
 
-impl TravelLimitConstraint {
-    fn calculate_travel(&self, route: &Route, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let prev = activity_ctx.prev;
-        let tar = activity_ctx.target;
-        let next = activity_ctx.next;
+struct CordonCrossingLimitConstraint {
+    code: ViolationCode,
+    cordon_fn: CordonFn,
+    limit_fn: ActivitySizeResolver,
+}
 
-        let prev_dep = prev.schedule.departure;
+impl FeatureConstraint for CordonCrossingLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+
+                let is_crossing = |from: &Activity, to: &Activity| {
+                    usize::from((self.cordon_fn)(from.place.location) != (self.cordon_fn)(to.place.location))
+                };
+
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let (added, removed) = activity_ctx.next.map_or((is_crossing(prev, target), 0), |next| {
+                    (is_crossing(prev, target) + is_crossing(target, next), is_crossing(prev, next))
+                });
 
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(route, prev, tar, prev_dep);
-        if next.is_none() {
-            return (prev_to_tar_dis, prev_to_tar_dur);
+                let crossings =
+                    route_ctx.state().get_route_state::<usize>(CORDON_CROSSINGS_KEY).copied().unwrap_or_default();
+
+                if crossings + added - removed > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
         }
+    }
 
-        let next = next.unwrap();
-        let tar_dep = prev_dep + prev_to_tar_dur;
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE merged job is served at source location, so candidate should be on the same side of cordon
+        let is_inside = |job: &Job| {
+            job.to_single().places.iter().filter_map(|place| place.location).any(|location| (self.cordon_fn)(location))
+        };
 
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(route, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(route, tar, next, tar_dep);
+        match (&source, &candidate) {
+            (Job::Single(_), Job::Single(_)) if is_inside(&source) == is_inside(&candidate) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct ShiftSpanLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    limit_fn: TravelLimitFn<Duration>,
+}
+
+impl ShiftSpanLimitConstraint {
+    /// Estimates tour span after insertion of the target activity.
+    fn estimate_span(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Duration {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let span =
+            route_ctx.state().get_route_state::<Option<(Timestamp, Timestamp)>>(SHIFT_SPAN_KEY).cloned().flatten();
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        // NOTE when previous activity is not a job one, the target becomes the first job activity
+        let first_arrival = match (prev.job.as_ref(), span) {
+            (Some(_), Some((first_arrival, _))) => first_arrival,
+            _ => arrival,
+        };
+
+        // NOTE propagate the schedule shift till it is absorbed by waiting time or job activities end
+        let (_, _, last_departure) = route
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .try_fold((target.place.location, departure, departure), |(location, departure, last), activity| {
+                if activity.job.is_none() {
+                    return Err((location, departure, last));
+                }
 
-        (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        location,
+                        activity.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let service_start = arrival.max(activity.place.time.start);
+                let old_service_start = get_service_start(activity);
+
+                if service_start <= old_service_start {
+                    let last = span.map_or(last, |(_, last_departure)| last_departure.max(last));
+                    return Err((location, departure, last));
+                }
+
+                let departure = self.activity.estimate_departure(route, activity, arrival);
+
+                Ok((activity.place.location, departure, departure))
+            })
+            .unwrap_or_else(|result| result);
+
+        last_departure - first_arrival
     }
+}
 
-    fn calculate_leg_travel_info(
-        &self,
-        route: &Route,
-        first: &Activity,
-        second: &Activity,
-        departure: Timestamp,
-    ) -> (Distance, Duration) {
-        let first_to_second_dis = self.transport.distance(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
-        let first_to_second_dur = self.transport.duration(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
+impl FeatureConstraint for ShiftSpanLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
 
-        let second_arr = departure + first_to_second_dur;
-        let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
+                if self.estimate_span(route_ctx, activity_ctx) > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct InterruptionLimitConstraint {
+    code: ViolationCode,
+    interruption_fn: InterruptionFn,
+    limit_fn: ActivitySizeResolver,
+}
 
-        (first_to_second_dis, second_dep - departure)
+impl FeatureConstraint for InterruptionLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                job.as_single().filter(|single| (self.interruption_fn)(single))?;
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+
+                let interruptions = route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| activity.job.as_ref())
+                    .filter(|single| (self.interruption_fn)(single))
+                    .count();
+
+                if interruptions >= limit {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
     }
 }
 
+struct TravelLimitConstraint {
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    tour_distance_limit_fn: TravelLimitFn<Distance>,
+    tour_duration_limit_fn: TravelLimitFn<Duration>,
+}
+
 impl FeatureConstraint for TravelLimitConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
@@ -150,7 +484,8 @@ impl FeatureConstraint for TravelLimitConstraint {
                 let tour_duration_limit = (self.tour_duration_limit_fn)(route_ctx.route().actor.as_ref());
 
                 if tour_distance_limit.is_some() || tour_duration_limit.is_some() {
-                    let (change_distance, change_duration) = self.calculate_travel(route_ctx.route(), activity_ctx);
+                    let (change_distance, change_duration) =
+                        calculate_travel(self.transport.as_ref(), route_ctx.route(), activity_ctx);
 
                     if let Some(distance_limit) = tour_distance_limit {
                         let curr_dis = route_ctx.state().get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
@@ -179,6 +514,249 @@ impl FeatureConstraint for TravelLimitConstraint {
     }
 }
 
+struct RouteCostLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_cost_limit_fn: TravelLimitFn<Cost>,
+}
+
+impl FeatureConstraint for RouteCostLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                (self.route_cost_limit_fn)(route_ctx.route().actor.as_ref()).and_then(|cost_limit| {
+                    if cost_limit < estimate_route_cost(self.transport.as_ref(), route_ctx, activity_ctx) {
+                        ConstraintViolation::skip(self.code)
+                    } else {
+                        None
+                    }
+                })
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct FleetCostBudgetConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl FeatureConstraint for FleetCostBudgetConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                route_ctx.state().get_route_state::<Cost>(REMAINING_BUDGET_KEY).and_then(|&remaining_budget| {
+                    let route_cost = estimate_route_cost(self.transport.as_ref(), route_ctx, activity_ctx)
+                        + get_route_stop_cost(route_ctx.route());
+
+                    if remaining_budget < route_cost {
+                        ConstraintViolation::skip(self.code)
+                    } else {
+                        None
+                    }
+                })
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct LifoMultiJobsConstraint {
+    code: ViolationCode,
+    lifo_fn: LifoFn,
+}
+
+impl FeatureConstraint for LifoMultiJobsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                if !(self.lifo_fn)(route_ctx.route().actor.as_ref()) {
+                    return None;
+                }
+
+                let job = activity_ctx.target.retrieve_job().filter(|job| job.as_multi().is_some())?;
+
+                // NOTE positions are doubled to put target activity between existing ones
+                let mut intervals = route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .enumerate()
+                    .filter_map(|(idx, activity)| {
+                        activity.retrieve_job().filter(|job| job.as_multi().is_some()).map(|job| (job, idx * 2))
+                    })
+                    .fold(HashMap::<Job, (usize, usize)>::new(), |mut acc, (job, position)| {
+                        acc.entry(job)
+                            .and_modify(|(start, end)| {
+                                *start = (*start).min(position);
+                                *end = (*end).max(position);
+                            })
+                            .or_insert((position, position));
+                        acc
+                    });
+
+                let position = activity_ctx.index * 2 + 1;
+                let (start, end) = intervals
+                    .remove(&job)
+                    .map_or((position, position), |(start, end)| (start.min(position), end.max(position)));
+
+                let is_crossing = intervals.values().any(|&(other_start, other_end)| {
+                    (start < other_start && other_start < end && end < other_end)
+                        || (other_start < start && start < other_end && other_end < end)
+                });
+
+                if is_crossing {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct DetourLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    detour_limit_fn: DetourLimitFn,
+}
+
+impl FeatureConstraint for DetourLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let detour_limit =
+                    activity_ctx.target.job.as_ref().and_then(|single| (self.detour_limit_fn)(single))?;
+                let (_, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx.route(), activity_ctx);
+
+                if change_duration > detour_limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct WaitLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    wait_limit_fn: WaitLimitFn,
+}
+
+impl FeatureConstraint for WaitLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let wait_limit = target.job.as_ref().and_then(|single| (self.wait_limit_fn)(single))?;
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route_ctx.route(),
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let waiting = (target.place.time.start - arrival).max(0.);
+
+                if waiting > wait_limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct TotalDetourObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TotalDetourObjective {
+    /// Returns a detour of the target activity, if it is a job activity.
+    fn get_detour(&self, route: &Route, prev: &Activity, target: &Activity, next: Option<&Activity>) -> Distance {
+        if target.job.is_none() {
+            return Distance::default();
+        }
+
+        let (distance, _) =
+            calculate_travel(self.transport.as_ref(), route, &ActivityContext { index: 0, prev, target, next });
+
+        distance
+    }
+
+    fn get_route_detour(&self, route: &Route) -> Distance {
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        (1..activities.len())
+            .map(|idx| self.get_detour(route, activities[idx - 1], activities[idx], activities.get(idx + 1).copied()))
+            .sum()
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let prev_prev = activity_ctx.index.checked_sub(1).and_then(|idx| route.tour.get(idx));
+        let next_next = route.tour.get(activity_ctx.index + 2);
+
+        let prev_detour = |next: Option<&Activity>| {
+            prev_prev.map_or(Distance::default(), |prev_prev| self.get_detour(route, prev_prev, prev, next))
+        };
+        let next_detour =
+            |prev: &Activity| next.map_or(Distance::default(), |next| self.get_detour(route, prev, next, next_next));
+
+        let old_detour = prev_detour(next) + next_detour(prev);
+        let new_detour = prev_detour(Some(target)) + self.get_detour(route, prev, target, next) + next_detour(target);
+
+        new_detour - old_detour
+    }
+}
+
+impl Objective for TotalDetourObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_detour(route_ctx.route())).sum()
+    }
+}
+
+impl FeatureObjective for TotalDetourObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
 struct TravelLimitState {
     tour_duration_limit_fn: TravelLimitFn<Duration>,
     state_keys: Vec<StateKey>,
@@ -199,3 +777,242 @@ impl FeatureState for TravelLimitState {
         self.state_keys.iter()
     }
 }
+
+struct ShiftSpanLimitState {
+    limit_fn: TravelLimitFn<Duration>,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for ShiftSpanLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let span = route_ctx.route().tour.all_activities().filter(|activity| activity.job.is_some()).fold(
+            None,
+            |span: Option<(Timestamp, Timestamp)>, activity| {
+                let first_arrival = span.map_or(activity.schedule.arrival, |(first_arrival, _)| first_arrival);
+                Some((first_arrival, activity.schedule.departure))
+            },
+        );
+
+        route_ctx.state_mut().put_route_state(SHIFT_SPAN_KEY, span);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+struct FleetCostBudgetState {
+    budget: Cost,
+    state_keys: Vec<StateKey>,
+}
+
+impl FleetCostBudgetState {
+    /// Stores for each tour the budget left after paying for all other tours.
+    fn update_remaining_budgets(&self, solution_ctx: &mut SolutionContext) {
+        let total_cost = solution_ctx.routes.iter().map(get_total_route_cost).sum::<Cost>();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let remaining_budget = self.budget - (total_cost - get_total_route_cost(route_ctx));
+            let current = route_ctx.state().get_route_state::<Cost>(REMAINING_BUDGET_KEY).copied();
+
+            if current.map_or(true, |current| compare_floats(current, remaining_budget) != Ordering::Equal) {
+                route_ctx.state_mut().put_route_state(REMAINING_BUDGET_KEY, remaining_budget);
+            }
+        });
+
+        // NOTE routes available in registry are not used yet, so they can take whatever is left
+        solution_ctx
+            .registry
+            .update_route_states(|state| state.put_route_state(REMAINING_BUDGET_KEY, self.budget - total_cost));
+    }
+}
+
+impl FeatureState for FleetCostBudgetState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_remaining_budgets(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_remaining_budgets(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+struct CordonCrossingLimitState {
+    cordon_fn: CordonFn,
+    limit_fn: ActivitySizeResolver,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for CordonCrossingLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let tour = &route_ctx.route().tour;
+        let crossings = tour
+            .all_activities()
+            .zip(tour.all_activities().skip(1))
+            .filter(|(from, to)| (self.cordon_fn)(from.place.location) != (self.cordon_fn)(to.place.location))
+            .count();
+
+        route_ctx.state_mut().put_route_state(CORDON_CROSSINGS_KEY, crossings);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+struct OpenMultiJobsLimitState {
+    limit_fn: ActivitySizeResolver,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for OpenMultiJobsLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let (route, state) = route_ctx.as_mut();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        // NOTE multi job is open starting from its first activity till its last one (exclusive)
+        let mut changes = vec![0_i32; activities.len() + 1];
+        activities
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, activity)| {
+                activity.retrieve_job().filter(|job| job.as_multi().is_some()).map(|job| (job, idx))
+            })
+            .collect_group_by_key(|(job, _)| job.clone())
+            .into_values()
+            .filter_map(|indices| {
+                let first = indices.first().map(|(_, idx)| *idx)?;
+                let last = indices.last().map(|(_, idx)| *idx)?;
+                Some((first, last))
+            })
+            .filter(|(first, last)| first < last)
+            .for_each(|(first, last)| {
+                changes[first] += 1;
+                changes[last] -= 1;
+            });
+
+        activities.iter().zip(changes.iter()).fold(0_i32, |open_jobs, (activity, change)| {
+            let open_jobs = open_jobs + change;
+            state.put_activity_state(OPEN_MULTI_JOBS_KEY, activity, open_jobs.max(0) as usize);
+            open_jobs
+        });
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Estimates cost of the tour, excluding stop costs, when target activity is inserted.
+fn estimate_route_cost(
+    transport: &(dyn TransportCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> Cost {
+    let (change_distance, change_duration) = calculate_travel(transport, route_ctx.route(), activity_ctx);
+
+    let state = route_ctx.state();
+    let distance = state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.) + change_distance;
+    let duration = state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.) + change_duration;
+
+    get_route_cost(route_ctx.route().actor.as_ref(), distance, duration)
+}
+
+/// Calculates distance and duration change of the tour when target activity is inserted.
+fn calculate_travel(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    activity_ctx: &ActivityContext,
+) -> (Distance, Duration) {
+    let prev = activity_ctx.prev;
+    let tar = activity_ctx.target;
+    let next = activity_ctx.next;
+
+    let prev_dep = prev.schedule.departure;
+
+    let (prev_to_tar_dis, prev_to_tar_dur) = calculate_leg_travel_info(transport, route, prev, tar, prev_dep);
+    if next.is_none() {
+        return (prev_to_tar_dis, prev_to_tar_dur);
+    }
+
+    let next = next.unwrap();
+    let tar_dep = prev_dep + prev_to_tar_dur;
+
+    let (prev_to_next_dis, prev_to_next_dur) = calculate_leg_travel_info(transport, route, prev, next, prev_dep);
+    let (tar_to_next_dis, tar_to_next_dur) = calculate_leg_travel_info(transport, route, tar, next, tar_dep);
+
+    (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+}
+
+fn calculate_leg_travel_info(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    first: &Activity,
+    second: &Activity,
+    departure: Timestamp,
+) -> (Distance, Duration) {
+    let first_to_second_dis =
+        transport.distance(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+    let first_to_second_dur =
+        transport.duration(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+
+    let second_arr = departure + first_to_second_dur;
+    let second_wait = (second.place.time.start - second_arr).max(0.);
+    let second_dep = second_arr + second_wait + second.place.duration;
+
+    (first_to_second_dis, second_dep - departure)
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/tour_overlap_test.rs"]
+mod tour_overlap_test;
+
+use super::*;
+
+/// Specifies a function which returns (x, y) coordinate of the given location, if it is known.
+pub type LocationCoordFn = Arc<dyn Fn(Location) -> Option<(f64, f64)> + Send + Sync>;
+
+/// Creates a feature which tries to keep tours geographically separated by penalizing their
+/// overlap. The overlap is measured as amount of job activities located within a bounding box
+/// of any other tour.
+///
+/// `coord_fn` maps location into a coordinate, locations without coordinate are ignored.
+pub fn create_tour_overlap_feature(
+    name: &str,
+    coord_fn: LocationCoordFn,
+    state_key: StateKey,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(TourOverlapObjective { coord_fn: coord_fn.clone(), state_key })
+        .with_state(TourOverlapState { coord_fn, state_keys: vec![state_key] })
+        .build()
+}
+
+/// A bounding box of tour's job activities.
+#[derive(Clone, Debug, PartialEq)]
+struct BoundingBox {
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl BoundingBox {
+    fn new(point: (f64, f64)) -> Self {
+        Self { min: point, max: point }
+    }
+
+    fn from_points(points: impl Iterator<Item = (f64, f64)>) -> Option<Self> {
+        points.fold(None, |acc: Option<Self>, point| match acc {
+            Some(bbox) => Some(bbox.extend(point)),
+            None => Some(Self::new(point)),
+        })
+    }
+
+    fn extend(&self, (x, y): (f64, f64)) -> Self {
+        Self { min: (self.min.0.min(x), self.min.1.min(y)), max: (self.max.0.max(x), self.max.1.max(y)) }
+    }
+
+    fn contains(&self, (x, y): (f64, f64)) -> bool {
+        x >= self.min.0 && x <= self.max.0 && y >= self.min.1 && y <= self.max.1
+    }
+}
+
+struct TourOverlapObjective {
+    coord_fn: LocationCoordFn,
+    state_key: StateKey,
+}
+
+impl Objective for TourOverlapObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.state.get(&self.state_key).and_then(|s| s.downcast_ref::<Cost>()).copied().unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for TourOverlapObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let job_points = job
+                    .places()
+                    .filter_map(|place| place.location.and_then(|location| (self.coord_fn)(location)))
+                    .collect::<Vec<_>>();
+
+                if job_points.is_empty() {
+                    return Cost::default();
+                }
+
+                let old_bbox = get_bounding_box(route_ctx, self.state_key, &self.coord_fn);
+                let new_bbox = job_points.iter().fold(old_bbox.clone(), |acc, &point| match acc {
+                    Some(bbox) => Some(bbox.extend(point)),
+                    None => Some(BoundingBox::new(point)),
+                });
+
+                let others = solution_ctx.routes.iter().filter(|other| *other != *route_ctx);
+
+                // jobs of other tours which are captured by extended bounding box
+                let captured = others
+                    .clone()
+                    .flat_map(|other| get_job_points(other, &self.coord_fn))
+                    .filter(|&point| {
+                        let was_inside = old_bbox.as_ref().map_or(false, |bbox| bbox.contains(point));
+                        let is_inside = new_bbox.as_ref().map_or(false, |bbox| bbox.contains(point));
+
+                        !was_inside && is_inside
+                    })
+                    .count();
+
+                // job itself located inside bounding boxes of other tours
+                let covered = others
+                    .filter_map(|other| get_bounding_box(other, self.state_key, &self.coord_fn))
+                    .map(|bbox| job_points.iter().filter(|&&point| bbox.contains(point)).count())
+                    .sum::<usize>();
+
+                (captured + covered) as Cost
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct TourOverlapState {
+    coord_fn: LocationCoordFn,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for TourOverlapState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let bbox = BoundingBox::from_points(get_job_points(route_ctx, &self.coord_fn));
+
+        route_ctx.state_mut().put_route_state(self.state_keys[0], bbox);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        let state_key = self.state_keys[0];
+        let boxes = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| get_bounding_box(route_ctx, state_key, &self.coord_fn))
+            .collect::<Vec<_>>();
+
+        let fitness = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .map(|(route_idx, route_ctx)| {
+                get_job_points(route_ctx, &self.coord_fn)
+                    .map(|point| {
+                        boxes
+                            .iter()
+                            .enumerate()
+                            .filter(|(idx, bbox)| {
+                                *idx != route_idx && bbox.as_ref().map_or(false, |bbox| bbox.contains(point))
+                            })
+                            .count()
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>() as Cost;
+
+        solution_ctx.state.insert(state_key, Arc::new(fitness));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_job_points<'a>(
+    route_ctx: &'a RouteContext,
+    coord_fn: &'a LocationCoordFn,
+) -> impl Iterator<Item = (f64, f64)> + Clone + 'a {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .filter_map(move |activity| (coord_fn)(activity.place.location))
+}
+
+fn get_bounding_box(route_ctx: &RouteContext, state_key: StateKey, coord_fn: &LocationCoordFn) -> Option<BoundingBox> {
+    route_ctx
+        .state()
+        .get_route_state::<Option<BoundingBox>>(state_key)
+        .cloned()
+        .unwrap_or_else(|| BoundingBox::from_points(get_job_points(route_ctx, coord_fn)))
+}
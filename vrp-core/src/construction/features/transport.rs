@@ -6,10 +6,14 @@ mod transport_test;
 
 use super::*;
 use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
-use crate::models::common::Timestamp;
-use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::construction::heuristics::is_new_stop;
+use crate::models::common::{Distance, Timestamp};
+use crate::models::problem::{ActivityCost, Single, StopCostDimension, TransportCost, TravelTime};
 use crate::models::solution::Activity;
 
+/// Specifies a function which returns a pay for serving the job.
+pub type JobPayFn = Arc<dyn Fn(&Job) -> Cost + Send + Sync>;
+
 // TODO
 //  remove get_total_cost, get_route_costs, get_max_cost methods from contexts
 //  add validation rule which ensures usage of only one of these methods.
@@ -72,6 +76,27 @@ pub fn create_minimize_distance_feature(
     )
 }
 
+/// Creates a travel costs feature which maximizes earnings as global objective: total pay for served
+/// jobs minus distance costs. It is useful when drivers are paid per served job, not per distance.
+pub fn create_maximize_earnings_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    job_pay_fn: JobPayFn,
+    time_window_code: ViolationCode,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TransportConstraint {
+            code: time_window_code,
+            transport: transport.clone(),
+            activity: activity.clone(),
+        })
+        .with_state(TransportState::new(transport.clone(), activity.clone()))
+        .with_objective(EarningsObjective { transport, activity, job_pay_fn })
+        .build()
+}
+
 fn create_feature(
     name: &str,
     transport: Arc<dyn TransportCost + Send + Sync>,
@@ -253,7 +278,11 @@ impl TransportObjective {
             (0., 0., 0.)
         };
 
-        let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right;
+        let new_costs = tp_cost_left
+            + tp_cost_right
+            + act_cost_left
+            + act_cost_right
+            + self.estimate_stops(route_ctx, activity_ctx);
 
         // no jobs yet or open vrp.
         if !route_ctx.route().tour.has_jobs() || next.is_none() {
@@ -274,6 +303,18 @@ impl TransportObjective {
         new_costs - old_costs
     }
 
+    fn estimate_stops(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        route_ctx.route().actor.vehicle.dimens.get_stop_cost().map_or(Cost::default(), |stop_cost| {
+            let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+            let count = |is_stop: bool| if is_stop { 1. } else { 0. };
+
+            let new_stops = count(is_new_stop(prev, target)) + next.map_or(0., |next| count(is_new_stop(target, next)));
+            let old_stops = next.map_or(0., |next| count(is_new_stop(prev, next)));
+
+            (new_stops - old_stops) * stop_cost
+        })
+    }
+
     fn analyze_route_leg(
         &self,
         route_ctx: &RouteContext,
@@ -312,6 +353,69 @@ impl FeatureObjective for TransportObjective {
     }
 }
 
+struct EarningsObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    job_pay_fn: JobPayFn,
+}
+
+impl EarningsObjective {
+    fn get_distance_cost(route_ctx: &RouteContext, distance: Distance) -> Cost {
+        let actor = route_ctx.route().actor.as_ref();
+
+        distance * (actor.vehicle.costs.per_distance + actor.driver.costs.per_distance)
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let departure = prev.schedule.departure;
+
+        let get_distance = |from: &Activity, to: &Activity, time: Timestamp| {
+            self.transport.distance(route, from.place.location, to.place.location, TravelTime::Departure(time))
+        };
+
+        let left = get_distance(prev, target, departure);
+        let distance = next.map_or(left, |next| {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    prev.place.location,
+                    target.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let target_departure = self.activity.estimate_departure(route, target, arrival);
+
+            left + get_distance(target, next, target_departure) - get_distance(prev, next, departure)
+        });
+
+        Self::get_distance_cost(route_ctx, distance)
+    }
+}
+
+impl Objective for EarningsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().fold(Cost::default(), |acc, route_ctx| {
+            let distance = route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+            let pay = route_ctx.route().tour.jobs().map(|job| (self.job_pay_fn)(&job)).sum::<Cost>();
+
+            acc + Self::get_distance_cost(route_ctx, distance) - pay
+        })
+    }
+}
+
+impl FeatureObjective for EarningsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        // NOTE estimate is a marginal change of fitness: pay is counted once per job, distance per activity
+        match move_ctx {
+            MoveContext::Route { job, .. } => -(self.job_pay_fn)(job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
 struct TransportState {
     schedule_state_keys: ScheduleStateKeys,
     transport: Arc<dyn TransportCost + Send + Sync>,
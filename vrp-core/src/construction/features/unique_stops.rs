@@ -0,0 +1,59 @@
+//! A feature to minimize amount of unique stops served by the fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/unique_stops_test.rs"]
+mod unique_stops_test;
+
+use super::*;
+use crate::models::solution::Route;
+use hashbrown::HashSet;
+
+/// Creates a feature which minimizes amount of unique stops across all tours. A stop is a distinct
+/// job location visited by a vehicle, so multiple visits of the same location by different vehicles
+/// are counted separately. As result, jobs sharing the same location are consolidated onto fewer
+/// vehicles, e.g. to reduce congestion at a shared pickup point.
+pub fn create_minimize_unique_stops_feature(name: &str) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(UniqueStopsObjective {}).build()
+}
+
+struct UniqueStopsObjective {}
+
+impl Objective for UniqueStopsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| count_unique_stops(route_ctx.route())).sum::<usize>() as Cost
+    }
+}
+
+impl FeatureObjective for UniqueStopsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let location = activity_ctx.target.place.location;
+                let is_visited = route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .any(|activity| activity.job.is_some() && activity.place.location == location);
+
+                if is_visited {
+                    Cost::default()
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+}
+
+fn count_unique_stops(route: &Route) -> usize {
+    route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| activity.place.location)
+        .collect::<HashSet<_>>()
+        .len()
+}
@@ -1,7 +1,13 @@
 //! Provides features to balance work.
 
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/work_balance_test.rs"]
+mod work_balance_test;
+
 use super::*;
+use crate::construction::heuristics::{get_total_route_cost, is_new_stop};
 use crate::models::common::{CapacityDimension, LoadOps};
+use crate::models::solution::Route;
 use rosomaxa::algorithms::math::get_cv_safe;
 use std::cmp::Ordering;
 
@@ -12,6 +18,7 @@ pub type LoadBalanceFn<T> = Arc<dyn Fn(&T, &T) -> f64 + Send + Sync>;
 pub fn create_max_load_balanced_feature<T: LoadOps>(
     name: &str,
     threshold: Option<f64>,
+    tolerance: Option<f64>,
     load_balance_fn: LoadBalanceFn<T>,
 ) -> Result<Feature, String> {
     let default_capacity = T::default();
@@ -19,10 +26,7 @@ pub fn create_max_load_balanced_feature<T: LoadOps>(
 
     let get_load_ratio = Arc::new(move |route_ctx: &RouteContext| {
         let capacity = route_ctx.route().actor.vehicle.dimens.get_capacity().unwrap();
-        let intervals = route_ctx
-            .state()
-            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
-            .unwrap_or(&default_intervals);
+        let intervals = route_ctx.marker_intervals(RELOAD_INTERVALS_KEY).unwrap_or(&default_intervals);
 
         intervals
             .iter()
@@ -43,11 +47,15 @@ pub fn create_max_load_balanced_feature<T: LoadOps>(
         get_cv_safe(ctx.routes.iter().map(|route_ctx| get_load_ratio(route_ctx)).collect::<Vec<_>>().as_slice())
     });
 
-    create_feature(name, threshold, BALANCE_MAX_LOAD_KEY, route_estimate_fn, solution_estimate_fn)
+    create_feature(name, threshold, tolerance, BALANCE_MAX_LOAD_KEY, route_estimate_fn, solution_estimate_fn)
 }
 
 /// Creates a feature which balances activities across all tours.
-pub fn create_activity_balanced_feature(name: &str, threshold: Option<f64>) -> Result<Feature, String> {
+pub fn create_activity_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+) -> Result<Feature, String> {
     let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| route_ctx.route().tour.job_activity_count() as f64);
     let solution_estimate_fn = Arc::new(|solution_ctx: &SolutionContext| {
         get_cv_safe(
@@ -60,22 +68,68 @@ pub fn create_activity_balanced_feature(name: &str, threshold: Option<f64>) -> R
         )
     });
 
-    create_feature(name, threshold, BALANCE_ACTIVITY_KEY, route_estimate_fn, solution_estimate_fn)
+    create_feature(name, threshold, tolerance, BALANCE_ACTIVITY_KEY, route_estimate_fn, solution_estimate_fn)
+}
+
+/// Creates a feature which balances amount of stops (physical visits) across all tours.
+/// Unlike activity balancing, activities served at the same location one after another are
+/// counted as a single stop.
+pub fn create_stop_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+) -> Result<Feature, String> {
+    let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| get_stop_count(route_ctx.route()) as f64);
+    let solution_estimate_fn = Arc::new(|solution_ctx: &SolutionContext| {
+        get_cv_safe(
+            solution_ctx
+                .routes
+                .iter()
+                .map(|route_ctx| get_stop_count(route_ctx.route()) as f64)
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )
+    });
+
+    create_feature(name, threshold, tolerance, BALANCE_STOP_KEY, route_estimate_fn, solution_estimate_fn)
+}
+
+/// Creates a feature which balances total cost, including fixed one, across all tours.
+pub fn create_cost_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+) -> Result<Feature, String> {
+    let route_estimate_fn = Arc::new(get_total_route_cost);
+    let solution_estimate_fn = Arc::new(|solution_ctx: &SolutionContext| {
+        get_cv_safe(solution_ctx.routes.iter().map(get_total_route_cost).collect::<Vec<_>>().as_slice())
+    });
+
+    create_feature(name, threshold, tolerance, BALANCE_COST_KEY, route_estimate_fn, solution_estimate_fn)
 }
 
 /// Creates a feature which which balances travelled durations across all tours.
-pub fn create_duration_balanced_feature(name: &str, threshold: Option<f64>) -> Result<Feature, String> {
-    create_transport_balanced_feature(name, threshold, TOTAL_DURATION_KEY, BALANCE_DURATION_KEY)
+pub fn create_duration_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+) -> Result<Feature, String> {
+    create_transport_balanced_feature(name, threshold, tolerance, TOTAL_DURATION_KEY, BALANCE_DURATION_KEY)
 }
 
 /// Creates a feature which which balances travelled distances across all tours.
-pub fn create_distance_balanced_feature(name: &str, threshold: Option<f64>) -> Result<Feature, String> {
-    create_transport_balanced_feature(name, threshold, TOTAL_DISTANCE_KEY, BALANCE_DISTANCE_KEY)
+pub fn create_distance_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+) -> Result<Feature, String> {
+    create_transport_balanced_feature(name, threshold, tolerance, TOTAL_DISTANCE_KEY, BALANCE_DISTANCE_KEY)
 }
 
 fn create_transport_balanced_feature(
     name: &str,
     threshold: Option<f64>,
+    tolerance: Option<f64>,
     value_key: i32,
     state_key: i32,
 ) -> Result<Feature, String> {
@@ -93,12 +147,22 @@ fn create_transport_balanced_feature(
         )
     });
 
-    create_feature(name, threshold, state_key, route_estimate_fn, solution_estimate_fn)
+    create_feature(name, threshold, tolerance, state_key, route_estimate_fn, solution_estimate_fn)
+}
+
+fn get_stop_count(route: &Route) -> usize {
+    route
+        .tour
+        .all_activities()
+        .zip(route.tour.all_activities().skip(1))
+        .filter(|(prev, next)| is_new_stop(prev, next))
+        .count()
 }
 
 fn create_feature(
     name: &str,
     threshold: Option<f64>,
+    tolerance: Option<f64>,
     state_key: StateKey,
     route_estimate_fn: Arc<dyn Fn(&RouteContext) -> f64 + Send + Sync>,
     solution_estimate_fn: Arc<dyn Fn(&SolutionContext) -> f64 + Send + Sync>,
@@ -107,6 +171,7 @@ fn create_feature(
         .with_name(name)
         .with_objective(WorkBalanceObjective {
             threshold,
+            tolerance,
             state_key,
             route_estimate_fn: route_estimate_fn.clone(),
             solution_estimate_fn: solution_estimate_fn.clone(),
@@ -122,6 +187,7 @@ fn create_feature(
 
 struct WorkBalanceObjective {
     threshold: Option<f64>,
+    tolerance: Option<f64>,
     state_key: StateKey,
     route_estimate_fn: Arc<dyn Fn(&RouteContext) -> f64 + Send + Sync>,
     solution_estimate_fn: Arc<dyn Fn(&SolutionContext) -> f64 + Send + Sync>,
@@ -134,6 +200,11 @@ impl Objective for WorkBalanceObjective {
         let fitness_a = self.fitness(a);
         let fitness_b = self.fitness(b);
 
+        // NOTE tolerance is checked first to avoid ordering churn caused by tiny fitness differences
+        if self.tolerance.map_or(false, |tolerance| (fitness_a - fitness_b).abs() <= tolerance) {
+            return Ordering::Equal;
+        }
+
         if let Some(threshold) = self.threshold {
             if fitness_a < threshold && fitness_b < threshold {
                 return Ordering::Equal;
@@ -4,10 +4,10 @@ mod context_test;
 
 use crate::construction::features::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, Timestamp};
 use crate::models::problem::*;
 use crate::models::solution::*;
-use crate::models::GoalContext;
+use crate::models::{GoalContext, StateKey, ViolationCode};
 use crate::models::{Problem, Solution};
 use crate::utils::short_type_name;
 use hashbrown::{HashMap, HashSet};
@@ -16,7 +16,7 @@ use rosomaxa::prelude::*;
 use rustc_hash::FxHasher;
 use std::any::Any;
 use std::fmt::{Debug, Formatter};
-use std::hash::BuildHasherDefault;
+use std::hash::{BuildHasherDefault, Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -55,6 +55,17 @@ impl InsertionContext {
         ctx
     }
 
+    /// Creates insertion context from existing one where activities which are served or started
+    /// before cutoff time are locked, so only the rest of the routes and unassigned jobs are
+    /// optimized further (e.g. for re-planning after real time disruptions).
+    pub fn new_with_cutoff(
+        insertion_ctx: &InsertionContext,
+        cutoff: Timestamp,
+        code: ViolationCode,
+    ) -> Result<Self, String> {
+        create_insertion_context_with_cutoff(insertion_ctx, cutoff, code)
+    }
+
     /// Restores valid context state.
     pub fn restore(&mut self) {
         self.problem.goal.accept_solution_state(&mut self.solution);
@@ -74,6 +85,32 @@ impl HeuristicSolution for InsertionContext {
             environment: self.environment.clone(),
         }
     }
+
+    fn fingerprint(&self) -> u64 {
+        // NOTE routes are hashed independently to make fingerprint insensitive to their order
+        let mut route_fingerprints = self
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let route = route_ctx.route();
+                let mut hasher = FxHasher::default();
+
+                Arc::as_ptr(&route.actor).hash(&mut hasher);
+                route.tour.all_activities().filter_map(|activity| activity.retrieve_job()).for_each(|job| {
+                    job.hash(&mut hasher);
+                });
+
+                hasher.finish()
+            })
+            .collect::<Vec<_>>();
+        route_fingerprints.sort_unstable();
+
+        let mut hasher = FxHasher::default();
+        route_fingerprints.hash(&mut hasher);
+
+        hasher.finish()
+    }
 }
 
 impl Debug for InsertionContext {
@@ -126,24 +163,7 @@ pub struct SolutionContext {
 impl SolutionContext {
     /// Gets total cost of the solution.
     pub fn get_total_cost(&self) -> Cost {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
-        };
-
-        self.routes.iter().fold(Cost::default(), |acc, route_ctx| {
-            let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
-            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
-
-            acc + get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
-        })
+        self.routes.iter().fold(Cost::default(), |acc, route_ctx| acc + get_total_route_cost(route_ctx))
     }
 
     /// Returns amount of jobs considered by solution context.
@@ -187,6 +207,54 @@ impl SolutionContext {
     }
 }
 
+/// Returns route cost for given actor, route distance and duration: it includes fixed, distance
+/// and time costs of both vehicle and driver.
+/// Vehicle distance cost is evaluated using its distance cost tiers, if they are specified.
+pub fn get_route_cost(actor: &Actor, distance: f64, duration: f64) -> Cost {
+    let get_cost = |costs: &Costs, distance_cost: Cost| {
+        costs.fixed
+            + distance_cost
+            // NOTE this is incorrect when timing costs are different: fitness value will be
+            // different from actual cost. However we accept this so far as it is simpler for
+            // implementation and pragmatic format does not expose this feature
+            // .
+            // TODO calculate actual cost
+            + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+    };
+
+    get_cost(&actor.vehicle.costs, get_vehicle_distance_cost(actor.vehicle.as_ref(), distance))
+        + get_cost(&actor.driver.costs, actor.driver.costs.per_distance * distance)
+}
+
+/// Returns a total stop cost of the route: vehicle's stop cost is applied for each job activity
+/// which location is different from the previous activity one.
+pub fn get_route_stop_cost(route: &Route) -> Cost {
+    route.actor.vehicle.dimens.get_stop_cost().map_or(Cost::default(), |stop_cost| {
+        let stops = route
+            .tour
+            .all_activities()
+            .zip(route.tour.all_activities().skip(1))
+            .filter(|(prev, next)| is_new_stop(prev, next))
+            .count();
+
+        stops as Cost * stop_cost
+    })
+}
+
+/// Gets total cost of the route which includes fixed, distance, time and stop costs.
+pub(crate) fn get_total_route_cost(route_ctx: &RouteContext) -> Cost {
+    let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+    let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+
+    get_route_cost(route_ctx.route.actor.as_ref(), distance, duration) + get_route_stop_cost(&route_ctx.route)
+}
+
+/// Checks whether the next activity starts a new stop: it is a job activity at a location
+/// different from the previous activity one.
+pub(crate) fn is_new_stop(prev: &Activity, next: &Activity) -> bool {
+    next.job.is_some() && prev.place.location != next.place.location
+}
+
 impl Debug for SolutionContext {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(short_type_name::<Self>())
@@ -290,6 +358,13 @@ impl RouteContext {
         &mut self.state
     }
 
+    /// Returns marker intervals (e.g. reloads or recharges) stored in route state under given key.
+    /// Each interval is a pair of start and end activity indices of the tour segment.
+    /// Returns `None` when the feature which maintains the intervals is not active.
+    pub fn marker_intervals(&self, key: StateKey) -> Option<&Vec<(usize, usize)>> {
+        self.state.get_route_state::<Vec<(usize, usize)>>(key)
+    }
+
     /// Returns true if context is stale. Context is marked stale when it is accessed by `mut`
     /// methods. A general motivation of the flag is to avoid recalculating non-changed states.
     pub fn is_stale(&self) -> bool {
@@ -511,6 +586,12 @@ impl RegistryContext {
         self.registry.available().find(|a| actor == a.as_ref()).and_then(|a| self.index.get(&a))
     }
 
+    /// Updates states of all routes kept in the registry. These routes are not used yet, so they
+    /// are not marked as stale.
+    pub(crate) fn update_route_states(&mut self, update_fn: impl Fn(&mut RouteState)) {
+        self.index.values_mut().for_each(|route_ctx| update_fn(&mut route_ctx.state));
+    }
+
     /// Gets route for given actor and marks it as used.
     /// Returns None if actor is already in use.
     /// NOTE: you need to call free route to make it to be available again.
@@ -1,14 +1,19 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/factories_test.rs"]
+mod factories_test;
+
 use super::*;
-use crate::models::common::{Cost, Schedule};
+use crate::construction::features::create_locked_jobs_feature;
+use crate::models::common::{Cost, Schedule, TimeSpan, Timestamp};
 use crate::models::problem::*;
 use crate::models::solution::*;
-use crate::models::OP_START_MSG;
-use crate::models::{LockOrder, Problem, Solution};
+use crate::models::{Lock, LockDetail, LockOrder, LockPosition, Problem, Solution, ViolationCode, OP_START_MSG};
 use hashbrown::{HashMap, HashSet};
 use rosomaxa::prelude::Environment;
 use std::sync::Arc;
 
 type ActivityPlace = crate::models::solution::Place;
+type JobPlace = crate::models::problem::Place;
 
 /// Creates insertion context from existing solution.
 pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environment>) -> InsertionContext {
@@ -160,6 +165,82 @@ pub fn create_insertion_context_from_solution(
     insertion_ctx
 }
 
+/// Creates insertion context from existing one where activities which are served or started
+/// before cutoff time are locked to their routes. Served jobs with multiple places or time windows
+/// are replaced with their copies which have only the place and time window used by the activity.
+pub fn create_insertion_context_with_cutoff(
+    insertion_ctx: &InsertionContext,
+    cutoff: Timestamp,
+    code: ViolationCode,
+) -> Result<InsertionContext, String> {
+    let original_problem = insertion_ctx.problem.as_ref();
+    let mut solution: Solution = insertion_ctx.solution.deep_copy().into();
+    let mut replacements: HashMap<Job, Job> = HashMap::new();
+
+    let locks = solution.routes.iter_mut().try_fold(Vec::new(), |mut acc, route| {
+        let jobs = get_served_jobs(route, cutoff)
+            .into_iter()
+            .map(|job| {
+                if let Some(fixed_job) = replacements.get(&job) {
+                    return fixed_job.clone();
+                }
+
+                match create_fixed_job(route, &job) {
+                    Some(fixed_job) => {
+                        replacements.insert(job, fixed_job.clone());
+                        fixed_job
+                    }
+                    None => job,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !jobs.is_empty() {
+            route.tour = replace_tour_jobs(&route.tour, &replacements);
+
+            let actor = route.actor.clone();
+            acc.push(Arc::new(Lock::new(
+                Arc::new(move |other: &Actor| *other == *actor),
+                vec![LockDetail::new(LockOrder::Strict, LockPosition::Departure, jobs)],
+                false,
+            )));
+        }
+
+        Ok::<_, String>(acc)
+    })?;
+
+    let mut goal = original_problem.goal.as_ref().clone();
+    goal.constraints.extend(
+        create_locked_jobs_feature("cutoff_locked_jobs", original_problem.fleet.as_ref(), locks.as_slice(), code)?
+            .constraint,
+    );
+
+    let replace_job = |job: Job| replacements.get(&job).cloned().unwrap_or(job);
+    let jobs = if replacements.is_empty() {
+        original_problem.jobs.clone()
+    } else {
+        let jobs = original_problem.jobs.all().map(replace_job).collect();
+        Arc::new(Jobs::new(original_problem.fleet.as_ref(), jobs, &original_problem.transport))
+    };
+
+    let problem = Arc::new(Problem {
+        fleet: original_problem.fleet.clone(),
+        jobs,
+        locks: original_problem
+            .locks
+            .iter()
+            .map(|lock| replace_lock_jobs(lock, &replace_job))
+            .chain(locks.iter().cloned())
+            .collect(),
+        goal: Arc::new(goal),
+        activity: original_problem.activity.clone(),
+        transport: original_problem.transport.clone(),
+        extras: original_problem.extras.clone(),
+    });
+
+    Ok(InsertionContext::new_from_solution(problem, (solution, None), insertion_ctx.environment.clone()))
+}
+
 /// Creates an empty insertion context.
 pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<Environment>) -> InsertionContext {
     InsertionContext {
@@ -180,6 +261,117 @@ pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<En
     }
 }
 
+/// Returns jobs of activities started before cutoff. If a multi job is partially served, the prefix is
+/// extended to its last activity, so the multi job is not split by a lock.
+fn get_served_jobs(route: &Route, cutoff: Timestamp) -> Vec<Job> {
+    let activities = route.tour.all_activities().filter(|activity| activity.job.is_some()).collect::<Vec<_>>();
+    let mut size = activities.iter().take_while(|activity| activity.schedule.arrival <= cutoff).count();
+
+    let mut idx = 0;
+    while idx < size {
+        if let Some(job @ Job::Multi(_)) = activities[idx].retrieve_job() {
+            let last_idx = activities.iter().rposition(|activity| activity.retrieve_job().as_ref() == Some(&job));
+            size = size.max(last_idx.map_or(0, |last_idx| last_idx + 1));
+        }
+        idx += 1;
+    }
+
+    activities.iter().take(size).map(|activity| activity.retrieve_job().expect("activity should have a job")).collect()
+}
+
+/// Creates a copy of the job which has only place and time window used by its activities in the route.
+/// Returns None when the job has already a single place with a single time window.
+fn create_fixed_job(route: &Route, job: &Job) -> Option<Job> {
+    let is_fixed = |single: &Single| {
+        single.places.len() == 1
+            && single.places.first().map_or(false, |place| {
+                place.times.len() == 1 && place.times.first().and_then(|time| time.as_time_window()).is_some()
+            })
+    };
+
+    let create_fixed_single = |single: &Arc<Single>| {
+        let activity = route
+            .tour
+            .all_activities()
+            .find(|activity| activity.job.as_ref().map_or(false, |other| Arc::ptr_eq(other, single)))
+            .expect("served job should have activity in the route");
+
+        Arc::new(Single {
+            places: vec![JobPlace {
+                location: Some(activity.place.location),
+                duration: activity.place.duration,
+                times: vec![TimeSpan::Window(activity.place.time.clone())],
+            }],
+            dimens: single.dimens.clone(),
+        })
+    };
+
+    match job {
+        Job::Single(single) if !is_fixed(single) => Some(Job::Single(create_fixed_single(single))),
+        Job::Multi(multi) if !multi.jobs.iter().all(|single| is_fixed(single)) => {
+            // NOTE keep sub jobs in the order they are served as locks rely on it
+            let singles = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref())
+                .filter(|single| multi.jobs.iter().any(|other| Arc::ptr_eq(other, single)))
+                .map(create_fixed_single)
+                .collect();
+
+            Some(Job::Multi(Multi::new_shared(singles, multi.dimens.clone())))
+        }
+        _ => None,
+    }
+}
+
+/// Creates a copy of the tour where activities refer to replaced jobs.
+fn replace_tour_jobs(tour: &Tour, replacements: &HashMap<Job, Job>) -> Tour {
+    let mut sequence_job_usage: HashMap<Job, usize> = Default::default();
+    let mut new_tour = Tour::default();
+
+    tour.all_activities().for_each(|activity| {
+        let job =
+            activity.job.as_ref().map(|single| match activity.retrieve_job().and_then(|job| replacements.get(&job)) {
+                Some(Job::Single(fixed)) => fixed.clone(),
+                Some(job @ Job::Multi(fixed)) => {
+                    // NOTE sub jobs of replaced multi job are kept in the order they are served
+                    let idx = sequence_job_usage.get(job).cloned().unwrap_or(0);
+                    sequence_job_usage.insert(job.clone(), idx + 1);
+                    fixed.jobs.get(idx).cloned().expect("invalid multi job")
+                }
+                None => single.clone(),
+            });
+        let activity = Activity { job, ..activity.deep_copy() };
+
+        if activity.job.is_some() {
+            new_tour.insert_last(activity);
+        } else if new_tour.total() == 0 {
+            new_tour.set_start(activity);
+        } else {
+            new_tour.set_end(activity);
+        }
+    });
+
+    new_tour
+}
+
+fn replace_lock_jobs(lock: &Arc<Lock>, replace_job: &dyn Fn(Job) -> Job) -> Arc<Lock> {
+    Arc::new(Lock::new(
+        lock.condition_fn.clone(),
+        lock.details
+            .iter()
+            .map(|detail| {
+                LockDetail::new(
+                    detail.order.clone(),
+                    detail.position.clone(),
+                    detail.jobs.iter().cloned().map(replace_job).collect(),
+                )
+            })
+            .collect(),
+        lock.is_lazy,
+    ))
+}
+
 fn create_registry_context(problem: &Problem, registry: Registry) -> RegistryContext {
     let modifier = problem.extras.get("route_modifier").and_then(|s| s.downcast_ref::<RouteModifier>());
     let constraint = problem.goal.clone();
@@ -11,6 +11,7 @@ use std::iter::Sum;
 use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
+const COMPARTMENT_CAPACITY_DIMENSION_KEY: &str = "ccp";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
@@ -36,6 +37,12 @@ where
 {
 }
 
+/// Allows to scale load, e.g. to extend vehicle capacity by allowed overflow.
+pub trait ScalableLoad: LoadOps {
+    /// Returns load scaled by given factor and rounded down to whole units.
+    fn scale(&self, factor: f64) -> Self;
+}
+
 /// Represents job demand, both static and dynamic.
 pub struct Demand<T: LoadOps> {
     /// Keeps static and dynamic pickup amount.
@@ -52,6 +59,14 @@ pub trait CapacityDimension<T: LoadOps> {
     fn get_capacity(&self) -> Option<&T>;
 }
 
+/// A trait to get or set capacities of vehicle's compartments.
+pub trait CompartmentCapacityDimension<T: LoadOps> {
+    /// Sets compartment capacities.
+    fn set_compartment_capacities(&mut self, capacities: Vec<T>) -> &mut Self;
+    /// Gets compartment capacities.
+    fn get_compartment_capacities(&self) -> Option<&Vec<T>>;
+}
+
 /// A trait to get or set demand.
 pub trait DemandDimension<T: LoadOps> {
     /// Sets demand.
@@ -101,6 +116,17 @@ impl<T: LoadOps> CapacityDimension<T> for Dimensions {
     }
 }
 
+impl<T: LoadOps> CompartmentCapacityDimension<T> for Dimensions {
+    fn set_compartment_capacities(&mut self, capacities: Vec<T>) -> &mut Self {
+        self.set_value(COMPARTMENT_CAPACITY_DIMENSION_KEY, capacities);
+        self
+    }
+
+    fn get_compartment_capacities(&self) -> Option<&Vec<T>> {
+        self.get_value(COMPARTMENT_CAPACITY_DIMENSION_KEY)
+    }
+}
+
 impl<T: LoadOps> DemandDimension<T> for Dimensions {
     fn set_demand(&mut self, demand: Demand<T>) -> &mut Self {
         self.set_value(DEMAND_DIMENSION_KEY, demand);
@@ -128,6 +154,12 @@ impl SingleDimLoad {
 
 impl LoadOps for SingleDimLoad {}
 
+impl ScalableLoad for SingleDimLoad {
+    fn scale(&self, factor: f64) -> Self {
+        Self::new((self.value as f64 * factor).floor() as i32)
+    }
+}
+
 impl Load for SingleDimLoad {
     fn is_not_empty(&self) -> bool {
         self.value != 0
@@ -258,6 +290,18 @@ impl Load for MultiDimLoad {
 
 impl LoadOps for MultiDimLoad {}
 
+impl ScalableLoad for MultiDimLoad {
+    fn scale(&self, factor: f64) -> Self {
+        let mut dimens = *self;
+
+        dimens.load.iter_mut().for_each(|item| {
+            *item = (*item as f64 * factor).floor() as i32;
+        });
+
+        dimens
+    }
+}
+
 impl Default for MultiDimLoad {
     fn default() -> Self {
         Self { load: [0; LOAD_DIMENSION_SIZE], size: 0 }
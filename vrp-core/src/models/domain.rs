@@ -61,6 +61,7 @@ pub struct Solution {
 }
 
 /// An enumeration which specifies how jobs should be ordered in tour.
+#[derive(Clone)]
 pub enum LockOrder {
     /// Jobs can be reshuffled in any order.
     Any,
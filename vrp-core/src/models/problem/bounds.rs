@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/bounds_test.rs"]
+mod bounds_test;
+
+use crate::models::common::*;
+use crate::models::problem::{Costs, DistanceCostTiersDimension, Job, Place, Single};
+use crate::models::solution::{Activity, Route, Tour};
+use crate::models::Problem;
+use hashbrown::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Returns a lower bound of the total cost required to serve given jobs. Can be used to estimate
+/// an optimality gap of the found solution: jobs are expected to be the ones served by it.
+///
+/// The bound is a sum of:
+/// - a minimum fixed cost of vehicle usage;
+/// - a minimum cost to reach each job activity from any other location plus its minimum service cost;
+/// - a minimum cost to return from any job location when all vehicles have to return.
+///
+/// Time windows, waiting time and other constraints are ignored, so the bound is not tight.
+pub fn get_cost_lower_bound(problem: &Problem, jobs: &[Job]) -> Cost {
+    let singles = jobs
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.iter().collect(),
+        })
+        .collect::<Vec<_>>();
+
+    if singles.is_empty() {
+        return Cost::default();
+    }
+
+    let fleet = problem.fleet.as_ref();
+    let driver_costs = get_min_costs(fleet.drivers.iter().map(|driver| &driver.costs));
+    let vehicle_costs = get_min_costs(fleet.vehicles.iter().map(|vehicle| &vehicle.costs));
+
+    // NOTE use the cheapest distance and time costs per routing profile
+    let profiles = fleet.vehicles.iter().fold(Vec::<(Profile, Cost, Cost)>::new(), |mut acc, vehicle| {
+        let vehicle_per_distance = vehicle
+            .dimens
+            .get_distance_cost_tiers()
+            .map_or(vehicle.costs.per_distance, |tiers| tiers.min_rate(vehicle.costs.per_distance));
+        let per_distance = vehicle_per_distance + driver_costs.per_distance;
+        let per_time = vehicle.costs.per_driving_time + driver_costs.per_driving_time;
+
+        match acc
+            .iter_mut()
+            .find(|(profile, _, _)| profile.index == vehicle.profile.index && profile.scale == vehicle.profile.scale)
+        {
+            Some((_, distance_cost, time_cost)) => {
+                *distance_cost = distance_cost.min(per_distance);
+                *time_cost = time_cost.min(per_time);
+            }
+            None => acc.push((vehicle.profile.clone(), per_distance, per_time)),
+        }
+
+        acc
+    });
+
+    let transport = problem.transport.as_ref();
+    let get_travel_cost = |from: Location, to: Location| {
+        profiles
+            .iter()
+            .map(|(profile, per_distance, per_time)| {
+                transport.distance_approx(profile, from, to) * per_distance
+                    + transport.duration_approx(profile, from, to) * per_time
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    };
+
+    let details = fleet.vehicles.iter().flat_map(|vehicle| vehicle.details.iter());
+    let starts =
+        details.clone().filter_map(|detail| detail.start.as_ref().map(|start| start.location)).collect::<HashSet<_>>();
+    let ends = details.map(|detail| detail.end.as_ref().map(|end| end.location)).collect::<Option<Vec<_>>>();
+
+    let location_usage = get_location_usage(singles.as_slice());
+    let locations = location_usage.keys().cloned().chain(starts.iter().cloned()).collect::<HashSet<_>>();
+
+    let get_arrival_cost = |location: Location| {
+        // NOTE location shared by several job activities or by vehicle start can be reached for free
+        if starts.contains(&location) || location_usage.get(&location).map_or(false, |usage| *usage > 1) {
+            return Cost::default();
+        }
+
+        locations
+            .iter()
+            .filter(|&&other| other != location)
+            .map(|&other| get_travel_cost(other, location))
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    };
+
+    // NOTE service cost is estimated using activity costs as service time can depend on actor
+    let activity_cost = problem.activity.as_ref();
+    let routes =
+        fleet.actors.iter().map(|actor| Route { actor: actor.clone(), tour: Tour::new(actor) }).collect::<Vec<_>>();
+    let get_service_cost = |single: &Arc<Single>, place: &Place| {
+        let mut activity = Activity::new_with_job(single.clone());
+        activity.place.duration = place.duration;
+
+        routes
+            .iter()
+            .map(|route| activity_cost.cost(route, &activity, activity.place.time.start))
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    };
+
+    let activities_cost = singles
+        .iter()
+        .map(|single| {
+            single
+                .places
+                .iter()
+                .map(|place| place.location.map_or(0., get_arrival_cost) + get_service_cost(single, place))
+                .min_by(|a, b| a.total_cmp(b))
+                .unwrap_or_default()
+        })
+        .sum::<Cost>();
+
+    let return_cost = ends.map_or(Cost::default(), |ends| {
+        singles
+            .iter()
+            .flat_map(|single| single.places.iter().map(|place| place.location))
+            .flat_map(|location| {
+                ends.iter().map(move |&end| location.map_or(0., |location| get_travel_cost(location, end)))
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default()
+    });
+
+    driver_costs.fixed + vehicle_costs.fixed + activities_cost + return_cost
+}
+
+fn get_min_costs<'a>(costs: impl Iterator<Item = &'a Costs>) -> Costs {
+    costs
+        .fold(None, |acc: Option<Costs>, costs| {
+            Some(acc.map_or_else(
+                || costs.clone(),
+                |acc| Costs {
+                    fixed: acc.fixed.min(costs.fixed),
+                    per_distance: acc.per_distance.min(costs.per_distance),
+                    per_driving_time: acc.per_driving_time.min(costs.per_driving_time),
+                    per_waiting_time: acc.per_waiting_time.min(costs.per_waiting_time),
+                    per_service_time: acc.per_service_time.min(costs.per_service_time),
+                },
+            ))
+        })
+        .unwrap_or(Costs {
+            fixed: 0.,
+            per_distance: 0.,
+            per_driving_time: 0.,
+            per_waiting_time: 0.,
+            per_service_time: 0.,
+        })
+}
+
+/// Returns amount of different jobs which have a place at the given location.
+fn get_location_usage(singles: &[&Arc<Single>]) -> HashMap<Location, usize> {
+    singles.iter().fold(HashMap::new(), |mut acc, single| {
+        single
+            .places
+            .iter()
+            .filter_map(|place| place.location)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .for_each(|location| *acc.entry(location).or_insert(0) += 1);
+
+        acc
+    })
+}
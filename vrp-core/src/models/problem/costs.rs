@@ -183,6 +183,226 @@ impl TransportCost for DynamicTransportCost {
     }
 }
 
+/// Specifies an index of time windows when transition between two locations can be started.
+pub type TravelGatesIndex = HashMap<(Location, Location), Vec<TimeWindow>>;
+
+/// Provides the way to calculate transport costs when some transitions can be started only within
+/// specific time windows (e.g. drawbridge or ferry schedule). When departure happens outside of
+/// open time window, waiting time till the next opening is added to travel duration. When there is
+/// no suitable time window, the transition is considered as unreachable (negative distance and duration).
+pub struct GatedTransportCost {
+    gates: TravelGatesIndex,
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl GatedTransportCost {
+    /// Creates a new instance of `GatedTransportCost`.
+    pub fn new(gates: TravelGatesIndex, inner: Arc<dyn TransportCost + Send + Sync>) -> Result<Self, String> {
+        let gates = gates
+            .into_iter()
+            .map(|(edge, mut times)| {
+                if times.iter().any(|tw| tw.start > tw.end) {
+                    return Err(format!("invalid travel gate time window for edge: {edge:?}"));
+                }
+
+                times.sort_by(|a, b| compare_floats(a.start, b.start));
+
+                Ok((edge, times))
+            })
+            .collect::<Result<TravelGatesIndex, _>>()?;
+
+        Ok(Self { gates, inner })
+    }
+
+    /// Returns departure time for gated transition or `None` if transition is not possible.
+    fn get_departure(&self, times: &[TimeWindow], duration: Duration, travel_time: TravelTime) -> Option<Timestamp> {
+        match travel_time {
+            TravelTime::Departure(departure) => {
+                times.iter().find(|tw| tw.end >= departure).map(|tw| departure.max(tw.start))
+            }
+            TravelTime::Arrival(arrival) => {
+                let departure = arrival - duration;
+                times.iter().rev().find(|tw| tw.start <= departure).map(|tw| departure.min(tw.end))
+            }
+        }
+    }
+}
+
+impl TransportCost for GatedTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let duration = self.inner.duration(route, from, to, travel_time);
+
+        match self.gates.get(&(from, to)) {
+            Some(times) if duration >= 0. => match (self.get_departure(times, duration, travel_time), travel_time) {
+                (Some(departure), TravelTime::Departure(time)) => departure - time + duration,
+                (Some(departure), TravelTime::Arrival(time)) => time - departure,
+                (None, _) => -1.,
+            },
+            _ => duration,
+        }
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        let distance = self.inner.distance(route, from, to, travel_time);
+
+        match self.gates.get(&(from, to)) {
+            Some(times) if distance >= 0. => {
+                let duration = self.inner.duration(route, from, to, travel_time);
+                self.get_departure(times, duration, travel_time).map_or(-1., |_| distance)
+            }
+            _ => distance,
+        }
+    }
+}
+
+/// A transport cost which selects one of inner transport costs (e.g. matrices for different time of
+/// the day) depending on travel time. Each bucket is active from the previous boundary (inclusive)
+/// till the next one (exclusive), time independent (approximate) costs use the first bucket.
+pub struct TimeDependentTransportCost {
+    boundaries: Vec<Timestamp>,
+    buckets: Vec<Arc<dyn TransportCost + Send + Sync>>,
+}
+
+impl TimeDependentTransportCost {
+    /// Creates a new instance of `TimeDependentTransportCost`. Boundaries should be sorted and their
+    /// amount should be one less than amount of buckets.
+    pub fn new(boundaries: Vec<Timestamp>, buckets: Vec<Arc<dyn TransportCost + Send + Sync>>) -> Result<Self, String> {
+        if buckets.is_empty() || boundaries.len() + 1 != buckets.len() {
+            return Err("amount of time buckets should be one more than amount of boundaries".to_string());
+        }
+
+        if boundaries.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("time bucket boundaries should be sorted in ascending order".to_string());
+        }
+
+        Ok(Self { boundaries, buckets })
+    }
+
+    fn get_bucket(&self, travel_time: TravelTime) -> &(dyn TransportCost + Send + Sync) {
+        let timestamp = match travel_time {
+            TravelTime::Arrival(arrival) => arrival,
+            TravelTime::Departure(departure) => departure,
+        };
+
+        let idx = self.boundaries.partition_point(|&boundary| boundary <= timestamp);
+
+        self.buckets[idx].as_ref()
+    }
+}
+
+impl TransportCost for TimeDependentTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.buckets[0].duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.buckets[0].distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.get_bucket(travel_time).duration(route, from, to, travel_time)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.get_bucket(travel_time).distance(route, from, to, travel_time)
+    }
+}
+
+/// A transport cost which uses manhattan distance between location coordinates. Travel duration is
+/// calculated from distance and speed, location is an index in the coordinates list.
+pub struct ManhattanTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    speed: f64,
+}
+
+impl ManhattanTransportCost {
+    /// Creates a new instance of `ManhattanTransportCost`.
+    pub fn new(coordinates: Vec<(f64, f64)>, speed: f64) -> Result<Self, String> {
+        validate_speed(speed)?;
+
+        Ok(Self { coordinates, speed })
+    }
+}
+
+impl TransportCost for ManhattanTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.distance_approx(profile, from, to) / self.speed * profile.scale
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        let (from, to) = (self.coordinates[from], self.coordinates[to]);
+
+        (from.0 - to.0).abs() + (from.1 - to.1).abs()
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+}
+
+/// A transport cost which uses great-circle distance (in meters) between location coordinates
+/// specified as (latitude, longitude) in degrees. Travel duration is calculated from distance and
+/// speed (in meters per second), location is an index in the coordinates list.
+pub struct HaversineTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    speed: f64,
+}
+
+impl HaversineTransportCost {
+    /// Creates a new instance of `HaversineTransportCost`.
+    pub fn new(coordinates: Vec<(f64, f64)>, speed: f64) -> Result<Self, String> {
+        validate_speed(speed)?;
+
+        Ok(Self { coordinates, speed })
+    }
+}
+
+impl TransportCost for HaversineTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.distance_approx(profile, from, to) / self.speed * profile.scale
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        const EARTH_RADIUS: f64 = 6_371_000.;
+
+        let ((lat1, lng1), (lat2, lng2)) = (self.coordinates[from], self.coordinates[to]);
+        let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+        let (d_lat, d_lng) = (lat2 - lat1, (lng2 - lng1).to_radians());
+
+        let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.).sin().powi(2);
+
+        2. * EARTH_RADIUS * a.sqrt().atan2((1. - a).sqrt())
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+}
+
+fn validate_speed(speed: f64) -> Result<(), String> {
+    if speed > 0. {
+        Ok(())
+    } else {
+        Err(format!("speed should be positive, got: {speed}"))
+    }
+}
+
 /// Contains matrix routing data for specific profile and, optionally, time.
 pub struct MatrixData {
     /// A routing profile index.
@@ -25,6 +25,86 @@ pub struct Costs {
     pub per_service_time: f64,
 }
 
+/// Represents a piecewise per-distance cost of the vehicle: each tier specifies a rate applied to
+/// the part of route distance which exceeds its threshold until the next tier's threshold is reached.
+/// The distance below the first threshold is charged using regular per distance cost.
+#[derive(Clone, Debug)]
+pub struct DistanceCostTiers {
+    tiers: Vec<(Distance, Cost)>,
+}
+
+impl DistanceCostTiers {
+    /// Creates a new instance of `DistanceCostTiers` from (threshold, rate) pairs.
+    pub fn new(mut tiers: Vec<(Distance, Cost)>) -> Self {
+        tiers.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self { tiers }
+    }
+
+    /// Returns a cost of the given total distance using base rate for the part below the first tier.
+    pub fn cost(&self, base_rate: Cost, distance: Distance) -> Cost {
+        let (cost, threshold, rate) = self.tiers.iter().take_while(|(threshold, _)| *threshold < distance).fold(
+            (Cost::default(), Distance::default(), base_rate),
+            |(acc, prev_threshold, prev_rate), &(threshold, rate)| {
+                (acc + (threshold - prev_threshold) * prev_rate, threshold, rate)
+            },
+        );
+
+        cost + (distance - threshold) * rate
+    }
+
+    /// Returns the lowest rate which can be applied to any part of the distance.
+    pub fn min_rate(&self, base_rate: Cost) -> Cost {
+        self.tiers.iter().fold(base_rate, |acc, &(_, rate)| acc.min(rate))
+    }
+}
+
+/// A trait to get or set vehicle's distance cost tiers.
+pub trait DistanceCostTiersDimension {
+    /// Sets distance cost tiers.
+    fn set_distance_cost_tiers(&mut self, tiers: DistanceCostTiers) -> &mut Self;
+    /// Gets distance cost tiers if present.
+    fn get_distance_cost_tiers(&self) -> Option<&DistanceCostTiers>;
+}
+
+impl DistanceCostTiersDimension for Dimensions {
+    fn set_distance_cost_tiers(&mut self, tiers: DistanceCostTiers) -> &mut Self {
+        self.set_value("distance_cost_tiers", tiers);
+        self
+    }
+
+    fn get_distance_cost_tiers(&self) -> Option<&DistanceCostTiers> {
+        self.get_value("distance_cost_tiers")
+    }
+}
+
+/// Returns a distance cost of the vehicle for the given total route distance.
+pub fn get_vehicle_distance_cost(vehicle: &Vehicle, distance: Distance) -> Cost {
+    vehicle
+        .dimens
+        .get_distance_cost_tiers()
+        .map_or(vehicle.costs.per_distance * distance, |tiers| tiers.cost(vehicle.costs.per_distance, distance))
+}
+
+/// A trait to get or set vehicle's stop cost: a fixed handling cost which is applied for each job
+/// activity served at a location different from the previous activity one.
+pub trait StopCostDimension {
+    /// Sets stop cost.
+    fn set_stop_cost(&mut self, cost: Cost) -> &mut Self;
+    /// Gets stop cost if present.
+    fn get_stop_cost(&self) -> Option<Cost>;
+}
+
+impl StopCostDimension for Dimensions {
+    fn set_stop_cost(&mut self, cost: Cost) -> &mut Self {
+        self.set_value("stop_cost", cost);
+        self
+    }
+
+    fn get_stop_cost(&self) -> Option<Cost> {
+        self.get_value("stop_cost").cloned()
+    }
+}
+
 /// Represents driver detail (reserved for future use).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct DriverDetail {}
@@ -1,5 +1,8 @@
 //! Problem domain models.
 
+mod bounds;
+pub use self::bounds::*;
+
 mod costs;
 pub use self::costs::*;
 
@@ -3,6 +3,9 @@
 mod route;
 pub use self::route::{Activity, Commute, CommuteInfo, Place, Route};
 
+mod on_time;
+pub use self::on_time::*;
+
 mod registry;
 pub use self::registry::Registry;
 
@@ -0,0 +1,86 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/on_time_test.rs"]
+mod on_time_test;
+
+use crate::models::common::Duration;
+use crate::models::problem::{Single, TransportCost, TravelTime};
+use crate::models::solution::Route;
+use crate::models::Solution;
+use std::sync::Arc;
+
+/// Estimates probability of serving each job activity of the solution within its time window when
+/// travel times are uncertain. See `get_route_on_time_probabilities` for details.
+pub fn get_on_time_probabilities(
+    solution: &Solution,
+    transport: &(dyn TransportCost + Send + Sync),
+    variability: f64,
+) -> Result<Vec<(Arc<Single>, f64)>, String> {
+    solution.routes.iter().try_fold(Vec::default(), |mut acc, route| {
+        acc.extend(get_route_on_time_probabilities(route, transport, variability)?);
+        Ok(acc)
+    })
+}
+
+/// Estimates probability of serving each job activity of the route within its time window.
+///
+/// Accumulated travel duration from the tour start till the activity is assumed to be lognormally
+/// distributed with the median equal to the planned value and `variability` as the standard deviation
+/// of its logarithm. Delays absorbed by waiting are not taken into account, so the estimate is
+/// conservative.
+pub fn get_route_on_time_probabilities(
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    variability: f64,
+) -> Result<Vec<(Arc<Single>, f64)>, String> {
+    if !variability.is_finite() || variability <= 0. {
+        return Err(format!("variability should be positive and finite, got: {variability}"));
+    }
+
+    let probabilities = route
+        .tour
+        .legs()
+        .scan(Duration::default(), |travel, (activities, _)| {
+            if let [prev, next] = activities {
+                *travel += transport.duration(
+                    route,
+                    prev.place.location,
+                    next.place.location,
+                    TravelTime::Departure(prev.schedule.departure),
+                );
+
+                Some(next.job.clone().map(|job| {
+                    let slack = next.place.time.end - next.schedule.arrival;
+                    (job, get_probability(*travel, slack, variability))
+                }))
+            } else {
+                Some(None)
+            }
+        })
+        .flatten()
+        .collect();
+
+    Ok(probabilities)
+}
+
+/// Returns probability that lognormal travel duration with the given median doesn't exceed it plus slack.
+fn get_probability(travel: Duration, slack: Duration, variability: f64) -> f64 {
+    match (travel > 0., slack >= 0.) {
+        (_, false) => 0.,
+        (false, true) => 1.,
+        (true, true) => normal_cdf(((travel + slack) / travel).ln() / variability),
+    }
+}
+
+fn normal_cdf(value: f64) -> f64 {
+    0.5 * (1. + erf(value / std::f64::consts::SQRT_2))
+}
+
+/// Approximates error function with maximum error of 1.5e-7 (Abramowitz and Stegun, 7.1.26).
+fn erf(value: f64) -> f64 {
+    let sign = value.signum();
+    let x = value.abs();
+    let t = 1. / (1. + 0.3275911 * x);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    sign * (1. - poly * (-x * x).exp())
+}
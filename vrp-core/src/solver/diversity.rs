@@ -0,0 +1,49 @@
+//! Contains functionality to estimate diversity of solutions in population.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/diversity_test.rs"]
+mod diversity_test;
+
+use super::*;
+use crate::models::problem::{Actor, Job};
+
+/// Returns diversity of given solutions as a mean pairwise assignment distance between them.
+/// Low value indicates that population has converged, so search is likely to be stuck in local optimum.
+pub fn get_population_diversity(solutions: &[InsertionContext]) -> f64 {
+    let assignments = solutions.iter().map(get_assignments).collect::<Vec<_>>();
+
+    let (total, pairs) = assignments
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, left)| assignments.iter().skip(idx + 1).map(move |right| (left, right)))
+        .fold((0., 0), |(total, pairs), (left, right)| (total + get_assignment_distance(left, right), pairs + 1));
+
+    if pairs == 0 {
+        0.
+    } else {
+        total / pairs as f64
+    }
+}
+
+/// Returns a distance between two solutions in `[0, 1]` range: a share of assigned jobs which are
+/// not served by the same actor in both solutions.
+fn get_assignment_distance(left: &HashMap<Job, Arc<Actor>>, right: &HashMap<Job, Arc<Actor>>) -> f64 {
+    let shared =
+        left.iter().filter(|(job, actor)| right.get(*job).map_or(false, |other| Arc::ptr_eq(actor, other))).count();
+    let total = left.len() + right.keys().filter(|job| !left.contains_key(*job)).count();
+
+    if total == 0 {
+        0.
+    } else {
+        1. - shared as f64 / total as f64
+    }
+}
+
+fn get_assignments(insertion_ctx: &InsertionContext) -> HashMap<Job, Arc<Actor>> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs().map(move |job| (job, route_ctx.route().actor.clone())))
+        .collect()
+}
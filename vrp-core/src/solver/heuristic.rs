@@ -32,6 +32,8 @@ pub type GreedyPopulation = Greedy<GoalContext, InsertionContext>;
 pub type ElitismPopulation = Elitism<GoalContext, InsertionContext>;
 /// A type for rosomaxa population.
 pub type RosomaxaPopulation = Rosomaxa<GoalContext, InsertionContext>;
+/// A type for simulated annealing population.
+pub type AnnealingPopulation = Annealing<GoalContext, InsertionContext>;
 
 /// A type alias for domain specific termination type.
 pub type DynTermination = dyn Termination<Context = RefinementContext, Objective = GoalContext> + Send + Sync;
@@ -275,6 +277,7 @@ mod builder {
             context: vec![Box::<VicinityClustering>::default()],
             solution: vec![
                 Box::<AdvanceDeparture>::default(),
+                Box::<SpreadDeparture>::default(),
                 Box::<UnassignmentReason>::default(),
                 Box::<VicinityClustering>::default(),
             ],
@@ -85,6 +85,10 @@
 //! latency introduced. Reinforcement technics are used here (Markov Decision Process).
 //!
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 extern crate rand;
 
 use crate::construction::heuristics::InsertionContext;
@@ -107,6 +111,15 @@ pub mod search;
 
 mod heuristic;
 
+mod replay;
+pub use self::replay::*;
+
+mod termination;
+pub use self::termination::*;
+
+mod diversity;
+pub use self::diversity::*;
+
 /// A key to store a filter for heuristic methods applied by dynamic hyper-heuristic.
 pub const HEURISTIC_FILTER_KEY: &str = "heuristic_filter";
 
@@ -116,6 +129,8 @@ const TABU_LIST_KEY: i32 = 1;
 const SOLUTION_ORDER_KEY: i32 = 2;
 /// A key to store solution weights information.
 const SOLUTION_WEIGHTS_KEY: i32 = 3;
+/// A key to store jobs assigned by relaxed reinsertion within the name of applied relaxation.
+pub const RELAXED_JOBS_KEY: i32 = 4;
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -127,6 +142,8 @@ pub struct RefinementContext {
     pub state: HashMap<String, Box<dyn Any + Sync + Send>>,
     /// Provides some basic implementation of context functionality.
     inner_context: TelemetryHeuristicContext<GoalContext, InsertionContext>,
+    /// An optional evolution replay recorder.
+    replay: Option<EvolutionReplay>,
 }
 
 /// Defines instant refinement speed type.
@@ -149,13 +166,18 @@ impl RefinementContext {
     ) -> Self {
         let inner_context =
             TelemetryHeuristicContext::new(problem.goal.clone(), population, telemetry_mode, environment.clone());
-        Self { problem, environment, inner_context, state: Default::default() }
+        Self { problem, environment, inner_context, state: Default::default(), replay: None }
     }
 
     /// Adds solution to population.
     pub fn add_solution(&mut self, solution: InsertionContext) {
         self.inner_context.add_solution(solution);
     }
+
+    /// Sets observer which receives generation snapshots during refinement.
+    pub fn set_generation_observer(&mut self, observer: TelemetryObserver) {
+        self.inner_context.set_generation_observer(observer);
+    }
 }
 
 impl HeuristicContext for RefinementContext {
@@ -191,7 +213,12 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: f64, generation_time: Timer) {
-        self.inner_context.on_generation(offspring, termination_estimate, generation_time)
+        self.inner_context.on_generation(offspring, termination_estimate, generation_time);
+
+        if let Some(replay) = self.replay.as_ref() {
+            let best = self.inner_context.ranked().next().map(|(solution, _)| solution);
+            replay.on_generation(self.inner_context.statistics().generation, best);
+        }
     }
 
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
@@ -233,8 +260,9 @@ impl InitialOperator for RecreateInitialOperator {
     type Objective = GoalContext;
     type Solution = InsertionContext;
 
-    fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution {
-        let insertion_ctx = InsertionContext::new(heuristic_ctx.problem.clone(), heuristic_ctx.environment.clone());
+    fn create(&self, heuristic_ctx: &Self::Context, random: Arc<dyn Random + Send + Sync>) -> Self::Solution {
+        let environment = Arc::new(Environment { random, ..heuristic_ctx.environment.as_ref().clone() });
+        let insertion_ctx = InsertionContext::new(heuristic_ctx.problem.clone(), environment);
         self.recreate.run(heuristic_ctx, insertion_ctx)
     }
 }
@@ -289,6 +317,18 @@ impl Solver {
         Self { problem, config }
     }
 
+    /// Sets evolution replay which records best known solutions while solver is running.
+    pub fn with_replay(mut self, replay: EvolutionReplay) -> Self {
+        self.config.context.replay = Some(replay);
+        self
+    }
+
+    /// Sets observer which receives generation snapshots while solver is running.
+    pub fn with_generation_observer(mut self, observer: TelemetryObserver) -> Self {
+        self.config.context.set_generation_observer(observer);
+        self
+    }
+
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
     /// or error description, if solution cannot be found.
     pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
@@ -298,15 +338,69 @@ impl Solver {
             self.problem.fleet.actors.len()
         ));
 
+        let replay = self.config.context.replay.clone();
         let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let metrics = metrics.map(|metrics| TelemetryMetrics {
+            diversity: Some(get_population_diversity(solutions.as_slice())),
+            ..metrics
+        });
 
         // NOTE select the first best individual from population
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
             .ok_or_else(|| "cannot find any solution".to_string())?;
 
+        Ok(Self::get_result(insertion_ctx, replay, metrics))
+    }
+
+    /// Solves a Vehicle Routing Problem using only the first configured initial method (or the first
+    /// initial solution, if specified) without running any evolution generations. It is much faster
+    /// than `solve`, but solution quality is usually worse, so it is useful for latency critical
+    /// re-planning. Reported telemetry metrics, if any, have zero generations.
+    pub fn solve_greedy(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
+        let EvolutionConfig { initial, processing, context, .. } = self.config;
+
+        let replay = context.replay.clone();
+        let mut heuristic_ctx = processing.context.iter().fold(context, |ctx, hook| hook.pre_process(ctx));
+
+        let item_time = Timer::start();
+        let insertion_ctx = match initial.individuals.into_iter().next() {
+            Some(insertion_ctx) => insertion_ctx,
+            None => initial
+                .operators
+                .first()
+                .map(|(operator, _)| {
+                    let random = heuristic_ctx.environment.random.clone();
+                    operator.create(&heuristic_ctx, random.derive(0).unwrap_or(random))
+                })
+                .ok_or_else(|| "at least one initial method has to be specified".to_string())?,
+        };
+        heuristic_ctx.on_initial(insertion_ctx, item_time);
+
+        let (population, metrics) = heuristic_ctx.on_result()?;
+        let solutions = population.ranked().map(|(insertion_ctx, _)| insertion_ctx.deep_copy()).collect::<Vec<_>>();
+        let metrics = metrics.map(|metrics| TelemetryMetrics {
+            diversity: Some(get_population_diversity(solutions.as_slice())),
+            ..metrics
+        });
+
+        let insertion_ctx = solutions.into_iter().next().ok_or_else(|| "cannot find any solution".to_string())?;
+        let insertion_ctx = processing.solution.iter().fold(insertion_ctx, |ctx, hook| hook.post_process(ctx));
+
+        Ok(Self::get_result(insertion_ctx, replay, metrics))
+    }
+
+    fn get_result(
+        insertion_ctx: InsertionContext,
+        replay: Option<EvolutionReplay>,
+        metrics: Option<TelemetryMetrics>,
+    ) -> (Solution, Cost, Option<TelemetryMetrics>) {
+        if let Some(replay) = replay {
+            replay.on_result(&insertion_ctx);
+        }
+
         let cost = insertion_ctx.solution.get_total_cost();
         let solution = insertion_ctx.solution.into();
 
-        Ok((solution, cost, metrics))
+        (solution, cost, metrics)
     }
 }
@@ -6,6 +6,12 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod relaxed_reinsertion;
+pub use self::relaxed_reinsertion::{Relaxation, RelaxedReinsertion};
+
+mod spread_departure;
+pub use self::spread_departure::{DepartureSpreadDimension, SpreadDeparture};
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
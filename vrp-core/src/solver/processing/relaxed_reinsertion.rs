@@ -0,0 +1,104 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/relaxed_reinsertion_test.rs"]
+mod relaxed_reinsertion_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::{GoalContext, Problem};
+use crate::solver::RELAXED_JOBS_KEY;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+/// Specifies a relaxation of the original goal, e.g. with widened soft bounds of some constraints.
+pub struct Relaxation {
+    /// A name of relaxation used to report jobs assigned with it.
+    pub name: String,
+    /// A relaxed goal used to insert unassigned jobs.
+    pub goal: Arc<GoalContext>,
+}
+
+/// Tries to assign jobs left unassigned after the main solve using relaxations in the given order.
+/// Jobs assigned this way are stored within the name of applied relaxation in solution state using
+/// `RELAXED_JOBS_KEY` as `HashMap<Job, String>`.
+pub struct RelaxedReinsertion {
+    relaxations: Vec<Relaxation>,
+}
+
+impl RelaxedReinsertion {
+    /// Creates a new instance of `RelaxedReinsertion`.
+    pub fn new(relaxations: Vec<Relaxation>) -> Self {
+        Self { relaxations }
+    }
+}
+
+impl HeuristicSolutionProcessing for RelaxedReinsertion {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+        let original = insertion_ctx.problem.clone();
+
+        let unassigned = insertion_ctx.solution.unassigned.clone();
+        let mut relaxed_jobs = HashMap::<Job, String>::default();
+
+        for relaxation in self.relaxations.iter() {
+            if insertion_ctx.solution.unassigned.is_empty() {
+                break;
+            }
+
+            // NOTE reset unassignment codes as they are reported by the previously used goal
+            let candidates = insertion_ctx.solution.unassigned.drain().map(|(job, _)| job).collect::<Vec<_>>();
+            insertion_ctx.solution.required.extend(candidates.iter().cloned());
+
+            insertion_ctx.problem = create_problem_with_goal(original.as_ref(), relaxation.goal.clone());
+            mark_routes_stale(&mut insertion_ctx);
+            insertion_ctx = InsertionHeuristic::default().process(
+                insertion_ctx,
+                &AllJobSelector::default(),
+                &AllRouteSelector::default(),
+                &LegSelection::Exhaustive,
+                &BestResultSelector::default(),
+            );
+
+            relaxed_jobs.extend(
+                candidates
+                    .into_iter()
+                    .filter(|job| !insertion_ctx.solution.unassigned.contains_key(job))
+                    .map(|job| (job, relaxation.name.clone())),
+            );
+        }
+
+        insertion_ctx.problem = original;
+        mark_routes_stale(&mut insertion_ctx);
+        insertion_ctx.restore();
+
+        // NOTE keep original reasons as reasons reported by relaxed goals can be misleading
+        insertion_ctx.solution.unassigned.iter_mut().for_each(|(job, info)| {
+            if let Some(original_info) = unassigned.get(job) {
+                *info = original_info.clone();
+            }
+        });
+
+        insertion_ctx.solution.state.insert(RELAXED_JOBS_KEY, Arc::new(relaxed_jobs));
+
+        insertion_ctx
+    }
+}
+
+fn create_problem_with_goal(problem: &Problem, goal: Arc<GoalContext>) -> Arc<Problem> {
+    Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal,
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    })
+}
+
+/// Marks routes as stale, so their state is recalculated by the goal currently used.
+fn mark_routes_stale(insertion_ctx: &mut InsertionContext) {
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| route_ctx.mark_stale(true));
+}
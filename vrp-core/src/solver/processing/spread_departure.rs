@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/spread_departure_test.rs"]
+mod spread_departure_test;
+
+use super::*;
+use crate::construction::enablers::{update_route_departure, ScheduleStateKeys};
+use crate::construction::features::DepartureSpread;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{Timestamp, ValueDimension};
+use crate::models::Extras;
+
+/// A trait to get or set departure spread config.
+pub trait DepartureSpreadDimension {
+    /// Sets departure spread.
+    fn set_departure_spread(&mut self, spread: DepartureSpread) -> &mut Self;
+    /// Gets departure spread.
+    fn get_departure_spread(&self) -> Option<&DepartureSpread>;
+}
+
+impl DepartureSpreadDimension for Extras {
+    fn set_departure_spread(&mut self, spread: DepartureSpread) -> &mut Self {
+        self.set_value("departure_spread", spread);
+        self
+    }
+
+    fn get_departure_spread(&self) -> Option<&DepartureSpread> {
+        self.get_value("departure_spread")
+    }
+}
+
+/// Provides way to spread departure times of the routes as specified by problem's departure spread
+/// config. Departures are only postponed within the slack which keeps routes feasible.
+#[derive(Default)]
+pub struct SpreadDeparture {
+    state_keys: ScheduleStateKeys,
+}
+
+impl HeuristicSolutionProcessing for SpreadDeparture {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let spread = if let Some(spread) = solution.problem.extras.get_departure_spread() {
+            spread.clone()
+        } else {
+            return solution;
+        };
+
+        let mut insertion_ctx = solution.deep_copy();
+        let problem = insertion_ctx.problem.clone();
+        let (activity, transport) = (problem.activity.as_ref(), problem.transport.as_ref());
+
+        let mut routes = insertion_ctx
+            .solution
+            .routes
+            .iter_mut()
+            .filter_map(|route_ctx| get_departure(route_ctx).map(|departure| (departure, route_ctx)))
+            .collect::<Vec<_>>();
+        routes.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+        let latest_departure = routes.last().map(|(departure, _)| *departure);
+
+        routes.into_iter().fold(None, |prev_departure: Option<Timestamp>, (departure, route_ctx)| {
+            let desired = match (&spread, prev_departure, latest_departure) {
+                (DepartureSpread::Stagger { interval }, Some(prev_departure), _) => prev_departure + interval,
+                (DepartureSpread::Band, _, Some(latest_departure)) => latest_departure,
+                _ => departure,
+            };
+
+            let new_departure = desired.min(departure + get_departure_slack(route_ctx, &self.state_keys));
+
+            if new_departure > departure {
+                update_route_departure(route_ctx, activity, transport, new_departure, &self.state_keys);
+                Some(new_departure)
+            } else {
+                Some(departure)
+            }
+        });
+
+        problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+        insertion_ctx
+    }
+}
+
+fn get_departure(route_ctx: &RouteContext) -> Option<Timestamp> {
+    let tour = &route_ctx.route().tour;
+
+    if tour.has_jobs() {
+        tour.start().map(|start| start.schedule.departure)
+    } else {
+        None
+    }
+}
+
+/// Returns max time the departure can be postponed without violating time constraints of the route.
+fn get_departure_slack(route_ctx: &RouteContext, state_keys: &ScheduleStateKeys) -> Timestamp {
+    let route = route_ctx.route();
+    let (start, first) = match (route.tour.start(), route.tour.get(1)) {
+        (Some(start), Some(first)) => (start, first),
+        _ => return 0.,
+    };
+
+    let latest_allowed_departure = route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(f64::MAX);
+    let arrival_slack = route_ctx
+        .state()
+        .get_activity_state::<f64>(state_keys.latest_arrival, first)
+        .map_or(0., |latest_arrival| latest_arrival - first.schedule.arrival);
+
+    arrival_slack.min(latest_allowed_departure - start.schedule.departure).max(0.)
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/replay_test.rs"]
+mod replay_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::Solution;
+use std::sync::{Arc, Mutex};
+
+/// A recorded best known solution of a specific generation.
+pub type ReplayFrame = (usize, Solution);
+
+/// Records best known solutions while evolution is running in order to replay how the solution
+/// evolved. To keep amount of recorded data reasonable, only every k-th generation is recorded.
+/// The final solution returned by the solver is always recorded as the last frame.
+///
+/// The instance is cheap to clone: all clones share the same recorded frames.
+#[derive(Clone)]
+pub struct EvolutionReplay {
+    sampling: usize,
+    inner: Arc<Mutex<ReplayState>>,
+}
+
+#[derive(Default)]
+struct ReplayState {
+    generation: usize,
+    frames: Vec<ReplayFrame>,
+}
+
+impl EvolutionReplay {
+    /// Creates a new instance of `EvolutionReplay` which records best known solution of every
+    /// `sampling` generation.
+    pub fn new(sampling: usize) -> Self {
+        assert_ne!(sampling, 0);
+        Self { sampling, inner: Arc::new(Mutex::new(ReplayState::default())) }
+    }
+
+    /// Records best known solution if generation matches sampling.
+    pub fn on_generation(&self, generation: usize, best: Option<&InsertionContext>) {
+        let mut state = self.inner.lock().unwrap();
+        state.generation = generation;
+
+        if let Some(best) = best.filter(|_| generation % self.sampling == 0) {
+            state.frames.push((generation, create_solution(best)));
+        }
+    }
+
+    /// Records final solution as the last frame.
+    pub fn on_result(&self, solution: &InsertionContext) {
+        let mut state = self.inner.lock().unwrap();
+        let generation = state.generation;
+
+        // NOTE final solution replaces the frame recorded for the same generation
+        if state.frames.last().map_or(false, |(last, _)| *last == generation) {
+            state.frames.pop();
+        }
+
+        state.frames.push((generation, create_solution(solution)));
+    }
+
+    /// Takes all recorded frames leaving the replay empty.
+    pub fn take_frames(&self) -> Vec<ReplayFrame> {
+        std::mem::take(&mut self.inner.lock().unwrap().frames)
+    }
+}
+
+fn create_solution(insertion_ctx: &InsertionContext) -> Solution {
+    insertion_ctx.solution.deep_copy().into()
+}
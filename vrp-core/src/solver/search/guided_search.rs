@@ -0,0 +1,227 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/guided_search_test.rs"]
+mod guided_search_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Distance, Location};
+use crate::models::*;
+use crate::solver::*;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
+
+/// Specifies penalties of the edges defined by location pairs.
+type EdgePenalties = HashMap<(Location, Location), usize>;
+
+/// Specifies max penalty of a single edge.
+const MAX_EDGE_PENALTY: usize = 4;
+
+/// A search operator which follows guided local search principle: it keeps penalty memory of
+/// frequently used expensive edges and biases inner search to avoid them when improvement stalls.
+/// Penalties are bounded per edge and decay once inner search finds an improvement again.
+pub struct GuidedSearch {
+    inner_search: TargetSearchOperator,
+    memory: RwLock<PenaltyMemory>,
+    stagnation_limit: usize,
+    penalty_factor: f64,
+}
+
+impl GuidedSearch {
+    /// Creates a new instance of `GuidedSearch`.
+    /// `stagnation_limit` specifies amount of non improving searches before penalties are updated.
+    /// `penalty_factor` scales an average edge distance which is used as a cost of one penalty.
+    pub fn new(inner_search: TargetSearchOperator, stagnation_limit: usize, penalty_factor: f64) -> Self {
+        assert!(stagnation_limit > 0);
+        assert!(penalty_factor > 0.);
+
+        Self { inner_search, memory: RwLock::new(PenaltyMemory::default()), stagnation_limit, penalty_factor }
+    }
+}
+
+impl HeuristicSearchOperator for GuidedSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let (penalties, penalty_cost) = {
+            let memory = self.memory.read().unwrap();
+            (memory.penalties.clone(), memory.penalty_cost)
+        };
+
+        let new_insertion_ctx = if penalties.is_empty() {
+            self.inner_search.search(heuristic_ctx, solution)
+        } else {
+            let guided_insertion_ctx = create_guided_insertion_ctx(solution, penalties, penalty_cost);
+            let mut new_insertion_ctx = self.inner_search.search(heuristic_ctx, &guided_insertion_ctx);
+
+            new_insertion_ctx.problem = solution.problem.clone();
+            finalize_insertion_ctx(&mut new_insertion_ctx);
+
+            new_insertion_ctx
+        };
+
+        let is_improved = solution.problem.goal.total_order(&new_insertion_ctx, solution) == Ordering::Less;
+        self.memory.write().unwrap().update(solution, is_improved, self.stagnation_limit, self.penalty_factor);
+
+        new_insertion_ctx
+    }
+}
+
+#[derive(Default)]
+struct PenaltyMemory {
+    penalties: Arc<EdgePenalties>,
+    penalty_cost: Cost,
+    stagnation: usize,
+}
+
+impl PenaltyMemory {
+    fn update(&mut self, insertion_ctx: &InsertionContext, is_improved: bool, limit: usize, factor: f64) {
+        if is_improved {
+            self.stagnation = 0;
+            self.decay();
+            return;
+        }
+
+        self.stagnation += 1;
+        if self.stagnation < limit {
+            return;
+        }
+
+        self.stagnation = 0;
+
+        let edges = get_edges(insertion_ctx);
+        if edges.is_empty() {
+            return;
+        }
+
+        let total_distance = edges.iter().map(|(_, distance)| *distance).sum::<Distance>();
+        self.penalty_cost = factor * total_distance / edges.len() as f64;
+
+        // NOTE penalize edges with max utility as in classical guided local search
+        let get_penalty = |edge: &(Location, Location)| self.penalties.get(edge).copied().unwrap_or(0);
+        let get_utility = |edge: &(Location, Location), distance: Distance| distance / (1 + get_penalty(edge)) as f64;
+        let edges = edges.into_iter().filter(|(edge, _)| get_penalty(edge) < MAX_EDGE_PENALTY).collect::<Vec<_>>();
+        let max_utility = edges.iter().map(|(edge, distance)| get_utility(edge, *distance)).fold(0., f64::max);
+
+        if max_utility > 0. {
+            let mut penalties = self.penalties.as_ref().clone();
+            edges
+                .iter()
+                .filter(|(edge, distance)| compare_floats(get_utility(edge, *distance), max_utility).is_eq())
+                .for_each(|(edge, _)| {
+                    *penalties.entry(*edge).or_insert(0) += 1;
+                });
+
+            self.penalties = Arc::new(penalties);
+        }
+    }
+
+    /// Decreases all penalties by one, so edges penalized in the past can be used again.
+    fn decay(&mut self) {
+        if self.penalties.is_empty() {
+            return;
+        }
+
+        let penalties = self
+            .penalties
+            .iter()
+            .filter(|(_, &penalty)| penalty > 1)
+            .map(|(&edge, &penalty)| (edge, penalty - 1))
+            .collect::<EdgePenalties>();
+
+        self.penalties = Arc::new(penalties);
+    }
+}
+
+/// Returns edges used in the solution with their distances.
+fn get_edges(insertion_ctx: &InsertionContext) -> Vec<((Location, Location), Distance)> {
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = route_ctx.route();
+            let profile = &route.actor.vehicle.profile;
+            let locations = route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
+
+            locations
+                .windows(2)
+                .map(|leg| ((leg[0], leg[1]), transport.distance_approx(profile, leg[0], leg[1])))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn create_guided_insertion_ctx(
+    insertion_ctx: &InsertionContext,
+    penalties: Arc<EdgePenalties>,
+    penalty_cost: Cost,
+) -> InsertionContext {
+    let problem = &insertion_ctx.problem;
+    let goal = problem.goal.as_ref();
+
+    let objective: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync> =
+        Arc::new(EdgePenaltyObjective { penalties, penalty_cost });
+
+    // NOTE penalties are added to the least important level to bias cost estimation only
+    let mut local_objectives = goal.local_objectives.clone();
+    match local_objectives.last_mut() {
+        Some(objectives) => objectives.push(objective),
+        None => local_objectives.push(vec![objective]),
+    }
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.problem = Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: Arc::new(GoalContext { local_objectives, ..goal.clone() }),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    });
+
+    insertion_ctx
+}
+
+struct EdgePenaltyObjective {
+    penalties: Arc<EdgePenalties>,
+    penalty_cost: Cost,
+}
+
+impl EdgePenaltyObjective {
+    fn get_penalty(&self, from: Location, to: Location) -> Cost {
+        self.penalties.get(&(from, to)).map_or(Cost::default(), |&penalty| penalty as Cost * self.penalty_cost)
+    }
+}
+
+impl Objective for EdgePenaltyObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, _: &Self::Solution) -> f64 {
+        // NOTE penalties are used only to guide insertion, they are not part of the global objective
+        Cost::default()
+    }
+}
+
+impl FeatureObjective for EdgePenaltyObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { activity_ctx, .. } => {
+                let prev = activity_ctx.prev.place.location;
+                let target = activity_ctx.target.place.location;
+
+                let (next_penalty, old_penalty) = activity_ctx.next.map_or((0., 0.), |next| {
+                    let next = next.place.location;
+                    (self.get_penalty(target, next), self.get_penalty(prev, next))
+                });
+
+                self.get_penalty(prev, target) + next_penalty - old_penalty
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_kopt_test.rs"]
+mod exchange_kopt_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Job, TransportCost};
+use crate::models::solution::Route;
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+use std::iter::once;
+
+const MIN_JOBS: usize = 2;
+
+/// A local search operator which improves a single route using 2-opt (segment reversal) and
+/// or-opt (segment relocation) moves. Moves are ranked by approximated transport cost change and
+/// applied through regular insertion evaluation, so hard constraints are respected.
+/// Only moves within given neighborhood size (max distance between positions in the tour) are
+/// considered, so amount of evaluated moves grows linearly with the tour size.
+pub struct ExchangeKOpt {
+    max_segment_size: usize,
+    max_neighborhood_size: usize,
+    max_attempts: usize,
+}
+
+impl ExchangeKOpt {
+    /// Creates a new instance of `ExchangeKOpt`.
+    pub fn new(max_segment_size: usize, max_neighborhood_size: usize, max_attempts: usize) -> Self {
+        assert!(max_segment_size > 0);
+        assert!(max_neighborhood_size > 0);
+        assert!(max_attempts > 0);
+
+        Self { max_segment_size, max_neighborhood_size, max_attempts }
+    }
+}
+
+impl Default for ExchangeKOpt {
+    fn default() -> Self {
+        Self::new(3, 8, 4)
+    }
+}
+
+impl LocalOperator for ExchangeKOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let route_indices = get_route_indices(insertion_ctx);
+
+        if route_indices.is_empty() {
+            return None;
+        }
+
+        let route_idx =
+            route_indices[insertion_ctx.environment.random.uniform_int(0, route_indices.len() as i32 - 1) as usize];
+
+        let mut moves =
+            get_improving_moves(insertion_ctx, route_idx, self.max_segment_size, self.max_neighborhood_size);
+        moves.sort_by(|a, b| compare_floats(a.delta, b.delta));
+
+        moves.into_iter().take(self.max_attempts).find_map(|kopt_move| apply_move(insertion_ctx, route_idx, &kopt_move))
+    }
+}
+
+/// Specifies a move with its approximated cost change.
+struct KOptMove {
+    delta: Cost,
+    kind: KOptMoveKind,
+}
+
+enum KOptMoveKind {
+    /// Reverses jobs at positions `start..=end`.
+    Reverse { start: usize, end: usize },
+    /// Moves segment of `size` jobs starting at `start` position before job at `target` position.
+    Relocate { start: usize, size: usize, target: usize },
+}
+
+impl KOptMove {
+    /// Returns a first affected position and new order of jobs starting from it.
+    fn get_order(&self) -> (usize, Vec<usize>) {
+        match self.kind {
+            KOptMoveKind::Reverse { start, end } => (start, (start..=end).rev().collect()),
+            KOptMoveKind::Relocate { start, size, target } if target < start => {
+                (target, (start..start + size).chain(target..start).collect())
+            }
+            KOptMoveKind::Relocate { start, size, target } => {
+                (start, (start + size..target).chain(start..start + size).collect())
+            }
+        }
+    }
+}
+
+fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, route_ctx)| {
+            let tour = &route_ctx.route().tour;
+            // NOTE multi jobs are ignored as reversal breaks their precedence in most of the cases
+            let is_movable =
+                tour.jobs().all(|job| job.as_single().is_some() && !insertion_ctx.solution.locked.contains(&job));
+
+            if is_movable && tour.job_count() >= MIN_JOBS {
+                Some(idx)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn get_improving_moves(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    max_segment_size: usize,
+    max_neighborhood_size: usize,
+) -> Vec<KOptMove> {
+    let route = insertion_ctx.solution.routes[route_idx].route();
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    let start = route.tour.start().map(|activity| activity.place.location);
+    // NOTE open tour ends with job activity
+    let end = route.tour.end().filter(|activity| activity.job.is_none()).map(|activity| activity.place.location);
+    // NOTE job at position `idx` has node index `idx + 1`
+    let nodes = once(start).chain(get_jobs(route).into_iter().map(|(_, location)| Some(location))).chain(once(end));
+    let nodes = nodes.collect::<Vec<_>>();
+    let size = nodes.len() - 2;
+
+    // NOTE time dependent costs are ignored here, final evaluation is done by the goal
+    let get_cost = |from: usize, to: usize| match (nodes[from], nodes[to]) {
+        (Some(from), Some(to)) => get_approx_cost(transport, route, from, to),
+        _ => 0.,
+    };
+
+    // NOTE prefix sums of leg costs in forward and backward directions give path cost in O(1)
+    let (forward, backward) = (0..nodes.len() - 1).fold(
+        (vec![Cost::default()], vec![Cost::default()]),
+        |(mut forward, mut backward), idx| {
+            forward.push(forward[idx] + get_cost(idx, idx + 1));
+            backward.push(backward[idx] + get_cost(idx + 1, idx));
+            (forward, backward)
+        },
+    );
+
+    let create_move = |delta: Cost, kind: KOptMoveKind| if delta < 0. { Some(KOptMove { delta, kind }) } else { None };
+
+    // 2-opt: reverse segment
+    let two_opt = (0..size)
+        .flat_map(|i| ((i + 1)..size.min(i + max_neighborhood_size + 1)).map(move |j| (i, j)))
+        .filter_map(|(i, j)| {
+            let (first, last) = (i + 1, j + 1);
+            let old_cost = get_cost(first - 1, first) + (forward[last] - forward[first]) + get_cost(last, last + 1);
+            let new_cost = get_cost(first - 1, last) + (backward[last] - backward[first]) + get_cost(first, last + 1);
+
+            create_move(new_cost - old_cost, KOptMoveKind::Reverse { start: i, end: j })
+        });
+
+    // or-opt: relocate segment before given target position
+    let or_opt = (1..=max_segment_size.min(size - 1))
+        .flat_map(|segment_size| (0..=(size - segment_size)).map(move |i| (segment_size, i)))
+        .flat_map(|(segment_size, i)| {
+            let min_target = i.saturating_sub(max_neighborhood_size);
+            let max_target = size.min(i + segment_size + max_neighborhood_size);
+
+            (min_target..=max_target)
+                .filter(move |&target| target < i || target > i + segment_size)
+                .map(move |target| (segment_size, i, target))
+        })
+        .filter_map(|(segment_size, i, target)| {
+            let (first, last) = (i + 1, i + segment_size);
+            let (target_prev, target_next) = (target, target + 1);
+
+            let old_cost = get_cost(first - 1, first) + get_cost(last, last + 1) + get_cost(target_prev, target_next);
+            let new_cost = get_cost(first - 1, last + 1) + get_cost(target_prev, first) + get_cost(last, target_next);
+
+            create_move(new_cost - old_cost, KOptMoveKind::Relocate { start: i, size: segment_size, target })
+        });
+
+    two_opt.chain(or_opt).collect()
+}
+
+fn apply_move(insertion_ctx: &InsertionContext, route_idx: usize, kopt_move: &KOptMove) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    let route_ctx = new_insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let jobs = get_jobs(route_ctx.route());
+    let (start, order) = kopt_move.get_order();
+    let jobs = order.iter().map(|&idx| jobs[idx].0.clone()).collect::<Vec<_>>();
+
+    jobs.iter().for_each(|job| {
+        assert!(route_ctx.route_mut().tour.remove(job));
+    });
+    new_insertion_ctx.problem.goal.accept_route_state(route_ctx);
+    new_insertion_ctx.solution.required.extend(jobs.iter().cloned());
+
+    let goal = new_insertion_ctx.problem.goal.clone();
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    let is_success = jobs.iter().enumerate().all(|(offset, job)| {
+        let eval_ctx = EvaluationContext {
+            goal: goal.as_ref(),
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let insertion = eval_job_insertion_in_route(
+            &new_insertion_ctx,
+            &eval_ctx,
+            &new_insertion_ctx.solution.routes[route_idx],
+            InsertionPosition::Concrete(start + offset),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => {
+                apply_insertion_success(&mut new_insertion_ctx, success);
+                true
+            }
+            InsertionResult::Failure(_) => false,
+        }
+    });
+
+    if is_success {
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+        Some(new_insertion_ctx)
+    } else {
+        None
+    }
+}
+
+/// Returns jobs with their locations in the order they appear in the tour.
+fn get_jobs(route: &Route) -> Vec<(Job, Location)> {
+    route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity.place.location)))
+        .collect()
+}
+
+fn get_approx_cost(transport: &(dyn TransportCost + Send + Sync), route: &Route, from: Location, to: Location) -> Cost {
+    let actor = route.actor.as_ref();
+    let profile = &actor.vehicle.profile;
+
+    let distance = transport.distance_approx(profile, from, to);
+    let duration = transport.duration_approx(profile, from, to);
+
+    distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
+        + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
+}
@@ -27,6 +27,9 @@ pub(crate) use self::utils::*;
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
+mod guided_search;
+pub use self::guided_search::GuidedSearch;
+
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
 
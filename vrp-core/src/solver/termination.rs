@@ -0,0 +1,73 @@
+//! Contains VRP specific termination criteria.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/termination_test.rs"]
+mod termination_test;
+
+use super::*;
+use crate::models::problem::Job;
+
+/// Specifies a function which returns true if job is a plan job and should be counted as required.
+pub type PlanJobFn = Arc<dyn Fn(&Job) -> bool + Send + Sync>;
+
+/// A termination criteria which stops search when the best known solution serves at least a
+/// target fraction of required plan jobs. It is useful when it is enough to know that most of the
+/// jobs can be served, e.g. for capacity planning.
+pub struct MinServedRatio {
+    target: f64,
+    plan_job_fn: PlanJobFn,
+}
+
+impl MinServedRatio {
+    /// Creates a new instance of `MinServedRatio` with target ratio in `(0, 1]` range. Only jobs
+    /// accepted by `plan_job_fn` are counted, e.g. to exclude conditional jobs such as breaks.
+    pub fn new(target: f64, plan_job_fn: PlanJobFn) -> Result<Self, String> {
+        if target > 0. && target <= 1. {
+            Ok(Self { target, plan_job_fn })
+        } else {
+            Err(format!("served ratio should be in (0, 1] range, got: {target}"))
+        }
+    }
+}
+
+impl Termination for MinServedRatio {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        // NOTE ignore pareto front, use the first solution only
+        heuristic_ctx.ranked().next().map_or(false, |(insertion_ctx, _)| {
+            get_served_ratio(insertion_ctx, self.plan_job_fn.as_ref()) >= self.target
+        })
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
+
+/// Returns a fraction of required plan jobs served in the solution. Ignored jobs are not counted.
+pub fn get_served_ratio(insertion_ctx: &InsertionContext, plan_job_fn: &dyn Fn(&Job) -> bool) -> f64 {
+    let solution = &insertion_ctx.solution;
+
+    let served = solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .filter(|job| plan_job_fn(job))
+        .count();
+    let unserved = solution
+        .required
+        .iter()
+        .filter(|job| !solution.unassigned.contains_key(*job))
+        .chain(solution.unassigned.keys())
+        .filter(|job| plan_job_fn(job))
+        .count();
+
+    let total = served + unserved;
+    if total == 0 {
+        return 1.;
+    }
+
+    served as f64 / total as f64
+}
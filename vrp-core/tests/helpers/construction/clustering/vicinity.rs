@@ -93,7 +93,11 @@ pub fn create_cluster_config() -> ClusterConfig {
         },
         visiting: VisitPolicy::Return,
         serving: ServingPolicy::Original { parking: 0. },
-        filtering: FilterPolicy { job_filter: Arc::new(|_| true), actor_filter: Arc::new(|_| true) },
+        filtering: FilterPolicy {
+            job_filter: Arc::new(|_| true),
+            actor_filter: Arc::new(|_| true),
+            pair_filter: Arc::new(|_, _| true),
+        },
         building: BuilderPolicy {
             ordering_global_fn: Arc::new(move |(left_job, left_candidates), (right_job, right_candidates)| {
                 ordering_rule(left_candidates.len().cmp(&right_candidates.len()), left_job, right_job)
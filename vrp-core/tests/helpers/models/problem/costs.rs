@@ -54,3 +54,24 @@ impl TestActivityCost {
         Arc::new(Self::default())
     }
 }
+
+/// Delays departure from each activity, e.g. because of reserved time or scaled service duration.
+pub struct DelayedActivityCost {
+    delay: Timestamp,
+}
+
+impl ActivityCost for DelayedActivityCost {
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        SimpleActivityCost::default().estimate_departure(route, activity, arrival) + self.delay
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        SimpleActivityCost::default().estimate_arrival(route, activity, departure - self.delay)
+    }
+}
+
+impl DelayedActivityCost {
+    pub fn new_shared(delay: Timestamp) -> Arc<dyn ActivityCost + Sync + Send> {
+        Arc::new(Self { delay })
+    }
+}
@@ -95,6 +95,11 @@ impl VehicleBuilder {
         self
     }
 
+    pub fn compartments(&mut self, capacities: Vec<i32>) -> &mut VehicleBuilder {
+        self.vehicle.dimens.set_compartment_capacities(capacities.into_iter().map(SingleDimLoad::new).collect());
+        self
+    }
+
     pub fn costs(&mut self, costs: Costs) -> &mut VehicleBuilder {
         self.vehicle.costs = costs;
         self
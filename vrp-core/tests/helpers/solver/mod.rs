@@ -49,6 +49,29 @@ pub fn generate_matrix_distances_from_points(points: &[Point]) -> Vec<f64> {
     points.iter().cloned().flat_map(|p_a| points.iter().map(move |p_b| p_a.distance_to_point(p_b))).collect()
 }
 
+/// Generates problem and solution with one route which serves jobs at given points in the given order.
+/// Euclidean distances between points are used as transport costs.
+pub fn generate_matrix_routes_from_points(points: &[(f64, f64)]) -> (Problem, Solution) {
+    let points = points.iter().map(|&(x, y)| Point::new(x, y)).collect::<Vec<_>>();
+    let matrix = generate_matrix_distances_from_points(points.as_slice());
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix.clone())])
+        .expect("cannot create transport");
+
+    generate_matrix_routes(
+        points.len(),
+        1,
+        false,
+        |_, activity| {
+            create_goal_ctx_with_feature(
+                create_minimize_transport_costs_feature("transport", transport, activity, 1).unwrap(),
+            )
+        },
+        test_single_with_id_and_location,
+        |v| v,
+        |_| (matrix.clone(), matrix.clone()),
+    )
+}
+
 pub fn generate_matrix_routes_with_disallow_list(
     rows: usize,
     cols: usize,
@@ -27,8 +27,11 @@ pub fn can_create_job_clusters() {
         Job::Single(test_single_with_id("job3")),
     ];
     let constraint = create_goal_context(vec![]);
-    let filtering =
-        FilterPolicy { job_filter: Arc::new(|job| get_job_id(job) != "job3"), actor_filter: Arc::new(|_| true) };
+    let filtering = FilterPolicy {
+        job_filter: Arc::new(|job| get_job_id(job) != "job3"),
+        actor_filter: Arc::new(|_| true),
+        pair_filter: Arc::new(|_, _| true),
+    };
     let config = ClusterConfig { filtering, ..create_cluster_config() };
     let fleet = test_fleet();
     let problem = create_problem_with_goal_ctx_jobs_and_fleet(constraint, jobs, fleet);
@@ -40,3 +43,27 @@ pub fn can_create_job_clusters() {
     let clustered = &cluster.1;
     assert_eq!(clustered.len(), 2);
 }
+
+#[test]
+pub fn can_create_job_clusters_with_pair_filter() {
+    let jobs = vec![
+        Job::Single(test_single_with_id("job1")),
+        Job::Single(test_single_with_id("job2")),
+        Job::Single(test_single_with_id("job3")),
+    ];
+    let constraint = create_goal_context(vec![]);
+    let filtering = FilterPolicy {
+        job_filter: Arc::new(|_| true),
+        actor_filter: Arc::new(|_| true),
+        pair_filter: Arc::new(|center, candidate| get_job_id(center) != "job3" && get_job_id(candidate) != "job3"),
+    };
+    let config = ClusterConfig { filtering, ..create_cluster_config() };
+    let fleet = test_fleet();
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(constraint, jobs, fleet);
+
+    let clusters = create_job_clusters(problem, Arc::new(Environment::default()), &config);
+
+    assert_eq!(clusters.len(), 1);
+    let clustered = clusters.first().unwrap().1.iter().map(|job| get_job_id(job).as_str()).collect::<HashSet<_>>();
+    assert_eq!(clustered, ["job1", "job2"].into_iter().collect());
+}
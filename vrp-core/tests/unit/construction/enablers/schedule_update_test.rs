@@ -0,0 +1,43 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+parameterized_test! {can_estimate_service_starts, (tw_starts, delay, expected), {
+    can_estimate_service_starts_impl(tw_starts, delay, expected);
+}}
+
+can_estimate_service_starts! {
+    case01_shift_absorbed_by_next: ((30., 0.), 0., (15., vec![])),
+    case02_shift_propagated_to_end: ((0., 0.), 0., (15., vec![(20, 25.), (30, 35.), (0, 65.)])),
+    case03_shift_absorbed_in_middle: ((0., 40.), 0., (15., vec![(20, 25.)])),
+    case04_shift_with_delayed_departure: ((0., 0.), 2., (17., vec![(20, 29.), (30, 41.), (0, 73.)])),
+}
+
+fn can_estimate_service_starts_impl(
+    tw_starts: (Timestamp, Timestamp),
+    delay: Timestamp,
+    expected: (Timestamp, Vec<(Location, Timestamp)>),
+) {
+    let fleet = test_fleet();
+    let (activity, transport) = (DelayedActivityCost::new_shared(delay), TestTransportCost::new_shared());
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location(10),
+            test_activity_with_location_and_tw(20, TimeWindow::new(tw_starts.0, 1000.)),
+            test_activity_with_location_and_tw(30, TimeWindow::new(tw_starts.1, 1000.)),
+        ],
+    );
+    update_schedules(&mut route_ctx, activity.as_ref(), transport.as_ref());
+    let route = route_ctx.route();
+    let target = test_activity_with_location_tw_and_duration(15, TimeWindow::new(0., 1000.), 5.);
+    let activity_ctx =
+        ActivityContext { index: 1, prev: route.tour.get(1).unwrap(), target: &target, next: route.tour.get(2) };
+
+    let (service_start, shifted) = estimate_service_starts(route, &activity_ctx, activity.as_ref(), transport.as_ref());
+
+    let shifted = shifted.into_iter().map(|(activity, time)| (activity.place.location, time)).collect::<Vec<_>>();
+    assert_eq!((service_start, shifted), expected);
+}
@@ -1,12 +1,12 @@
 use super::*;
-use crate::construction::heuristics::{ActivityContext, RouteState};
+use crate::construction::heuristics::{ActivityContext, RouteContext, RouteState, SolutionContext};
 use crate::helpers::construction::features::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
-use crate::models::problem::{Job, Vehicle};
-use crate::models::solution::Activity;
+use crate::models::problem::{Job, Multi, Vehicle};
+use crate::models::solution::{Activity, Route};
 use std::sync::Arc;
 
 const STATE_KEY: StateKey = 2;
@@ -140,6 +140,62 @@ fn can_evaluate_demand_on_activity_impl(
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_evaluate_peak_load_of_interleaved_multi_jobs, (has_pickup, prev_idx, expected), {
+    can_evaluate_peak_load_of_interleaved_multi_jobs_impl(has_pickup, prev_idx, expected);
+}}
+
+can_evaluate_peak_load_of_interleaved_multi_jobs! {
+    case01_pickup_before_peak: (false, 0, None),
+    case02_pickup_at_peak: (false, 2, create_constraint_violation(false)),
+    case03_delivery_before_peak: (true, 2, None),
+    case04_delivery_at_peak: (true, 3, create_constraint_violation(false)),
+    case05_delivery_after_peak: (true, 4, create_constraint_violation(false)),
+}
+
+fn can_evaluate_peak_load_of_interleaved_multi_jobs_impl(
+    has_pickup: bool,
+    prev_idx: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let create_pickup_delivery_job = |id: &str| {
+        test_multi_with_id(
+            id,
+            vec![
+                test_single_with_simple_demand(create_simple_dynamic_demand(1)),
+                test_single_with_simple_demand(create_simple_dynamic_demand(-1)),
+            ],
+        )
+    };
+    let create_activity = |job: &Arc<Multi>, idx: usize| test_activity_with_job(job.jobs[idx].clone());
+    let (job1, job2, job3) =
+        (create_pickup_delivery_job("job1"), create_pickup_delivery_job("job2"), create_pickup_delivery_job("job3"));
+    // NOTE load is 1, 2, 1, 0 and becomes 1, 2, 3, 2, 1 when pickup of third job is inserted after the first one
+    let mut activities = vec![
+        create_activity(&job1, 0),
+        create_activity(&job2, 0),
+        create_activity(&job1, 1),
+        create_activity(&job2, 1),
+    ];
+    if has_pickup {
+        activities.insert(1, create_activity(&job3, 0));
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(2)).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let feature = create_feature();
+    feature.state.unwrap().accept_route_state(&mut route_ctx);
+    let target = create_activity(&job3, if has_pickup { 1 } else { 0 });
+    let activity_ctx = ActivityContext {
+        index: prev_idx,
+        prev: route_ctx.route().tour.get(prev_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(prev_idx + 1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
 parameterized_test! {can_merge_jobs_with_demand, (cluster, candidate, expected), {
     can_merge_jobs_with_demand_impl(cluster, candidate, expected);
 }}
@@ -189,3 +245,316 @@ fn can_merge_jobs_with_demand_impl(
         (Err(result), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+parameterized_test! {can_merge_jobs_with_demand_within_fleet_capacity, (cluster, candidate, expected), {
+    can_merge_jobs_with_demand_within_fleet_capacity_impl(cluster, candidate, expected);
+}}
+
+can_merge_jobs_with_demand_within_fleet_capacity! {
+    case01_pickup_only: ((3, 0), (2, 0), Ok((5, 0))),
+    case02_pickup_only_overflow: ((3, 0), (3, 0), Err(VIOLATION_CODE)),
+    case03_delivery_only: ((0, 2), (0, 2), Ok((0, 4))),
+    case04_delivery_only_overflow: ((0, 3), (0, 3), Err(VIOLATION_CODE)),
+    case05_mixed: ((4, 1), (0, 4), Ok((4, 5))),
+    case06_mixed_delivery_overflow: ((1, 3), (0, 3), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_with_demand_within_fleet_capacity_impl(
+    cluster: (i32, i32),
+    candidate: (i32, i32),
+    expected: Result<(i32, i32), ViolationCode>,
+) {
+    let create_job = |(pickup, delivery): (i32, i32)| {
+        Job::Single(test_single_with_simple_demand(Demand::<SingleDimLoad> {
+            pickup: (SingleDimLoad::new(pickup), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(delivery), SingleDimLoad::default()),
+        }))
+    };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(3).build(),
+            VehicleBuilder::default().id("v2").capacity(5).build(),
+        ])
+        .build();
+    let constraint = create_capacity_limit_with_fleet_feature::<SingleDimLoad>("capacity", VIOLATION_CODE, &fleet)
+        .unwrap()
+        .constraint
+        .unwrap();
+
+    let result = constraint
+        .merge(create_job(cluster), create_job(candidate))
+        .map(|job| job.dimens().get_demand().cloned().unwrap())
+        .map(|demand: Demand<SingleDimLoad>| (demand.pickup.0.value, demand.delivery.0.value));
+
+    assert_eq!(result, expected);
+}
+
+struct TestMultiTrip;
+
+impl MultiTrip for TestMultiTrip {
+    type Constraint = SingleDimLoad;
+
+    fn is_marker_job(&self, job: &Job) -> bool {
+        job.dimens().get_id().map_or(false, |id| id == "reload")
+    }
+
+    fn is_assignable(&self, _: &Route, job: &Job) -> bool {
+        self.is_marker_job(job)
+    }
+
+    fn is_multi_trip_needed(&self, _: &RouteContext) -> bool {
+        false
+    }
+
+    fn get_state_code(&self) -> Option<i32> {
+        None
+    }
+
+    fn filter_markers<'a>(&'a self, _: &'a Route, _: &'a [Job]) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync> {
+        Box::new(std::iter::empty())
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
+
+fn create_compartments_feature(compartments: Vec<CompartmentPredicate<SingleDimLoad>>) -> Feature {
+    create_capacity_limit_with_compartments_feature::<SingleDimLoad>(
+        "compartments",
+        VIOLATION_CODE,
+        Arc::new(TestMultiTrip),
+        compartments,
+    )
+    .unwrap()
+}
+
+fn create_delivery_compartment() -> CompartmentPredicate<SingleDimLoad> {
+    Arc::new(|demand: &Demand<SingleDimLoad>| demand.delivery.0.is_not_empty())
+}
+
+fn create_activity_with_demand(size: i32) -> Activity {
+    test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size)))
+}
+
+parameterized_test! {can_evaluate_demand_on_compartments, (size, neighbours, expected), {
+    can_evaluate_demand_on_compartments_impl(size, neighbours, expected);
+}}
+
+can_evaluate_demand_on_compartments! {
+    case01_delivery_overflow: (-1, (0, 1), create_constraint_violation(true)),
+    case02_delivery_overflow_at_end: (-1, (3, 4), create_constraint_violation(true)),
+    case03_pickup_fits: (1, (0, 1), None),
+    case04_pickup_overflow: (2, (0, 1), create_constraint_violation(false)),
+    case05_pickup_fits_at_end: (1, (3, 4), None),
+}
+
+fn can_evaluate_demand_on_compartments_impl(
+    size: i32,
+    neighbours: (usize, usize),
+    expected: Option<ConstraintViolation>,
+) {
+    // NOTE deliveries and pickups (e.g. returned goods) are kept in separate compartments
+    let pickup_compartment: CompartmentPredicate<SingleDimLoad> =
+        Arc::new(|demand: &Demand<SingleDimLoad>| demand.pickup.0.is_not_empty());
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(4).compartments(vec![2, 2]).build())
+        .build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![-1, -1, 1].into_iter().map(create_activity_with_demand).collect(),
+    );
+    let compartment_feature = create_compartments_feature(vec![create_delivery_compartment(), pickup_compartment]);
+    let capacity_feature = create_feature();
+    compartment_feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    capacity_feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = create_activity_with_demand(size);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(neighbours.0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(neighbours.1),
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+
+    let result = compartment_feature.constraint.unwrap().evaluate(&move_ctx);
+
+    assert_eq!(result, expected);
+    // NOTE total load always fits vehicle capacity
+    assert_eq!(capacity_feature.constraint.unwrap().evaluate(&move_ctx), None);
+}
+
+parameterized_test! {can_use_vehicle_specific_compartment_capacities, (vehicle_id, expected), {
+    can_use_vehicle_specific_compartment_capacities_impl(vehicle_id, expected);
+}}
+
+can_use_vehicle_specific_compartment_capacities! {
+    case01_small_compartment: ("v1", create_constraint_violation(true)),
+    case02_big_compartment: ("v2", None),
+    case03_no_compartments: ("v3", create_constraint_violation(true)),
+}
+
+fn can_use_vehicle_specific_compartment_capacities_impl(vehicle_id: &str, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(4).compartments(vec![1]).build(),
+            VehicleBuilder::default().id("v2").capacity(4).compartments(vec![2]).build(),
+            VehicleBuilder::default().id("v3").capacity(4).build(),
+        ])
+        .build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, vehicle_id, vec![create_activity_with_demand(-1)]);
+    let feature = create_compartments_feature(vec![create_delivery_compartment()]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = create_activity_with_demand(-1);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_reset_compartment_load_on_reload, (prev_idx, expected), {
+    can_reset_compartment_load_on_reload_impl(prev_idx, expected);
+}}
+
+can_reset_compartment_load_on_reload! {
+    case01_first_trip_is_full: (0, create_constraint_violation(false)),
+    case02_second_trip_has_space: (3, None),
+}
+
+fn can_reset_compartment_load_on_reload_impl(prev_idx: usize, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(4).compartments(vec![2]).build())
+        .build();
+    // NOTE first trip is full, second one has a free space
+    let activities = vec![
+        create_activity_with_demand(-1),
+        create_activity_with_demand(-1),
+        test_activity_with_job(test_single_with_id("reload")),
+        create_activity_with_demand(-1),
+    ];
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let feature = create_compartments_feature(vec![create_delivery_compartment()]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = create_activity_with_demand(-1);
+    let activity_ctx = ActivityContext {
+        index: prev_idx,
+        prev: route_ctx.route().tour.get(prev_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(prev_idx + 1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_demand_without_compartment() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(4).compartments(vec![2]).build())
+        .build();
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let feature = create_compartments_feature(vec![create_delivery_compartment()]);
+    let evaluate = |size: i32| {
+        let job = Job::Single(test_single_with_simple_demand(create_simple_demand(size)));
+        feature.constraint.as_ref().unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job))
+    };
+
+    assert_eq!(evaluate(-1), None);
+    assert_eq!(evaluate(1), create_constraint_violation(true));
+}
+
+fn create_overflow_feature(max_ratio: f64, penalty: Cost) -> Result<Feature, String> {
+    create_capacity_limit_with_overflow_feature::<SingleDimLoad>(
+        "capacity",
+        VIOLATION_CODE,
+        Arc::new(NoMultiTrip::default()),
+        OverflowPolicy { max_ratio, penalty },
+    )
+}
+
+parameterized_test! {can_evaluate_demand_with_overflow, (sizes, size, expected, expected_cost), {
+    can_evaluate_demand_with_overflow_impl(sizes, size, expected, expected_cost);
+}}
+
+can_evaluate_demand_with_overflow! {
+    case01_no_overflow: (vec![-4, -5], -1, None, 0.),
+    case02_soft_overflow: (vec![-5, -5], -1, None, 10.),
+    case03_soft_overflow_at_ceiling: (vec![-5, -5], -2, None, 20.),
+    case04_hard_overflow: (vec![-5, -5], -3, create_constraint_violation(true), 30.),
+    case05_already_overloaded: (vec![-5, -6], -1, None, 10.),
+    case06_pickup_overflow: (vec![5, 5], 1, None, 10.),
+}
+
+fn can_evaluate_demand_with_overflow_impl(
+    sizes: Vec<i32>,
+    size: i32,
+    expected: Option<ConstraintViolation>,
+    expected_cost: Cost,
+) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        sizes
+            .into_iter()
+            .map(|size| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size))))
+            .collect(),
+    );
+    let feature = create_overflow_feature(0.2, 100.).unwrap();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size)));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+
+    let result = feature.constraint.unwrap().evaluate(&move_ctx);
+    let cost = feature.objective.unwrap().estimate(&move_ctx);
+
+    assert_eq!(result, expected);
+    assert!((cost - expected_cost).abs() < 1E-6, "unexpected cost: {cost}");
+}
+
+parameterized_test! {can_evaluate_demand_on_route_with_overflow, (size, expected), {
+    can_evaluate_demand_on_route_with_overflow_impl(size, expected);
+}}
+
+can_evaluate_demand_on_route_with_overflow! {
+    case01: (13, create_constraint_violation(true)),
+    case02: (12, None),
+    case03: (10, None),
+}
+
+fn can_evaluate_demand_on_route_with_overflow_impl(size: i32, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = Job::Single(test_single_with_simple_demand(create_simple_demand(size)));
+    let feature = create_overflow_feature(0.2, 100.).unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_invalid_overflow_policy() {
+    assert!(create_overflow_feature(-0.1, 100.).is_err());
+    assert!(create_overflow_feature(0.1, -1.).is_err());
+    assert!(create_overflow_feature(0.1, 1.).is_ok());
+}
@@ -0,0 +1,280 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::Place;
+
+const VIOLATION_CODE: ViolationCode = 1;
+const STATE_KEY: StateKey = 2;
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_test_feature(pickup: &Job, delivery: &Job) -> Feature {
+    create_test_feature_with_activity(pickup, delivery, TestActivityCost::new_shared())
+}
+
+fn create_test_feature_with_activity(
+    pickup: &Job,
+    delivery: &Job,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Feature {
+    create_cross_route_precedence_feature(
+        "cross_route_precedence",
+        &[(pickup.clone(), delivery.clone())],
+        TestTransportCost::new_shared(),
+        activity,
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+    .unwrap()
+}
+
+fn create_activity(job: &Job, location: Location, time: TimeWindow, schedule: Schedule) -> Activity {
+    ActivityBuilder::default()
+        .place(Place { location, duration: schedule.departure - schedule.arrival, time })
+        .schedule(schedule)
+        .job(Some(job.to_single().clone()))
+        .build()
+}
+
+fn create_pickup_route(fleet: &Fleet, pickup: &Job, departure: Timestamp) -> RouteContext {
+    let activity = create_activity(pickup, 10, DEFAULT_ACTIVITY_TIME_WINDOW, Schedule::new(10., departure));
+
+    create_route_context_with_activities(fleet, "v1", vec![activity])
+}
+
+parameterized_test! {can_evaluate_delivery_insertion, (pickup_departure, delivery_time_start, expected), {
+    can_evaluate_delivery_insertion_impl(pickup_departure, delivery_time_start, expected);
+}}
+
+can_evaluate_delivery_insertion! {
+    case01_delivery_before_pickup: (15., 0., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_delivery_after_pickup: (15., 20., None),
+    case03_delivery_at_pickup_departure: (15., 15., None),
+}
+
+fn can_evaluate_delivery_insertion_impl(
+    pickup_departure: Timestamp,
+    delivery_time_start: Timestamp,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_test_fleet();
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery = Job::Single(test_single_with_id("delivery"));
+    let feature = create_test_feature(&pickup, &delivery);
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_pickup_route(&fleet, &pickup, pickup_departure));
+    solution_ctx.routes.push(create_route_context_with_activities(&fleet, "v2", vec![]));
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[1];
+    let target = create_activity(
+        &delivery,
+        5,
+        TimeWindow::new(delivery_time_start, 1000.),
+        Schedule::new(delivery_time_start, delivery_time_start),
+    );
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_delivery_route, (has_pickup, expected), {
+    can_evaluate_delivery_route_impl(has_pickup, expected);
+}}
+
+can_evaluate_delivery_route! {
+    case01_pickup_assigned: (true, None),
+    case02_pickup_unassigned: (false, ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+fn can_evaluate_delivery_route_impl(has_pickup: bool, expected: Option<ConstraintViolation>) {
+    let fleet = create_test_fleet();
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery = Job::Single(test_single_with_id("delivery"));
+    let feature = create_test_feature(&pickup, &delivery);
+    let mut solution_ctx = create_empty_solution_context();
+    if has_pickup {
+        solution_ctx.routes.push(create_pickup_route(&fleet, &pickup, 15.));
+    }
+    solution_ctx.routes.push(create_route_context_with_activities(&fleet, "v2", vec![]));
+    let route_ctx = solution_ctx.routes.last().unwrap();
+
+    let result =
+        feature.constraint.as_ref().unwrap().evaluate(&MoveContext::route(&solution_ctx, route_ctx, &delivery));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_unassign_delivery_served_before_pickup, (delivery_service_start, expected_unassigned), {
+    can_unassign_delivery_served_before_pickup_impl(delivery_service_start, expected_unassigned);
+}}
+
+can_unassign_delivery_served_before_pickup! {
+    case01_served_before: (10., true),
+    case02_served_after: (20., false),
+}
+
+fn can_unassign_delivery_served_before_pickup_impl(delivery_service_start: Timestamp, expected_unassigned: bool) {
+    let fleet = create_test_fleet();
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery = Job::Single(test_single_with_id("delivery"));
+    let feature = create_test_feature(&pickup, &delivery);
+    let mut solution_ctx = create_empty_solution_context();
+    let delivery_activity = create_activity(
+        &delivery,
+        5,
+        DEFAULT_ACTIVITY_TIME_WINDOW,
+        Schedule::new(delivery_service_start, delivery_service_start),
+    );
+    solution_ctx.routes.push(create_pickup_route(&fleet, &pickup, 15.));
+    solution_ctx.routes.push(create_route_context_with_activities(&fleet, "v2", vec![delivery_activity]));
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[1].route().tour.has_job(&delivery), !expected_unassigned);
+    assert_eq!(solution_ctx.unassigned.contains_key(&delivery), expected_unassigned);
+    if expected_unassigned {
+        assert!(matches!(solution_ctx.unassigned.get(&delivery), Some(UnassignmentInfo::Simple(VIOLATION_CODE))));
+    }
+}
+
+#[test]
+fn can_return_err_if_job_is_used_in_multiple_pairs() {
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery1 = Job::Single(test_single_with_id("delivery1"));
+    let delivery2 = Job::Single(test_single_with_id("delivery2"));
+
+    let result = create_cross_route_precedence_feature(
+        "cross_route_precedence",
+        &[(pickup.clone(), delivery1), (pickup, delivery2)],
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    );
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_validate_precedence_chains, (pairs, expected_ok), {
+    can_validate_precedence_chains_impl(pairs, expected_ok);
+}}
+
+can_validate_precedence_chains! {
+    case01_chain: (vec![("a", "b"), ("b", "c")], true),
+    case02_two_chains: (vec![("a", "b"), ("c", "d")], true),
+    case03_two_pickups: (vec![("a", "c"), ("b", "c")], false),
+    case04_cycle: (vec![("a", "b"), ("b", "c"), ("c", "a")], false),
+    case05_short_cycle: (vec![("a", "b"), ("b", "a")], false),
+}
+
+fn can_validate_precedence_chains_impl(pairs: Vec<(&str, &str)>, expected_ok: bool) {
+    let jobs = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|id| (id, Job::Single(test_single_with_id(id))))
+        .collect::<HashMap<_, _>>();
+    let pairs =
+        pairs.into_iter().map(|(pickup, delivery)| (jobs[pickup].clone(), jobs[delivery].clone())).collect::<Vec<_>>();
+
+    let result = create_cross_route_precedence_feature(
+        "cross_route_precedence",
+        pairs.as_slice(),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    );
+
+    assert_eq!(result.is_ok(), expected_ok);
+}
+
+#[test]
+fn can_unassign_rest_of_chain_when_middle_visit_is_violated() {
+    let fleet = create_test_fleet();
+    let first = Job::Single(test_single_with_id("first"));
+    let second = Job::Single(test_single_with_id("second"));
+    let third = Job::Single(test_single_with_id("third"));
+    let feature = create_cross_route_precedence_feature(
+        "cross_route_precedence",
+        &[(first.clone(), second.clone()), (second.clone(), third.clone())],
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+    .unwrap();
+    let mut solution_ctx = create_empty_solution_context();
+    let second_activity = create_activity(&second, 5, DEFAULT_ACTIVITY_TIME_WINDOW, Schedule::new(10., 10.));
+    let third_activity = create_activity(&third, 7, DEFAULT_ACTIVITY_TIME_WINDOW, Schedule::new(30., 30.));
+    solution_ctx.routes.push(create_pickup_route(&fleet, &first, 15.));
+    solution_ctx.routes.push(create_route_context_with_activities(&fleet, "v2", vec![second_activity, third_activity]));
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.routes[0].route().tour.has_job(&first));
+    assert!(solution_ctx.unassigned.contains_key(&second));
+    assert!(solution_ctx.unassigned.contains_key(&third));
+}
+
+parameterized_test! {can_evaluate_pickup_departure_using_activity_cost, (delay, expected), {
+    can_evaluate_pickup_departure_using_activity_cost_impl(delay, expected);
+}}
+
+can_evaluate_pickup_departure_using_activity_cost! {
+    case01_departure_before_delivery: (0., None),
+    case02_departure_after_delivery: (10., ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+fn can_evaluate_pickup_departure_using_activity_cost_impl(delay: Timestamp, expected: Option<ConstraintViolation>) {
+    let fleet = create_test_fleet();
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery = Job::Single(test_single_with_id("delivery"));
+    let feature = create_test_feature_with_activity(&pickup, &delivery, DelayedActivityCost::new_shared(delay));
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v2", vec![]);
+    // NOTE delivery is served in another route starting at 10
+    route_ctx
+        .state_mut()
+        .put_route_state(STATE_KEY, vec![(delivery.clone(), (10., 10.))].into_iter().collect::<PartnerTimes>());
+    let target = create_activity(&pickup, 5, DEFAULT_ACTIVITY_TIME_WINDOW, Schedule::new(5., 5.));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_use_service_start_instead_of_place_duration_for_assigned_delivery() {
+    let fleet = create_test_fleet();
+    let pickup = Job::Single(test_single_with_id("pickup"));
+    let delivery = Job::Single(test_single_with_id("delivery"));
+    let feature = create_test_feature(&pickup, &delivery);
+    let mut solution_ctx = create_empty_solution_context();
+    // NOTE service takes longer than place duration (e.g. scaled by vehicle) and starts at 20
+    let mut delivery_activity = create_activity(&delivery, 5, TimeWindow::new(20., 1000.), Schedule::new(10., 30.));
+    delivery_activity.place.duration = 0.;
+    solution_ctx.routes.push(create_pickup_route(&fleet, &pickup, 25.));
+    solution_ctx.routes.push(create_route_context_with_activities(&fleet, "v2", vec![delivery_activity]));
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.contains_key(&delivery));
+}
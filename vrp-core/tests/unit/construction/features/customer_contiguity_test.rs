@@ -0,0 +1,84 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const CUSTOMER_ID_KEY: &str = "customer_id";
+
+fn create_customer_id_fn() -> CustomerIdFn {
+    Arc::new(|single| single.dimens.get_value::<String>(CUSTOMER_ID_KEY))
+}
+
+fn create_activity_with_customer(customer_id: Option<&str>) -> Activity {
+    let mut single =
+        Single { places: vec![test_place_with_location(Some(DEFAULT_JOB_LOCATION))], dimens: Default::default() };
+    if let Some(customer_id) = customer_id {
+        single.dimens.set_value(CUSTOMER_ID_KEY, customer_id.to_string());
+    }
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location(DEFAULT_JOB_LOCATION) }
+}
+
+fn create_route_ctx(customer_ids: &[Option<&str>]) -> RouteContext {
+    let fleet = test_fleet();
+    let activities = customer_ids.iter().map(|id| create_activity_with_customer(*id)).collect();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let feature = create_customer_contiguity_feature("customer", create_customer_id_fn(), 2.).unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(&[
+        Some("a"),
+        Some("b"),
+        Some("a"),
+        Some("a"),
+        None,
+        Some("b"),
+    ]));
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, (1. + 3.) * 2.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (customer_ids, target_id, index, expected), {
+    can_estimate_activity_insertion_impl(customer_ids, target_id, index, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_next_to_same_customer: (&[Some("a"), Some("b")], Some("a"), 1, 0.),
+    case02_after_foreign_customer: (&[Some("a"), Some("b")], Some("a"), 2, 10.),
+    case03_between_same_customer: (&[Some("a"), Some("a")], Some("b"), 1, 10.),
+    case04_between_same_customer_no_id: (&[Some("a"), Some("a")], None, 1, 10.),
+    case05_fill_existing_gap: (&[Some("a"), Some("b"), Some("a")], Some("a"), 1, 0.),
+    case06_no_customers: (&[None, None], None, 1, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(
+    customer_ids: &[Option<&str>],
+    target_id: Option<&str>,
+    index: usize,
+    expected: Cost,
+) {
+    let feature = create_customer_contiguity_feature("customer", create_customer_id_fn(), 10.).unwrap();
+    let route_ctx = create_route_ctx(customer_ids);
+    let target = create_activity_with_customer(target_id);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let cost = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(cost, expected);
+}
+
+#[test]
+fn can_validate_penalty() {
+    assert!(create_customer_contiguity_feature("customer", create_customer_id_fn(), -1.).is_err());
+}
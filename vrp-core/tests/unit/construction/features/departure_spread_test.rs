@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Job;
+use std::iter::once;
+
+fn create_insertion_ctx_with_departures(departures: Vec<Timestamp>) -> InsertionContext {
+    let vehicle_ids = (0..departures.len()).map(|idx| format!("v{idx}")).collect::<Vec<_>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vehicle_ids.iter().map(|id| VehicleBuilder::default().id(id).build()).collect())
+        .build();
+
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vehicle_ids
+        .iter()
+        .zip(departures)
+        .map(|(vehicle_id, departure)| {
+            let mut route_ctx =
+                create_route_context_with_activities(&fleet, vehicle_id, vec![test_activity_with_location(10)]);
+            route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+            route_ctx
+        })
+        .collect();
+
+    insertion_ctx
+}
+
+parameterized_test! {can_calculate_departure_spread_fitness, (spread, departures, expected), {
+    can_calculate_departure_spread_fitness_impl(spread, departures, expected);
+}}
+
+can_calculate_departure_spread_fitness! {
+    case01_stagger_same: (DepartureSpread::Stagger { interval: 10. }, vec![0., 0., 0.], 20.),
+    case02_stagger_partial: (DepartureSpread::Stagger { interval: 10. }, vec![12., 0., 5.], 5. + 3.),
+    case03_stagger_enough: (DepartureSpread::Stagger { interval: 10. }, vec![0., 10., 25.], 0.),
+    case04_band_same: (DepartureSpread::Band, vec![0., 0., 0.], 0.),
+    case05_band_spread: (DepartureSpread::Band, vec![12., 0., 5.], 12.),
+}
+
+fn can_calculate_departure_spread_fitness_impl(spread: DepartureSpread, departures: Vec<Timestamp>, expected: Cost) {
+    let feature = create_departure_spread_feature("departure_spread", spread).unwrap();
+    let insertion_ctx = create_insertion_ctx_with_departures(departures);
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_new_route_consistently_with_fitness, (spread, departures, departure), {
+    can_estimate_new_route_consistently_with_fitness_impl(spread, departures, departure);
+}}
+
+can_estimate_new_route_consistently_with_fitness! {
+    case01_stagger_close: (DepartureSpread::Stagger { interval: 10. }, vec![0., 20.], 15.),
+    case02_stagger_far: (DepartureSpread::Stagger { interval: 10. }, vec![0., 10.], 25.),
+    case03_band_inside: (DepartureSpread::Band, vec![0., 20.], 15.),
+    case04_band_outside: (DepartureSpread::Band, vec![0., 20.], 30.),
+    case05_band_second_route: (DepartureSpread::Band, vec![0.], 30.),
+}
+
+fn can_estimate_new_route_consistently_with_fitness_impl(
+    spread: DepartureSpread,
+    departures: Vec<Timestamp>,
+    departure: Timestamp,
+) {
+    let feature = create_departure_spread_feature("departure_spread", spread).unwrap();
+    let objective = feature.objective.as_ref().unwrap();
+    let insertion_ctx =
+        create_insertion_ctx_with_departures(departures.iter().cloned().chain(once(departure)).collect());
+    let old_insertion_ctx = create_insertion_ctx_with_departures(departures);
+    let mut route_ctx = insertion_ctx.solution.routes.last().unwrap().deep_copy();
+    route_ctx.route_mut().tour.remove_activity_at(1);
+    let job = Job::Single(test_single_with_location(Some(10)));
+
+    let estimate = objective.estimate(&MoveContext::route(&old_insertion_ctx.solution, &route_ctx, &job));
+    let used_route_estimate = objective.estimate(&MoveContext::route(
+        &old_insertion_ctx.solution,
+        insertion_ctx.solution.routes.last().unwrap(),
+        &job,
+    ));
+
+    assert_eq!(estimate, objective.fitness(&insertion_ctx) - objective.fitness(&old_insertion_ctx));
+    assert_eq!(used_route_estimate, 0.);
+}
+
+#[test]
+fn can_reject_negative_interval() {
+    let result = create_departure_spread_feature("departure_spread", DepartureSpread::Stagger { interval: -1. });
+
+    assert!(result.is_err());
+}
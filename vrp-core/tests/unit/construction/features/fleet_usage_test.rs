@@ -53,3 +53,227 @@ fn can_properly_estimate_solutions_impl(left: &[f64], right: &[f64], expected: O
 
     assert_eq!(result, expected);
 }
+
+mod vehicle_types {
+    use super::*;
+    use crate::helpers::models::problem::*;
+    use crate::models::problem::{Fleet, Job};
+    use std::sync::Arc;
+
+    const VEHICLE_TYPE_KEY: &str = "vehicle_type";
+
+    fn create_fleet() -> Fleet {
+        let create_vehicle = |id: &str, vehicle_type: &str| {
+            let mut vehicle = test_vehicle_with_id(id);
+            vehicle.dimens.set_value(VEHICLE_TYPE_KEY, vehicle_type.to_string());
+            vehicle
+        };
+
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![create_vehicle("a1", "a"), create_vehicle("a2", "a"), create_vehicle("b1", "b")])
+            .build()
+    }
+
+    fn create_feature() -> Feature {
+        create_minimize_vehicle_types_feature(
+            "min_vehicle_types",
+            Arc::new(|actor| actor.vehicle.dimens.get_value::<String>(VEHICLE_TYPE_KEY)),
+        )
+        .unwrap()
+    }
+
+    fn create_route_ctx(fleet: &Fleet, vehicle: &str, has_job: bool) -> RouteContext {
+        let activities = if has_job { vec![test_activity_with_job(test_single_with_id("job"))] } else { vec![] };
+        create_route_context_with_activities(fleet, vehicle, activities)
+    }
+
+    parameterized_test! {can_calculate_fitness, (routes, expected), {
+        can_calculate_fitness_impl(routes, expected);
+    }}
+
+    can_calculate_fitness! {
+        case01_single_type: (vec![("a1", true), ("a2", true)], 1.),
+        case02_mixed_types: (vec![("a1", true), ("b1", true)], 2.),
+        case03_ignore_empty: (vec![("a1", true), ("a2", true), ("b1", false)], 1.),
+        case04_empty: (vec![], 0.),
+    }
+
+    fn can_calculate_fitness_impl(routes: Vec<(&str, bool)>, expected: Cost) {
+        let fleet = create_fleet();
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes =
+            routes.into_iter().map(|(vehicle, has_job)| create_route_ctx(&fleet, vehicle, has_job)).collect();
+
+        let result = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_estimate_route, (vehicle, has_job, expected), {
+        can_estimate_route_impl(vehicle, has_job, expected);
+    }}
+
+    can_estimate_route! {
+        case01_new_route_of_used_type: ("a2", false, 0.),
+        case02_new_route_of_new_type: ("b1", false, 1.),
+        case03_used_route_of_new_type: ("b1", true, 0.),
+    }
+
+    fn can_estimate_route_impl(vehicle: &str, has_job: bool, expected: Cost) {
+        let fleet = create_fleet();
+        let mut solution_ctx = create_empty_solution_context();
+        solution_ctx.routes.push(create_route_ctx(&fleet, "a1", true));
+        let route_ctx = create_route_ctx(&fleet, vehicle, has_job);
+        let job = Job::Single(test_single_with_id("job2"));
+
+        let result = create_feature().objective.unwrap().estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod overtime {
+    use super::*;
+    use crate::helpers::models::problem::*;
+    use crate::models::problem::{Fleet, Job};
+    use std::sync::Arc;
+
+    const OVERTIME_KEY: &str = "overtime_penalty";
+
+    fn create_fleet() -> Fleet {
+        let create_vehicle = |id: &str, penalty: Option<Cost>| {
+            let mut vehicle = test_vehicle_with_id(id);
+            if let Some(penalty) = penalty {
+                vehicle.dimens.set_value(OVERTIME_KEY, penalty);
+            }
+            vehicle
+        };
+
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![
+                create_vehicle("r1", None),
+                create_vehicle("o1", Some(10.)),
+                create_vehicle("o2", Some(5.)),
+            ])
+            .build()
+    }
+
+    fn create_feature() -> Feature {
+        create_minimize_overtime_feature(
+            "min_overtime",
+            Arc::new(|actor| actor.vehicle.dimens.get_value::<Cost>(OVERTIME_KEY).cloned()),
+        )
+        .unwrap()
+    }
+
+    fn create_route_ctx(fleet: &Fleet, vehicle: &str, has_job: bool) -> RouteContext {
+        let activities = if has_job { vec![test_activity_with_job(test_single_with_id("job"))] } else { vec![] };
+        create_route_context_with_activities(fleet, vehicle, activities)
+    }
+
+    parameterized_test! {can_calculate_fitness, (routes, expected), {
+        can_calculate_fitness_impl(routes, expected);
+    }}
+
+    can_calculate_fitness! {
+        case01_regular_only: (vec![("r1", true)], 0.),
+        case02_single_overtime: (vec![("r1", true), ("o1", true)], 10.),
+        case03_all_overtime: (vec![("o1", true), ("o2", true)], 15.),
+        case04_ignore_empty: (vec![("r1", true), ("o1", false)], 0.),
+        case05_empty: (vec![], 0.),
+    }
+
+    fn can_calculate_fitness_impl(routes: Vec<(&str, bool)>, expected: Cost) {
+        let fleet = create_fleet();
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes =
+            routes.into_iter().map(|(vehicle, has_job)| create_route_ctx(&fleet, vehicle, has_job)).collect();
+
+        let result = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_estimate_route, (vehicle, has_job, expected), {
+        can_estimate_route_impl(vehicle, has_job, expected);
+    }}
+
+    can_estimate_route! {
+        case01_new_regular_route: ("r1", false, 0.),
+        case02_new_overtime_route: ("o1", false, 10.),
+        case03_used_overtime_route: ("o1", true, 0.),
+    }
+
+    fn can_estimate_route_impl(vehicle: &str, has_job: bool, expected: Cost) {
+        let fleet = create_fleet();
+        let solution_ctx = create_empty_solution_context();
+        let route_ctx = create_route_ctx(&fleet, vehicle, has_job);
+        let job = Job::Single(test_single_with_id("job2"));
+
+        let result = create_feature().objective.unwrap().estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod tours_deviation {
+    use super::*;
+    use crate::helpers::models::problem::*;
+    use crate::models::problem::Job;
+
+    fn create_route_ctx(insertion_ctx: &InsertionContext, has_job: bool) -> RouteContext {
+        let activities = if has_job { vec![test_activity_with_job(test_single_with_id("job"))] } else { vec![] };
+        create_route_context_with_activities(insertion_ctx.problem.fleet.as_ref(), "v1", activities)
+    }
+
+    parameterized_test! {can_calculate_fitness, (target, used, empty, expected), {
+        can_calculate_fitness_impl(target, used, empty, expected);
+    }}
+
+    can_calculate_fitness! {
+        case01_same: (2, 2, 0, 0.),
+        case02_less: (3, 1, 0, 2.),
+        case03_more: (1, 3, 0, 2.),
+        case04_ignore_empty: (2, 1, 1, 1.),
+    }
+
+    fn can_calculate_fitness_impl(target: usize, used: usize, empty: usize, expected: Cost) {
+        let mut insertion_ctx = create_empty_insertion_context();
+        let routes = (0..used)
+            .map(|_| create_route_ctx(&insertion_ctx, true))
+            .chain((0..empty).map(|_| create_route_ctx(&insertion_ctx, false)))
+            .collect();
+        insertion_ctx.solution.routes = routes;
+
+        let result = create_minimize_tours_deviation_feature("tours_deviation", target).unwrap().objective.unwrap();
+
+        assert_eq!(result.fitness(&insertion_ctx), expected);
+    }
+
+    parameterized_test! {can_estimate_route, (target, used, has_job, expected), {
+        can_estimate_route_impl(target, used, has_job, expected);
+    }}
+
+    can_estimate_route! {
+        case01_new_route_below_target: (2, 1, false, -1.),
+        case02_new_route_at_target: (2, 2, false, 1.),
+        case03_new_route_above_target: (1, 2, false, 1.),
+        case04_used_route: (2, 2, true, 0.),
+    }
+
+    fn can_estimate_route_impl(target: usize, used: usize, has_job: bool, expected: Cost) {
+        let mut insertion_ctx = create_empty_insertion_context();
+        let routes = (0..used).map(|_| create_route_ctx(&insertion_ctx, true)).collect();
+        insertion_ctx.solution.routes = routes;
+        let route_ctx = create_route_ctx(&insertion_ctx, has_job);
+        let job = Job::Single(test_single_with_id("job2"));
+        let feature = create_minimize_tours_deviation_feature("tours_deviation", target).unwrap();
+
+        let result =
+            feature.objective.unwrap().estimate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+}
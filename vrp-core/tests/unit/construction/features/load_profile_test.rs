@@ -0,0 +1,78 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+
+fn create_route_ctx(sizes: Vec<i32>) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(4).build())
+        .build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        sizes
+            .into_iter()
+            .map(|size| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size))))
+            .collect(),
+    );
+    create_capacity_limit_feature::<SingleDimLoad>("capacity", 0)
+        .unwrap()
+        .state
+        .unwrap()
+        .accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_fitness, (sizes, expected), {
+    can_calculate_fitness_impl(sizes, expected);
+}}
+
+can_calculate_fitness! {
+    case01_deliver_down: (vec![-1, -1, -1], 0.),
+    case02_pickup_before_deliveries: (vec![1, -1, -1], 0.),
+    case03_interleaved: (vec![-1, 1, -1], 0.25),
+    case04_pickup_after_deliveries: (vec![-1, -1, 1], 0.25),
+    case05_several_increases: (vec![-1, 1, -1, 1], 0.5),
+}
+
+fn can_calculate_fitness_impl(sizes: Vec<i32>, expected: f64) {
+    let feature = create_deliver_down_load_feature::<SingleDimLoad>("deliver_down", 1.).unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(sizes));
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+#[test]
+fn can_prefer_deliver_down_ordering() {
+    // NOTE all activities share the same location, so any ordering has equal distance
+    let feature = create_deliver_down_load_feature::<SingleDimLoad>("deliver_down", 10.).unwrap();
+    let objective = feature.objective.unwrap();
+    let route_ctx = create_route_ctx(vec![-1, -1]);
+    let target = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(1)));
+
+    let costs = (0..3)
+        .map(|index| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            };
+            objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(costs, vec![0., 2.5, 2.5]);
+}
+
+#[test]
+fn can_reject_negative_penalty() {
+    assert!(create_deliver_down_load_feature::<SingleDimLoad>("deliver_down", -1.).is_err());
+}
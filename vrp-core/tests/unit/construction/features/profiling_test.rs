@@ -0,0 +1,61 @@
+use super::*;
+use crate::models::examples::create_example_problem;
+use crate::solver::{create_default_config_builder, Solver};
+use rosomaxa::evolution::TelemetryMode;
+
+fn create_profiled_problem() -> (Arc<Problem>, ConstraintProfiler) {
+    let problem = create_example_problem();
+    let features = vec![
+        create_minimize_unassigned_jobs_feature("min_jobs", Arc::new(|_, _| 1.)).unwrap(),
+        create_minimize_tours_feature("min_tours").unwrap(),
+        create_minimize_distance_feature("min_distance", problem.transport.clone(), problem.activity.clone(), 1)
+            .unwrap(),
+        create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+    ];
+    let feature_map =
+        vec![vec!["min_jobs".to_string()], vec!["min_tours".to_string()], vec!["min_distance".to_string()]];
+
+    let (features, profiler) = create_profiled_features(features);
+    let goal = GoalContext::new(features.as_slice(), feature_map.as_slice(), feature_map.as_slice()).unwrap();
+
+    let problem = Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: Arc::new(goal),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    });
+
+    (problem, profiler)
+}
+
+#[test]
+fn can_report_constraint_timings_after_solve() {
+    let (problem, profiler) = create_profiled_problem();
+    let config = create_default_config_builder(problem.clone(), Arc::new(Environment::default()), TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    Solver::new(problem, config).solve().unwrap();
+
+    let report = profiler.report();
+    let mut names = report.iter().map(|timing| timing.name.as_str()).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["capacity", "min_distance"]);
+    assert!(report.iter().all(|timing| timing.calls > 0 && timing.total > StdDuration::default()));
+    assert!(report.windows(2).all(|pair| pair[0].total >= pair[1].total));
+}
+
+#[test]
+fn can_keep_features_without_constraint_unchanged() {
+    let features = vec![create_minimize_tours_feature("min_tours").unwrap()];
+
+    let (features, profiler) = create_profiled_features(features);
+
+    assert_eq!(features.len(), 1);
+    assert!(features[0].constraint.is_none());
+    assert!(profiler.report().is_empty());
+}
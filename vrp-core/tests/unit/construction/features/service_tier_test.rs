@@ -0,0 +1,131 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::ValueDimension;
+use crate::models::problem::Single;
+
+const TIER_KEY: &str = "tier";
+
+fn create_service_tier_fn() -> ServiceTierFn {
+    Arc::new(|dimens| {
+        dimens.get_value::<String>(TIER_KEY).and_then(|tier| match tier.as_str() {
+            "gold" => Some(ServiceTier { penalty: 10., earliness: 2. }),
+            "bronze" => Some(ServiceTier { penalty: 1., earliness: 0. }),
+            _ => None,
+        })
+    })
+}
+
+fn create_single_with_tier(location: Location, tier: Option<&str>) -> Single {
+    let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+    if let Some(tier) = tier {
+        single.dimens.set_value(TIER_KEY, tier.to_string());
+    }
+
+    single
+}
+
+fn create_activity_with_tier(location: Location, tier: Option<&str>) -> Activity {
+    Activity { job: Some(Arc::new(create_single_with_tier(location, tier))), ..test_activity_with_location(location) }
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+fn create_feature() -> Feature {
+    create_service_tier_feature(
+        "service_tier",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_service_tier_fn(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(vec![
+        create_activity_with_tier(10, Some("gold")),
+        create_activity_with_tier(20, Some("bronze")),
+        create_activity_with_tier(30, None),
+    ]));
+    insertion_ctx.solution.unassigned.extend(vec![
+        (Job::Single(Arc::new(create_single_with_tier(40, Some("gold")))), UnassignmentInfo::Unknown),
+        (Job::Single(Arc::new(create_single_with_tier(50, Some("bronze")))), UnassignmentInfo::Unknown),
+        (Job::Single(Arc::new(create_single_with_tier(60, None))), UnassignmentInfo::Unknown),
+    ]);
+
+    let fitness = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 10. * 2. + 10. + 1.);
+}
+
+parameterized_test! {can_estimate_job_insertion, (tier, expected), {
+    can_estimate_job_insertion_impl(tier, expected);
+}}
+
+can_estimate_job_insertion! {
+    case01_gold: (Some("gold"), -10.),
+    case02_bronze: (Some("bronze"), -1.),
+    case03_no_tier: (None, 0.),
+}
+
+fn can_estimate_job_insertion_impl(tier: Option<&str>, expected: Cost) {
+    let insertion_ctx = create_empty_insertion_context();
+    let route_ctx = create_route_ctx(vec![]);
+    let job = Job::Single(Arc::new(create_single_with_tier(10, tier)));
+
+    let result =
+        create_feature().objective.unwrap().estimate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_earlier_service_of_higher_tier() {
+    let objective = create_feature().objective.unwrap();
+    let route_ctx = create_route_ctx(vec![create_activity_with_tier(10, None), create_activity_with_tier(20, None)]);
+    let target = create_activity_with_tier(5, Some("gold"));
+
+    let costs = (0..3)
+        .map(|index| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            };
+            objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(costs, vec![10., 30., 70.]);
+}
+
+#[test]
+fn can_estimate_earliness_of_all_shifted_activities() {
+    let objective = create_feature().objective.unwrap();
+    let route_ctx = create_route_ctx(vec![
+        create_activity_with_tier(10, None),
+        create_activity_with_tier(20, Some("gold")),
+        create_activity_with_tier(30, Some("gold")),
+    ]);
+    let target = create_activity_with_tier(25, None);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let cost = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    // NOTE insertion shifts both gold activities by 10
+    assert_eq!(cost, 2. * 10. * 2.);
+}
@@ -0,0 +1,437 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeInterval;
+use crate::models::problem::{VehicleDetail, VehiclePlace};
+use std::cmp::Ordering;
+
+const TARGET_TIME_KEY: &str = "target_time";
+
+fn create_target_time_fn() -> TargetTimeFn {
+    Arc::new(|single| single.dimens.get_value::<Timestamp>(TARGET_TIME_KEY).cloned())
+}
+
+fn create_activity_with_target(location: Location, target: Option<Timestamp>) -> Activity {
+    let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+    if let Some(target) = target {
+        single.dimens.set_value(TARGET_TIME_KEY, target);
+    }
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) }
+}
+
+fn create_open_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            VehicleBuilder::default()
+                .id("v1")
+                .details(vec![VehicleDetail {
+                    start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                    end: None,
+                }])
+                .build(),
+        )
+        .build();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let feature = create_target_time_feature(
+        "target_time",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        1.,
+        2.,
+    )
+    .unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_open_route_ctx(vec![
+        create_activity_with_target(10, Some(15.)),
+        create_activity_with_target(20, Some(17.)),
+        create_activity_with_target(30, None),
+    ]));
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 5. + 3. * 2.);
+}
+
+fn get_estimate_and_fitness_change(
+    feature: &Feature,
+    activities: Vec<Activity>,
+    target: Activity,
+    index: usize,
+) -> (Cost, Cost) {
+    let transport_feature = create_minimize_transport_costs_feature(
+        "transport",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        1,
+    )
+    .unwrap();
+    let create_insertion_ctx = |activities: Vec<Activity>| {
+        let mut insertion_ctx = create_empty_insertion_context();
+        let mut route_ctx = create_open_route_ctx(activities);
+        transport_feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        insertion_ctx.solution.routes.push(route_ctx);
+        if let Some(state) = feature.state.as_ref() {
+            state.accept_solution_state(&mut insertion_ctx.solution);
+        }
+        insertion_ctx
+    };
+    let objective = feature.objective.as_ref().unwrap();
+
+    let insertion_ctx = create_insertion_ctx(activities.iter().map(|activity| activity.deep_copy()).collect());
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let estimate = objective.estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    let mut activities = activities;
+    activities.insert(index, target.deep_copy());
+    let fitness_change = objective.fitness(&create_insertion_ctx(activities)) - objective.fitness(&insertion_ctx);
+
+    (estimate, fitness_change)
+}
+
+#[test]
+fn can_estimate_deviation_of_all_shifted_activities() {
+    let feature = create_target_time_feature(
+        "target_time",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        1.,
+        2.,
+    )
+    .unwrap();
+    let activities = vec![
+        create_activity_with_target(10, Some(12.)),
+        create_activity_with_target(20, Some(22.)),
+        create_activity_with_target(30, Some(28.)),
+    ];
+
+    let (estimate, fitness_change) =
+        get_estimate_and_fitness_change(&feature, activities, create_activity_with_target(25, None), 1);
+
+    // NOTE insertion shifts both following activities by 10: (-2 + 8 * 2) + (12 - 2) * 2
+    assert_eq!(estimate, 34.);
+    assert_eq!(estimate, fitness_change);
+}
+
+parameterized_test! {can_choose_insertion_position, (earliness, expected_index), {
+    can_choose_insertion_position_impl(earliness, expected_index);
+}}
+
+can_choose_insertion_position! {
+    case01_cheap_deviation: (0.1, 1),
+    case02_expensive_deviation: (10., 2),
+}
+
+fn can_choose_insertion_position_impl(earliness: Cost, expected_index: usize) {
+    let (transport, activity) = (TestTransportCost::new_shared(), TestActivityCost::new_shared());
+    let transport_feature =
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity, 1).unwrap();
+    let target_feature = create_target_time_feature(
+        "target_time",
+        transport,
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        earliness,
+        1.,
+    )
+    .unwrap();
+    let mut route_ctx =
+        create_open_route_ctx(vec![create_activity_with_target(10, None), create_activity_with_target(20, None)]);
+    transport_feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let goal =
+        create_goal_ctx_with_features(vec![transport_feature, target_feature], vec![vec!["transport", "target_time"]]);
+    let target = create_activity_with_target(15, Some(25.));
+
+    let (index, _) = (0..route_ctx.route().tour.total())
+        .map(|index| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            };
+
+            (index, goal.estimate(&MoveContext::activity(&route_ctx, &activity_ctx)))
+        })
+        .min_by(|(_, a), (_, b)| a.cmp(b))
+        .unwrap();
+
+    assert_eq!(index, expected_index);
+}
+
+parameterized_test! {can_limit_late_activities, (location, target_time, index, limit, expected), {
+    can_limit_late_activities_impl(location, target_time, index, limit, expected);
+}}
+
+can_limit_late_activities! {
+    case01_late_below_limit: (30, Some(25.), 2, Some(2), None),
+    case02_late_above_limit: (30, Some(25.), 2, Some(1), ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_in_time: (30, Some(30.), 2, Some(1), None),
+    case04_shift_absorbed: (15, None, 1, Some(1), None),
+    case05_shift_makes_next_late: (25, None, 1, Some(1), ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_no_limit: (30, Some(25.), 2, None, None),
+}
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn can_limit_late_activities_impl(
+    location: Location,
+    target_time: Option<Timestamp>,
+    index: usize,
+    limit: Option<usize>,
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_max_late_activities_feature(
+        "max_late",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        Arc::new(move |_| limit),
+    )
+    .unwrap();
+    let mut route_ctx = create_open_route_ctx(vec![
+        create_activity_with_target(10, Some(5.)),
+        create_activity_with_target(20, Some(20.)),
+    ]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = create_activity_with_target(location, target_time);
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_prefer_smaller_max_lateness, (is_minimax, expected), {
+    can_prefer_smaller_max_lateness_impl(is_minimax, expected);
+}}
+
+can_prefer_smaller_max_lateness! {
+    case01_minimax_lateness: (true, Ordering::Less),
+    case02_total_lateness: (false, Ordering::Greater),
+}
+
+fn can_prefer_smaller_max_lateness_impl(is_minimax: bool, expected: Ordering) {
+    let feature = if is_minimax {
+        create_minimize_max_lateness_feature(
+            "max_lateness",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            create_target_time_fn(),
+        )
+    } else {
+        create_target_time_feature(
+            "target_time",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            create_target_time_fn(),
+            1.,
+            1.,
+        )
+    }
+    .unwrap();
+    let create_insertion_ctx = |targets: Vec<(Location, Timestamp)>| {
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes.push(create_open_route_ctx(
+            targets.into_iter().map(|(location, target)| create_activity_with_target(location, Some(target))).collect(),
+        ));
+        insertion_ctx
+    };
+    // NOTE service start is equal to location: everyone is slightly late vs a single job is very late
+    let slightly_late = create_insertion_ctx(vec![(10, 5.), (20, 15.)]);
+    let very_late = create_insertion_ctx(vec![(10, 10.), (20, 12.)]);
+
+    let result = feature.objective.as_ref().unwrap().total_order(&slightly_late, &very_late);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_estimate_max_lateness_increase() {
+    let feature = create_minimize_max_lateness_feature(
+        "max_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+    )
+    .unwrap();
+    let mut route_ctx = create_open_route_ctx(vec![create_activity_with_target(10, Some(5.))]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let estimate = |target: Timestamp| {
+        let target = create_activity_with_target(20, Some(target));
+        let activity_ctx =
+            ActivityContext { index: 2, prev: route_ctx.route().tour.get(1).unwrap(), target: &target, next: None };
+
+        feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+    };
+
+    // NOTE service start of the new activity is 20 + job duration, route's max lateness is 5
+    assert_eq!(estimate(20.), 0.);
+    assert_eq!(estimate(18. + DEFAULT_JOB_DURATION), 0.);
+    assert_eq!(estimate(12. + DEFAULT_JOB_DURATION), 3.);
+}
+
+#[test]
+fn can_estimate_max_lateness_of_all_shifted_activities() {
+    let feature = create_minimize_max_lateness_feature(
+        "max_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+    )
+    .unwrap();
+    let activities = vec![
+        create_activity_with_target(10, Some(10.)),
+        create_activity_with_target(20, Some(20.)),
+        create_activity_with_target(30, Some(27.)),
+    ];
+
+    let (estimate, fitness_change) =
+        get_estimate_and_fitness_change(&feature, activities, create_activity_with_target(25, None), 1);
+
+    // NOTE insertion shifts both following activities by 10, so the last one becomes the latest: 13 - 3
+    assert_eq!(estimate, 10.);
+    assert_eq!(estimate, fitness_change);
+}
+
+parameterized_test! {can_estimate_max_lateness_against_other_tours, (target_time, expected), {
+    can_estimate_max_lateness_against_other_tours_impl(target_time, expected);
+}}
+
+can_estimate_max_lateness_against_other_tours! {
+    case01_below_other_tour: (12., 0.),
+    case02_above_other_tour: (5., 5.),
+}
+
+fn can_estimate_max_lateness_against_other_tours_impl(target_time: Timestamp, expected: Cost) {
+    let feature = create_minimize_max_lateness_feature(
+        "max_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+    )
+    .unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_open_route_ctx(vec![create_activity_with_target(10, Some(0.))]));
+    insertion_ctx.solution.routes.push(create_open_route_ctx(vec![create_activity_with_target(10, Some(5.))]));
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let route_ctx = insertion_ctx.solution.routes.get(1).unwrap();
+    let target = create_activity_with_target(20, Some(target_time));
+    let activity_ctx =
+        ActivityContext { index: 1, prev: route_ctx.route().tour.get(1).unwrap(), target: &target, next: None };
+
+    let estimate = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    // NOTE other tour's max lateness is 10, the tour's own one is 5
+    assert_eq!(estimate, expected);
+}
+
+const VALUE_KEY: &str = "value";
+
+fn create_value_fn() -> JobValueFn {
+    Arc::new(|single| single.dimens.get_value::<Cost>(VALUE_KEY).copied().unwrap_or_default())
+}
+
+fn create_activity_with_value(location: Location, target: Timestamp, value: Cost) -> Activity {
+    let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+    single.dimens.set_value(TARGET_TIME_KEY, target);
+    single.dimens.set_value(VALUE_KEY, value);
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) }
+}
+
+#[test]
+fn can_calculate_value_weighted_lateness_fitness() {
+    let feature = create_minimize_value_weighted_lateness_feature(
+        "weighted_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        create_value_fn(),
+    )
+    .unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_open_route_ctx(vec![
+        create_activity_with_value(10, 5., 10.),
+        create_activity_with_value(20, 25., 100.),
+        create_activity_with_value(30, 27., 1.),
+    ]));
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 5. * 10. + 3. * 1.);
+}
+
+#[test]
+fn can_estimate_value_weighted_lateness_of_all_shifted_activities() {
+    let feature = create_minimize_value_weighted_lateness_feature(
+        "weighted_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        create_value_fn(),
+    )
+    .unwrap();
+    let activities = vec![
+        create_activity_with_value(10, 10., 1.),
+        create_activity_with_value(20, 25., 10.),
+        create_activity_with_value(30, 32., 100.),
+    ];
+
+    let (estimate, fitness_change) =
+        get_estimate_and_fitness_change(&feature, activities, create_activity_with_value(25, 100., 1.), 1);
+
+    // NOTE insertion shifts both following activities by 10: 5 * 10 + 8 * 100
+    assert_eq!(estimate, 850.);
+    assert_eq!(estimate, fitness_change);
+}
+
+#[test]
+fn can_prefer_high_value_job_served_on_time() {
+    let feature = create_minimize_value_weighted_lateness_feature(
+        "weighted_lateness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        create_target_time_fn(),
+        create_value_fn(),
+    )
+    .unwrap();
+    let create_insertion_ctx = |activities: Vec<Activity>| {
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes.push(create_open_route_ctx(activities));
+        insertion_ctx
+    };
+    // NOTE service start is equal to location
+    let high_value_on_time =
+        create_insertion_ctx(vec![create_activity_with_value(10, 10., 100.), create_activity_with_value(20, 15., 1.)]);
+    let low_value_on_time =
+        create_insertion_ctx(vec![create_activity_with_value(10, 15., 1.), create_activity_with_value(20, 10., 100.)]);
+
+    let result = feature.objective.as_ref().unwrap().total_order(&high_value_on_time, &low_value_on_time);
+
+    assert_eq!(result, Ordering::Less);
+}
@@ -64,3 +64,37 @@ fn can_merge_value() {
 
     assert_eq!(merged.dimens().get_value::<f64>("value").cloned(), Some(12.))
 }
+
+#[test]
+fn can_estimate_job_value_from_priority_tier() {
+    let (base, max_tier) = (10_f64, 3);
+    let objective = create_maximize_total_job_value_feature(
+        "value",
+        JobReadValueFn::Left(create_priority_tier_value_fn(
+            Arc::new(|job| match get_job_id(job).as_str() {
+                "tier1" => Some(1),
+                "tier3" => Some(3),
+                _ => None,
+            }),
+            Arc::new(move |tier| base.powi((max_tier - tier) as i32)),
+        )),
+        Arc::new(|job, _| job),
+        VIOLATION_CODE,
+    )
+    .unwrap()
+    .objective
+    .unwrap();
+    let route_ctx = create_empty_route_ctx();
+    let solution_ctx = create_empty_solution_context();
+    let estimate = |id: &str| {
+        objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &Job::Single(test_single_with_id(id))))
+    };
+
+    let tier1_value = estimate("tier1");
+    let tier3_value = (0..5).map(|_| estimate("tier3")).sum::<f64>();
+
+    assert_eq!(tier1_value, -100.);
+    assert_eq!(tier3_value, -5.);
+    assert_eq!(estimate("no_tier"), 0.);
+    assert!(tier1_value < tier3_value);
+}
@@ -160,3 +160,607 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod cost {
+    use super::*;
+    use crate::construction::features::tour_limits::create_route_cost_limit_feature;
+    use crate::models::common::Cost;
+
+    const COST_CODE: ViolationCode = 4;
+
+    parameterized_test! {can_check_route_cost_limit, (location, limit, expected), {
+        can_check_route_cost_limit_impl(location, limit, expected);
+    }}
+
+    can_check_route_cost_limit! {
+        case01_exceeded: (76, Some(400.), ConstraintViolation::skip(COST_CODE)),
+        case02_within_limit: (74, Some(400.), None),
+        case03_no_limit: (76, None, None),
+    }
+
+    fn can_check_route_cost_limit_impl(location: Location, limit: Option<Cost>, expected: Option<ConstraintViolation>) {
+        let mut state = RouteState::default();
+        state.put_route_state(TOTAL_DISTANCE_KEY, 50.);
+        state.put_route_state(TOTAL_DURATION_KEY, 50.);
+        let route_ctx = RouteContext::new_with_state(create_route_with_activities(&test_fleet(), "v1", vec![]), state);
+        let feature = create_route_cost_limit_feature(
+            "cost_limit",
+            TestTransportCost::new_shared(),
+            Arc::new(move |_| limit),
+            COST_CODE,
+        )
+        .unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(50),
+                target: &test_activity_with_location(location),
+                next: Some(&test_activity_with_location(50)),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod budget {
+    use super::*;
+    use crate::models::common::Cost;
+
+    const BUDGET_CODE: ViolationCode = 5;
+
+    parameterized_test! {can_check_fleet_cost_budget, (location, remaining_budget, expected), {
+        can_check_fleet_cost_budget_impl(location, remaining_budget, expected);
+    }}
+
+    can_check_fleet_cost_budget! {
+        case01_exceeded: (76, Some(400.), ConstraintViolation::skip(BUDGET_CODE)),
+        case02_within_budget: (74, Some(400.), None),
+        case03_no_budget_state: (76, None, None),
+    }
+
+    fn can_check_fleet_cost_budget_impl(
+        location: Location,
+        remaining_budget: Option<Cost>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let mut state = RouteState::default();
+        state.put_route_state(TOTAL_DISTANCE_KEY, 50.);
+        state.put_route_state(TOTAL_DURATION_KEY, 50.);
+        if let Some(remaining_budget) = remaining_budget {
+            state.put_route_state(REMAINING_BUDGET_KEY, remaining_budget);
+        }
+        let route_ctx = RouteContext::new_with_state(create_route_with_activities(&test_fleet(), "v1", vec![]), state);
+        let feature =
+            create_fleet_cost_budget_feature("budget", TestTransportCost::new_shared(), 1000., BUDGET_CODE).unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(50),
+                target: &test_activity_with_location(location),
+                next: Some(&test_activity_with_location(50)),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_update_remaining_budget_of_routes() {
+        let fleet = test_fleet();
+        let create_route_ctx = |distance: f64| {
+            let mut state = RouteState::default();
+            state.put_route_state(TOTAL_DISTANCE_KEY, distance);
+            state.put_route_state(TOTAL_DURATION_KEY, 0.);
+            RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), state)
+        };
+        let mut solution_ctx = create_empty_solution_context();
+        solution_ctx.routes = vec![create_route_ctx(100.), create_route_ctx(200.)];
+        let feature =
+            create_fleet_cost_budget_feature("budget", TestTransportCost::new_shared(), 1000., BUDGET_CODE).unwrap();
+
+        feature.state.unwrap().accept_solution_state(&mut solution_ctx);
+
+        let budgets = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.state().get_route_state::<Cost>(REMAINING_BUDGET_KEY).cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(budgets, vec![Some(600.), Some(800.)]);
+    }
+
+    #[test]
+    fn can_update_remaining_budget_of_other_routes_on_insertion() {
+        let fleet = test_fleet();
+        let create_route_ctx = |distance: f64| {
+            let mut state = RouteState::default();
+            state.put_route_state(TOTAL_DISTANCE_KEY, distance);
+            state.put_route_state(TOTAL_DURATION_KEY, 0.);
+            RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), state)
+        };
+        let mut solution_ctx = create_empty_solution_context();
+        solution_ctx.routes = vec![create_route_ctx(100.), create_route_ctx(200.)];
+        let state = create_fleet_cost_budget_feature("budget", TestTransportCost::new_shared(), 1000., BUDGET_CODE)
+            .unwrap()
+            .state
+            .unwrap();
+        state.accept_solution_state(&mut solution_ctx);
+
+        solution_ctx.routes[0].state_mut().put_route_state(TOTAL_DISTANCE_KEY, 300.);
+        state.accept_insertion(&mut solution_ctx, 0, &Job::Single(Arc::new(test_single())));
+
+        let budgets = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.state().get_route_state::<Cost>(REMAINING_BUDGET_KEY).cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(budgets, vec![Some(600.), Some(400.)]);
+    }
+
+    #[test]
+    fn can_update_remaining_budget_of_registry_routes_without_marking_them_stale() {
+        let mut solution_ctx = create_empty_solution_context();
+        let state = create_fleet_cost_budget_feature("budget", TestTransportCost::new_shared(), 1000., BUDGET_CODE)
+            .unwrap()
+            .state
+            .unwrap();
+
+        state.accept_solution_state(&mut solution_ctx);
+
+        let routes = solution_ctx
+            .registry
+            .next_route()
+            .map(|route_ctx| {
+                (route_ctx.is_stale(), route_ctx.state().get_route_state::<Cost>(REMAINING_BUDGET_KEY).cloned())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(routes, vec![(false, Some(1000.))]);
+    }
+}
+
+mod open_multi_jobs {
+    use super::*;
+    use crate::models::problem::Multi;
+
+    const VIOLATION_CODE: ViolationCode = 5;
+
+    fn create_multi(id: &str) -> Arc<Multi> {
+        test_multi_with_id(id, vec![test_single_with_id("pickup"), test_single_with_id("delivery")])
+    }
+
+    parameterized_test! {can_limit_open_multi_jobs, (has_pickup, index, limit, expected), {
+        can_limit_open_multi_jobs_impl(has_pickup, index, limit, expected);
+    }}
+
+    can_limit_open_multi_jobs! {
+        case01_open_after_start: (false, 0, Some(2), None),
+        case02_open_after_one_pickup: (false, 2, Some(2), None),
+        case03_open_after_two_pickups: (false, 3, Some(2), ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_open_after_all_deliveries: (false, 5, Some(2), None),
+        case05_no_limit: (false, 3, None, None),
+
+        case06_close_after_one_pickup: (true, 2, Some(2), None),
+        case07_close_after_two_pickups: (true, 3, Some(2), ConstraintViolation::skip(VIOLATION_CODE)),
+        case08_close_after_all_deliveries: (true, 5, Some(2), ConstraintViolation::skip(VIOLATION_CODE)),
+        case09_close_after_all_deliveries: (true, 5, Some(3), None),
+    }
+
+    fn can_limit_open_multi_jobs_impl(
+        has_pickup: bool,
+        index: usize,
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let (multi_a, multi_b, multi_c) = (create_multi("a"), create_multi("b"), create_multi("c"));
+        let get_activity = |multi: &Arc<Multi>, idx: usize| test_activity_with_job(multi.jobs[idx].clone());
+        // NOTE tour: start, c.pickup (optional), a.pickup, b.pickup, b.delivery, a.delivery
+        let activities = vec![
+            get_activity(&multi_c, 0),
+            get_activity(&multi_a, 0),
+            get_activity(&multi_b, 0),
+            get_activity(&multi_b, 1),
+            get_activity(&multi_a, 1),
+        ];
+        let activities = activities.into_iter().skip(if has_pickup { 0 } else { 1 }).collect();
+        let index = if has_pickup { index } else { index.saturating_sub(1) };
+        let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", activities);
+        let feature =
+            create_open_multi_jobs_limit_feature("open_multi_jobs", VIOLATION_CODE, Arc::new(move |_| limit)).unwrap();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let target = get_activity(&multi_c, if has_pickup { 1 } else { 0 });
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod lifo {
+    use super::*;
+    use crate::models::problem::Multi;
+
+    const VIOLATION_CODE: ViolationCode = 6;
+
+    fn create_multi(id: &str) -> Arc<Multi> {
+        test_multi_with_id(id, vec![test_single_with_id("pickup"), test_single_with_id("delivery")])
+    }
+
+    parameterized_test! {can_keep_lifo_order, (has_pickup, index, is_lifo, expected), {
+        can_keep_lifo_order_impl(has_pickup, index, is_lifo, expected);
+    }}
+
+    can_keep_lifo_order! {
+        case01_deliver_on_top: (true, 1, true, None),
+        case02_deliver_under_one: (true, 2, true, ConstraintViolation::skip(VIOLATION_CODE)),
+        case03_deliver_under_two: (true, 3, true, ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_deliver_after_all: (true, 5, true, None),
+        case05_no_lifo: (true, 3, false, None),
+        case06_pickup_on_top: (false, 2, true, None),
+        case07_pickup_after_all: (false, 4, true, None),
+    }
+
+    fn can_keep_lifo_order_impl(has_pickup: bool, index: usize, is_lifo: bool, expected: Option<ConstraintViolation>) {
+        let (multi_a, multi_b, multi_c) = (create_multi("a"), create_multi("b"), create_multi("c"));
+        let get_activity = |multi: &Arc<Multi>, idx: usize| test_activity_with_job(multi.jobs[idx].clone());
+        // NOTE tour: start, c.pickup (optional), a.pickup, b.pickup, b.delivery, a.delivery
+        let activities = vec![
+            get_activity(&multi_c, 0),
+            get_activity(&multi_a, 0),
+            get_activity(&multi_b, 0),
+            get_activity(&multi_b, 1),
+            get_activity(&multi_a, 1),
+        ];
+        let activities = activities.into_iter().skip(if has_pickup { 0 } else { 1 }).collect();
+        let route_ctx = create_route_context_with_activities(&test_fleet(), "v1", activities);
+        let feature = create_lifo_multi_jobs_feature("lifo", VIOLATION_CODE, Arc::new(move |_| is_lifo)).unwrap();
+        let target = get_activity(&multi_c, if has_pickup { 1 } else { 0 });
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod detour {
+    use super::*;
+    use crate::models::common::{Duration, ValueDimension};
+    use crate::models::problem::Single;
+    use crate::models::solution::Activity;
+
+    const DETOUR_CODE: ViolationCode = 5;
+    const DETOUR_LIMIT_KEY: &str = "detour_limit";
+
+    fn create_activity_with_detour_limit(location: Location, limit: Option<Duration>) -> Activity {
+        let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+        if let Some(limit) = limit {
+            single.dimens.set_value(DETOUR_LIMIT_KEY, limit);
+        }
+
+        Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) }
+    }
+
+    parameterized_test! {can_check_detour_limit, (location, limit, expected), {
+        can_check_detour_limit_impl(location, limit, expected);
+    }}
+
+    can_check_detour_limit! {
+        case01_exceeded: (60, Some(15.), ConstraintViolation::skip(DETOUR_CODE)),
+        case02_within_limit: (60, Some(25.), None),
+        case03_no_limit: (60, None, None),
+        case04_on_the_way: (50, Some(0.), None),
+    }
+
+    fn can_check_detour_limit_impl(location: Location, limit: Option<Duration>, expected: Option<ConstraintViolation>) {
+        let route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![]);
+        let feature = create_detour_limit_feature(
+            "detour_limit",
+            TestTransportCost::new_shared(),
+            Arc::new(|single| single.dimens.get_value::<Duration>(DETOUR_LIMIT_KEY).cloned()),
+            DETOUR_CODE,
+        )
+        .unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(50),
+                target: &create_activity_with_detour_limit(location, limit),
+                next: Some(&test_activity_with_location(50)),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod total_detour {
+    use super::*;
+    use crate::helpers::models::domain::create_empty_insertion_context;
+    use crate::models::solution::Activity;
+
+    fn create_route_ctx(locations: &[Location]) -> RouteContext {
+        let activities = locations
+            .iter()
+            .map(|location| Activity {
+                job: Some(test_single_with_location(Some(*location))),
+                ..test_activity_with_location(*location)
+            })
+            .collect();
+
+        create_route_context_with_activities(&test_fleet(), "v1", activities)
+    }
+
+    fn get_fitness(feature: &Feature, locations: &[Location]) -> Cost {
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes.push(create_route_ctx(locations));
+
+        feature.objective.as_ref().unwrap().fitness(&insertion_ctx)
+    }
+
+    parameterized_test! {can_estimate_insertion, (locations, target, index, expected), {
+        can_estimate_insertion_impl(locations, target, index, expected);
+    }}
+
+    can_estimate_insertion! {
+        case01_nested: (&[10, 20], 15, 1, -10.),
+        case02_on_the_way_back: (&[10, 20], 30, 2, 0.),
+        case03_before_first: (&[10, 20], 5, 0, 0.),
+        case04_empty_route: (&[], 10, 0, 20.),
+    }
+
+    fn can_estimate_insertion_impl(locations: &[Location], target: Location, index: usize, expected: Cost) {
+        let feature = create_minimize_total_detour_feature("detour", TestTransportCost::new_shared()).unwrap();
+        let route_ctx = create_route_ctx(locations);
+        let target_activity =
+            Activity { job: Some(test_single_with_location(Some(target))), ..test_activity_with_location(target) };
+        let activity_ctx = ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target_activity,
+            next: route_ctx.route().tour.get(index + 1),
+        };
+        let mut new_locations = locations.to_vec();
+        new_locations.insert(index, target);
+
+        let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+        assert_eq!(result, get_fitness(&feature, &new_locations) - get_fitness(&feature, locations));
+    }
+}
+
+mod wait {
+    use super::*;
+    use crate::models::common::{Duration, TimeWindow, Timestamp, ValueDimension};
+    use crate::models::problem::Single;
+    use crate::models::solution::Activity;
+
+    const WAIT_CODE: ViolationCode = 5;
+    const WAIT_LIMIT_KEY: &str = "wait_limit";
+
+    fn create_activity_with_wait_limit(location: Location, start: Timestamp, limit: Option<Duration>) -> Activity {
+        let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+        if let Some(limit) = limit {
+            single.dimens.set_value(WAIT_LIMIT_KEY, limit);
+        }
+
+        let mut activity = Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) };
+        activity.place.time = TimeWindow::new(start, 1000.);
+
+        activity
+    }
+
+    parameterized_test! {can_check_wait_limit, (start, limit, expected), {
+        can_check_wait_limit_impl(start, limit, expected);
+    }}
+
+    can_check_wait_limit! {
+        case01_exceeded: (30., Some(15.), ConstraintViolation::skip(WAIT_CODE)),
+        case02_within_limit: (30., Some(25.), None),
+        case03_no_limit: (30., None, None),
+        case04_no_waiting: (5., Some(0.), None),
+    }
+
+    fn can_check_wait_limit_impl(start: Timestamp, limit: Option<Duration>, expected: Option<ConstraintViolation>) {
+        let route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![]);
+        let feature = create_wait_limit_feature(
+            "wait_limit",
+            TestTransportCost::new_shared(),
+            Arc::new(|single| single.dimens.get_value::<Duration>(WAIT_LIMIT_KEY).cloned()),
+            WAIT_CODE,
+        )
+        .unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(0),
+                target: &create_activity_with_wait_limit(10, start, limit),
+                next: None,
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod cordon {
+    use super::*;
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    parameterized_test! {can_limit_cordon_crossings, (location, index, limit, expected), {
+        can_limit_cordon_crossings_impl(location, index, limit, expected);
+    }}
+
+    can_limit_cordon_crossings! {
+        case01_inside_after_inside: (12, 2, Some(2), None),
+        case02_inside_before_inside: (12, 1, Some(2), None),
+        case03_inside_between_outside: (12, 0, Some(2), ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_inside_between_outside_higher_limit: (12, 0, Some(4), None),
+        case05_outside_after_inside: (3, 2, Some(2), None),
+        case06_no_limit: (12, 0, None, None),
+    }
+
+    fn can_limit_cordon_crossings_impl(
+        location: Location,
+        index: usize,
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        // NOTE tour: start (outside), 5 (outside), 15 (inside), end (outside)
+        let activities = vec![test_activity_with_location(5), test_activity_with_location(15)];
+        let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", activities);
+        let feature = create_cordon_crossing_limit_feature(
+            "cordon",
+            VIOLATION_CODE,
+            Arc::new(|location| (10..=20).contains(&location)),
+            Arc::new(move |_| limit),
+        )
+        .unwrap();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let target = test_activity_with_location(location);
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod shift_span {
+    use super::*;
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    parameterized_test! {can_limit_shift_span, (location, index, delay, limit, expected), {
+        can_limit_shift_span_impl(location, index, delay, limit, expected);
+    }}
+
+    can_limit_shift_span! {
+        case01_last_within_limit: (25, 2, 0., Some(15.), None),
+        case02_last_above_limit: (25, 2, 0., Some(14.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case03_first_above_limit: (5, 0, 0., Some(14.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_middle_shift_absorbed: (15, 1, 0., Some(10.), None),
+        case05_middle_shift_propagated: (30, 1, 0., Some(29.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case06_middle_shift_within_limit: (30, 1, 0., Some(30.), None),
+        case07_no_limit: (30, 1, 0., None, None),
+        case08_last_delayed_above_limit: (25, 2, 1., Some(15.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case09_middle_delayed_above_limit: (30, 1, 1., Some(31.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case10_middle_delayed_within_limit: (30, 1, 1., Some(32.), None),
+    }
+
+    fn can_limit_shift_span_impl(
+        location: Location,
+        index: usize,
+        delay: Duration,
+        limit: Option<Duration>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        // NOTE tour: start (0), 10, 20, end (0): span is 10
+        let activities = vec![test_activity_with_location(10), test_activity_with_location(20)];
+        let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", activities);
+        let feature = create_shift_span_limit_feature(
+            "shift_span",
+            VIOLATION_CODE,
+            TestTransportCost::new_shared(),
+            DelayedActivityCost::new_shared(delay),
+            Arc::new(move |_| limit),
+        )
+        .unwrap();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let target = test_activity_with_location(location);
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod interruption {
+    use super::*;
+    use crate::models::problem::Single;
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    fn is_interruption(single: &Single) -> bool {
+        single.dimens.get_id().map_or(false, |id| id.starts_with("break") || id.starts_with("reload"))
+    }
+
+    parameterized_test! {can_limit_interruptions, (tour_jobs, job_id, limit, expected), {
+        can_limit_interruptions_impl(tour_jobs, job_id, limit, expected);
+    }}
+
+    can_limit_interruptions! {
+        case01_below_limit: (vec!["job1", "break"], "reload", Some(2), None),
+        case02_at_limit_mixed: (vec!["break", "job1", "reload1"], "reload2", Some(2), ConstraintViolation::fail(VIOLATION_CODE)),
+        case03_at_limit_regular_job: (vec!["break", "reload1"], "job2", Some(2), None),
+        case04_no_limit: (vec!["break", "reload1"], "reload2", None, None),
+        case05_zero_limit: (vec!["job1"], "break", Some(0), ConstraintViolation::fail(VIOLATION_CODE)),
+    }
+
+    fn can_limit_interruptions_impl(
+        tour_jobs: Vec<&str>,
+        job_id: &str,
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let solution_ctx = create_empty_solution_context();
+        let route_ctx = create_route_context_with_activities(
+            &test_fleet(),
+            "v1",
+            tour_jobs.into_iter().map(|id| test_activity_with_job(test_single_with_id(id))).collect(),
+        );
+        let job = Job::Single(test_single_with_id(job_id));
+        let constraint = create_interruption_limit_feature(
+            "interruption_limit",
+            VIOLATION_CODE,
+            Arc::new(is_interruption),
+            Arc::new(move |_| limit),
+        )
+        .unwrap()
+        .constraint
+        .unwrap();
+
+        let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::solver::{generate_matrix_routes_with_defaults, get_job_by_id, rearrange_jobs_in_routes};
+use rosomaxa::utils::Environment;
+
+fn create_coord_fn(rows: usize) -> LocationCoordFn {
+    Arc::new(move |location| Some(((location / rows) as f64, (location % rows) as f64)))
+}
+
+fn create_insertion_ctx_and_feature(rows: usize, cols: usize) -> (InsertionContext, Feature) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+    let insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let feature = create_tour_overlap_feature("overlap", create_coord_fn(rows), 1).expect("cannot create feature");
+
+    (insertion_ctx, feature)
+}
+
+fn accept_states(insertion_ctx: &mut InsertionContext, feature: &Feature) {
+    let state = feature.state.as_ref().unwrap();
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| state.accept_route_state(route_ctx));
+    state.accept_solution_state(&mut insertion_ctx.solution);
+}
+
+parameterized_test! {can_estimate_job_insertion, (candidate, expected), {
+    can_estimate_job_insertion_impl(candidate, expected);
+}}
+
+can_estimate_job_insertion! {
+    // c0 c3 c6
+    // c1 c4 c7
+    // c2 c5 c8
+    case_01_own_column: ((0, "c1"), 0.),
+    case_02_neighbour_column: ((1, "c0"), 4.),
+    case_03_far_column: ((0, "c8"), 7.),
+}
+
+fn can_estimate_job_insertion_impl(candidate: (usize, &str), expected: f64) {
+    let (route_idx, job_id) = candidate;
+    let (mut insertion_ctx, feature) = create_insertion_ctx_and_feature(3, 3);
+    accept_states(&mut insertion_ctx, &feature);
+
+    let estimation = feature.objective.as_ref().unwrap().estimate(&MoveContext::Route {
+        solution_ctx: &insertion_ctx.solution,
+        route_ctx: &insertion_ctx.solution.routes[route_idx],
+        job: &get_job_by_id(&insertion_ctx, job_id).unwrap(),
+    });
+
+    assert_eq!(estimation, expected);
+}
+
+parameterized_test! {can_calculate_fitness, (job_order, expected), {
+    can_calculate_fitness_impl(job_order, expected);
+}}
+
+can_calculate_fitness! {
+    case_01_separated: (vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"], vec!["c6", "c7", "c8"]], 0.),
+    case_02_overlapped: (vec![vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"], vec!["c6", "c7", "c8"]], 6.),
+    case_03_crossed: (vec![vec!["c0", "c4", "c8"], vec!["c6", "c2"], vec!["c1", "c3", "c5", "c7"]], 18.),
+}
+
+fn can_calculate_fitness_impl(job_order: Vec<Vec<&str>>, expected: f64) {
+    let (mut insertion_ctx, feature) = create_insertion_ctx_and_feature(3, 3);
+    rearrange_jobs_in_routes(&mut insertion_ctx, job_order.as_slice());
+    accept_states(&mut insertion_ctx, &feature);
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+#[test]
+fn can_update_bounding_boxes_of_stale_routes() {
+    let (mut insertion_ctx, feature) = create_insertion_ctx_and_feature(3, 3);
+    accept_states(&mut insertion_ctx, &feature);
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 0.);
+
+    // NOTE move jobs without accepting route states, so routes keep outdated bounding boxes
+    [(0, 1, "c1"), (1, 0, "c4")].iter().for_each(|&(from, to, job_id)| {
+        let job = get_job_by_id(&insertion_ctx, job_id).unwrap();
+        let routes = &mut insertion_ctx.solution.routes;
+        let tour = &routes[from].route().tour;
+        let activity = tour.get(tour.index(&job).unwrap()).unwrap().deep_copy();
+
+        routes[from].route_mut().tour.remove(&job);
+        routes[to].route_mut().tour.insert_last(activity);
+    });
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 6.);
+    assert!(insertion_ctx.solution.routes.iter().all(|route_ctx| {
+        route_ctx.state().get_route_state::<Option<BoundingBox>>(1).cloned()
+            == Some(BoundingBox::from_points(get_job_points(route_ctx, &create_coord_fn(3))))
+    }));
+}
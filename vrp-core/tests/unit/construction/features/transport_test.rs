@@ -432,3 +432,184 @@ mod time_dependent {
         }
     }
 }
+
+mod fitness {
+    use super::*;
+    use crate::helpers::models::domain::create_empty_insertion_context;
+    use rosomaxa::prelude::compare_floats;
+    use std::cmp::Ordering;
+
+    fn create_insertion_ctx(feature: &Feature, locations: Vec<Location>) -> InsertionContext {
+        let mut insertion_ctx = create_empty_insertion_context();
+        let mut route_ctx = create_route_context_with_activities(
+            &test_fleet(),
+            "v1",
+            locations.into_iter().map(test_activity_with_location).collect(),
+        );
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        insertion_ctx.solution.routes.push(route_ctx);
+
+        insertion_ctx
+    }
+
+    parameterized_test! {can_prefer_more_served_jobs_with_earnings_objective, (is_earnings, expected), {
+        can_prefer_more_served_jobs_with_earnings_objective_impl(is_earnings, expected);
+    }}
+
+    can_prefer_more_served_jobs_with_earnings_objective! {
+        case01_cost: (false, Ordering::Less),
+        case02_earnings: (true, Ordering::Greater),
+    }
+
+    fn can_prefer_more_served_jobs_with_earnings_objective_impl(is_earnings: bool, expected: Ordering) {
+        let (transport, activity) = (TestTransportCost::new_shared(), TestActivityCost::new_shared());
+        let feature = if is_earnings {
+            create_maximize_earnings_feature("earnings", transport, activity, Arc::new(|_| 200.), VIOLATION_CODE)
+        } else {
+            create_minimize_transport_costs_feature("transport", transport, activity, VIOLATION_CODE)
+        }
+        .unwrap();
+        let objective = feature.objective.as_ref().unwrap();
+        let less_jobs = create_insertion_ctx(&feature, vec![10, 20]);
+        let more_jobs = create_insertion_ctx(&feature, vec![10, 20, 50]);
+
+        let result = compare_floats(objective.fitness(&less_jobs), objective.fitness(&more_jobs));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_estimate_marginal_earnings_change, (index, location), {
+        can_estimate_marginal_earnings_change_impl(index, location);
+    }}
+
+    can_estimate_marginal_earnings_change! {
+        case01_first: (1, 5),
+        case02_middle: (2, 15),
+        case03_last: (3, 30),
+        case04_detour: (2, 40),
+    }
+
+    fn can_estimate_marginal_earnings_change_impl(index: usize, location: Location) {
+        let (transport, activity) = (TestTransportCost::new_shared(), TestActivityCost::new_shared());
+        let feature =
+            create_maximize_earnings_feature("earnings", transport, activity, Arc::new(|_| 200.), VIOLATION_CODE)
+                .unwrap();
+        let objective = feature.objective.as_ref().unwrap();
+        let mut insertion_ctx = create_insertion_ctx(&feature, vec![10, 20]);
+        let fitness_before = objective.fitness(&insertion_ctx);
+        let target = test_activity_with_location(location);
+        let job = Job::Single(target.job.clone().unwrap());
+
+        let route_ctx = &insertion_ctx.solution.routes[0];
+        let activity_ctx = ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index - 1).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index),
+        };
+        let estimate = objective.estimate(&MoveContext::route(&insertion_ctx.solution, route_ctx, &job))
+            + objective.estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+        let route_ctx = &mut insertion_ctx.solution.routes[0];
+        route_ctx.route_mut().tour.insert_at(target, index);
+        feature.state.as_ref().unwrap().accept_route_state(route_ctx);
+        let fitness_after = objective.fitness(&insertion_ctx);
+
+        assert_eq!(compare_floats(fitness_after - fitness_before, estimate), Ordering::Equal);
+    }
+}
+
+mod stops {
+    use super::*;
+    use crate::helpers::models::domain::create_empty_insertion_context;
+    use crate::models::problem::{Fleet, StopCostDimension};
+
+    const STOP_COST: Cost = 100.;
+
+    fn create_fleet(stop_cost: Option<Cost>) -> Fleet {
+        let mut vehicle = test_vehicle_with_id("v1");
+        if let Some(stop_cost) = stop_cost {
+            vehicle.dimens.set_stop_cost(stop_cost);
+        }
+
+        FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+    }
+
+    fn create_route_ctx(fleet: &Fleet, locations: Vec<Location>) -> RouteContext {
+        create_route_context_with_activities(
+            fleet,
+            "v1",
+            locations.into_iter().map(test_activity_with_location).collect(),
+        )
+    }
+
+    fn create_feature() -> Feature {
+        create_minimize_transport_costs_feature(
+            "transport",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+        )
+        .unwrap()
+    }
+
+    parameterized_test! {can_estimate_stop_cost, (locations, target_location, expected_stops), {
+        can_estimate_stop_cost_impl(locations, target_location, expected_stops);
+    }}
+
+    can_estimate_stop_cost! {
+        case01_same_location_as_prev: (vec![10, 20], 10, 0.),
+        case02_same_location_as_next: (vec![10, 20], 20, 0.),
+        case03_new_location: (vec![10, 20], 15, 1.),
+        case04_between_same_locations: (vec![10, 10], 15, 2.),
+    }
+
+    fn can_estimate_stop_cost_impl(locations: Vec<Location>, target_location: Location, expected_stops: Cost) {
+        let estimate = |stop_cost: Option<Cost>| {
+            let fleet = create_fleet(stop_cost);
+            let mut route_ctx = create_route_ctx(&fleet, locations.clone());
+            let feature = create_feature();
+            feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+            let target = test_activity_with_location(target_location);
+            let activity_ctx = ActivityContext {
+                index: 1,
+                prev: route_ctx.route().tour.get(1).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(2),
+            };
+
+            feature.objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        };
+
+        let result = estimate(Some(STOP_COST)) - estimate(None);
+
+        assert_eq!(result, expected_stops * STOP_COST);
+    }
+
+    parameterized_test! {can_calculate_fitness_with_stop_cost, (locations, expected_stops), {
+        can_calculate_fitness_with_stop_cost_impl(locations, expected_stops);
+    }}
+
+    can_calculate_fitness_with_stop_cost! {
+        case01_different_locations: (vec![10, 20, 30], 3.),
+        case02_shared_location: (vec![10, 10, 20], 2.),
+        case03_empty: (vec![], 0.),
+    }
+
+    fn can_calculate_fitness_with_stop_cost_impl(locations: Vec<Location>, expected_stops: Cost) {
+        let fitness = |stop_cost: Option<Cost>| {
+            let fleet = create_fleet(stop_cost);
+            let feature = create_feature();
+            let mut route_ctx = create_route_ctx(&fleet, locations.clone());
+            feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+            let mut insertion_ctx = create_empty_insertion_context();
+            insertion_ctx.solution.routes.push(route_ctx);
+
+            feature.objective.unwrap().fitness(&insertion_ctx)
+        };
+
+        let result = fitness(Some(STOP_COST)) - fitness(None);
+
+        assert_eq!(result, expected_stops * STOP_COST);
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(locations: &[Location]) -> RouteContext {
+    let fleet = test_fleet();
+    let activities = locations.iter().map(|location| test_activity_with_location(*location)).collect();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let feature = create_minimize_unique_stops_feature("unique_stops").unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(&[10, 20, 10, 30]));
+    insertion_ctx.solution.routes.push(create_route_ctx(&[10, 40]));
+
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 5.);
+}
+
+parameterized_test! {can_estimate_activity, (location, expected), {
+    can_estimate_activity_impl(location, expected);
+}}
+
+can_estimate_activity! {
+    case01_visited_location: (20, 0.),
+    case02_new_location: (30, 1.),
+    case03_depot_location: (0, 1.),
+}
+
+fn can_estimate_activity_impl(location: Location, expected: Cost) {
+    let feature = create_minimize_unique_stops_feature("unique_stops").unwrap();
+    let route_ctx = create_route_ctx(&[10, 20]);
+    let target = test_activity_with_location(location);
+    let activity_ctx =
+        ActivityContext { index: 0, prev: route_ctx.route().tour.get(0).unwrap(), target: &target, next: None };
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,105 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Location;
+use crate::models::problem::{Costs, Vehicle};
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, locations: &[Location]) -> RouteContext {
+    let activities = locations.iter().map(|location| test_activity_with_location(*location)).collect();
+
+    create_route_context_with_activities(fleet, vehicle_id, activities)
+}
+
+#[test]
+fn can_balance_stops_instead_of_activities() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(&fleet, "v1", &[10, 10, 10, 20]));
+    insertion_ctx.solution.routes.push(create_route_ctx(&fleet, "v2", &[10, 20, 30, 40]));
+    let activity_feature = create_activity_balanced_feature("activity_balance", None, None).unwrap();
+    let stop_feature = create_stop_balanced_feature("stop_balance", None, None).unwrap();
+
+    let activity_fitness = activity_feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+    let stop_fitness = stop_feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(activity_fitness, 0.);
+    assert!(stop_fitness > 0.);
+}
+
+parameterized_test! {can_count_stops, (locations, expected), {
+    can_count_stops_impl(locations, expected);
+}}
+
+can_count_stops! {
+    case01_same_location: (vec![10, 10, 10], 1),
+    case02_different_locations: (vec![10, 20, 30], 3),
+    case03_returning_location: (vec![10, 20, 10], 3),
+    case04_depot_location: (vec![0, 10], 1),
+}
+
+fn can_count_stops_impl(locations: Vec<Location>, expected: usize) {
+    let route_ctx = create_route_ctx(&test_fleet(), "v1", locations.as_slice());
+
+    let result = get_stop_count(route_ctx.route());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_balance_cost_with_different_fixed_costs() {
+    let create_vehicle =
+        |id: &str, fixed: Cost| Vehicle { costs: Costs { fixed, ..test_costs() }, ..test_vehicle_with_id(id) };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_vehicle("v1", 0.), create_vehicle("v2", 100.)])
+        .build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = ["v1", "v2"]
+        .iter()
+        .map(|vehicle_id| {
+            let mut route_ctx = create_route_ctx(&fleet, vehicle_id, &[10]);
+            route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, 20.);
+            route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, 20.);
+            route_ctx
+        })
+        .collect();
+    let distance_feature = create_distance_balanced_feature("distance_balance", None, None).unwrap();
+    let cost_feature = create_cost_balanced_feature("cost_balance", None, None).unwrap();
+
+    let distance_fitness = distance_feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+    let cost_fitness = cost_feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(distance_fitness, 0.);
+    assert!(cost_fitness > 0.);
+}
+
+parameterized_test! {can_compare_with_tolerance, (fitness, tolerance, expected), {
+    can_compare_with_tolerance_impl(fitness, tolerance, expected);
+}}
+
+can_compare_with_tolerance! {
+    case01_near_equal_with_tolerance: ((0.5, 0.500001), Some(0.0001), Ordering::Equal),
+    case02_near_equal_without_tolerance: ((0.5, 0.500001), None, Ordering::Less),
+    case03_different_with_tolerance: ((0.5, 0.6), Some(0.0001), Ordering::Less),
+}
+
+fn can_compare_with_tolerance_impl(fitness: (f64, f64), tolerance: Option<f64>, expected: Ordering) {
+    let create_insertion_ctx = |fitness: f64| {
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.state.insert(BALANCE_ACTIVITY_KEY, Arc::new(fitness));
+        insertion_ctx
+    };
+    let feature = create_activity_balanced_feature("activity_balance", None, tolerance).unwrap();
+
+    let result = feature
+        .objective
+        .as_ref()
+        .unwrap()
+        .total_order(&create_insertion_ctx(fitness.0), &create_insertion_ctx(fitness.1));
+
+    assert_eq!(result, expected);
+}
@@ -1,10 +1,14 @@
-use crate::construction::heuristics::{RouteState, UnassignmentInfo};
+use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::{RouteContext, RouteState, UnassignmentInfo};
 use crate::helpers::construction::features::create_goal_ctx_with_transport;
 use crate::helpers::construction::heuristics::create_insertion_context;
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_fleet, SingleBuilder};
 use crate::helpers::models::solution::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
 use crate::models::solution::Registry;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -68,6 +72,18 @@ fn can_put_and_get_empty_route_state() {
     assert!(result.is_none());
 }
 
+#[test]
+fn can_get_marker_intervals() {
+    let mut route_ctx = RouteContext::new(test_actor());
+
+    assert!(route_ctx.marker_intervals(1).is_none());
+
+    route_ctx.state_mut().put_route_state(1, vec![(0_usize, 2_usize), (2, 4)]);
+
+    assert_eq!(route_ctx.marker_intervals(1), Some(&vec![(0, 2), (2, 4)]));
+    assert!(route_ctx.marker_intervals(2).is_none());
+}
+
 #[test]
 fn can_remove_activity_states() {
     let mut route_state = RouteState::default();
@@ -119,3 +135,22 @@ fn can_use_debug_fmt_for_insertion_ctx() {
     assert!(result.contains("unassigned"));
     assert!(result.contains("id: \"single\""));
 }
+
+#[test]
+fn can_get_fingerprint_of_solution_structure() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    let fingerprint = insertion_ctx.fingerprint();
+
+    let mut reordered_routes = insertion_ctx.deep_copy();
+    reordered_routes.solution.routes.reverse();
+    assert_eq!(reordered_routes.fingerprint(), fingerprint);
+
+    let mut reordered_jobs = insertion_ctx.deep_copy();
+    let tour = &mut reordered_jobs.solution.routes[0].route_mut().tour;
+    let activity = tour.get(1).unwrap().deep_copy();
+    tour.remove_activity_at(1);
+    tour.insert_at(activity, 2);
+    assert_ne!(reordered_jobs.fingerprint(), fingerprint);
+}
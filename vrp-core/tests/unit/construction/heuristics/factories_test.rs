@@ -0,0 +1,163 @@
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::construction::features::create_goal_ctx_with_feature;
+use crate::helpers::models::problem::{get_job_id, test_place_with_location};
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::common::{IdDimension, TimeSpan, TimeWindow, Timestamp};
+use crate::models::problem::{Place, Single};
+use crate::models::solution::Route;
+use crate::models::{Problem, Solution};
+use crate::solver::{create_default_config_builder, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_insertion_ctx(problem: Problem, solution: Solution) -> InsertionContext {
+    let environment = Arc::new(Environment { logger: Arc::new(|_| {}), ..Environment::default() });
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+fn get_served_ids(route: &Route, cutoff: Timestamp) -> Vec<String> {
+    route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.schedule.arrival <= cutoff)
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_job_id(&job).clone())
+        .collect()
+}
+
+fn get_cutoff(insertion_ctx: &InsertionContext) -> Timestamp {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter_map(|route_ctx| route_ctx.route().tour.get(2))
+        .map(|activity| activity.schedule.arrival)
+        .fold(0., f64::max)
+}
+
+#[test]
+fn can_lock_activities_served_before_cutoff() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+    let cutoff = get_cutoff(&insertion_ctx);
+    let expected = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| get_served_ids(route_ctx.route(), cutoff))
+        .collect::<Vec<_>>();
+
+    let result = InsertionContext::new_with_cutoff(&insertion_ctx, cutoff, VIOLATION_CODE).expect("cannot lock");
+
+    assert!(expected.iter().all(|ids| !ids.is_empty()));
+    assert_eq!(result.problem.locks.len(), expected.len());
+    assert_eq!(result.solution.locked.len(), expected.iter().map(|ids| ids.len()).sum::<usize>());
+    result.problem.locks.iter().zip(expected.iter()).for_each(|(lock, ids)| {
+        let lock_ids = lock.details[0].jobs.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+        assert_eq!(&lock_ids, ids);
+    });
+    assert!(result.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_skip_locking_when_cutoff_is_before_start() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+
+    let result = InsertionContext::new_with_cutoff(&insertion_ctx, -1., VIOLATION_CODE).expect("cannot lock");
+
+    assert!(result.problem.locks.is_empty());
+    assert!(result.solution.locked.is_empty());
+}
+
+#[test]
+fn can_lock_job_with_multiple_time_windows_before_cutoff() {
+    let (problem, solution) = generate_matrix_routes(
+        3,
+        1,
+        false,
+        |transport, activity| {
+            create_goal_ctx_with_feature(
+                create_minimize_transport_costs_feature("transport", transport, activity, 1).unwrap(),
+            )
+        },
+        |id, location| {
+            let times =
+                vec![TimeSpan::Window(TimeWindow::new(0., 1000.)), TimeSpan::Window(TimeWindow::new(2000., 3000.))];
+            let mut single = Single {
+                places: vec![Place { times, ..test_place_with_location(location) }],
+                dimens: Default::default(),
+            };
+            single.dimens.set_id(id);
+            Arc::new(single)
+        },
+        |v| v,
+        |data| (data.clone(), data),
+    );
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+    let cutoff = get_cutoff(&insertion_ctx);
+
+    let result = InsertionContext::new_with_cutoff(&insertion_ctx, cutoff, VIOLATION_CODE).expect("cannot lock");
+
+    let locked_jobs = result.problem.locks.iter().flat_map(|lock| lock.details[0].jobs.iter()).collect::<Vec<_>>();
+    assert_eq!(locked_jobs.len(), 2);
+    locked_jobs.into_iter().for_each(|job| {
+        let single = job.to_single();
+        assert_eq!(single.places.len(), 1);
+        assert_eq!(single.places[0].times.len(), 1);
+        assert_eq!(single.places[0].times[0].as_time_window(), Some(TimeWindow::new(0., 1000.)));
+        assert!(result.problem.jobs.all().any(|other| other == *job));
+        assert!(result.solution.locked.contains(job));
+        assert!(result.solution.routes[0].route().tour.contains(job));
+    });
+    assert_eq!(result.problem.jobs.size(), 3);
+
+    let problem = result.problem.clone();
+    let environment = result.environment.clone();
+    let (solution, _, _) = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_init_solutions(vec![result], None)
+        .with_max_generations(Some(10))
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .expect("cannot build solver")
+        .solve()
+        .expect("cannot solve problem");
+
+    assert_eq!(&get_served_ids(&solution.routes[0], f64::MAX)[..2], &["c0".to_string(), "c1".to_string()]);
+}
+
+#[test]
+fn can_keep_locked_prefix_in_final_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+    let cutoff = get_cutoff(&insertion_ctx);
+    let insertion_ctx = InsertionContext::new_with_cutoff(&insertion_ctx, cutoff, VIOLATION_CODE).expect("cannot lock");
+    let expected = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| (route_ctx.route().actor.clone(), get_served_ids(route_ctx.route(), cutoff)))
+        .collect::<Vec<_>>();
+    let problem = insertion_ctx.problem.clone();
+    let environment = insertion_ctx.environment.clone();
+
+    let (solution, _, _) = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_init_solutions(vec![insertion_ctx], None)
+        .with_max_generations(Some(50))
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .expect("cannot build solver")
+        .solve()
+        .expect("cannot solve problem");
+
+    expected.into_iter().for_each(|(actor, prefix)| {
+        let route = solution.routes.iter().find(|route| route.actor == actor).expect("cannot find route");
+        let route_ids = get_served_ids(route, f64::MAX);
+        assert_eq!(&route_ids[..prefix.len()], prefix.as_slice());
+    });
+}
@@ -1,5 +1,5 @@
 mod single {
-    use crate::models::common::{Load, SingleDimLoad};
+    use crate::models::common::{Load, ScalableLoad, SingleDimLoad};
 
     fn from_value(load: i32) -> SingleDimLoad {
         SingleDimLoad::new(load)
@@ -46,10 +46,16 @@ mod single {
         assert!(from_value(10).can_fit(&from_value(5)));
         assert!(!from_value(5).can_fit(&from_value(10)));
     }
+
+    #[test]
+    fn can_scale_load_down() {
+        assert_eq!(from_value(9).scale(1.15), from_value(10));
+        assert_eq!(from_value(10).scale(1.), from_value(10));
+    }
 }
 
 mod multi {
-    use crate::models::common::{Load, MultiDimLoad};
+    use crate::models::common::{Load, MultiDimLoad, ScalableLoad};
     use std::cmp::Ordering;
 
     fn from_vec(load: Vec<i32>) -> MultiDimLoad {
@@ -102,4 +108,9 @@ mod multi {
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
     }
+
+    #[test]
+    fn can_scale_load_down() {
+        assert_eq!(from_vec(vec![10, 9, 0]).scale(1.15), from_vec(vec![11, 10, 0]));
+    }
 }
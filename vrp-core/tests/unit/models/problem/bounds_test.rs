@@ -0,0 +1,72 @@
+use super::*;
+use crate::construction::features::{create_minimize_transport_costs_feature, create_minimize_unassigned_jobs_feature};
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::create_problem_with_goal_ctx_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::models::examples::create_example_problem;
+use crate::models::Solution;
+use crate::solver::{create_default_config_builder, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn solve(problem: Arc<Problem>) -> (Solution, Cost) {
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let (solution, cost, _) = Solver::new(problem, config).solve().unwrap();
+
+    (solution, cost)
+}
+
+fn get_served_jobs(solution: &Solution) -> Vec<Job> {
+    solution.routes.iter().flat_map(|route| route.tour.jobs()).collect()
+}
+
+fn create_test_problem(locations: Vec<Location>) -> Arc<Problem> {
+    let transport = create_minimize_transport_costs_feature(
+        "transport",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        1,
+    )
+    .unwrap();
+    let unassigned = create_minimize_unassigned_jobs_feature("unassigned", Arc::new(|_, _| 1.)).unwrap();
+    let goal = create_goal_ctx_with_features(vec![unassigned, transport], vec![vec!["unassigned"], vec!["transport"]]);
+    let jobs = locations.into_iter().map(|location| Job::Single(test_single_with_location(Some(location)))).collect();
+
+    create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, test_fleet())
+}
+
+#[test]
+fn can_get_zero_bound_without_jobs() {
+    let problem = create_test_problem(vec![]);
+
+    assert_eq!(get_cost_lower_bound(&problem, &[]), 0.);
+}
+
+#[test]
+fn can_get_bound_equal_to_cost_for_trivial_problem() {
+    let problem = create_example_problem();
+    let (solution, cost) = solve(problem.clone());
+
+    let bound = get_cost_lower_bound(&problem, get_served_jobs(&solution).as_slice());
+
+    assert_eq!(cost, 42.);
+    assert_eq!(bound, cost);
+}
+
+#[test]
+fn can_get_bound_not_exceeding_found_cost() {
+    let problem = create_test_problem(vec![5, 10]);
+    let (solution, cost) = solve(problem.clone());
+
+    let bound = get_cost_lower_bound(&problem, get_served_jobs(&solution).as_slice());
+
+    // NOTE vehicle and driver costs are summed: each distance and duration unit costs 2
+    assert_eq!(bound, (5. + 5. + 5.) * 4.);
+    assert!(bound <= cost, "bound {bound} should not exceed cost {cost}");
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::helpers::models::problem::TestTransportCost;
 use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
 
 fn create_matrix_data(
@@ -102,6 +103,32 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+parameterized_test! {can_use_travel_gates, (from, to, travel_time, expected), {
+    can_use_travel_gates_impl(from, to, travel_time, expected);
+}}
+
+can_use_travel_gates! {
+    case01_departure_in_window: (0, 10, TravelTime::Departure(12.), (10., 10.)),
+    case02_departure_before_window: (0, 10, TravelTime::Departure(5.), (10., 15.)),
+    case03_departure_between_windows: (0, 10, TravelTime::Departure(25.), (10., 35.)),
+    case04_departure_after_windows: (0, 10, TravelTime::Departure(61.), (-1., -1.)),
+    case05_arrival_in_window: (0, 10, TravelTime::Arrival(25.), (10., 10.)),
+    case06_arrival_after_window: (0, 10, TravelTime::Arrival(40.), (10., 20.)),
+    case07_arrival_before_windows: (0, 10, TravelTime::Arrival(15.), (-1., -1.)),
+    case08_not_gated_edge: (10, 0, TravelTime::Departure(61.), (10., 10.)),
+}
+
+fn can_use_travel_gates_impl(from: Location, to: Location, travel_time: TravelTime, expected: (Distance, Duration)) {
+    let route_ctx = create_empty_route_ctx();
+    let gates = vec![((0, 10), vec![TimeWindow::new(50., 60.), TimeWindow::new(10., 20.)])].into_iter().collect();
+    let transport = GatedTransportCost::new(gates, TestTransportCost::new_shared()).unwrap();
+
+    let distance = transport.distance(route_ctx.route(), from, to, travel_time);
+    let duration = transport.duration(route_ctx.route(), from, to, travel_time);
+
+    assert_eq!((distance, duration), expected);
+}
+
 parameterized_test! {can_search_for_reserved_time, (times, tests), {
     can_search_for_reserved_time_impl(times, tests);
 }}
@@ -139,6 +166,125 @@ fn can_search_for_reserved_time_impl(times: Vec<(f64, f64)>, tests: Vec<((f64, f
     }
 }
 
+fn create_time_dependent_transport(duration: Duration) -> Arc<dyn TransportCost + Send + Sync> {
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, vec![0., duration, duration, 0.], vec![0., 5., 5., 0.])])
+        .unwrap()
+}
+
+parameterized_test! {can_select_time_dependent_transport_cost, (travel_time, expected), {
+    can_select_time_dependent_transport_cost_impl(travel_time, expected);
+}}
+
+can_select_time_dependent_transport_cost! {
+    case01_before_congestion: (TravelTime::Departure(50.), 10.),
+    case02_congestion_start: (TravelTime::Departure(100.), 20.),
+    case03_during_congestion: (TravelTime::Departure(150.), 20.),
+    case04_congestion_end: (TravelTime::Departure(200.), 10.),
+    case05_after_congestion: (TravelTime::Departure(250.), 10.),
+    case06_arrival_during_congestion: (TravelTime::Arrival(120.), 20.),
+}
+
+fn can_select_time_dependent_transport_cost_impl(travel_time: TravelTime, expected: Duration) {
+    let route_ctx = create_empty_route_ctx();
+    let transport = TimeDependentTransportCost::new(
+        vec![100., 200.],
+        vec![
+            create_time_dependent_transport(10.),
+            create_time_dependent_transport(20.),
+            create_time_dependent_transport(10.),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(transport.duration(route_ctx.route(), 0, 1, travel_time), expected);
+    assert_eq!(transport.distance(route_ctx.route(), 0, 1, travel_time), 5.);
+    assert_eq!(transport.duration_approx(&Profile::default(), 0, 1), 10.);
+}
+
+#[test]
+fn can_make_leg_more_expensive_during_congestion() {
+    let route_ctx = create_empty_route_ctx();
+    let transport = TimeDependentTransportCost::new(
+        vec![100., 200.],
+        vec![
+            create_time_dependent_transport(10.),
+            create_time_dependent_transport(20.),
+            create_time_dependent_transport(10.),
+        ],
+    )
+    .unwrap();
+
+    let normal = transport.cost(route_ctx.route(), 0, 1, TravelTime::Departure(0.));
+    let congested = transport.cost(route_ctx.route(), 0, 1, TravelTime::Departure(150.));
+
+    assert!(congested > normal);
+}
+
+parameterized_test! {can_validate_time_dependent_transport_cost, (boundaries, buckets, expected), {
+    can_validate_time_dependent_transport_cost_impl(boundaries, buckets, expected);
+}}
+
+can_validate_time_dependent_transport_cost! {
+    case01_valid: (vec![100.], 2, true),
+    case02_no_buckets: (vec![], 0, false),
+    case03_too_many_boundaries: (vec![100., 200.], 2, false),
+    case04_unsorted_boundaries: (vec![200., 100.], 3, false),
+    case05_single_bucket: (vec![], 1, true),
+}
+
+fn can_validate_time_dependent_transport_cost_impl(boundaries: Vec<Timestamp>, buckets: usize, expected: bool) {
+    let buckets = (0..buckets).map(|_| create_time_dependent_transport(10.)).collect();
+
+    let result = TimeDependentTransportCost::new(boundaries, buckets);
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+#[test]
+fn can_calculate_manhattan_distance_and_duration() {
+    let route_ctx = create_empty_route_ctx();
+    let transport = ManhattanTransportCost::new(vec![(0., 0.), (3., 4.), (-1., 2.)], 2.).unwrap();
+    let scaled = Profile::new(0, Some(2.));
+
+    assert_eq!(transport.distance_approx(&Profile::default(), 0, 1), 7.);
+    assert_eq!(transport.distance_approx(&Profile::default(), 1, 2), 6.);
+    assert_eq!(transport.duration_approx(&Profile::default(), 0, 1), 3.5);
+    assert_eq!(transport.duration_approx(&scaled, 0, 1), 7.);
+    assert_eq!(transport.distance(route_ctx.route(), 0, 1, TravelTime::Departure(0.)), 7.);
+    assert_eq!(transport.duration(route_ctx.route(), 0, 1, TravelTime::Departure(0.)), 3.5);
+}
+
+parameterized_test! {can_calculate_haversine_distance, (from, to, expected), {
+    can_calculate_haversine_distance_impl(from, to, expected);
+}}
+
+can_calculate_haversine_distance! {
+    case01_same_point: ((52.52, 13.405), (52.52, 13.405), 0.),
+    case02_equator_degree: ((0., 0.), (0., 1.), 111_194.93),
+    case03_meridian_degree: ((0., 0.), (1., 0.), 111_194.93),
+    case04_quarter_of_equator: ((0., 0.), (0., 90.), 10_007_543.40),
+    case05_north_pole: ((0., 0.), (90., 0.), 10_007_543.40),
+}
+
+fn can_calculate_haversine_distance_impl(from: (f64, f64), to: (f64, f64), expected: Distance) {
+    let route_ctx = create_empty_route_ctx();
+    let transport = HaversineTransportCost::new(vec![from, to], 10.).unwrap();
+
+    let distance = transport.distance_approx(&Profile::default(), 0, 1);
+    let duration = transport.duration_approx(&Profile::default(), 0, 1);
+
+    assert!((distance - expected).abs() < 0.01);
+    assert!((duration - expected / 10.).abs() < 0.01);
+    assert_eq!(transport.distance(route_ctx.route(), 0, 1, TravelTime::Departure(0.)), distance);
+    assert_eq!(transport.duration(route_ctx.route(), 0, 1, TravelTime::Arrival(0.)), duration);
+}
+
+#[test]
+fn can_detect_invalid_speed_for_geometric_transport_costs() {
+    assert!(ManhattanTransportCost::new(vec![], 0.).is_err());
+    assert!(HaversineTransportCost::new(vec![], -1.).is_err());
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext};
@@ -1,4 +1,5 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::{test_driver, test_vehicle, test_vehicle_with_id, FleetBuilder};
+use crate::models::problem::*;
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +20,37 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+parameterized_test! {can_calculate_distance_cost_tiers, (tiers, distance, expected), {
+    can_calculate_distance_cost_tiers_impl(tiers, distance, expected);
+}}
+
+can_calculate_distance_cost_tiers! {
+    case01_below_first_tier: (vec![(100., 0.5)], 50., 100.),
+    case02_at_first_tier: (vec![(100., 0.5)], 100., 200.),
+    case03_above_first_tier: (vec![(100., 0.5)], 150., 225.),
+    case04_several_tiers: (vec![(100., 1.5), (300., 1.)], 400., 200. + 300. + 100.),
+    case05_unsorted_tiers: (vec![(300., 1.), (100., 1.5)], 400., 200. + 300. + 100.),
+    case06_zero_threshold: (vec![(0., 3.)], 10., 30.),
+}
+
+fn can_calculate_distance_cost_tiers_impl(tiers: Vec<(f64, f64)>, distance: f64, expected: f64) {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.costs.per_distance = 2.;
+    vehicle.dimens.set_distance_cost_tiers(DistanceCostTiers::new(tiers));
+
+    let result = get_vehicle_distance_cost(&vehicle, distance);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_get_min_rate_of_distance_cost_tiers, (tiers, expected), {
+    assert_eq!(DistanceCostTiers::new(tiers).min_rate(2.), expected);
+}}
+
+can_get_min_rate_of_distance_cost_tiers! {
+    case01_no_tiers: (vec![], 2.),
+    case02_cheaper_tier: (vec![(100., 0.5)], 0.5),
+    case03_more_expensive_tier: (vec![(100., 3.)], 2.),
+    case04_several_tiers: (vec![(100., 1.5), (300., 1.)], 1.),
+}
@@ -0,0 +1,35 @@
+use super::*;
+use crate::helpers::models::problem::{test_fleet, TestTransportCost};
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+fn get_probabilities(variability: f64) -> Result<Vec<f64>, String> {
+    let route = create_route_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(0., 10.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(0., 1000.)),
+            test_activity_with_location_and_tw(30, TimeWindow::new(0., 20.)),
+        ],
+    );
+
+    get_route_on_time_probabilities(&route, TestTransportCost::new_shared().as_ref(), variability)
+        .map(|probabilities| probabilities.into_iter().map(|(_, probability)| probability).collect())
+}
+
+#[test]
+fn can_estimate_on_time_probabilities() {
+    let probabilities = get_probabilities(0.2).unwrap();
+
+    assert_eq!(probabilities.len(), 3);
+    assert!((probabilities[0] - 0.5).abs() < 1E-6);
+    assert!(probabilities[1] > 0.999);
+    assert_eq!(probabilities[2], 0.);
+}
+
+#[test]
+fn can_reject_invalid_variability() {
+    assert!(get_probabilities(0.).is_err());
+    assert!(get_probabilities(f64::NAN).is_err());
+}
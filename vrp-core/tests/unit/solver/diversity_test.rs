@@ -0,0 +1,66 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::examples::create_example_problem;
+use crate::solver::create_default_config_builder;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 4, false);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+fn unassign_routes(insertion_ctx: &InsertionContext, routes: usize) -> InsertionContext {
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    (0..routes).for_each(|_| {
+        let route_ctx = insertion_ctx.solution.routes.remove(0);
+        insertion_ctx
+            .solution
+            .unassigned
+            .extend(route_ctx.route().tour.jobs().map(|job| (job, UnassignmentInfo::Unknown)));
+        insertion_ctx.solution.registry.free_route(route_ctx);
+    });
+
+    insertion_ctx
+}
+
+#[test]
+fn can_estimate_diversity_of_converged_and_diverse_populations() {
+    let insertion_ctx = create_insertion_ctx();
+    let converged = vec![insertion_ctx.deep_copy(), insertion_ctx.deep_copy(), insertion_ctx.deep_copy()];
+    let diverse = vec![unassign_routes(&insertion_ctx, 1), unassign_routes(&insertion_ctx, 2), insertion_ctx];
+
+    let converged = get_population_diversity(converged.as_slice());
+    let diverse = get_population_diversity(diverse.as_slice());
+
+    assert_eq!(converged, 0.);
+    assert!(diverse > converged);
+    assert!(diverse < 1.);
+}
+
+#[test]
+fn can_estimate_diversity_of_single_solution() {
+    let diversity = get_population_diversity(&[create_insertion_ctx()]);
+
+    assert_eq!(diversity, 0.);
+}
+
+#[test]
+fn can_report_final_population_diversity_in_metrics() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(
+        problem.clone(),
+        environment,
+        TelemetryMode::OnlyMetrics { track_population: 100 },
+    )
+    .with_max_generations(Some(10))
+    .build()
+    .unwrap();
+
+    let (_, _, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    let diversity = metrics.and_then(|metrics| metrics.diversity).expect("should have diversity");
+    assert!((0. ..=1.).contains(&diversity));
+}
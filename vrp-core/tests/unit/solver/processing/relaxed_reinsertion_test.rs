@@ -0,0 +1,129 @@
+use super::*;
+use crate::construction::enablers::NoMultiTrip;
+use crate::construction::features::*;
+use crate::helpers::construction::features::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, SingleDimLoad};
+use crate::models::Feature;
+use rosomaxa::evolution::HeuristicSolutionProcessing;
+
+const VIOLATION_CODE: i32 = 2;
+
+fn create_transport_feature() -> Feature {
+    create_minimize_transport_costs_feature(
+        "transport",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        1,
+    )
+    .unwrap()
+}
+
+fn create_strict_goal() -> GoalContext {
+    create_goal_ctx_with_features(
+        vec![
+            create_transport_feature(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+        ],
+        vec![vec!["transport"]],
+    )
+}
+
+fn create_overflow_goal() -> GoalContext {
+    let capacity = create_capacity_limit_with_overflow_feature::<SingleDimLoad>(
+        "capacity",
+        VIOLATION_CODE,
+        Arc::new(NoMultiTrip::default()),
+        OverflowPolicy { max_ratio: 0.5, penalty: 100. },
+    )
+    .unwrap();
+
+    create_goal_ctx_with_features(vec![create_transport_feature(), capacity], vec![vec!["capacity"], vec!["transport"]])
+}
+
+fn create_job(id: &str, size: i32) -> Job {
+    SingleBuilder::default().id(id).demand(create_simple_demand(-size)).build_as_job_ref()
+}
+
+fn create_test_insertion_ctx(assigned: Job, unassigned: Job) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .build();
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(
+        create_strict_goal(),
+        vec![assigned.clone(), unassigned.clone()],
+        fleet,
+    );
+
+    let insertion_ctx = InsertionContext {
+        problem: problem.clone(),
+        solution: SolutionContext {
+            required: vec![assigned],
+            registry: create_registry_context(problem.fleet.as_ref()),
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    };
+
+    let mut insertion_ctx = InsertionHeuristic::default().process(
+        insertion_ctx,
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &LegSelection::Exhaustive,
+        &BestResultSelector::default(),
+    );
+    insertion_ctx.solution.unassigned.insert(unassigned, UnassignmentInfo::Simple(VIOLATION_CODE));
+
+    insertion_ctx
+}
+
+fn get_relaxed_jobs(insertion_ctx: &InsertionContext) -> Vec<(String, String)> {
+    let mut relaxed_jobs = insertion_ctx
+        .solution
+        .state
+        .get(&RELAXED_JOBS_KEY)
+        .and_then(|state| state.downcast_ref::<HashMap<Job, String>>())
+        .unwrap()
+        .iter()
+        .map(|(job, name)| (job.dimens().get_id().unwrap().clone(), name.clone()))
+        .collect::<Vec<_>>();
+    relaxed_jobs.sort();
+
+    relaxed_jobs
+}
+
+#[test]
+fn can_assign_job_with_relaxation() {
+    let insertion_ctx = create_test_insertion_ctx(create_job("job1", 8), create_job("job2", 5));
+    let processing = RelaxedReinsertion::new(vec![Relaxation {
+        name: "capacity_overflow".to_string(),
+        goal: Arc::new(create_overflow_goal()),
+    }]);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.routes[0].route().tour.job_count(), 2);
+    assert_eq!(get_relaxed_jobs(&insertion_ctx), vec![("job2".to_string(), "capacity_overflow".to_string())]);
+}
+
+#[test]
+fn can_keep_job_unassigned_when_relaxation_is_not_enough() {
+    let insertion_ctx = create_test_insertion_ctx(create_job("job1", 8), create_job("job2", 8));
+    let processing = RelaxedReinsertion::new(vec![Relaxation {
+        name: "capacity_overflow".to_string(),
+        goal: Arc::new(create_overflow_goal()),
+    }]);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), vec!["job2".to_string()]);
+    assert!(matches!(
+        insertion_ctx.solution.unassigned.values().next(),
+        Some(UnassignmentInfo::Simple(VIOLATION_CODE))
+    ));
+    assert!(get_relaxed_jobs(&insertion_ctx).is_empty());
+}
@@ -0,0 +1,101 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::Problem;
+use rosomaxa::evolution::HeuristicSolutionProcessing;
+use std::sync::Arc;
+
+fn create_test_insertion_ctx(spread: Option<DepartureSpread>, departures: Vec<Timestamp>) -> InsertionContext {
+    let vehicle_ids = (0..departures.len()).map(|idx| format!("v{idx}")).collect::<Vec<_>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vehicle_ids.iter().map(|id| VehicleBuilder::default().id(id).build()).collect())
+        .build();
+    let goal = create_goal_ctx_with_features(
+        vec![create_minimize_transport_costs_feature(
+            "transport",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            1,
+        )
+        .unwrap()],
+        vec![vec!["transport"]],
+    );
+    let mut extras = Extras::default();
+    if let Some(spread) = spread {
+        extras.set_departure_spread(spread);
+    }
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, vec![], fleet);
+    let problem = Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: vec![],
+        goal: problem.goal.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: Arc::new(extras),
+    });
+
+    let mut insertion_ctx = InsertionContext { problem: problem.clone(), ..create_empty_insertion_context() };
+    insertion_ctx.solution.routes = vehicle_ids
+        .iter()
+        .zip(departures)
+        .map(|(vehicle_id, departure)| {
+            let mut route_ctx = create_route_context_with_activities(
+                problem.fleet.as_ref(),
+                vehicle_id,
+                vec![test_activity_with_location(10)],
+            );
+            route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+            route_ctx
+        })
+        .collect();
+    problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+fn get_departures(insertion_ctx: &InsertionContext) -> Vec<Timestamp> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route().tour.start().unwrap().schedule.departure)
+        .collect()
+}
+
+parameterized_test! {can_spread_departures, (spread, departures, expected), {
+    can_spread_departures_impl(spread, departures, expected);
+}}
+
+can_spread_departures! {
+    case01_stagger: (Some(DepartureSpread::Stagger { interval: 10. }), vec![0., 0., 0.], vec![0., 10., 20.]),
+    case02_stagger_unordered: (Some(DepartureSpread::Stagger { interval: 10. }), vec![0., 15., 5.], vec![0., 20., 10.]),
+    case03_band: (Some(DepartureSpread::Band), vec![0., 5., 20.], vec![20., 20., 20.]),
+    case04_no_spread: (None, vec![0., 0., 0.], vec![0., 0., 0.]),
+}
+
+fn can_spread_departures_impl(spread: Option<DepartureSpread>, departures: Vec<Timestamp>, expected: Vec<Timestamp>) {
+    let insertion_ctx = create_test_insertion_ctx(spread, departures);
+
+    let insertion_ctx = SpreadDeparture::default().post_process(insertion_ctx);
+
+    assert_eq!(get_departures(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_keep_departure_within_slack() {
+    let mut insertion_ctx = create_test_insertion_ctx(Some(DepartureSpread::Stagger { interval: 10. }), vec![0., 0.]);
+    let route_ctx = insertion_ctx.solution.routes.get_mut(1).unwrap();
+    route_ctx.route_mut().tour.get_mut(1).unwrap().place.time = TimeWindow::new(0., 15.);
+    let problem = insertion_ctx.problem.clone();
+    problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    let insertion_ctx = SpreadDeparture::default().post_process(insertion_ctx);
+
+    assert_eq!(get_departures(&insertion_ctx), vec![0., 5.]);
+}
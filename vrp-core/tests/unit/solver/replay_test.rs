@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::models::domain::create_simple_insertion_ctx;
+use crate::models::examples::create_example_problem;
+use crate::solver::{create_default_config_builder, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::prelude::Environment;
+
+parameterized_test! {can_sample_generations, (sampling, last_generation, expected), {
+    can_sample_generations_impl(sampling, last_generation, expected);
+}}
+
+can_sample_generations! {
+    case01_replace_last: (2, 4, vec![0, 2, 4]),
+    case02_append_last: (2, 5, vec![0, 2, 4, 5]),
+    case03_every_generation: (1, 2, vec![0, 1, 2]),
+    case04_large_sampling: (10, 3, vec![0, 3]),
+}
+
+fn can_sample_generations_impl(sampling: usize, last_generation: usize, expected: Vec<usize>) {
+    let insertion_ctx = create_simple_insertion_ctx(10., 0);
+    let replay = EvolutionReplay::new(sampling);
+
+    (0..=last_generation).for_each(|generation| replay.on_generation(generation, Some(&insertion_ctx)));
+    replay.on_result(&insertion_ctx);
+
+    let generations = replay.take_frames().into_iter().map(|(generation, _)| generation).collect::<Vec<_>>();
+    assert_eq!(generations, expected);
+    assert!(replay.take_frames().is_empty());
+}
+
+#[test]
+fn can_record_solver_run() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let replay = EvolutionReplay::new(3);
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let (solution, _, _) = Solver::new(problem, config).with_replay(replay.clone()).solve().unwrap();
+
+    let frames = replay.take_frames();
+    assert!(frames.len() > 1);
+    let (_, last) = frames.last().unwrap();
+    assert_eq!(last.routes.len(), solution.routes.len());
+    assert_eq!(last.unassigned.len(), solution.unassigned.len());
+}
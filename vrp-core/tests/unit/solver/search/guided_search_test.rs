@@ -0,0 +1,126 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_from_points};
+use crate::solver::search::{RecreateWithCheapest, Ruin, RuinAndRecreate};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+const GENERATIONS: usize = 20;
+
+/// Removes one job per run in round robin order, so recreate step relocates a single job at once.
+#[derive(Default)]
+struct JobRemoval {
+    counter: AtomicUsize,
+}
+
+impl Ruin for JobRemoval {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let goal = insertion_ctx.problem.goal.clone();
+        let size = insertion_ctx.problem.jobs.size();
+        let job_idx = self.counter.fetch_add(1, AtomicOrdering::Relaxed) % size;
+        let job = insertion_ctx.problem.jobs.all().nth(job_idx).unwrap();
+
+        insertion_ctx.solution.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(&job)).for_each(
+            |route_ctx| {
+                route_ctx.route_mut().tour.remove(&job);
+                goal.accept_route_state(route_ctx);
+            },
+        );
+        insertion_ctx.solution.required.push(job);
+
+        insertion_ctx
+    }
+}
+
+fn create_insertion_ctx(points: &[(f64, f64)]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_from_points(points);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+fn create_ruin_recreate(insertion_ctx: &InsertionContext) -> TargetSearchOperator {
+    Arc::new(RuinAndRecreate::new(
+        Arc::new(JobRemoval::default()),
+        Arc::new(RecreateWithCheapest::new(insertion_ctx.environment.random.clone())),
+    ))
+}
+
+fn run_search(operator: &TargetSearchOperator, insertion_ctx: &InsertionContext) -> Vec<InsertionContext> {
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    (0..GENERATIONS)
+        .scan(insertion_ctx.deep_copy(), |insertion_ctx, _| {
+            *insertion_ctx = operator.search(&refinement_ctx, insertion_ctx);
+            assert!(insertion_ctx.solution.unassigned.is_empty());
+
+            Some(insertion_ctx.deep_copy())
+        })
+        .collect()
+}
+
+#[test]
+fn can_penalize_edges_with_max_utility() {
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (0., 4.), (1., 4.), (1., 1.)]);
+    let mut memory = PenaltyMemory::default();
+
+    memory.update(&insertion_ctx, false, 1, 1.);
+    assert_eq!(memory.penalties.as_ref(), &vec![((0, 1), 1)].into_iter().collect::<EdgePenalties>());
+    assert!((memory.penalty_cost - (4. + 1. + 3. + 2_f64.sqrt()) / 5.).abs() < 1E-6);
+
+    memory.update(&insertion_ctx, false, 1, 1.);
+    assert_eq!(memory.penalties.as_ref(), &vec![((0, 1), 1), ((2, 3), 1)].into_iter().collect::<EdgePenalties>());
+}
+
+#[test]
+fn can_skip_penalties_update_when_search_improves() {
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (0., 4.), (1., 4.), (1., 1.)]);
+    let mut memory = PenaltyMemory::default();
+
+    memory.update(&insertion_ctx, false, 2, 1.);
+    memory.update(&insertion_ctx, true, 2, 1.);
+    memory.update(&insertion_ctx, false, 2, 1.);
+
+    assert!(memory.penalties.is_empty());
+}
+
+#[test]
+fn can_decay_penalties_when_search_improves() {
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (0., 4.), (1., 4.), (1., 1.)]);
+    let mut memory = PenaltyMemory::default();
+
+    memory.update(&insertion_ctx, false, 1, 1.);
+    memory.update(&insertion_ctx, false, 1, 1.);
+    memory.update(&insertion_ctx, false, 1, 1.);
+    assert_eq!(memory.penalties.as_ref(), &vec![((0, 1), 2), ((2, 3), 1)].into_iter().collect::<EdgePenalties>());
+
+    memory.update(&insertion_ctx, true, 1, 1.);
+    assert_eq!(memory.penalties.as_ref(), &vec![((0, 1), 1)].into_iter().collect::<EdgePenalties>());
+
+    memory.update(&insertion_ctx, true, 1, 1.);
+    assert!(memory.penalties.is_empty());
+}
+
+#[test]
+fn can_bound_edge_penalties() {
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (0., 4.), (1., 4.), (1., 1.)]);
+    let mut memory = PenaltyMemory::default();
+
+    (0..100).for_each(|_| memory.update(&insertion_ctx, false, 1, 1.));
+
+    assert!(!memory.penalties.is_empty());
+    assert!(memory.penalties.values().all(|&penalty| penalty <= MAX_EDGE_PENALTY));
+}
+
+#[test]
+fn can_escape_local_optimum() {
+    // NOTE initial tour cannot be improved by relocating any single job, but it is not optimal
+    let insertion_ctx = create_insertion_ctx(&[(2., 0.), (1., 0.), (0., 0.), (3., 2.), (4., 5.), (2., 4.)]);
+    let goal = insertion_ctx.problem.goal.clone();
+
+    let plain_search = create_ruin_recreate(&insertion_ctx);
+    let plain_solutions = run_search(&plain_search, &insertion_ctx);
+    assert!(plain_solutions.iter().all(|solution| goal.total_order(solution, &insertion_ctx) != Ordering::Less));
+
+    let guided_search: TargetSearchOperator = Arc::new(GuidedSearch::new(create_ruin_recreate(&insertion_ctx), 1, 1.));
+    let guided_solutions = run_search(&guided_search, &insertion_ctx);
+    assert!(guided_solutions.iter().any(|solution| goal.total_order(solution, &insertion_ctx) == Ordering::Less));
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx(points: &[(f64, f64)], locked_ids: &[&str]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_from_points(points);
+
+    promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    )
+}
+
+fn get_route_distance(insertion_ctx: &InsertionContext) -> f64 {
+    let route = insertion_ctx.solution.routes[0].route();
+    let profile = &route.actor.vehicle.profile;
+    let locations = route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
+
+    locations.windows(2).map(|leg| insertion_ctx.problem.transport.distance_approx(profile, leg[0], leg[1])).sum()
+}
+
+#[test]
+fn can_remove_crossing_within_route() {
+    // NOTE jobs are served as (0, 0) -> (1, 1) -> (0, 1) -> (1, 0), so diagonals are crossing
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (1., 1.), (0., 1.), (1., 0.)], &[]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    assert!((get_route_distance(&insertion_ctx) - (2. + 2. * 2_f64.sqrt())).abs() < 1E-6);
+
+    let result = ExchangeKOpt::default().explore(&refinement_ctx, &insertion_ctx).expect("no improvement");
+
+    assert!((get_route_distance(&result) - 4.).abs() < 1E-6);
+    assert_eq!(result.solution.routes[0].route().tour.job_count(), 4);
+    assert!(result.solution.unassigned.is_empty());
+}
+
+parameterized_test! {can_skip_route_without_improving_moves, (points, locked_ids), {
+    can_skip_route_without_improving_moves_impl(points, locked_ids);
+}}
+
+can_skip_route_without_improving_moves! {
+    case01_optimal_tour: (&[(0., 0.), (0., 1.), (1., 1.), (1., 0.)], &[]),
+    case02_locked_jobs: (&[(0., 0.), (1., 1.), (0., 1.), (1., 0.)], &["c1", "c2"]),
+}
+
+fn can_skip_route_without_improving_moves_impl(points: &[(f64, f64)], locked_ids: &[&str]) {
+    let insertion_ctx = create_insertion_ctx(points, locked_ids);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let expected_ids = get_customer_ids_from_routes(&insertion_ctx);
+
+    let result = ExchangeKOpt::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected_ids);
+}
+
+#[test]
+fn can_relocate_segment_with_or_opt_move() {
+    // NOTE c1 is misplaced: segment reversal cannot fix it as good as relocation
+    let insertion_ctx = create_insertion_ctx(&[(0., 0.), (1., 4.), (0., 2.), (0., 3.), (3., 3.), (3., 1.)], &[]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = ExchangeKOpt::default().explore(&refinement_ctx, &insertion_ctx).expect("no improvement");
+
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["c0", "c2", "c3", "c1", "c4", "c5"]]);
+    assert!((get_route_distance(&result) - 11.812559).abs() < 1E-6);
+}
+
+parameterized_test! {can_evaluate_moves_within_neighborhood, (max_segment_size, max_neighborhood_size), {
+    can_evaluate_moves_within_neighborhood_impl(max_segment_size, max_neighborhood_size);
+}}
+
+can_evaluate_moves_within_neighborhood! {
+    case01_small_neighborhood: (1, 1),
+    case02_medium_neighborhood: (2, 3),
+    case03_large_neighborhood: (3, 10),
+}
+
+fn can_evaluate_moves_within_neighborhood_impl(max_segment_size: usize, max_neighborhood_size: usize) {
+    let points = [(0., 0.), (4., 1.), (1., 3.), (3., 0.), (0., 2.), (2., 4.), (4., 3.), (1., 1.)];
+    let insertion_ctx = create_insertion_ctx(&points, &[]);
+    let route = insertion_ctx.solution.routes[0].route();
+    let locations = route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
+    let get_distance = |locations: &[usize]| -> f64 {
+        locations
+            .windows(2)
+            .map(|leg| get_approx_cost(insertion_ctx.problem.transport.as_ref(), route, leg[0], leg[1]))
+            .sum()
+    };
+    let original = get_distance(&locations);
+
+    let moves = get_improving_moves(&insertion_ctx, 0, max_segment_size, max_neighborhood_size);
+
+    assert!(!moves.is_empty());
+    moves.iter().for_each(|kopt_move| {
+        let (start, order) = kopt_move.get_order();
+        let span = match kopt_move.kind {
+            KOptMoveKind::Reverse { start, end } => end - start,
+            KOptMoveKind::Relocate { start, size, target } if target < start => {
+                assert!(size <= max_segment_size);
+                start - target
+            }
+            KOptMoveKind::Relocate { start, size, target } => {
+                assert!(size <= max_segment_size);
+                target - start - size
+            }
+        };
+        assert!(span <= max_neighborhood_size);
+
+        // NOTE job at position `idx` is at index `idx + 1` in the tour
+        let mut changed = locations.clone();
+        order.iter().enumerate().for_each(|(offset, &idx)| changed[start + offset + 1] = locations[idx + 1]);
+        assert!((get_distance(&changed) - original - kopt_move.delta).abs() < 1E-6);
+    });
+}
@@ -0,0 +1,177 @@
+use super::*;
+use crate::helpers::models::problem::get_job_id;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::examples::create_example_problem;
+use rosomaxa::utils::{Parallelism, ThreadPool, Timer};
+use std::sync::RwLock;
+
+#[test]
+fn can_solve_greedy_without_generations() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(
+        problem.clone(),
+        environment,
+        TelemetryMode::OnlyMetrics { track_population: 100 },
+    )
+    .with_max_generations(Some(1000))
+    .build()
+    .unwrap();
+
+    let (solution, cost, metrics) = Solver::new(problem, config).solve_greedy().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    assert!(cost > 0.);
+    let metrics = metrics.expect("should have metrics");
+    assert_eq!(metrics.generations, 0);
+    assert!(metrics.diversity.is_some());
+}
+
+#[test]
+fn can_reproduce_solution_with_the_same_seed() {
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 6, false);
+    let problem = Arc::new(problem);
+    let solve_with_seed = |seed: u64| {
+        // NOTE run on a single thread to avoid non-determinism introduced by data parallelism
+        ThreadPool::new(1).execute(|| {
+            let environment = Arc::new(
+                Environment { parallelism: Parallelism::new(1, 1), logger: Arc::new(|_| {}), ..Environment::default() }
+                    .with_seed(seed),
+            );
+            let config = create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+                .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+                .with_max_generations(Some(100))
+                .build()
+                .unwrap();
+
+            let (solution, cost, _) = Solver::new(problem.clone(), config).solve().unwrap();
+            let tours = solution
+                .routes
+                .iter()
+                .map(|route| route.tour.jobs().map(|job| get_job_id(&job).clone()).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            (cost, tours, solution.unassigned.len())
+        })
+    };
+
+    let (cost_a, tours_a, unassigned_a) = solve_with_seed(42);
+    let (cost_b, tours_b, unassigned_b) = solve_with_seed(42);
+
+    assert!(cost_a > 0.);
+    assert_eq!(unassigned_a, 0);
+    assert_eq!(cost_a, cost_b);
+    assert_eq!(tours_a, tours_b);
+    assert_eq!(unassigned_a, unassigned_b);
+}
+
+#[cfg(feature = "async-evolution")]
+#[test]
+fn can_solve_with_async_evolution() {
+    use rosomaxa::evolution::strategies::{AsyncIterative, AsyncParams};
+    use rosomaxa::get_default_selection_size;
+
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment { logger: Arc::new(|_| {}), ..Environment::new_with_time_quota(Some(10)) });
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let async_strategy = AsyncIterative::new(
+        AsyncParams::new(3, selection_size),
+        1,
+        problem.goal.clone(),
+        Box::new({
+            let problem = problem.clone();
+            let environment = environment.clone();
+            move || get_dynamic_heuristic(problem.clone(), environment.clone())
+        }),
+        Box::new({
+            let problem = problem.clone();
+            let environment = environment.clone();
+            move |_, population| {
+                RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone())
+            }
+        }),
+    );
+
+    let config = create_default_config_builder(
+        problem.clone(),
+        environment,
+        TelemetryMode::OnlyMetrics { track_population: 100 },
+    )
+    .with_max_generations(Some(100))
+    .with_strategy(Box::new(async_strategy))
+    .build()
+    .unwrap();
+
+    let (solution, cost, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    assert!(cost > 0.);
+    assert!(solution.unassigned.is_empty());
+    assert!(metrics.expect("should have metrics").generations >= 100);
+}
+
+#[test]
+fn can_stop_converged_search_before_max_generations() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment { logger: Arc::new(|_| {}), ..Environment::default() });
+    let config = create_default_config_builder(
+        problem.clone(),
+        environment,
+        TelemetryMode::OnlyMetrics { track_population: 100 },
+    )
+    .with_max_generations(Some(1000))
+    .with_min_improvement(Some((20, 0.)), "min_improvement".to_string())
+    .build()
+    .unwrap();
+
+    let (_, cost, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert!(metrics.expect("should have metrics").generations < 1000);
+}
+
+#[test]
+fn can_stop_evolution_before_deadline_with_reserve() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment { logger: Arc::new(|_| {}), ..Environment::new_with_time_quota(Some(2)) });
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_time(Some(2))
+        .with_max_time_reserve(Some(1))
+        .with_max_generations(Some(usize::MAX))
+        .build()
+        .unwrap();
+    let timer = Timer::start();
+
+    let (_, cost, _) = Solver::new(problem, config).solve().unwrap();
+
+    let elapsed = timer.elapsed_secs_as_f64();
+    assert_eq!(cost, 42.);
+    assert!(elapsed > 0.9, "elapsed: {elapsed}");
+    assert!(elapsed < 2., "elapsed: {elapsed}");
+}
+
+#[test]
+fn can_observe_generations_while_solving() {
+    let generations = 10;
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(generations))
+        .build()
+        .unwrap();
+    let observed = Arc::new(RwLock::new(vec![]));
+
+    let (solution, _, _) = Solver::new(problem, config)
+        .with_generation_observer(Box::new({
+            let observed = observed.clone();
+            move |generation| observed.write().unwrap().push(generation.number)
+        }))
+        .solve()
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    let observed = observed.read().unwrap().clone();
+    assert!(!observed.is_empty());
+    assert!(observed.windows(2).all(|numbers| numbers[0] + 1 == numbers[1]));
+    assert!(observed.last().map_or(false, |&number| number <= generations));
+}
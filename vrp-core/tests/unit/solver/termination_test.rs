@@ -0,0 +1,83 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::examples::create_example_problem;
+use crate::solver::create_default_config_builder;
+
+parameterized_test! {can_detect_min_served_ratio, (unassigned_routes, target, expected), {
+    can_detect_min_served_ratio_impl(unassigned_routes, target, expected);
+}}
+
+can_detect_min_served_ratio! {
+    case01_all_served: (0, 0.9, true),
+    case02_below_target: (1, 0.9, false),
+    case03_at_target: (1, 0.75, true),
+    case04_half_served: (2, 0.75, false),
+}
+
+fn can_detect_min_served_ratio_impl(unassigned_routes: usize, target: f64, expected: bool) {
+    let environment = Arc::new(Environment::default());
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(5, 4, false);
+    (0..unassigned_routes).for_each(|_| {
+        solution.registry.free_actor(&solution.routes[0].actor);
+        solution.unassigned.extend(solution.routes[0].tour.jobs().map(|job| (job, UnassignmentInfo::Unknown)));
+        solution.routes.remove(0);
+    });
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+    let mut refinement_ctx = create_default_refinement_ctx(problem);
+    refinement_ctx.add_solution(insertion_ctx);
+
+    let result = MinServedRatio::new(target, Arc::new(|_| true)).unwrap().is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_validate_target_ratio, (target, is_ok), {
+    assert_eq!(MinServedRatio::new(target, Arc::new(|_| true)).is_ok(), is_ok);
+}}
+
+can_validate_target_ratio! {
+    case01_zero: (0., false),
+    case02_negative: (-0.5, false),
+    case03_above_one: (1.1, false),
+    case04_one: (1., true),
+    case05_valid: (0.5, true),
+}
+
+#[test]
+fn can_count_only_required_plan_jobs() {
+    let environment = Arc::new(Environment::default());
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(5, 4, false);
+    let ignored = solution.routes[0].tour.jobs().collect::<Vec<_>>();
+    let excluded = solution.routes[1].tour.jobs().collect::<Vec<_>>();
+    solution.unassigned.extend(excluded.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)));
+    solution.registry.free_actor(&solution.routes[0].actor);
+    solution.registry.free_actor(&solution.routes[1].actor);
+    solution.routes.drain(0..2);
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    // NOTE ignored jobs and jobs rejected by the filter are counted neither as served nor unserved
+    insertion_ctx.solution.ignored.extend(ignored);
+
+    let ratio = get_served_ratio(&insertion_ctx, &|job| !excluded.contains(job));
+
+    assert_eq!(ratio, 1.);
+}
+
+#[test]
+fn can_terminate_early_when_served_ratio_is_reached() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let replay = EvolutionReplay::new(1);
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(1000))
+        .add_termination(Box::new(MinServedRatio::new(0.9, Arc::new(|_| true)).unwrap()))
+        .build()
+        .unwrap();
+
+    let (solution, _, _) = Solver::new(problem, config).with_replay(replay.clone()).solve().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    let last_generation = replay.take_frames().last().map(|(generation, _)| *generation).unwrap();
+    assert!(last_generation < 10);
+}
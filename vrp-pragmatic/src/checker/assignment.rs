@@ -19,6 +19,7 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
         check_jobs_match(ctx),
         check_dispatch(ctx),
         check_groups(ctx),
+        check_visits(ctx),
     ])
 }
 
@@ -196,7 +197,12 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 | (_, Some(_), Ok(None))
                                                 | (&None, &Some(_), Ok(Some(_))) => true,
                                                 (_, None, Ok(None)) => {
-                                                    let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
+                                                    let service_scale = if matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement") {
+                                                        ctx.get_vehicle(&tour.vehicle_id).ok().and_then(|vehicle| vehicle.service_scale).unwrap_or(1.)
+                                                    } else {
+                                                        1.
+                                                    };
+                                                    let expected_departure = time.start.max(place.time.start) + place.duration * service_scale + extra_time;
                                                     not_equal(time.end, expected_departure)
                                                 }
                                                 (Some(config), Some(commute), Ok(Some(d_commute))) => {
@@ -284,8 +290,25 @@ fn check_dispatch(ctx: &CheckerContext) -> Result<(), String> {
             .filter(|(_, _, activity)| activity.activity_type == "dispatch")
             .collect::<Vec<_>>();
 
-        if dispatch_in_tour.len() > 1 {
-            return Err(format!("more than one dispatch in the tour: '{}'", tour.vehicle_id));
+        let stages = vehicles_with_dispatch
+            .get(&tour.type_id)
+            .map_or(0, |ds| ds.iter().map(|d| d.stage.unwrap_or(1)).collect::<HashSet<_>>().len());
+
+        if dispatch_in_tour.len() > stages.max(1) {
+            return Err(format!("more than one dispatch per stage in the tour: '{}'", tour.vehicle_id));
+        }
+
+        if should_have_dispatch && !dispatch_in_tour.is_empty() && dispatch_in_tour.len() != stages {
+            return Err(format!("tour should have dispatch for each stage: '{}'", tour.vehicle_id));
+        }
+
+        let is_sequence = dispatch_in_tour.windows(2).all(|pair| {
+            let ((prev_stop, prev_activity, _), (next_stop, next_activity, _)) = (pair[0], pair[1]);
+            (next_stop == prev_stop && next_activity == prev_activity + 1)
+                || (next_stop == prev_stop + 1 && next_activity == 0)
+        });
+        if !is_sequence {
+            return Err(format!("dispatch stages are not visited one after another: '{}'", tour.vehicle_id));
         }
 
         if should_have_dispatch && dispatch_in_tour.is_empty() {
@@ -365,3 +388,45 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
         Err(format!("job groups are not respected: '{err_info}'"))
     }
 }
+
+/// Checks that visits of multi-visit jobs are served in their order.
+fn check_visits(ctx: &CheckerContext) -> Result<(), String> {
+    let times = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .map(|(stop, activity)| (activity.job_id.as_str(), ctx.get_activity_time(stop, activity)))
+        .collect::<HashMap<_, _>>();
+
+    let visits = ctx.problem.plan.jobs.iter().filter_map(|job| job.visit.as_ref().map(|visit| (visit, job))).fold(
+        HashMap::<_, Vec<_>>::default(),
+        |mut acc, (visit, job)| {
+            acc.entry(visit.id.as_str()).or_default().push((visit.order, job.id.as_str()));
+            acc
+        },
+    );
+
+    let mut violations = visits
+        .into_iter()
+        .filter(|(_, visits)| {
+            let mut visits = visits.clone();
+            visits.sort_by_key(|(order, _)| *order);
+
+            visits.windows(2).any(|pair| match (times.get(pair[0].1), times.get(pair[1].1)) {
+                (Some(prev), Some(next)) => prev.end > next.start,
+                (None, Some(_)) => true,
+                _ => false,
+            })
+        })
+        .map(|(visit_id, _)| visit_id.to_string())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("job visit order is not respected: '{}'", violations.join(",")))
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/binding_test.rs"]
+mod binding_test;
+
+use super::*;
+
+/// Specifies a constraint which is at its limit in the tour, so relaxing it might improve the solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindingConstraint {
+    /// Vehicle capacity is fully used at some stop of the tour.
+    Capacity,
+    /// Job activity is started exactly at the end of its time window.
+    TimeWindow {
+        /// A job id.
+        job_id: String,
+    },
+    /// Tour ends exactly at the latest end time of vehicle shift.
+    ShiftTime,
+    /// Tour distance is equal to the max distance limit.
+    MaxDistance,
+    /// Tour duration is equal to the max duration limit.
+    MaxDuration,
+    /// Amount of job activities in the tour is equal to the tour size limit.
+    TourSize,
+}
+
+/// Keeps binding constraints of a single tour.
+#[derive(Clone, Debug)]
+pub struct TourBinding {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A shift index.
+    pub shift_index: usize,
+    /// A list of binding constraints.
+    pub constraints: Vec<BindingConstraint>,
+}
+
+/// Returns constraints which are binding (have no slack left) in each tour of the solution:
+/// * vehicle capacity
+/// * job time windows
+/// * shift time
+/// * max distance, max duration and tour size limits
+pub fn get_binding_constraints(context: &CheckerContext) -> Result<Vec<TourBinding>, String> {
+    context
+        .solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+
+            let capacity = is_capacity_binding(vehicle, tour).then_some(BindingConstraint::Capacity);
+            let shift_time = is_shift_time_binding(vehicle, tour).then_some(BindingConstraint::ShiftTime);
+            let time_windows = get_binding_time_windows(context, tour)?;
+            let limits = get_binding_limits(vehicle, tour);
+
+            Ok(TourBinding {
+                vehicle_id: tour.vehicle_id.clone(),
+                shift_index: tour.shift_index,
+                constraints: capacity.into_iter().chain(time_windows).chain(shift_time).chain(limits).collect(),
+            })
+        })
+        .collect()
+}
+
+fn is_capacity_binding(vehicle: &VehicleType, tour: &Tour) -> bool {
+    tour.stops.iter().any(|stop| {
+        stop.load().iter().zip(vehicle.capacity.iter()).any(|(load, capacity)| *capacity > 0 && load >= capacity)
+    })
+}
+
+fn is_shift_time_binding(vehicle: &VehicleType, tour: &Tour) -> bool {
+    let shift_end =
+        vehicle.shifts.get(tour.shift_index).and_then(|shift| shift.end.as_ref()).map(|end| parse_time(&end.latest));
+
+    shift_end
+        .zip(tour.stops.last())
+        .map_or(false, |(shift_end, stop)| parse_time(&stop.schedule().arrival) >= shift_end)
+}
+
+fn get_binding_time_windows(context: &CheckerContext, tour: &Tour) -> Result<Vec<BindingConstraint>, String> {
+    tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_fold(
+        Vec::default(),
+        |mut acc, (stop, activity)| {
+            let activity_type = context.get_activity_type(tour, stop, activity)?;
+            let start = context.get_activity_time(stop, activity).start;
+
+            let is_binding = context.visit_job(
+                activity,
+                &activity_type,
+                |_, task| {
+                    task.places.iter().flat_map(|place| place.times.iter().flatten()).any(|time| {
+                        let tw = parse_time_window(time);
+                        tw.start <= start && start >= tw.end
+                    })
+                },
+                || false,
+            )?;
+
+            if is_binding {
+                acc.push(BindingConstraint::TimeWindow { job_id: activity.job_id.clone() });
+            }
+
+            Ok(acc)
+        },
+    )
+}
+
+fn get_binding_limits(vehicle: &VehicleType, tour: &Tour) -> Vec<BindingConstraint> {
+    let Some(limits) = vehicle.limits.as_ref() else { return Vec::default() };
+
+    let tour_size = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| !matches!(activity.activity_type.as_str(), "departure" | "arrival" | "break" | "reload"))
+        .count();
+
+    [
+        (
+            limits.max_distance.map_or(false, |limit| tour.statistic.distance as f64 >= limit),
+            BindingConstraint::MaxDistance,
+        ),
+        (
+            limits.max_duration.map_or(false, |limit| tour.statistic.duration as f64 >= limit),
+            BindingConstraint::MaxDuration,
+        ),
+        (limits.tour_size.map_or(false, |limit| tour_size >= limit), BindingConstraint::TourSize),
+    ]
+    .into_iter()
+    .filter_map(|(is_binding, constraint)| is_binding.then_some(constraint))
+    .collect()
+}
@@ -13,6 +13,13 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * max cost
+/// * tour size
+/// * max open jobs
+/// * max late activities
+/// * max shift span
+/// * max interruptions
+/// * lifo order
 fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -36,6 +43,15 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                 }
             }
 
+            if let Some(max_cost) = limits.max_cost {
+                if tour.statistic.cost > max_cost {
+                    return Err(format!(
+                        "max cost limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_cost, tour.statistic.cost, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
             if let Some(tour_size_limit) = limits.tour_size {
                 let shift = context.get_vehicle_shift(tour)?;
 
@@ -50,12 +66,185 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                     ))
                 }
             }
+
+            if let Some(max_open_jobs) = limits.max_open_jobs {
+                let open_jobs = get_max_open_jobs(context, tour);
+
+                if open_jobs > max_open_jobs {
+                    return Err(format!(
+                        "max open jobs limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_open_jobs, open_jobs, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_late_activities) = limits.max_late_activities {
+                let late_activities = get_late_activities(context, tour)?;
+
+                if late_activities > max_late_activities {
+                    return Err(format!(
+                        "max late activities limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_late_activities, late_activities, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_shift_span) = limits.max_shift_span {
+                let shift_span = get_shift_span(context, tour);
+
+                if shift_span > max_shift_span {
+                    return Err(format!(
+                        "max shift span limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_shift_span, shift_span, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_interruptions) = limits.max_interruptions {
+                let interruptions = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities().iter())
+                    .filter(|activity| activity.activity_type == "break" || activity.activity_type == "reload")
+                    .count();
+
+                if interruptions > max_interruptions {
+                    return Err(format!(
+                        "max interruptions limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_interruptions, interruptions, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+        }
+
+        if vehicle.lifo.unwrap_or(false) && !is_lifo_order(context, tour) {
+            return Err(format!(
+                "lifo order violation, vehicle id '{}', shift index: {}",
+                tour.vehicle_id, tour.shift_index
+            ));
         }
 
         Ok(())
     })
 }
 
+/// Returns max amount of jobs with multiple tasks which are started, but not yet finished in the tour.
+fn get_max_open_jobs(context: &CheckerContext, tour: &Tour) -> usize {
+    let job_ids = get_tour_job_ids(context, tour);
+
+    let mut remaining = job_ids.iter().fold(HashMap::<&str, usize>::new(), |mut acc, job_id| {
+        *acc.entry(*job_id).or_default() += 1;
+        acc
+    });
+
+    let (_, max_open) = job_ids.iter().fold((HashSet::<&str>::new(), 0), |(mut open, max_open), job_id| {
+        let count = remaining.get_mut(job_id).expect("job should be counted");
+        *count -= 1;
+
+        if *count == 0 {
+            open.remove(job_id);
+        } else {
+            open.insert(*job_id);
+        }
+
+        let max_open = max_open.max(open.len());
+
+        (open, max_open)
+    });
+
+    max_open
+}
+
+/// Returns time between arrival at the first job activity and departure from the last one.
+fn get_shift_span(context: &CheckerContext, tour: &Tour) -> f64 {
+    let times = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type != "departure" && activity.activity_type != "arrival")
+        .map(|(stop, activity)| context.get_activity_time(stop, activity))
+        .collect::<Vec<_>>();
+
+    match (times.first(), times.last()) {
+        (Some(first), Some(last)) => last.end - first.start,
+        _ => 0.,
+    }
+}
+
+/// Returns amount of job activities in the tour which are served after their target time.
+fn get_late_activities(context: &CheckerContext, tour: &Tour) -> Result<usize, String> {
+    tour.stops.iter().try_fold(0, |acc, stop| {
+        stop.activities().iter().try_fold(acc, |acc, activity| {
+            let activity_type = context.get_activity_type(tour, stop, activity)?;
+            let time = context.get_activity_time(stop, activity);
+            let location = context.get_activity_location(stop, activity);
+
+            let is_late = context.visit_job(
+                activity,
+                &activity_type,
+                |_, task| {
+                    task.target_time.as_ref().map_or(false, |target_time| {
+                        let duration = task
+                            .places
+                            .iter()
+                            .find(|place| Some(&place.location) == location.as_ref())
+                            .map_or(0., |place| place.duration);
+
+                        time.end - duration > parse_time(target_time)
+                    })
+                },
+                || false,
+            )?;
+
+            Ok(acc + is_late as usize)
+        })
+    })
+}
+
+/// Returns ids of the tour's job activities in order of their appearance.
+fn get_tour_job_ids<'a>(context: &CheckerContext, tour: &'a Tour) -> Vec<&'a str> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .filter(|job_id| context.get_job_by_id(job_id).is_some())
+        .collect()
+}
+
+/// Checks whether jobs with multiple tasks are finished in reverse order of their start.
+fn is_lifo_order(context: &CheckerContext, tour: &Tour) -> bool {
+    let job_ids = get_tour_job_ids(context, tour);
+
+    let mut remaining = job_ids.iter().fold(HashMap::<&str, usize>::new(), |mut acc, job_id| {
+        *acc.entry(*job_id).or_default() += 1;
+        acc
+    });
+
+    job_ids
+        .iter()
+        .try_fold(Vec::<&str>::new(), |mut open, job_id| {
+            let count = remaining.get_mut(job_id).expect("job should be counted");
+            *count -= 1;
+
+            // NOTE already started job can be continued only when it is the last started one
+            let is_started = open.contains(job_id);
+            if is_started && open.last() != Some(job_id) {
+                return None;
+            }
+
+            match (is_started, *count == 0) {
+                (true, true) => {
+                    open.pop();
+                }
+                (false, false) => open.push(job_id),
+                _ => {}
+            }
+
+            Some(open)
+        })
+        .is_some()
+}
+
 fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -37,7 +37,7 @@ pub struct CheckerContext {
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Depot(VehicleDispatch),
     Break(VehicleBreak),
     Reload(VehicleReload),
@@ -51,6 +51,7 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<String>> {
+        let problem = expand_vehicle_pools(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().cloned();
         let coord_index = CoordIndex::new(&problem);
@@ -175,7 +176,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id)),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
             "break" => shift
@@ -376,10 +377,30 @@ impl CheckerContext {
 
         let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
         let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
+
+        // NOTE apply the same min travel values as solver does when reads routing matrix
+        let is_error =
+            matrix.error_codes.as_ref().and_then(|codes| codes.get(matrix_idx)).map_or(false, |code| *code > 0);
+        let (distance, duration) = match self.get_matrix_min_travel(profile.index) {
+            Some(min_travel) if from_idx != to_idx && !is_error => {
+                (distance.max(min_travel.distance), duration.max(min_travel.duration))
+            }
+            _ => (distance, duration),
+        };
+
         let duration = (duration as f64 * profile.scale) as i64;
 
         Ok((distance, duration))
     }
+
+    fn get_matrix_min_travel(&self, profile_index: usize) -> Option<&MatrixMinTravel> {
+        self.problem
+            .fleet
+            .profiles
+            .iter()
+            .find(|profile| self.profile_index.get(&profile.name) == Some(&profile_index))
+            .and_then(|profile| profile.min_travel.as_ref())
+    }
 }
 
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
@@ -473,3 +494,6 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+mod binding;
+pub use crate::checker::binding::{get_binding_constraints, BindingConstraint, TourBinding};
@@ -22,10 +22,13 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
         let time_offset =
             parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.schedule().departure) as i64;
 
-        let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(i64, i64), String> {
+        let get_matrix_data = |from: &PointStop, to: &PointStop, departure: i64| -> Result<(i64, i64), String> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            let (distance, duration) = context.get_matrix_data(&profile, from_idx, to_idx)?;
+            let gate_waiting = get_gate_waiting(context, (&from.location, &to.location), departure)?;
+
+            Ok((distance, duration + gate_waiting))
         };
 
         //let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
@@ -39,7 +42,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
 
                 let (distance, duration, to_distance) = match (from, to) {
                     (Stop::Point(from), Stop::Point(to)) => {
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                     (_, Stop::Transit(transit)) => {
@@ -54,7 +57,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                             .unwrap()
                             .as_point()
                             .expect("two consistent transit stops are not supported");
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                 };
@@ -82,6 +85,33 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
     check_solution_statistic(&context.solution)
 }
 
+/// Returns time spent waiting for a travel gate to open when it is used on the given edge.
+fn get_gate_waiting(context: &CheckerContext, edge: (&Location, &Location), departure: i64) -> Result<i64, String> {
+    let (from, to) = edge;
+    let departure = departure as f64;
+
+    let times = context
+        .problem
+        .fleet
+        .gates
+        .iter()
+        .flatten()
+        .filter(|gate| gate.from == *from && gate.to == *to)
+        .flat_map(|gate| gate.times.iter().map(|tw| parse_time_window(tw)))
+        .collect::<Vec<_>>();
+
+    if times.is_empty() {
+        return Ok(0);
+    }
+
+    times
+        .iter()
+        .filter(|tw| tw.end >= departure)
+        .map(|tw| (tw.start - departure).max(0.) as i64)
+        .min()
+        .ok_or_else(|| format!("cannot pass travel gate from {from:?} to {to:?} at '{}'", format_time(departure)))
+}
+
 fn check_stop_statistic(
     arrival_time: i64,
     total_distance: i64,
@@ -125,22 +155,18 @@ fn check_tour_statistic(
         ));
     }
 
-    let dispatch_at_start_correction =
-        tour.stops
-            .first()
-            .and_then(|stop| stop.activities().get(1))
-            .and_then(|activity| {
-                if activity.activity_type == "dispatch" {
-                    Some(
-                        activity.time.as_ref().map_or(0, |interval| {
-                            parse_time(&interval.end) as i64 - parse_time(&interval.start) as i64
-                        }),
-                    )
-                } else {
-                    None
-                }
+    let dispatch_at_start_correction = tour.stops.first().map_or(0, |stop| {
+        stop.activities()
+            .iter()
+            .filter(|activity| activity.activity_type == "dispatch")
+            .map(|activity| {
+                activity
+                    .time
+                    .as_ref()
+                    .map_or(0, |interval| parse_time(&interval.end) as i64 - parse_time(&interval.start) as i64)
             })
-            .unwrap_or(0);
+            .sum::<i64>()
+    });
 
     let total_duration = departure_time - time_offset + dispatch_at_start_correction;
     if (total_duration - tour.statistic.duration).abs() > 1 {
@@ -1,8 +1,8 @@
 //! Specifies different entities as extension points on Dimensions type.
 
-use crate::construction::features::{BreakPolicy, JobSkills};
-use hashbrown::HashSet;
-use vrp_core::models::common::{Dimensions, ValueDimension};
+use crate::construction::features::{BreakPolicy, BreakSequence, JobSkills};
+use hashbrown::{HashMap, HashSet};
+use vrp_core::models::common::{Cost, Dimensions, Duration, TimeWindow, Timestamp, ValueDimension};
 
 /// Specifies vehicle entity.
 pub trait VehicleTie {
@@ -26,10 +26,45 @@ pub trait VehicleTie {
     /// Sets vehicle's skills set.
     fn set_vehicle_skills(&mut self, skills: HashSet<String>) -> &mut Self;
 
+    /// Gets vehicle's skills validity time windows.
+    fn get_vehicle_skill_validity(&self) -> Option<&HashMap<String, Vec<TimeWindow>>>;
+    /// Sets vehicle's skills validity time windows.
+    fn set_vehicle_skill_validity(&mut self, validity: HashMap<String, Vec<TimeWindow>>) -> &mut Self;
+
     /// Gets vehicle's tour size.
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's max idle time.
+    fn get_max_idle_time(&self) -> Option<Duration>;
+    /// Sets vehicle's max idle time.
+    fn set_max_idle_time(&mut self, max_idle_time: Duration) -> &mut Self;
+
+    /// Gets job types which vehicle's shift can serve.
+    fn get_shift_job_types(&self) -> Option<&HashSet<String>>;
+    /// Sets job types which vehicle's shift can serve.
+    fn set_shift_job_types(&mut self, job_types: HashSet<String>) -> &mut Self;
+
+    /// Gets shift overtime penalty.
+    fn get_shift_overtime_penalty(&self) -> Option<Cost>;
+    /// Sets shift overtime penalty.
+    fn set_shift_overtime_penalty(&mut self, penalty: Cost) -> &mut Self;
+
+    /// Gets whether vehicle is loaded in LIFO order.
+    fn get_vehicle_lifo(&self) -> Option<bool>;
+    /// Sets whether vehicle is loaded in LIFO order.
+    fn set_vehicle_lifo(&mut self, lifo: bool) -> &mut Self;
+
+    /// Gets vehicle's temperature range as (min, max).
+    fn get_vehicle_temperature(&self) -> Option<(f64, f64)>;
+    /// Sets vehicle's temperature range as (min, max).
+    fn set_vehicle_temperature(&mut self, temperature: (f64, f64)) -> &mut Self;
+
+    /// Gets vehicle service duration scale.
+    fn get_vehicle_service_scale(&self) -> Option<f64>;
+    /// Sets vehicle service duration scale.
+    fn set_vehicle_service_scale(&mut self, scale: f64) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -71,6 +106,15 @@ impl VehicleTie for Dimensions {
         self
     }
 
+    fn get_vehicle_skill_validity(&self) -> Option<&HashMap<String, Vec<TimeWindow>>> {
+        self.get_value("vehicle_skill_validity")
+    }
+
+    fn set_vehicle_skill_validity(&mut self, validity: HashMap<String, Vec<TimeWindow>>) -> &mut Self {
+        self.set_value("vehicle_skill_validity", validity);
+        self
+    }
+
     fn get_tour_size(&self) -> Option<usize> {
         self.get_value("tour_size").cloned()
     }
@@ -79,6 +123,60 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_max_idle_time(&self) -> Option<Duration> {
+        self.get_value("max_idle_time").cloned()
+    }
+
+    fn set_max_idle_time(&mut self, max_idle_time: Duration) -> &mut Self {
+        self.set_value("max_idle_time", max_idle_time);
+        self
+    }
+
+    fn get_shift_job_types(&self) -> Option<&HashSet<String>> {
+        self.get_value("shift_job_types")
+    }
+
+    fn set_shift_job_types(&mut self, job_types: HashSet<String>) -> &mut Self {
+        self.set_value("shift_job_types", job_types);
+        self
+    }
+
+    fn get_shift_overtime_penalty(&self) -> Option<Cost> {
+        self.get_value("shift_overtime_penalty").cloned()
+    }
+
+    fn set_shift_overtime_penalty(&mut self, penalty: Cost) -> &mut Self {
+        self.set_value("shift_overtime_penalty", penalty);
+        self
+    }
+
+    fn get_vehicle_lifo(&self) -> Option<bool> {
+        self.get_value("vehicle_lifo").cloned()
+    }
+
+    fn set_vehicle_lifo(&mut self, lifo: bool) -> &mut Self {
+        self.set_value("vehicle_lifo", lifo);
+        self
+    }
+
+    fn get_vehicle_temperature(&self) -> Option<(f64, f64)> {
+        self.get_value("vehicle_temperature").cloned()
+    }
+
+    fn set_vehicle_temperature(&mut self, temperature: (f64, f64)) -> &mut Self {
+        self.set_value("vehicle_temperature", temperature);
+        self
+    }
+
+    fn get_vehicle_service_scale(&self) -> Option<f64> {
+        self.get_value("vehicle_service_scale").cloned()
+    }
+
+    fn set_vehicle_service_scale(&mut self, scale: f64) -> &mut Self {
+        self.set_value("vehicle_service_scale", scale);
+        self
+    }
 }
 
 /// Specifies job entity.
@@ -103,11 +201,21 @@ pub trait JobTie {
     /// Sets job order.
     fn set_job_order(&mut self, order: Option<i32>) -> &mut Self;
 
+    /// Gets job target time.
+    fn get_job_target_time(&self) -> Option<Timestamp>;
+    /// Sets job target time.
+    fn set_job_target_time(&mut self, target_time: Option<Timestamp>) -> &mut Self;
+
     /// Gets job value.
     fn get_job_value(&self) -> Option<f64>;
     /// Sets job value.
     fn set_job_value(&mut self, value: Option<f64>) -> &mut Self;
 
+    /// Gets job priority tier.
+    fn get_job_priority(&self) -> Option<usize>;
+    /// Sets job priority tier.
+    fn set_job_priority(&mut self, priority: Option<usize>) -> &mut Self;
+
     /// Gets job group.
     fn get_job_group(&self) -> Option<&String>;
     /// Sets job group.
@@ -118,6 +226,46 @@ pub trait JobTie {
     /// Sets job compatibility.
     fn set_job_compatibility(&mut self, compatibility: Option<String>) -> &mut Self;
 
+    /// Checks whether job is a cleaning stop which resets compatibility.
+    fn get_job_cleaning(&self) -> bool;
+    /// Sets job cleaning flag.
+    fn set_job_cleaning(&mut self, cleaning: Option<bool>) -> &mut Self;
+
+    /// Gets job customer id.
+    fn get_job_customer_id(&self) -> Option<&String>;
+    /// Sets job customer id.
+    fn set_job_customer_id(&mut self, customer_id: Option<String>) -> &mut Self;
+
+    /// Gets job max detour time.
+    fn get_job_max_detour_time(&self) -> Option<Duration>;
+    /// Sets job max detour time.
+    fn set_job_max_detour_time(&mut self, max_detour_time: Option<Duration>) -> &mut Self;
+
+    /// Gets job max wait time.
+    fn get_job_max_wait_time(&self) -> Option<Duration>;
+    /// Sets job max wait time.
+    fn set_job_max_wait_time(&mut self, max_wait_time: Option<Duration>) -> &mut Self;
+
+    /// Gets job temperature range as (min, max).
+    fn get_job_temperature(&self) -> Option<(f64, f64)>;
+    /// Sets job temperature range as (min, max).
+    fn set_job_temperature(&mut self, temperature: Option<(f64, f64)>) -> &mut Self;
+
+    /// Gets job service tier.
+    fn get_job_tier(&self) -> Option<&String>;
+    /// Sets job service tier.
+    fn set_job_tier(&mut self, tier: Option<String>) -> &mut Self;
+
+    /// Gets reload minimum load factor.
+    fn get_reload_min_load_factor(&self) -> Option<f64>;
+    /// Sets reload minimum load factor.
+    fn set_reload_min_load_factor(&mut self, factor: Option<f64>) -> &mut Self;
+
+    /// Gets dispatch stage.
+    fn get_dispatch_stage(&self) -> Option<usize>;
+    /// Sets dispatch stage.
+    fn set_dispatch_stage(&mut self, stage: usize) -> &mut Self;
+
     /// Gets job (activity) type.
     fn get_job_type(&self) -> Option<&String>;
     /// Sets job (activity) type
@@ -178,6 +326,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_target_time(&self) -> Option<Timestamp> {
+        self.get_value("job_target_time").cloned()
+    }
+
+    fn set_job_target_time(&mut self, target_time: Option<Timestamp>) -> &mut Self {
+        if let Some(target_time) = target_time {
+            self.set_value("job_target_time", target_time);
+        } else {
+            self.remove("job_target_time");
+        }
+
+        self
+    }
+
     fn get_job_value(&self) -> Option<f64> {
         self.get_value("job_value").cloned()
     }
@@ -192,6 +354,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_priority(&self) -> Option<usize> {
+        self.get_value("job_priority").cloned()
+    }
+
+    fn set_job_priority(&mut self, priority: Option<usize>) -> &mut Self {
+        if let Some(priority) = priority {
+            self.set_value("job_priority", priority);
+        } else {
+            self.remove("job_priority");
+        }
+
+        self
+    }
+
     fn get_job_group(&self) -> Option<&String> {
         self.get_value("job_group")
     }
@@ -220,6 +396,113 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_cleaning(&self) -> bool {
+        self.get_value::<bool>("job_cleaning").copied().unwrap_or(false)
+    }
+
+    fn set_job_cleaning(&mut self, cleaning: Option<bool>) -> &mut Self {
+        if let Some(cleaning) = cleaning {
+            self.set_value("job_cleaning", cleaning);
+        } else {
+            self.remove("job_cleaning");
+        }
+
+        self
+    }
+
+    fn get_job_max_detour_time(&self) -> Option<Duration> {
+        self.get_value("job_max_detour_time").cloned()
+    }
+
+    fn set_job_max_detour_time(&mut self, max_detour_time: Option<Duration>) -> &mut Self {
+        if let Some(max_detour_time) = max_detour_time {
+            self.set_value("job_max_detour_time", max_detour_time);
+        } else {
+            self.remove("job_max_detour_time");
+        }
+
+        self
+    }
+
+    fn get_job_max_wait_time(&self) -> Option<Duration> {
+        self.get_value("job_max_wait_time").cloned()
+    }
+
+    fn set_job_max_wait_time(&mut self, max_wait_time: Option<Duration>) -> &mut Self {
+        if let Some(max_wait_time) = max_wait_time {
+            self.set_value("job_max_wait_time", max_wait_time);
+        } else {
+            self.remove("job_max_wait_time");
+        }
+
+        self
+    }
+
+    fn get_job_temperature(&self) -> Option<(f64, f64)> {
+        self.get_value("job_temperature").cloned()
+    }
+
+    fn set_job_temperature(&mut self, temperature: Option<(f64, f64)>) -> &mut Self {
+        if let Some(temperature) = temperature {
+            self.set_value("job_temperature", temperature);
+        } else {
+            self.remove("job_temperature");
+        }
+
+        self
+    }
+
+    fn get_job_tier(&self) -> Option<&String> {
+        self.get_value("job_tier")
+    }
+
+    fn set_job_tier(&mut self, tier: Option<String>) -> &mut Self {
+        if let Some(tier) = tier {
+            self.set_value("job_tier", tier);
+        } else {
+            self.remove("job_tier");
+        }
+
+        self
+    }
+
+    fn get_job_customer_id(&self) -> Option<&String> {
+        self.get_value("job_customer_id")
+    }
+
+    fn set_job_customer_id(&mut self, customer_id: Option<String>) -> &mut Self {
+        if let Some(customer_id) = customer_id {
+            self.set_value("job_customer_id", customer_id);
+        } else {
+            self.remove("job_customer_id");
+        }
+
+        self
+    }
+
+    fn get_reload_min_load_factor(&self) -> Option<f64> {
+        self.get_value("reload_min_load").cloned()
+    }
+
+    fn set_reload_min_load_factor(&mut self, factor: Option<f64>) -> &mut Self {
+        if let Some(factor) = factor {
+            self.set_value("reload_min_load", factor);
+        } else {
+            self.remove("reload_min_load");
+        }
+
+        self
+    }
+
+    fn get_dispatch_stage(&self) -> Option<usize> {
+        self.get_value("dispatch_stage").cloned()
+    }
+
+    fn set_dispatch_stage(&mut self, stage: usize) -> &mut Self {
+        self.set_value("dispatch_stage", stage);
+        self
+    }
+
     fn get_job_type(&self) -> Option<&String> {
         self.get_value("job_type")
     }
@@ -236,6 +519,10 @@ pub trait BreakTie {
     fn get_break_policy(&self) -> Option<BreakPolicy>;
     /// Sets break policy.
     fn set_break_policy(&mut self, policy: BreakPolicy) -> &mut Self;
+    /// Gets break sequence.
+    fn get_break_sequence(&self) -> Option<&BreakSequence>;
+    /// Sets break sequence.
+    fn set_break_sequence(&mut self, sequence: BreakSequence) -> &mut Self;
 }
 
 impl BreakTie for Dimensions {
@@ -247,4 +534,13 @@ impl BreakTie for Dimensions {
         self.set_value("break_policy", policy);
         self
     }
+
+    fn get_break_sequence(&self) -> Option<&BreakSequence> {
+        self.get_value("break_sequence")
+    }
+
+    fn set_break_sequence(&mut self, sequence: BreakSequence) -> &mut Self {
+        self.set_value("break_sequence", sequence);
+        self
+    }
 }
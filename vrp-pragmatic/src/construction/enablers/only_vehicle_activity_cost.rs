@@ -1,12 +1,37 @@
+use crate::construction::enablers::VehicleTie;
 use crate::core::models::solution::Route;
-use vrp_core::models::common::{Cost, Timestamp};
-use vrp_core::models::problem::{ActivityCost, SimpleActivityCost};
+use vrp_core::models::common::{Cost, Duration, Timestamp};
+use vrp_core::models::problem::ActivityCost;
 use vrp_core::models::solution::Activity;
 
 /// Uses costs only for a vehicle ignoring costs of a driver.
 #[derive(Default)]
 pub struct OnlyVehicleActivityCost {
-    inner: SimpleActivityCost,
+    has_service_scale: bool,
+}
+
+impl OnlyVehicleActivityCost {
+    /// Creates a new instance of `OnlyVehicleActivityCost` which additionally scales service duration
+    /// of the jobs using vehicle's service scale.
+    pub fn new_with_service_scale() -> Self {
+        Self { has_service_scale: true }
+    }
+
+    fn get_service_duration(&self, route: &Route, activity: &Activity) -> Duration {
+        let duration = activity.place.duration;
+
+        if !self.has_service_scale {
+            return duration;
+        }
+
+        // NOTE conditional jobs (breaks, reloads, etc.) are bound to the vehicle and are not scaled
+        let is_plan_job = activity.job.as_ref().map_or(false, |single| single.dimens.get_vehicle_id().is_none());
+
+        match route.actor.vehicle.dimens.get_vehicle_service_scale() {
+            Some(scale) if is_plan_job => duration * scale,
+            _ => duration,
+        }
+    }
 }
 
 impl ActivityCost for OnlyVehicleActivityCost {
@@ -14,16 +39,16 @@ impl ActivityCost for OnlyVehicleActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
-        let service = activity.place.duration;
+        let service = self.get_service_duration(route, activity);
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
-        self.inner.estimate_departure(route, activity, arrival)
+        arrival.max(activity.place.time.start) + self.get_service_duration(route, activity)
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        self.inner.estimate_arrival(route, activity, departure)
+        activity.place.time.end.min(departure - self.get_service_duration(route, activity))
     }
 }
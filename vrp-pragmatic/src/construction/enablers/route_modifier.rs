@@ -1,5 +1,6 @@
-use crate::construction::enablers::VehicleTie;
+use crate::construction::enablers::{JobTie, VehicleTie};
 use crate::format::JobIndex;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::*;
 use vrp_core::models::GoalContext;
@@ -17,15 +18,25 @@ pub fn get_route_modifier(goal: Arc<GoalContext>, job_index: JobIndex) -> RouteM
             .map(|idx| format!("{vehicle_id}_dispatch_{shift_index}_{idx}"))
             .map(|job_id| job_index.get(&job_id))
             .take_while(|job| job.is_some())
+            .flatten()
             .collect::<Vec<_>>();
 
+        // NOTE dispatch places of the same stage are alternatives, stages are visited in ascending order
+        let stages = candidates.iter().fold(BTreeMap::<_, Vec<_>>::new(), |mut acc, job| {
+            let stage = job.dimens().get_dispatch_stage().unwrap_or(1);
+            acc.entry(stage).or_default().push(*job);
+            acc
+        });
+
         let leg_selection = LegSelection::Exhaustive;
         let result_selector = BestResultSelector::default();
 
-        let result = candidates
-            .iter()
-            .filter_map(|job| {
-                job.map(|job| {
+        let dispatch_route_ctx = stages.values().try_fold(None, |dispatch_route_ctx: Option<RouteContext>, jobs| {
+            let current_route_ctx = dispatch_route_ctx.as_ref().unwrap_or(&route_ctx);
+
+            let success = jobs
+                .iter()
+                .filter_map(|job| {
                     let eval_ctx = EvaluationContext {
                         goal: goal.as_ref(),
                         job,
@@ -33,38 +44,40 @@ pub fn get_route_modifier(goal: Arc<GoalContext>, job_index: JobIndex) -> RouteM
                         result_selector: &result_selector,
                     };
 
-                    eval_job_constraint_in_route(
+                    match eval_job_constraint_in_route(
                         &eval_ctx,
-                        &route_ctx,
+                        current_route_ctx,
                         InsertionPosition::Last,
                         InsertionCost::default(),
                         None,
-                    )
-                })
-                .and_then(|result| match result {
-                    InsertionResult::Success(success) => Some(success),
-                    _ => None,
+                    ) {
+                        InsertionResult::Success(success) => Some(success),
+                        _ => None,
+                    }
                 })
-            })
-            .min_by(|a, b| a.cost.cmp(&b.cost));
+                .min_by(|a, b| a.cost.cmp(&b.cost))?;
 
-        if let Some(success) = result {
-            let mut route_ctx = route_ctx.deep_copy();
-            let route = route_ctx.route_mut();
+            let mut next_route_ctx = current_route_ctx.deep_copy();
+            let route = next_route_ctx.route_mut();
             success.activities.into_iter().for_each(|(activity, index)| {
                 route.tour.insert_at(activity, index + 1);
             });
-            goal.accept_route_state(&mut route_ctx);
+            goal.accept_route_state(&mut next_route_ctx);
 
-            route_ctx
-        } else {
-            let mut route_ctx = route_ctx;
+            Some(Some(next_route_ctx))
+        });
 
-            if !candidates.is_empty() {
-                route_ctx.state_mut().set_flag(state_flags::UNASSIGNABLE);
-            }
+        match dispatch_route_ctx {
+            Some(Some(dispatch_route_ctx)) => dispatch_route_ctx,
+            _ => {
+                let mut route_ctx = route_ctx;
+
+                if !candidates.is_empty() {
+                    route_ctx.state_mut().set_flag(state_flags::UNASSIGNABLE);
+                }
 
-            route_ctx
+                route_ctx
+            }
         }
     })
 }
@@ -22,6 +22,16 @@ pub enum BreakPolicy {
     SkipIfArrivalBeforeEnd,
 }
 
+/// Specifies a position of the optional break among other breaks of the same shift and a minimum
+/// time between the end of the previous break and the start of the next one.
+#[derive(Clone)]
+pub struct BreakSequence {
+    /// An order of the break within the shift.
+    pub order: usize,
+    /// A minimum separation time between adjacent breaks.
+    pub separation: Duration,
+}
+
 /// Creates a feature to schedule an optional break. Here, optional means that break sometimes can
 /// be skipped due to constraint violations or suboptimal search path in solution space.
 pub fn create_optional_break_feature(name: &str, code: ViolationCode) -> Result<Feature, String> {
@@ -56,9 +66,16 @@ impl OptionalBreakConstraint {
             .and_then(|_| ConstraintViolation::fail(self.code))
     }
 
-    fn evaluate_activity(&self, activity_ctx: &ActivityContext) -> Option<ConstraintViolation> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
         match as_break_job(activity_ctx.target) {
             Some(_) if activity_ctx.prev.job.is_none() => ConstraintViolation::skip(self.code),
+            Some(break_single) if !can_keep_sequence(route_ctx, activity_ctx, break_single) => {
+                ConstraintViolation::skip(self.code)
+            }
             _ => None,
         }
     }
@@ -68,7 +85,7 @@ impl FeatureConstraint for OptionalBreakConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
-            MoveContext::Activity { activity_ctx, .. } => self.evaluate_activity(activity_ctx),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
         }
     }
 
@@ -185,8 +202,12 @@ fn remove_invalid_breaks(ctx: &mut SolutionContext) {
                                 || !can_be_scheduled(route_ctx, break_single);
                             let is_ovrp_last =
                                 route_ctx.route().tour.end().map_or(false, |end| std::ptr::eq(activity, end));
+                            let is_out_of_sequence = break_single
+                                .dimens
+                                .get_break_sequence()
+                                .map_or(false, |sequence| is_out_of_sequence(route_ctx, activity, sequence));
 
-                            if is_orphan || is_not_on_time || is_ovrp_last {
+                            if is_orphan || is_not_on_time || is_ovrp_last || is_out_of_sequence {
                                 // NOTE remove break with removed job location
                                 breaks.insert(Job::Single(activity.job.as_ref().unwrap().clone()));
                             }
@@ -251,3 +272,40 @@ fn is_on_proper_time(route_ctx: &RouteContext, break_job: &Arc<Single>, actual_s
 
     get_break_time_windows(break_job, departure).any(|tw| tw.intersects(&actual_tw))
 }
+
+/// Checks whether break inserted at given position keeps order and separation time with other breaks.
+fn can_keep_sequence(route_ctx: &RouteContext, activity_ctx: &ActivityContext, break_single: &Arc<Single>) -> bool {
+    let sequence = if let Some(sequence) = break_single.dimens.get_break_sequence() { sequence } else { return true };
+
+    // NOTE travel time to break's location is ignored, so service start is estimated optimistically:
+    // violations caused by that are fixed when solution state is accepted
+    let target = activity_ctx.target;
+    let start = activity_ctx.prev.schedule.departure.max(target.place.time.start);
+    let end = start + target.place.duration;
+
+    get_sequenced_breaks(route_ctx).all(|(idx, activity, other)| {
+        if other.order < sequence.order {
+            idx <= activity_ctx.index && activity.schedule.departure + sequence.separation <= start
+        } else {
+            idx > activity_ctx.index
+                && end + sequence.separation <= activity.schedule.departure - activity.place.duration
+        }
+    })
+}
+
+/// Checks whether break is scheduled before the previous one or too close to any break preceding it.
+fn is_out_of_sequence(route_ctx: &RouteContext, activity: &Activity, sequence: &BreakSequence) -> bool {
+    let start = activity.schedule.departure - activity.place.duration;
+
+    get_sequenced_breaks(route_ctx).take_while(|(_, other_activity, _)| !std::ptr::eq(*other_activity, activity)).any(
+        |(_, other_activity, other)| {
+            other.order > sequence.order || other_activity.schedule.departure + sequence.separation > start
+        },
+    )
+}
+
+fn get_sequenced_breaks(route_ctx: &RouteContext) -> impl Iterator<Item = (usize, &Activity, &BreakSequence)> + '_ {
+    route_ctx.route().tour.all_activities().enumerate().filter_map(|(idx, activity)| {
+        as_break_job(activity).and_then(|single| single.dimens.get_break_sequence()).map(|other| (idx, activity, other))
+    })
+}
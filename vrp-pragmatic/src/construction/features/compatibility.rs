@@ -1,4 +1,5 @@
-//! A compatibility feature provides the way to avoid assigning some jobs in the same tour.
+//! A compatibility feature provides the way to avoid assigning some jobs in the same tour or,
+//! in soft mode, to minimize their mixing.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/compatibility_test.rs"]
@@ -6,14 +7,37 @@ mod compatibility_test;
 
 use super::*;
 use crate::construction::enablers::JobTie;
+use vrp_core::models::solution::Activity;
+use vrp_core::rosomaxa::prelude::Objective;
 
-/// Creates a compatibility feature as hard constraint.
-pub fn create_compatibility_feature(name: &str, code: ViolationCode, state_key: StateKey) -> Result<Feature, String> {
-    FeatureBuilder::default()
-        .with_name(name)
-        .with_constraint(CompatibilityConstraint { code, state_key })
-        .with_state(CompatibilityState { state_key, keys: vec![state_key] })
-        .build()
+/// Specifies how compatibility of jobs is enforced.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompatibilityMode {
+    /// Jobs with different compatibility classes are never assigned to the same tour.
+    Hard,
+    /// Jobs with different compatibility classes can be assigned to the same tour, but each change
+    /// of compatibility class which is not separated by a cleaning stop is penalized.
+    Soft,
+}
+
+/// Creates a compatibility feature as hard constraint or as soft one, depending on `mode`.
+pub fn create_compatibility_feature(
+    name: &str,
+    code: ViolationCode,
+    state_key: StateKey,
+    mode: CompatibilityMode,
+) -> Result<Feature, String> {
+    let builder = FeatureBuilder::default().with_name(name);
+
+    match mode {
+        CompatibilityMode::Hard => builder
+            .with_constraint(CompatibilityConstraint { code, state_key })
+            .with_state(CompatibilityState { state_key, keys: vec![state_key] }),
+        CompatibilityMode::Soft => builder
+            .with_objective(SoftCompatibilityObjective { state_key })
+            .with_state(SoftCompatibilityState { state_key, keys: vec![state_key] }),
+    }
+    .build()
 }
 
 struct CompatibilityConstraint {
@@ -79,3 +103,135 @@ impl FeatureState for CompatibilityState {
 fn get_route_compatibility(route_ctx: &RouteContext) -> Option<String> {
     route_ctx.route().tour.jobs().filter_map(|job| job.dimens().get_job_compatibility().cloned()).next()
 }
+
+struct SoftCompatibilityObjective {
+    state_key: StateKey,
+}
+
+impl SoftCompatibilityObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let target = activity_ctx.target;
+        if get_compatibility_change(target).is_none() {
+            return Cost::default();
+        }
+
+        let prev_compat = route_ctx
+            .state()
+            .get_activity_state::<Option<String>>(self.state_key, activity_ctx.prev)
+            .cloned()
+            .flatten();
+        let target_compat = get_last_seen_compatibility(prev_compat.clone(), target);
+
+        // NOTE only the first following activity which changes compatibility is affected by insertion
+        let next_cost = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .find(|activity| get_compatibility_change(activity).is_some())
+            .map_or(Cost::default(), |next| {
+                count_mixing(target_compat.as_ref(), next) - count_mixing(prev_compat.as_ref(), next)
+            });
+
+        count_mixing(prev_compat.as_ref(), target) + next_cost
+    }
+}
+
+impl Objective for SoftCompatibilityObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .fold((None, Cost::default()), |(last_seen, total), activity| {
+                        let total = total + count_mixing(last_seen.as_ref(), activity);
+                        (get_last_seen_compatibility(last_seen, activity), total)
+                    })
+                    .1
+            })
+            .sum()
+    }
+}
+
+impl FeatureObjective for SoftCompatibilityObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct SoftCompatibilityState {
+    state_key: StateKey,
+    keys: Vec<StateKey>,
+}
+
+impl FeatureState for SoftCompatibilityState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let (route, state) = route_ctx.as_mut();
+
+        route.tour.all_activities().fold(None, |last_seen, activity| {
+            let last_seen = get_last_seen_compatibility(last_seen, activity);
+            state.put_activity_state::<Option<String>>(self.state_key, activity, last_seen.clone());
+
+            last_seen
+        });
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.keys.iter()
+    }
+}
+
+/// Specifies how activity changes the last seen compatibility within the tour.
+enum CompatibilityChange<'a> {
+    /// A cleaning stop resets compatibility.
+    Reset,
+    /// A job with compatibility class.
+    Set(&'a String),
+}
+
+fn get_compatibility_change(activity: &Activity) -> Option<CompatibilityChange<'_>> {
+    activity.job.as_ref().and_then(|single| {
+        if single.dimens.get_job_cleaning() {
+            Some(CompatibilityChange::Reset)
+        } else {
+            single.dimens.get_job_compatibility().map(CompatibilityChange::Set)
+        }
+    })
+}
+
+fn get_last_seen_compatibility(last_seen: Option<String>, activity: &Activity) -> Option<String> {
+    match get_compatibility_change(activity) {
+        Some(CompatibilityChange::Reset) => None,
+        Some(CompatibilityChange::Set(compat)) => Some(compat.clone()),
+        None => last_seen,
+    }
+}
+
+fn count_mixing(last_seen: Option<&String>, activity: &Activity) -> Cost {
+    match (last_seen, get_compatibility_change(activity)) {
+        (Some(last_seen), Some(CompatibilityChange::Set(compat))) if last_seen != compat => 1.,
+        _ => Cost::default(),
+    }
+}
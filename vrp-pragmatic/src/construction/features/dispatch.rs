@@ -100,11 +100,7 @@ impl FeatureState for DispatchState {
         // NOTE remove tour with dispatch only
         solution_ctx.keep_routes(&|route_ctx| {
             let tour = &route_ctx.route().tour;
-            if tour.job_count() == 1 {
-                !tour.jobs().next().unwrap().as_single().map_or(false, is_dispatch_single)
-            } else {
-                true
-            }
+            tour.job_count() == 0 || !tour.jobs().all(|job| is_dispatch_job(&job))
         });
     }
 
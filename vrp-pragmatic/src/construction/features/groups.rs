@@ -8,17 +8,20 @@ use hashbrown::HashSet;
 #[path = "../../../tests/unit/construction/features/groups_test.rs"]
 mod groups_test;
 
-/// Creates a job group feature as hard constraint.
+/// Creates a job group feature as hard constraint. When `all_or_nothing_code` is specified, a group
+/// is either fully assigned or not at all: once any of its jobs is unassigned, the rest of the group
+/// is removed from the tour and unassigned with the given code.
 pub fn create_group_feature(
     name: &str,
     total_jobs: usize,
     code: ViolationCode,
     state_key: StateKey,
+    all_or_nothing_code: Option<ViolationCode>,
 ) -> Result<Feature, String> {
     FeatureBuilder::default()
         .with_name(name)
         .with_constraint(GroupConstraint { total_jobs, code, state_key })
-        .with_state(GroupState { state_key, state_keys: vec![state_key] })
+        .with_state(GroupState { state_key, all_or_nothing_code, state_keys: vec![state_key] })
         .build()
 }
 
@@ -65,6 +68,7 @@ impl FeatureConstraint for GroupConstraint {
 
 struct GroupState {
     state_key: StateKey,
+    all_or_nothing_code: Option<ViolationCode>,
     state_keys: Vec<StateKey>,
 }
 
@@ -83,6 +87,10 @@ impl FeatureState for GroupState {
     fn accept_route_state(&self, _: &mut RouteContext) {}
 
     fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        if let Some(code) = self.all_or_nothing_code {
+            unassign_partial_groups(solution_ctx, code);
+        }
+
         solution_ctx.routes.iter_mut().for_each(|route_ctx| {
             let groups = get_groups(route_ctx);
             route_ctx.state_mut().put_route_state(self.state_key, groups);
@@ -97,3 +105,39 @@ impl FeatureState for GroupState {
 fn get_groups(route_ctx: &RouteContext) -> HashSet<String> {
     route_ctx.route().tour.jobs().filter_map(|job| job.dimens().get_job_group().cloned()).collect()
 }
+
+/// Removes jobs of the groups which are assigned partially. It is idempotent: once a group is fully
+/// unassigned, it is not considered anymore.
+fn unassign_partial_groups(solution_ctx: &mut SolutionContext, code: ViolationCode) {
+    // NOTE unassigned jobs which are still required can be assigned later
+    let partial_groups = solution_ctx
+        .unassigned
+        .keys()
+        .filter(|job| !solution_ctx.required.contains(job))
+        .filter_map(|job| job.dimens().get_job_group().cloned())
+        .collect::<HashSet<_>>();
+
+    if partial_groups.is_empty() {
+        return;
+    }
+
+    let jobs_to_remove = solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .filter(|job| !solution_ctx.locked.contains(job))
+        .filter(|job| job.dimens().get_job_group().map_or(false, |group| partial_groups.contains(group)))
+        .collect::<Vec<_>>();
+
+    jobs_to_remove.into_iter().for_each(|job| {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(&job)).for_each(
+            |route_ctx| {
+                route_ctx.route_mut().tour.remove(&job);
+            },
+        );
+
+        solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(code));
+    });
+
+    solution_ctx.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs());
+}
@@ -0,0 +1,58 @@
+//! A shift job types feature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_types_test.rs"]
+mod job_types_test;
+
+use super::*;
+use crate::construction::enablers::{JobTie, VehicleTie};
+
+/// Creates a feature which allows vehicle shift to serve only jobs of specific types.
+pub fn create_shift_job_types_feature(name: &str, code: ViolationCode) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(ShiftJobTypesConstraint { code }).build()
+}
+
+struct ShiftJobTypesConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for ShiftJobTypesConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let job_types = route_ctx.route().actor.vehicle.dimens.get_shift_job_types()?;
+
+                // NOTE conditional jobs (e.g. break, dispatch, reload) are bound to vehicle and not restricted
+                let is_allowed = get_singles(job)
+                    .filter(|single| single.dimens.get_vehicle_id().is_none())
+                    .all(|single| single.dimens.get_job_type().map_or(false, |job_type| job_types.contains(job_type)));
+
+                if is_allowed {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let source_types = get_singles(&source).filter_map(|single| single.dimens.get_job_type()).collect::<Vec<_>>();
+        let has_same_types = get_singles(&candidate)
+            .all(|single| single.dimens.get_job_type().map_or(false, |job_type| source_types.contains(&job_type)));
+
+        if has_same_types {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
+
+fn get_singles(job: &Job) -> Box<dyn Iterator<Item = &Arc<Single>> + '_> {
+    match job {
+        Job::Single(single) => Box::new(std::iter::once(single)),
+        Job::Multi(multi) => Box::new(multi.jobs.iter()),
+    }
+}
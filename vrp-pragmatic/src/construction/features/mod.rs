@@ -17,6 +17,10 @@ pub const TOUR_ORDER_KEY: i32 = 1002;
 pub const RELOAD_RESOURCE_KEY: i32 = 1003;
 /// A key which tracks tour compactness state.
 pub const TOUR_COMPACTNESS_KEY: i32 = 1004;
+/// A key which tracks tour overlap state.
+pub const TOUR_OVERLAP_KEY: i32 = 1005;
+/// A key which tracks visit order state of multi-visit jobs.
+pub const VISIT_ORDER_KEY: i32 = 1006;
 
 mod breaks;
 pub use self::breaks::*;
@@ -30,6 +34,9 @@ pub use self::dispatch::*;
 pub mod groups;
 pub use self::groups::*;
 
+pub mod job_types;
+pub use self::job_types::*;
+
 pub mod reachable;
 pub use self::reachable::*;
 
@@ -38,3 +45,6 @@ pub use self::reloads::*;
 
 pub mod skills;
 pub use self::skills::*;
+
+pub mod temperature;
+pub use self::temperature::*;
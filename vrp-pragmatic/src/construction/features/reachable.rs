@@ -3,6 +3,10 @@
 use super::*;
 use vrp_core::models::problem::{TransportCost, TravelTime};
 
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/reachable_test.rs"]
+mod reachable_test;
+
 /// Creates a feature to check reachability of the jobs. It is a hard constraint.
 pub fn create_reachable_feature(
     name: &str,
@@ -12,6 +16,21 @@ pub fn create_reachable_feature(
     FeatureBuilder::default().with_name(name).with_constraint(ReachableConstraint { transport, code }).build()
 }
 
+/// Checks whether `to` location is reachable from `from` location for given profile. It uses the
+/// same rule as reachable constraint: negative distance marks unreachable location.
+pub fn is_reachable(
+    transport: &(dyn TransportCost + Send + Sync),
+    profile: &Profile,
+    from: Location,
+    to: Location,
+) -> bool {
+    is_reachable_distance(transport.distance_approx(profile, from, to))
+}
+
+fn is_reachable_distance(distance: Distance) -> bool {
+    distance >= 0.
+}
+
 struct ReachableConstraint {
     transport: Arc<dyn TransportCost + Send + Sync>,
     code: ViolationCode,
@@ -33,7 +52,7 @@ impl FeatureConstraint for ReachableConstraint {
                     TravelTime::Departure(prev.schedule.departure),
                 );
 
-                if prev_to_target < 0. {
+                if !is_reachable_distance(prev_to_target) {
                     return ConstraintViolation::skip(self.code);
                 }
 
@@ -44,7 +63,7 @@ impl FeatureConstraint for ReachableConstraint {
                         next.place.location,
                         TravelTime::Departure(target.schedule.departure),
                     );
-                    if target_to_next < 0. {
+                    if !is_reachable_distance(target_to_next) {
                         return ConstraintViolation::skip(self.code);
                     }
                 }
@@ -10,7 +10,7 @@ use crate::construction::enablers::{JobTie, VehicleTie};
 use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::marker::PhantomData;
-use std::ops::Range;
+use std::ops::{Mul, Range};
 use vrp_core::construction::enablers::MultiTrip;
 use vrp_core::construction::features::*;
 use vrp_core::models::problem::Single;
@@ -33,6 +33,7 @@ pub fn create_shared_reload_multi_trip_feature<T>(
     total_jobs: usize,
     constraint_code: ViolationCode,
     resource_key: StateKey,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 ) -> Result<Feature, String>
 where
     T: SharedResource + LoadOps,
@@ -42,6 +43,7 @@ where
 
     let multi_trip = create_reload_multi_trip(
         load_schedule_threshold_fn,
+        transport,
         Some(Box::new(move |route_ctx, activity, demand| {
             route_ctx
                 .state()
@@ -59,14 +61,43 @@ pub fn create_simple_reload_multi_trip_feature<T: LoadOps>(
     name: &str,
     capacity_feature_factory: CapacityFeatureFactoryFn<T>,
     load_schedule_threshold_fn: LoadScheduleThresholdFn<T>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 ) -> Result<Feature, String> {
-    let multi_trip = create_reload_multi_trip(load_schedule_threshold_fn, None);
+    let multi_trip = create_reload_multi_trip(load_schedule_threshold_fn, transport, None);
 
     (capacity_feature_factory)(name, Arc::new(multi_trip))
 }
 
+/// Creates a feature which allows to visit reload only when the preceding interval has used at
+/// least the fraction of vehicle capacity specified on reload job.
+pub fn create_reload_min_load_feature<T>(name: &str, code: ViolationCode) -> Result<Feature, String>
+where
+    T: LoadOps + Mul<f64, Output = T>,
+{
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ReloadMinLoadConstraint::<T> { code, phantom: Default::default() })
+        .build()
+}
+
+/// Creates a feature which restricts vehicle's waiting time by its max idle time. Longer waiting is
+/// allowed only after the vehicle has visited a reload place or at the beginning of its tour, so
+/// reloads are used to avoid long idle time at job places.
+pub fn create_reload_idle_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ReloadIdleConstraint { code, transport, activity })
+        .build()
+}
+
 fn create_reload_multi_trip<T: LoadOps>(
     load_schedule_threshold_fn: LoadScheduleThresholdFn<T>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
     place_capacity_threshold: Option<PlaceCapacityThresholdFn<T>>,
 ) -> FixedMultiTrip<T> {
     FixedMultiTrip {
@@ -86,6 +117,7 @@ fn create_reload_multi_trip<T: LoadOps>(
                     current.partial_cmp(&threshold_capacity) != Some(Ordering::Less)
                 })
                 .unwrap_or(false)
+                || has_idle_limit(route_ctx)
         }),
         is_obsolete_interval_fn: Box::new(move |route_ctx, left, right| {
             let capacity: T = route_ctx.route().actor.vehicle.dimens.get_capacity().cloned().unwrap_or_default();
@@ -121,6 +153,7 @@ fn create_reload_multi_trip<T: LoadOps>(
                 capacity.can_fit(&new_max_load_left) && capacity.can_fit(&new_max_load_right);
 
             has_enough_vehicle_capacity
+                && !is_idle_reload(route_ctx, transport.as_ref(), right.start)
                 && place_capacity_threshold.as_ref().map_or(true, |place_capacity_threshold| {
                     // total static delivery at left
                     let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0);
@@ -150,7 +183,7 @@ where
         total_jobs,
         constraint_code,
         resource_key,
-        Arc::new(move |route_ctx| route_ctx.state().get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)),
+        Arc::new(move |route_ctx| route_ctx.marker_intervals(RELOAD_INTERVALS_KEY)),
         Arc::new(move |activity| {
             activity.job.as_ref().and_then(|job| {
                 if is_reload_single(job.as_ref()) {
@@ -164,6 +197,88 @@ where
     )
 }
 
+struct ReloadMinLoadConstraint<T: LoadOps> {
+    code: ViolationCode,
+    phantom: PhantomData<T>,
+}
+
+impl<T> FeatureConstraint for ReloadMinLoadConstraint<T>
+where
+    T: LoadOps + Mul<f64, Output = T>,
+{
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let min_load_factor = activity_ctx
+                    .target
+                    .job
+                    .as_ref()
+                    .filter(|single| is_reload_single(single))
+                    .and_then(|single| single.dimens.get_reload_min_load_factor())?;
+                let capacity: &T = route_ctx.route().actor.vehicle.dimens.get_capacity()?;
+
+                // NOTE reload can be inserted at any position: max past capacity of the previous activity
+                //      is the max load from the start of its interval up to the insertion point, which
+                //      becomes the preceding interval once the reload is inserted
+                let interval_load: T = route_ctx
+                    .state()
+                    .get_activity_state(MAX_PAST_CAPACITY_KEY, activity_ctx.prev)
+                    .cloned()
+                    .unwrap_or_default();
+
+                if interval_load.partial_cmp(&(*capacity * min_load_factor)) == Some(Ordering::Less) {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct ReloadIdleConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl FeatureConstraint for ReloadIdleConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let max_idle_time = get_max_idle_time(route_ctx)?;
+                let route = route_ctx.route();
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+                let arrival = get_arrival(self.transport.as_ref(), route, prev, target, prev.schedule.departure);
+                let is_target_idle = !is_idle_allowed_after(prev) && target.place.time.start - arrival > max_idle_time;
+
+                let is_next_idle = activity_ctx.next.filter(|_| !is_idle_allowed_after(target)).map_or(false, |next| {
+                    let departure = self.activity.estimate_departure(route, target, arrival);
+                    next.place.time.start - get_arrival(self.transport.as_ref(), route, target, next, departure)
+                        > max_idle_time
+                });
+
+                if is_target_idle || is_next_idle {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
 /// Specifies obsolete interval function which takes left and right interval range. These
 /// intervals are separated by marker job activity.
 type ObsoleteIntervalFn = dyn Fn(&RouteContext, Range<usize>, Range<usize>) -> bool + Send + Sync;
@@ -275,6 +390,44 @@ impl<T: Send + Sync> FixedMultiTrip<T> {
     }
 }
 
+fn get_max_idle_time(route_ctx: &RouteContext) -> Option<Duration> {
+    route_ctx.route().actor.vehicle.dimens.get_max_idle_time()
+}
+
+/// Checks whether vehicle with max idle time has jobs, so reloads can be used to avoid long idle time.
+fn has_idle_limit(route_ctx: &RouteContext) -> bool {
+    get_max_idle_time(route_ctx).is_some() && route_ctx.route().tour.has_jobs()
+}
+
+/// Checks whether vehicle is allowed to wait longer than max idle time after given activity:
+/// it is the beginning of the tour or a reload place.
+fn is_idle_allowed_after(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(true, |single| is_reload_single(single))
+}
+
+/// Checks whether reload at given index is used to avoid long idle time.
+fn is_idle_reload(route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync), reload_idx: usize) -> bool {
+    let route = route_ctx.route();
+
+    get_max_idle_time(route_ctx)
+        .zip(reload_idx.checked_sub(1).and_then(|idx| route.tour.get(idx)))
+        .zip(route.tour.get(reload_idx + 1))
+        .map_or(false, |((max_idle_time, prev), next)| {
+            next.place.time.start - get_arrival(transport, route, prev, next, prev.schedule.departure) > max_idle_time
+        })
+}
+
+/// Returns arrival time at the target activity when vehicle departs from the previous one at given time.
+fn get_arrival(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    prev: &Activity,
+    target: &Activity,
+    departure: Timestamp,
+) -> Timestamp {
+    departure + transport.duration(route, prev.place.location, target.place.location, TravelTime::Departure(departure))
+}
+
 fn is_reload_single(single: &Single) -> bool {
     single.dimens.get_job_type().map_or(false, |t| t == "reload")
 }
@@ -6,7 +6,10 @@ mod skills_test;
 
 use super::*;
 use crate::construction::enablers::{JobTie, VehicleTie};
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
+use vrp_core::construction::enablers::estimate_service_starts;
+use vrp_core::models::solution::Activity;
+use vrp_core::rosomaxa::prelude::Objective;
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -16,15 +19,58 @@ pub struct JobSkills {
     pub one_of: Option<HashSet<String>>,
     /// Vehicle should have none of these skills defined.
     pub none_of: Option<HashSet<String>>,
+    /// Vehicle is preferred to have all of these skills defined.
+    pub preferred: Option<HashSet<String>>,
 }
 
-/// Creates a skills feature as hard constraint.
-pub fn create_skills_feature(name: &str, code: ViolationCode) -> Result<Feature, String> {
-    FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code }).build()
+/// Creates a skills feature as hard constraint. When vehicle skills have validity time windows,
+/// required skills are checked against the scheduled service time of job activities.
+pub fn create_skills_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code, transport, activity }).build()
+}
+
+/// Creates a preferred skills feature as soft constraint: a penalty is applied for each job served
+/// by a vehicle which doesn't have all of job's preferred skills.
+pub fn create_preferred_skills_feature(name: &str, penalty: Cost) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(PreferredSkillsObjective { penalty }).build()
 }
 
 struct SkillsConstraint {
     code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl SkillsConstraint {
+    /// Checks whether skills of the target activity and activities shifted by its insertion are valid
+    /// at their service time.
+    fn has_valid_schedule(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        validity: &HashMap<String, Vec<TimeWindow>>,
+    ) -> bool {
+        let route = route_ctx.route();
+        let vehicle_skills = route.actor.vehicle.dimens.get_vehicle_skills();
+        let is_valid_at = |activity: &Activity, service_start: Timestamp| {
+            activity.retrieve_job().map_or(true, |job| {
+                job.dimens()
+                    .get_job_skills()
+                    .map_or(true, |job_skills| has_valid_skills(job_skills, &vehicle_skills, validity, service_start))
+            })
+        };
+
+        let (service_start, shifted) =
+            estimate_service_starts(route, activity_ctx, self.activity.as_ref(), self.transport.as_ref());
+
+        is_valid_at(activity_ctx.target, service_start)
+            && shifted.into_iter().all(|(activity, service_start)| is_valid_at(activity, service_start))
+    }
 }
 
 impl FeatureConstraint for SkillsConstraint {
@@ -43,7 +89,15 @@ impl FeatureConstraint for SkillsConstraint {
 
                 None
             }
-            MoveContext::Activity { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let validity = route_ctx.route().actor.vehicle.dimens.get_vehicle_skill_validity()?;
+
+                if self.has_valid_schedule(route_ctx, activity_ctx, validity) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
         }
     }
 
@@ -78,6 +132,51 @@ impl FeatureConstraint for SkillsConstraint {
     }
 }
 
+struct PreferredSkillsObjective {
+    penalty: Cost,
+}
+
+impl PreferredSkillsObjective {
+    fn estimate_job(&self, route_ctx: &RouteContext, job: &Job) -> Cost {
+        let preferred = job.dimens().get_job_skills().and_then(|job_skills| job_skills.preferred.as_ref());
+        let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
+
+        let has_preferred = match (preferred, vehicle_skills) {
+            (Some(preferred), Some(vehicle_skills)) => preferred.is_subset(vehicle_skills),
+            (Some(preferred), None) => preferred.is_empty(),
+            (None, _) => true,
+        };
+
+        if has_preferred {
+            Cost::default()
+        } else {
+            self.penalty
+        }
+    }
+}
+
+impl Objective for PreferredSkillsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs().map(move |job| self.estimate_job(route_ctx, &job)))
+            .sum()
+    }
+}
+
+impl FeatureObjective for PreferredSkillsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.estimate_job(route_ctx, job),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
 fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
     match (job_skills.all_of.as_ref(), vehicle_skills) {
         (Some(job_skills), Some(vehicle_skills)) => job_skills.is_subset(vehicle_skills),
@@ -102,3 +201,25 @@ fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String
         _ => true,
     }
 }
+
+/// Checks whether required vehicle skills are valid at given time. Skills without validity time windows
+/// are considered as always valid.
+fn has_valid_skills(
+    job_skills: &JobSkills,
+    vehicle_skills: &Option<&HashSet<String>>,
+    validity: &HashMap<String, Vec<TimeWindow>>,
+    time: Timestamp,
+) -> bool {
+    let is_valid = |skill: &String| validity.get(skill).map_or(true, |times| times.iter().any(|tw| tw.contains(time)));
+
+    let is_all_of_valid = job_skills.all_of.as_ref().map_or(true, |skills| skills.iter().all(is_valid));
+    let is_one_of_valid = job_skills.one_of.as_ref().map_or(true, |skills| {
+        skills.is_empty()
+            || skills
+                .iter()
+                .filter(|skill| vehicle_skills.map_or(false, |v_skills| v_skills.contains(*skill)))
+                .any(is_valid)
+    });
+
+    is_all_of_valid && is_one_of_valid
+}
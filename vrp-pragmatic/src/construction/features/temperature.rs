@@ -0,0 +1,53 @@
+//! A temperature compatibility feature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/temperature_test.rs"]
+mod temperature_test;
+
+use super::*;
+use crate::construction::enablers::{JobTie, VehicleTie};
+
+/// Creates a feature which allows to serve a job with temperature range (e.g. frozen or chilled goods)
+/// only by vehicle which can keep the overlapping temperature range.
+pub fn create_temperature_feature(name: &str, code: ViolationCode) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(TemperatureConstraint { code }).build()
+}
+
+struct TemperatureConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for TemperatureConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let job_temperature = job.dimens().get_job_temperature()?;
+                let vehicle_temperature = route_ctx.route().actor.vehicle.dimens.get_vehicle_temperature();
+
+                if vehicle_temperature
+                    .map_or(false, |vehicle_temperature| is_overlapping(job_temperature, vehicle_temperature))
+                {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let source_temperature = source.dimens().get_job_temperature();
+        let candidate_temperature = candidate.dimens().get_job_temperature();
+
+        if source_temperature == candidate_temperature {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
+
+fn is_overlapping(left: (f64, f64), right: (f64, f64)) -> bool {
+    left.0 <= right.1 && right.0 <= left.1
+}
@@ -153,6 +153,21 @@ const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 15;
+const COST_LIMIT_CONSTRAINT_CODE: i32 = 16;
+const OPEN_JOBS_LIMIT_CONSTRAINT_CODE: i32 = 17;
+const JOB_TYPE_CONSTRAINT_CODE: i32 = 18;
+const MAX_DETOUR_CONSTRAINT_CODE: i32 = 19;
+const LIFO_CONSTRAINT_CODE: i32 = 20;
+const VISIT_ORDER_CONSTRAINT_CODE: i32 = 21;
+const LATE_ACTIVITIES_LIMIT_CONSTRAINT_CODE: i32 = 22;
+const MAX_WAIT_CONSTRAINT_CODE: i32 = 23;
+const TEMPERATURE_CONSTRAINT_CODE: i32 = 24;
+const CORDON_CONSTRAINT_CODE: i32 = 25;
+const SHIFT_SPAN_LIMIT_CONSTRAINT_CODE: i32 = 26;
+const STRICT_GROUP_CONSTRAINT_CODE: i32 = 27;
+const INTERRUPTION_LIMIT_CONSTRAINT_CODE: i32 = 28;
+const FLEET_COST_BUDGET_CONSTRAINT_CODE: i32 = 29;
+const MAX_IDLE_CONSTRAINT_CODE: i32 = 30;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -0,0 +1,138 @@
+//! Provides the way to read and write routing matrix in a compact binary format.
+//!
+//! The format is the following (all numbers are little-endian):
+//! * header: magic bytes `VRPM`, format version as `u32` and flags as `u32` (first bit marks error codes)
+//! * profile and timestamp: each as `u32` byte length followed by utf-8 bytes, `u32::MAX` length means absent value
+//! * amount of matrix entries as `u64`
+//! * travel times, distances and, optionally, error codes as arrays of `i64`
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use super::Matrix;
+use crate::format::{FormatError, MultiFormatError};
+use std::io::{BufRead, BufReader, BufWriter, Error, ErrorKind, Read, Write};
+
+const MAGIC: &[u8; 4] = b"VRPM";
+const VERSION: u32 = 1;
+const HAS_ERROR_CODES: u32 = 1;
+const NO_VALUE: u32 = u32::MAX;
+
+/// Checks whether the reader contains a routing matrix in binary format without consuming it.
+pub fn is_binary_matrix<R: Read>(reader: &mut BufReader<R>) -> bool {
+    reader.fill_buf().map_or(false, |buffer| buffer.starts_with(MAGIC))
+}
+
+/// Deserializes routing matrix in binary format from `BufReader`.
+pub fn deserialize_binary_matrix<R: Read>(mut reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+    read_matrix(&mut reader).map_err(|err| {
+        vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check input binary matrix: '{err}'"),
+        )]
+        .into()
+    })
+}
+
+/// Serializes routing matrix in binary format into a writer.
+pub fn serialize_binary_matrix<W: Write>(matrix: &Matrix, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    let size = matrix.travel_times.len();
+    if matrix.distances.len() != size || matrix.error_codes.as_ref().map_or(false, |codes| codes.len() != size) {
+        return Err(Error::new(ErrorKind::InvalidInput, "matrix arrays have different sizes"));
+    }
+
+    let flags = if matrix.error_codes.is_some() { HAS_ERROR_CODES } else { 0 };
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&flags.to_le_bytes())?;
+    write_string(writer, matrix.profile.as_ref())?;
+    write_string(writer, matrix.timestamp.as_ref())?;
+    writer.write_all(&(size as u64).to_le_bytes())?;
+
+    matrix
+        .travel_times
+        .iter()
+        .chain(matrix.distances.iter())
+        .chain(matrix.error_codes.iter().flatten())
+        .try_for_each(|value| writer.write_all(&value.to_le_bytes()))?;
+
+    writer.flush()
+}
+
+fn read_matrix<R: Read>(reader: &mut R) -> Result<Matrix, Error> {
+    let mut magic = [0_u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(Error::new(ErrorKind::InvalidData, "unknown format"));
+    }
+
+    let version = read_u32(reader)?;
+    if version != VERSION {
+        return Err(Error::new(ErrorKind::InvalidData, format!("unsupported version: {version}")));
+    }
+
+    let flags = read_u32(reader)?;
+    let profile = read_string(reader)?;
+    let timestamp = read_string(reader)?;
+
+    let mut buffer = [0_u8; 8];
+    reader.read_exact(&mut buffer)?;
+    let size = u64::from_le_bytes(buffer);
+
+    let travel_times = read_values(reader, size)?;
+    let distances = read_values(reader, size)?;
+    let error_codes = if flags & HAS_ERROR_CODES != 0 { Some(read_values(reader, size)?) } else { None };
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes })
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, Error> {
+    let mut buffer = [0_u8; 4];
+    reader.read_exact(&mut buffer)?;
+
+    Ok(u32::from_le_bytes(buffer))
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<Option<String>, Error> {
+    let length = read_u32(reader)?;
+    if length == NO_VALUE {
+        return Ok(None);
+    }
+
+    let buffer = read_bytes(reader, length as u64)?;
+
+    String::from_utf8(buffer).map(Some).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+}
+
+fn read_values<R: Read>(reader: &mut R, size: u64) -> Result<Vec<i64>, Error> {
+    let length = size.checked_mul(8).ok_or_else(|| Error::new(ErrorKind::InvalidData, "matrix size is too big"))?;
+    let buffer = read_bytes(reader, length)?;
+
+    Ok(buffer.chunks_exact(8).map(|chunk| i64::from_le_bytes(chunk.try_into().expect("chunk size is 8"))).collect())
+}
+
+/// Reads exactly `length` bytes. The length comes from untrusted input, so the buffer grows with
+/// the data actually read instead of being allocated upfront.
+fn read_bytes<R: Read>(reader: &mut R, length: u64) -> Result<Vec<u8>, Error> {
+    let mut buffer = Vec::new();
+    reader.take(length).read_to_end(&mut buffer)?;
+
+    if buffer.len() as u64 != length {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "unexpected end of binary matrix"));
+    }
+
+    Ok(buffer)
+}
+
+fn write_string<W: Write>(writer: &mut W, value: Option<&String>) -> Result<(), Error> {
+    match value {
+        Some(value) => {
+            writer.write_all(&(value.len() as u32).to_le_bytes())?;
+            writer.write_all(value.as_bytes())
+        }
+        None => writer.write_all(&NO_VALUE.to_le_bytes()),
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::construction::enablers::JobTie;
 use crate::format::problem::fleet_reader::get_profile_index_map;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::*;
 use vrp_core::models::common::Profile;
@@ -33,6 +33,21 @@ pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
                 filtering: get_filter_policy(filtering.as_ref()),
                 building: get_builder_policy(),
             })),
+            Clustering::Duplicates { profile } => Ok(Some(ClusterConfig {
+                profile: get_profile(api_problem, profile)?,
+                // NOTE duplicates share the same location, so there is no movement between them
+                threshold: ThresholdPolicy {
+                    moving_duration: f64::EPSILON,
+                    moving_distance: f64::EPSILON,
+                    min_shared_time: None,
+                    smallest_time_window: None,
+                    max_jobs_per_cluster: None,
+                },
+                visiting: VisitPolicy::ClosedContinuation,
+                serving: ServingPolicy::Original { parking: 0. },
+                filtering: get_duplicates_filter_policy(api_problem)?,
+                building: get_builder_policy(),
+            })),
         }
     } else {
         Ok(None)
@@ -84,8 +99,49 @@ fn get_filter_policy(filtering: Option<&VicinityFilteringPolicy>) -> FilterPolic
                 job.dimens().get_job_id().map_or(true, |job_id| !excluded_job_ids.contains(job_id))
             }),
             actor_filter: Arc::new(|_| true),
+            pair_filter: Arc::new(|_, _| true),
         }
     } else {
-        FilterPolicy { job_filter: Arc::new(|_| true), actor_filter: Arc::new(|_| true) }
+        FilterPolicy {
+            job_filter: Arc::new(|_| true),
+            actor_filter: Arc::new(|_| true),
+            pair_filter: Arc::new(|_, _| true),
+        }
     }
 }
+
+/// Creates filter policy which allows to cluster only jobs with identical definition except their ids.
+fn get_duplicates_filter_policy(api_problem: &ApiProblem) -> Result<FilterPolicy, String> {
+    let job_keys = api_problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| {
+            let key = serde_json::to_string(&Job { id: String::default(), ..job.clone() })
+                .map_err(|err| format!("cannot get job key: {err}"))?;
+
+            Ok((job.id.clone(), key))
+        })
+        .collect::<Result<HashMap<_, _>, String>>()?;
+
+    let duplicates = job_keys.values().fold(HashMap::<String, usize>::new(), |mut acc, key| {
+        *acc.entry(key.clone()).or_insert(0) += 1;
+        acc
+    });
+    let duplicate_ids = job_keys
+        .iter()
+        .filter(|(_, key)| duplicates.get(*key).map_or(false, |count| *count > 1))
+        .map(|(job_id, _)| job_id.clone())
+        .collect::<HashSet<_>>();
+
+    Ok(FilterPolicy {
+        job_filter: Arc::new(move |job| {
+            job.dimens().get_job_id().map_or(false, |job_id| duplicate_ids.contains(job_id))
+        }),
+        actor_filter: Arc::new(|_| true),
+        pair_filter: Arc::new(move |center, candidate| {
+            let get_key = |job: &CoreJob| job.dimens().get_job_id().and_then(|job_id| job_keys.get(job_id));
+            get_key(center).map_or(false, |key| Some(key) == get_key(candidate))
+        }),
+    })
+}
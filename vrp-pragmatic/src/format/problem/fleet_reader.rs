@@ -6,7 +6,7 @@ use super::*;
 use crate::construction::enablers::{create_typed_actor_groups, VehicleTie};
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
@@ -50,7 +50,7 @@ pub(crate) fn create_transport_costs(
             (profile, matrix.timestamp.clone(), matrix)
         })
         .map(|(profile, timestamp, matrix)| {
-            let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
+            let (mut durations, mut distances) = if let Some(error_codes) = &matrix.error_codes {
                 let capacity = matrix.distances.len();
 
                 let mut durations: Vec<Duration> = Vec::with_capacity(capacity);
@@ -72,6 +72,11 @@ pub(crate) fn create_transport_costs(
                 )
             };
 
+            if let Some(min_travel) = get_matrix_min_travel(api_problem, &matrix_profiles, profile) {
+                apply_min_travel(&mut durations, matrix.error_codes.as_ref(), min_travel.duration as f64);
+                apply_min_travel(&mut distances, matrix.error_codes.as_ref(), min_travel.distance as f64);
+            }
+
             MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
         })
         .collect::<Vec<_>>();
@@ -84,6 +89,32 @@ pub(crate) fn create_transport_costs(
     create_matrix_transport_cost(matrix_data)
 }
 
+/// Returns min travel values defined on the profile with given index.
+fn get_matrix_min_travel<'a>(
+    api_problem: &'a ApiProblem,
+    matrix_profiles: &HashMap<String, usize>,
+    profile_index: usize,
+) -> Option<&'a MatrixMinTravel> {
+    api_problem
+        .fleet
+        .profiles
+        .iter()
+        .find(|profile| matrix_profiles.get(&profile.name) == Some(&profile_index))
+        .and_then(|profile| profile.min_travel.as_ref())
+}
+
+/// Replaces non-positive values between different locations with min value.
+fn apply_min_travel(values: &mut [f64], error_codes: Option<&Vec<i64>>, min_value: f64) {
+    let size = (values.len() as f64).sqrt().round() as usize;
+
+    values.iter_mut().enumerate().filter(|(idx, _)| idx / size != idx % size).for_each(|(idx, value)| {
+        let is_error = error_codes.and_then(|codes| codes.get(idx)).map_or(false, |code| *code > 0);
+        if !is_error && *value < min_value {
+            *value = min_value;
+        }
+    });
+}
+
 pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
@@ -100,7 +131,14 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
+        let distance_tiers = vehicle
+            .costs
+            .distance_tiers
+            .as_ref()
+            .map(|tiers| DistanceCostTiers::new(tiers.iter().map(|tier| (tier.threshold, tier.rate)).collect()));
+
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let max_idle_time = vehicle.limits.as_ref().and_then(|l| l.max_idle_time);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -135,10 +173,42 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     .set_shift_index(shift_index)
                     .set_vehicle_id(vehicle_id.clone());
 
+                if let Some(distance_tiers) = distance_tiers.as_ref() {
+                    dimens.set_distance_cost_tiers(distance_tiers.clone());
+                }
+
+                if let Some(stop_cost) = vehicle.costs.stop {
+                    dimens.set_stop_cost(stop_cost);
+                }
+
                 if let Some(tour_size) = tour_size {
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(max_idle_time) = max_idle_time {
+                    dimens.set_max_idle_time(max_idle_time);
+                }
+
+                if let Some(job_types) = shift.job_types.as_ref() {
+                    dimens.set_shift_job_types(job_types.iter().cloned().collect());
+                }
+
+                if let Some(overtime_penalty) = shift.overtime_penalty {
+                    dimens.set_shift_overtime_penalty(overtime_penalty);
+                }
+
+                if let Some(lifo) = vehicle.lifo {
+                    dimens.set_vehicle_lifo(lifo);
+                }
+
+                if let Some(temperature) = vehicle.temperature.as_ref() {
+                    dimens.set_vehicle_temperature((temperature.min, temperature.max));
+                }
+
+                if let Some(service_scale) = vehicle.service_scale {
+                    dimens.set_vehicle_service_scale(service_scale);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
@@ -149,6 +219,20 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(skill_validity) = vehicle.skill_validity.as_ref() {
+                    dimens.set_vehicle_skill_validity(
+                        skill_validity
+                            .iter()
+                            .map(|validity| {
+                                (
+                                    validity.skill.clone(),
+                                    validity.times.iter().map(|tw| parse_time_window(tw)).collect(),
+                                )
+                            })
+                            .collect::<HashMap<_, _>>(),
+                    );
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
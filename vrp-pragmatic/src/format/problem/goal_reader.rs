@@ -2,16 +2,18 @@ use super::*;
 use crate::construction::enablers::{JobTie, VehicleTie};
 use crate::construction::features::*;
 use hashbrown::HashSet;
+use std::ops::Mul;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
 use vrp_core::construction::features::*;
 use vrp_core::models::common::{LoadOps, MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{ActivityCost, Actor, Jobs, Single, TransportCost};
-use vrp_core::models::{Feature, GoalContext, Lock};
+use vrp_core::models::problem::{ActivityCost, Actor, Jobs, Multi, Single, TransportCost};
+use vrp_core::models::{Feature, FeatureBuilder, GoalContext, Lock};
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn create_goal_context(
     api_problem: &ApiProblem,
     job_index: &JobIndex,
+    coord_index: Arc<CoordIndex>,
     jobs: Arc<Jobs>,
     fleet: Arc<CoreFleet>,
     transport: Arc<dyn TransportCost + Send + Sync>,
@@ -23,8 +25,14 @@ pub(crate) fn create_goal_context(
 
     // TODO what's about performance implications on order of features when they are evaluated?
 
-    let objective_features =
-        get_objective_features(api_problem, props, jobs.clone(), transport.clone(), activity.clone())?;
+    let objective_features = get_objective_features(
+        api_problem,
+        props,
+        coord_index.clone(),
+        jobs.clone(),
+        transport.clone(),
+        activity.clone(),
+    )?;
     let (global_objective_map, local_objective_map) = extract_feature_map(objective_features.as_slice())?;
     features.extend(objective_features.into_iter().flat_map(|features| features.into_iter()));
 
@@ -32,12 +40,51 @@ pub(crate) fn create_goal_context(
         features.push(create_reachable_feature("reachable", transport.clone(), REACHABLE_CONSTRAINT_CODE)?)
     }
 
-    features.push(get_capacity_feature("capacity", api_problem, jobs.as_ref(), job_index, props)?);
+    features.push(get_capacity_feature(
+        "capacity",
+        api_problem,
+        jobs.as_ref(),
+        job_index,
+        fleet.as_ref(),
+        transport.clone(),
+        props,
+    )?);
 
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature("tour_limit", api_problem, transport.clone())?)
     }
 
+    if props.has_tour_cost_limits {
+        features.push(get_tour_cost_limit_feature("tour_cost_limit", api_problem, transport.clone())?)
+    }
+
+    if let Some(budget) = get_fleet_cost_budget(api_problem) {
+        features.push(create_fleet_cost_budget_feature(
+            "fleet_cost_budget",
+            transport.clone(),
+            budget,
+            FLEET_COST_BUDGET_CONSTRAINT_CODE,
+        )?)
+    }
+
+    if props.has_detour_limits {
+        features.push(create_detour_limit_feature(
+            "detour_limit",
+            transport.clone(),
+            Arc::new(|single| single.dimens.get_job_max_detour_time()),
+            MAX_DETOUR_CONSTRAINT_CODE,
+        )?)
+    }
+
+    if props.has_wait_limits {
+        features.push(create_wait_limit_feature(
+            "wait_limit",
+            transport.clone(),
+            Arc::new(|single| single.dimens.get_job_max_wait_time()),
+            MAX_WAIT_CONSTRAINT_CODE,
+        )?)
+    }
+
     if props.has_breaks {
         features.push(create_optional_break_feature("break", BREAK_CONSTRAINT_CODE)?)
     }
@@ -46,16 +93,39 @@ pub(crate) fn create_goal_context(
         features.push(create_tour_order_hard_feature("tour_order", TOUR_ORDER_CONSTRAINT_CODE, get_tour_order_fn())?)
     }
 
-    if props.has_compatibility {
-        features.push(create_compatibility_feature("compatibility", COMPATIBILITY_CONSTRAINT_CODE, COMPATIBILITY_KEY)?);
+    if props.has_compatibility
+        && !global_objective_map.iter().flat_map(|o| o.iter()).any(|name| *name == "compatibility")
+    {
+        features.push(create_compatibility_feature(
+            "compatibility",
+            COMPATIBILITY_CONSTRAINT_CODE,
+            COMPATIBILITY_KEY,
+            CompatibilityMode::Hard,
+        )?);
     }
 
     if props.has_group {
-        features.push(create_group_feature("group", jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)?);
+        let all_or_nothing_code =
+            api_problem.plan.strict_groups.unwrap_or(false).then_some(STRICT_GROUP_CONSTRAINT_CODE);
+        features.push(create_group_feature(
+            "group",
+            jobs.size(),
+            GROUP_CONSTRAINT_CODE,
+            GROUP_KEY,
+            all_or_nothing_code,
+        )?);
     }
 
     if props.has_skills {
-        features.push(create_skills_feature("skills", SKILL_CONSTRAINT_CODE)?)
+        features.push(create_skills_feature("skills", SKILL_CONSTRAINT_CODE, transport.clone(), activity.clone())?)
+    }
+
+    if props.has_shift_job_types {
+        features.push(create_shift_job_types_feature("shift_job_types", JOB_TYPE_CONSTRAINT_CODE)?)
+    }
+
+    if props.has_temperature {
+        features.push(create_temperature_feature("temperature", TEMPERATURE_CONSTRAINT_CODE)?)
     }
 
     if props.has_dispatch {
@@ -74,12 +144,67 @@ pub(crate) fn create_goal_context(
         )?);
     }
 
+    if props.has_open_jobs_limits {
+        features.push(get_open_jobs_limit_feature("open_jobs_limit", api_problem)?);
+    }
+
+    if props.has_late_activities_limits {
+        features.push(get_late_activities_limit_feature(
+            "late_activities_limit",
+            api_problem,
+            transport.clone(),
+            activity.clone(),
+        )?);
+    }
+
+    if props.has_shift_span_limits {
+        features.push(get_shift_span_limit_feature(
+            "shift_span_limit",
+            api_problem,
+            transport.clone(),
+            activity.clone(),
+        )?);
+    }
+
+    if props.has_interruption_limits {
+        features.push(get_interruption_limit_feature("interruption_limit", api_problem)?);
+    }
+
+    if props.has_lifo {
+        features.push(create_lifo_multi_jobs_feature(
+            "lifo",
+            LIFO_CONSTRAINT_CODE,
+            Arc::new(|actor| actor.vehicle.dimens.get_vehicle_lifo().unwrap_or(false)),
+        )?);
+    }
+
+    if props.has_visits {
+        features.push(get_visit_order_feature(
+            "visit_order",
+            api_problem,
+            job_index,
+            transport.clone(),
+            activity.clone(),
+        )?);
+    }
+
+    if props.has_cordon {
+        features.push(get_cordon_feature("cordon", api_problem, coord_index.as_ref())?);
+    }
+
+    let has_max_idle_time =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_idle_time.is_some()));
+    if props.has_reloads && has_max_idle_time {
+        features.push(create_reload_idle_feature("reload_idle", MAX_IDLE_CONSTRAINT_CODE, transport, activity)?);
+    }
+
     GoalContext::new(features.as_slice(), global_objective_map.as_slice(), local_objective_map.as_slice())
 }
 
 fn get_objective_features(
     api_problem: &ApiProblem,
     props: &ProblemProperties,
+    coord_index: Arc<CoordIndex>,
     jobs: Arc<Jobs>,
     transport: Arc<dyn TransportCost + Send + Sync>,
     activity: Arc<dyn ActivityCost + Send + Sync>,
@@ -93,8 +218,12 @@ fn get_objective_features(
             vec![Objective::MinimizeCost],
         ];
 
+        if props.has_overtime {
+            objectives.insert(1, vec![Objective::MinimizeOvertime])
+        }
+
         if props.has_value {
-            objectives.insert(0, vec![Objective::MaximizeValue { breaks: None }])
+            objectives.insert(0, vec![Objective::MaximizeValue { breaks: None, budget: None, priority_base: None }])
         }
 
         objectives
@@ -124,12 +253,33 @@ fn get_objective_features(
                         activity.clone(),
                         TIME_CONSTRAINT_CODE,
                     ),
+                    Objective::MaximizeEarnings { job_pay } => create_maximize_earnings_feature(
+                        "max_earnings",
+                        transport.clone(),
+                        activity.clone(),
+                        Arc::new({
+                            let job_pay = *job_pay;
+                            move |job| match job.dimens().get_job_type().map(|job_type| job_type.as_str()) {
+                                Some("break") | Some("reload") | Some("dispatch") => 0.,
+                                _ => job_pay,
+                            }
+                        }),
+                        TIME_CONSTRAINT_CODE,
+                    ),
                     Objective::MinimizeTours => create_minimize_tours_feature("min_tours"),
                     Objective::MaximizeTours => create_maximize_tours_feature("max_tours"),
-                    Objective::MaximizeValue { breaks } => create_maximize_total_job_value_feature(
+                    Objective::MinimizeVehicleTypes => create_minimize_vehicle_types_feature(
+                        "min_vehicle_types",
+                        Arc::new(|actor| actor.vehicle.dimens.get_vehicle_type()),
+                    ),
+                    Objective::MinimizeToursDeviation { target } => {
+                        create_minimize_tours_deviation_feature("tours_deviation", *target)
+                    }
+                    Objective::MaximizeValue { breaks, priority_base, .. } => create_maximize_total_job_value_feature(
                         "max_value",
                         JobReadValueFn::Left(Arc::new({
                             let break_value = *breaks;
+                            let priority_value_fn = get_priority_value_fn(api_problem, *priority_base);
                             move |job| {
                                 job.dimens().get_job_value().unwrap_or_else(|| {
                                     job.dimens()
@@ -137,7 +287,7 @@ fn get_objective_features(
                                         .zip(break_value)
                                         .filter(|(job_type, _)| *job_type == "break")
                                         .map(|(_, break_value)| break_value)
-                                        .unwrap_or(0.)
+                                        .unwrap_or_else(|| (priority_value_fn)(job))
                                 })
                             }
                         })),
@@ -172,12 +322,18 @@ fn get_objective_features(
                             }
                         }),
                     ),
+                    Objective::MinimizeDetour => create_minimize_total_detour_feature("min_detour", transport.clone()),
+                    Objective::MinimizeOvertime => create_minimize_overtime_feature(
+                        "min_overtime",
+                        Arc::new(|actor| actor.vehicle.dimens.get_shift_overtime_penalty()),
+                    ),
                     Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
                     Objective::BalanceMaxLoad { options } => {
                         if props.has_multi_dimen_capacity {
                             create_max_load_balanced_feature::<MultiDimLoad>(
                                 "max_load_balance",
                                 get_threshold(options),
+                                get_tolerance(options),
                                 Arc::new(|loaded, capacity| {
                                     let mut max_ratio = 0_f64;
 
@@ -193,18 +349,31 @@ fn get_objective_features(
                             create_max_load_balanced_feature::<SingleDimLoad>(
                                 "max_load_balance",
                                 get_threshold(options),
+                                get_tolerance(options),
                                 Arc::new(|loaded, capacity| loaded.value as f64 / capacity.value as f64),
                             )
                         }
                     }
-                    Objective::BalanceActivities { options } => {
-                        create_activity_balanced_feature("activity_balance", get_threshold(options))
-                    }
-                    Objective::BalanceDistance { options } => {
-                        create_distance_balanced_feature("distance_balance", get_threshold(options))
+                    Objective::BalanceActivities { options } => create_activity_balanced_feature(
+                        "activity_balance",
+                        get_threshold(options),
+                        get_tolerance(options),
+                    ),
+                    Objective::BalanceStops { options } => {
+                        create_stop_balanced_feature("stop_balance", get_threshold(options), get_tolerance(options))
                     }
-                    Objective::BalanceDuration { options } => {
-                        create_duration_balanced_feature("duration_balance", get_threshold(options))
+                    Objective::BalanceDistance { options } => create_distance_balanced_feature(
+                        "distance_balance",
+                        get_threshold(options),
+                        get_tolerance(options),
+                    ),
+                    Objective::BalanceDuration { options } => create_duration_balanced_feature(
+                        "duration_balance",
+                        get_threshold(options),
+                        get_tolerance(options),
+                    ),
+                    Objective::BalanceCost { options } => {
+                        create_cost_balanced_feature("cost_balance", get_threshold(options), get_tolerance(options))
                     }
                     Objective::CompactTour { options } => {
                         let thresholds = Some((options.threshold, options.distance));
@@ -219,6 +388,80 @@ fn get_objective_features(
                     Objective::TourOrder => {
                         create_tour_order_soft_feature("tour_order", TOUR_ORDER_KEY, get_tour_order_fn())
                     }
+                    Objective::MinimizeIncompatibility => create_compatibility_feature(
+                        "compatibility",
+                        COMPATIBILITY_CONSTRAINT_CODE,
+                        COMPATIBILITY_KEY,
+                        CompatibilityMode::Soft,
+                    ),
+                    Objective::MinimizeTargetDeviation { earliness, lateness } => create_target_time_feature(
+                        "target_time",
+                        transport.clone(),
+                        activity.clone(),
+                        Arc::new(|single| single.dimens.get_job_target_time()),
+                        earliness.unwrap_or(1.),
+                        lateness.unwrap_or(1.),
+                    ),
+                    Objective::MinimizeMaxLateness => create_minimize_max_lateness_feature(
+                        "max_lateness",
+                        transport.clone(),
+                        activity.clone(),
+                        Arc::new(|single| single.dimens.get_job_target_time()),
+                    ),
+                    Objective::MinimizeWeightedLateness => create_minimize_value_weighted_lateness_feature(
+                        "weighted_lateness",
+                        transport.clone(),
+                        activity.clone(),
+                        Arc::new(|single| single.dimens.get_job_target_time()),
+                        Arc::new(|single| {
+                            // NOTE value of multi job is kept on its root
+                            Multi::roots(single)
+                                .and_then(|multi| multi.dimens.get_job_value())
+                                .or_else(|| single.dimens.get_job_value())
+                                .unwrap_or_default()
+                        }),
+                    ),
+                    Objective::PreferredSkills { penalty } => {
+                        create_preferred_skills_feature("preferred_skills", penalty.unwrap_or(1.))
+                    }
+                    Objective::DepartureSpread { options } => {
+                        create_departure_spread_feature("departure_spread", get_departure_spread(options))
+                    }
+                    Objective::MinimizeCustomerGaps { penalty } => create_customer_contiguity_feature(
+                        "customer_gaps",
+                        Arc::new(|single| single.dimens.get_job_customer_id()),
+                        penalty.unwrap_or(1.),
+                    ),
+                    Objective::MinimizeOverlap => create_tour_overlap_feature(
+                        "min_overlap",
+                        get_location_coord_fn(coord_index.clone()),
+                        TOUR_OVERLAP_KEY,
+                    ),
+                    Objective::MinimizeUniqueStops => create_minimize_unique_stops_feature("unique_stops"),
+                    Objective::DeliverDownLoad { penalty } => {
+                        if props.has_multi_dimen_capacity {
+                            create_deliver_down_load_feature::<MultiDimLoad>("deliver_down", penalty.unwrap_or(1.))
+                        } else {
+                            create_deliver_down_load_feature::<SingleDimLoad>("deliver_down", penalty.unwrap_or(1.))
+                        }
+                    }
+                    Objective::ServiceTiers { tiers } => {
+                        let tiers = tiers
+                            .iter()
+                            .map(|tier| {
+                                let penalty = tier.penalty.unwrap_or(1.);
+                                let earliness = tier.earliness.unwrap_or(0.);
+                                (tier.name.clone(), ServiceTier { penalty, earliness })
+                            })
+                            .collect::<HashMap<_, _>>();
+
+                        create_service_tier_feature(
+                            "service_tiers",
+                            transport.clone(),
+                            activity.clone(),
+                            Arc::new(move |dimens| dimens.get_job_tier().and_then(|tier| tiers.get(tier)).cloned()),
+                        )
+                    }
                 })
                 .collect()
         })
@@ -230,6 +473,8 @@ fn get_capacity_feature(
     api_problem: &ApiProblem,
     jobs: &Jobs,
     job_index: &JobIndex,
+    fleet: &CoreFleet,
+    transport: Arc<dyn TransportCost + Send + Sync>,
     props: &ProblemProperties,
 ) -> Result<Feature, String> {
     if props.has_reloads {
@@ -243,6 +488,7 @@ fn get_capacity_feature(
                 job_index,
                 MultiDimLoad::new,
                 Box::new(move |capacity| *capacity * threshold),
+                transport,
             )
         } else {
             get_capacity_with_reload_feature::<SingleDimLoad>(
@@ -252,30 +498,32 @@ fn get_capacity_feature(
                 job_index,
                 |capacity| SingleDimLoad::new(capacity.first().cloned().unwrap_or_default()),
                 Box::new(move |capacity| *capacity * threshold),
+                transport,
             )
         }
     } else if props.has_multi_dimen_capacity {
-        create_capacity_limit_feature::<MultiDimLoad>(name, CAPACITY_CONSTRAINT_CODE)
+        create_capacity_limit_with_fleet_feature::<MultiDimLoad>(name, CAPACITY_CONSTRAINT_CODE, fleet)
     } else {
-        create_capacity_limit_feature::<SingleDimLoad>(name, CAPACITY_CONSTRAINT_CODE)
+        create_capacity_limit_with_fleet_feature::<SingleDimLoad>(name, CAPACITY_CONSTRAINT_CODE, fleet)
     }
 }
 
-fn get_capacity_with_reload_feature<T: LoadOps + SharedResource>(
+fn get_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<f64, Output = T>>(
     name: &str,
     api_problem: &ApiProblem,
     jobs: &Jobs,
     job_index: &JobIndex,
     capacity_map: fn(Vec<i32>) -> T,
     load_schedule_threshold_fn: LoadScheduleThresholdFn<T>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 ) -> Result<Feature, String> {
     let reload_resources = get_reload_resources(api_problem, job_index, capacity_map);
     let capacity_feature_factory: CapacityFeatureFactoryFn<T> = Box::new(|name, multi_trip| {
         create_capacity_limit_with_multi_trip_feature(name, CAPACITY_CONSTRAINT_CODE, multi_trip)
     });
 
-    if reload_resources.is_empty() {
-        create_simple_reload_multi_trip_feature(name, capacity_feature_factory, load_schedule_threshold_fn)
+    let capacity_feature = if reload_resources.is_empty() {
+        create_simple_reload_multi_trip_feature(name, capacity_feature_factory, load_schedule_threshold_fn, transport)
     } else {
         create_shared_reload_multi_trip_feature(
             name,
@@ -285,7 +533,23 @@ fn get_capacity_with_reload_feature<T: LoadOps + SharedResource>(
             jobs.size(),
             RELOAD_RESOURCE_CONSTRAINT_CODE,
             RELOAD_RESOURCE_KEY,
+            transport,
         )
+    }?;
+
+    let has_reload_min_load = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| shift.reloads.iter().flatten())
+        .any(|reload| reload.min_load_factor.is_some());
+
+    if has_reload_min_load {
+        let min_load_feature = create_reload_min_load_feature::<T>(name, CAPACITY_CONSTRAINT_CODE)?;
+        FeatureBuilder::combine(name, &[capacity_feature, min_load_feature])
+    } else {
+        Ok(capacity_feature)
     }
 }
 
@@ -327,6 +591,220 @@ fn get_tour_limit_feature(
     )
 }
 
+fn get_open_jobs_limit_feature(name: &str, api_problem: &ApiProblem) -> Result<Feature, String> {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.max_open_jobs)
+                .map(|limit| (vehicle.type_id.clone(), limit))
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_open_multi_jobs_limit_feature(
+        name,
+        OPEN_JOBS_LIMIT_CONSTRAINT_CODE,
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limits.get(v_type)).cloned()
+        }),
+    )
+}
+
+fn get_late_activities_limit_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.max_late_activities)
+                .map(|limit| (vehicle.type_id.clone(), limit))
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_max_late_activities_feature(
+        name,
+        LATE_ACTIVITIES_LIMIT_CONSTRAINT_CODE,
+        transport,
+        activity,
+        Arc::new(|single| single.dimens.get_job_target_time()),
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limits.get(v_type)).cloned()
+        }),
+    )
+}
+
+/// Maps departure spread options to core model.
+pub(crate) fn get_departure_spread(options: &DepartureSpreadOptions) -> DepartureSpread {
+    match options {
+        DepartureSpreadOptions::Stagger { interval } => DepartureSpread::Stagger { interval: *interval },
+        DepartureSpreadOptions::Band => DepartureSpread::Band,
+    }
+}
+
+fn get_shift_span_limit_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.max_shift_span)
+                .map(|limit| (vehicle.type_id.clone(), limit))
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_shift_span_limit_feature(
+        name,
+        SHIFT_SPAN_LIMIT_CONSTRAINT_CODE,
+        transport,
+        activity,
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limits.get(v_type)).cloned()
+        }),
+    )
+}
+
+fn get_interruption_limit_feature(name: &str, api_problem: &ApiProblem) -> Result<Feature, String> {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.max_interruptions)
+                .map(|limit| (vehicle.type_id.clone(), limit))
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_interruption_limit_feature(
+        name,
+        INTERRUPTION_LIMIT_CONSTRAINT_CODE,
+        Arc::new(|single| {
+            single.dimens.get_job_type().map_or(false, |job_type| job_type == "break" || job_type == "reload")
+        }),
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limits.get(v_type)).cloned()
+        }),
+    )
+}
+
+fn get_tour_cost_limit_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) -> Result<Feature, String> {
+    let costs = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.max_cost).map(|cost| (vehicle.type_id.clone(), cost))
+        })
+        .collect::<HashMap<_, _>>();
+
+    create_route_cost_limit_feature(
+        name,
+        transport,
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| costs.get(v_type)).cloned()
+        }),
+        COST_LIMIT_CONSTRAINT_CODE,
+    )
+}
+
+fn get_fleet_cost_budget(api_problem: &ApiProblem) -> Option<f64> {
+    api_problem.objectives.iter().flatten().flatten().find_map(|objective| match objective {
+        Objective::MaximizeValue { budget, .. } => *budget,
+        _ => None,
+    })
+}
+
+fn get_priority_value_fn(api_problem: &ApiProblem, priority_base: Option<f64>) -> SimpleValueFn {
+    let base = priority_base.unwrap_or(10.);
+    let max_tier = api_problem.plan.jobs.iter().filter_map(|job| job.priority).max().unwrap_or(1);
+
+    create_priority_tier_value_fn(
+        Arc::new(|job| job.dimens().get_job_priority()),
+        Arc::new(move |tier| base.powi(max_tier.saturating_sub(tier) as i32)),
+    )
+}
+
+/// Creates a feature which keeps order of multi-visit job's visits, potentially served by different vehicles.
+fn get_cordon_feature(name: &str, api_problem: &ApiProblem, coord_index: &CoordIndex) -> Result<Feature, String> {
+    let cordon = api_problem.plan.cordon.as_ref().ok_or_else(|| "cordon is not specified".to_string())?;
+
+    // NOTE locations which are not used by jobs or vehicles are not indexed, so they can be ignored
+    let locations =
+        cordon.locations.iter().filter_map(|location| coord_index.get_by_loc(location)).collect::<HashSet<_>>();
+    let max_crossings = cordon.max_crossings;
+
+    create_cordon_crossing_limit_feature(
+        name,
+        CORDON_CONSTRAINT_CODE,
+        Arc::new(move |location| locations.contains(&location)),
+        Arc::new(move |_| Some(max_crossings)),
+    )
+}
+
+fn get_visit_order_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    job_index: &JobIndex,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    let visits = api_problem.plan.jobs.iter().filter_map(|job| job.visit.as_ref().map(|visit| (visit, job))).fold(
+        HashMap::<_, Vec<_>>::default(),
+        |mut acc, (visit, job)| {
+            acc.entry(visit.id.as_str()).or_default().push((visit.order, job.id.as_str()));
+            acc
+        },
+    );
+
+    let pairs = visits
+        .into_values()
+        .flat_map(|mut visits| {
+            visits.sort_by_key(|(order, _)| *order);
+            visits.windows(2).map(|pair| (pair[0].1, pair[1].1)).collect::<Vec<_>>()
+        })
+        .map(|(first, second)| {
+            let get_job =
+                |job_id: &str| job_index.get(job_id).cloned().ok_or_else(|| format!("cannot find job: '{job_id}'"));
+            Ok((get_job(first)?, get_job(second)?))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    create_cross_route_precedence_feature(
+        name,
+        pairs.as_slice(),
+        transport,
+        activity,
+        VISIT_ORDER_CONSTRAINT_CODE,
+        VISIT_ORDER_KEY,
+    )
+}
+
 fn get_reload_resources<T>(
     api_problem: &ApiProblem,
     job_index: &JobIndex,
@@ -426,6 +904,17 @@ fn get_threshold(options: &Option<BalanceOptions>) -> Option<f64> {
     options.as_ref().and_then(|o| o.threshold)
 }
 
+fn get_tolerance(options: &Option<BalanceOptions>) -> Option<f64> {
+    options.as_ref().and_then(|o| o.tolerance)
+}
+
+fn get_location_coord_fn(coord_index: Arc<CoordIndex>) -> LocationCoordFn {
+    Arc::new(move |location| match coord_index.get_by_idx(location) {
+        Some(Location::Coordinate { lat, lng }) => Some((lng, lat)),
+        _ => None,
+    })
+}
+
 fn get_tour_order_fn() -> TourOrderFn {
     TourOrderFn::Left(Arc::new(|single| {
         single
@@ -1,5 +1,9 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/job_reader_test.rs"]
+mod job_reader_test;
+
 use crate::construction::enablers::{BreakTie, JobTie, VehicleTie};
-use crate::construction::features::{BreakPolicy, JobSkills as FeatureJobSkills};
+use crate::construction::features::{BreakPolicy, BreakSequence, JobSkills as FeatureJobSkills};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
@@ -124,10 +128,14 @@ fn read_required_jobs(
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .map(|p| (Some(p.location.clone()), p.duration, parse_place_times(p), p.tag.clone()))
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+        single.dimens.set_job_target_time(task.target_time.as_ref().map(|time| parse_time(time)));
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -184,7 +192,15 @@ fn read_conditional_jobs(
             }
 
             if let Some(breaks) = &shift.breaks {
-                read_optional_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
+                read_optional_breaks(
+                    coord_index,
+                    job_index,
+                    &mut jobs,
+                    vehicle,
+                    shift_index,
+                    breaks,
+                    shift.break_separation,
+                );
             }
 
             if let Some(reloads) = &shift.reloads {
@@ -203,6 +219,7 @@ fn read_optional_breaks(
     vehicle: &VehicleType,
     shift_index: usize,
     breaks: &[VehicleBreak],
+    break_separation: Option<f64>,
 ) {
     (1..)
         .zip(breaks.iter().filter_map(|vehicle_break| match vehicle_break {
@@ -245,6 +262,10 @@ fn read_optional_breaks(
                         job.dimens.set_break_policy(policy);
                     }
 
+                    if let Some(separation) = break_separation {
+                        job.dimens.set_break_sequence(BreakSequence { order: break_idx, separation });
+                    }
+
                     (job_id, job)
                 })
                 .collect::<Vec<_>>()
@@ -287,8 +308,9 @@ fn read_dispatch(
             .for_each(|(place, vehicle_id)| {
                 let job_id = format!("{}_dispatch_{}_{}", vehicle_id, shift_index, dispatch_idx + 1);
 
-                let job =
+                let mut job =
                     get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "dispatch", shift_index, vec![place]);
+                job.dimens.set_dispatch_stage(dispatch.stage.unwrap_or(1));
 
                 add_conditional_job(job_index, jobs, job_id, job);
             });
@@ -313,7 +335,7 @@ fn read_reloads(
                     let job_id = format!("{vehicle_id}_reload_{shift_index}_{reload_idx}");
                     let times = parse_times(&place.times);
 
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -321,6 +343,7 @@ fn read_reloads(
                         shift_index,
                         vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
                     );
+                    job.dimens.set_reload_min_load_factor(place.min_load_factor);
 
                     (job_id, job)
                 })
@@ -408,8 +431,15 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .dimens
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
+        .set_job_priority(job.priority)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_cleaning(job.cleaning)
+        .set_job_customer_id(job.customer_id.clone())
+        .set_job_max_detour_time(job.max_detour_time)
+        .set_job_max_wait_time(job.max_wait_time)
+        .set_job_temperature(get_temperature(&job.temperature))
+        .set_job_tier(job.tier.clone())
         .set_job_skills(get_skills(&job.skills));
 
     Job::Single(Arc::new(single))
@@ -425,11 +455,29 @@ fn get_multi_job(
     dimens
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
+        .set_job_priority(job.priority)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_temperature(get_temperature(&job.temperature))
+        .set_job_tier(job.tier.clone())
         .set_job_skills(get_skills(&job.skills));
 
-    let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
+    // NOTE customer id, tier, compatibility, max detour and wait times are used on activity level,
+    // so they are propagated to each sub job
+    let singles = singles
+        .into_iter()
+        .map(|mut single| {
+            single
+                .dimens
+                .set_job_compatibility(job.compatibility.clone())
+                .set_job_cleaning(job.cleaning)
+                .set_job_customer_id(job.customer_id.clone())
+                .set_job_max_detour_time(job.max_detour_time)
+                .set_job_max_wait_time(job.max_wait_time)
+                .set_job_tier(job.tier.clone());
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
 
     let multi = if singles.len() == 2 && deliveries_start_index == 1 {
         Multi::new_shared(singles, dimens)
@@ -462,6 +510,7 @@ fn get_skills(skills: &Option<ApiJobSkills>) -> Option<FeatureJobSkills> {
         all_of: skills.all_of.as_ref().map(|all_of| all_of.iter().cloned().collect()),
         one_of: skills.one_of.as_ref().map(|any_of| any_of.iter().cloned().collect()),
         none_of: skills.none_of.as_ref().map(|none_of| none_of.iter().cloned().collect()),
+        preferred: skills.preferred.as_ref().map(|preferred| preferred.iter().cloned().collect()),
     })
 }
 
@@ -474,3 +523,54 @@ fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+fn parse_place_times(place: &JobPlace) -> Vec<TimeSpan> {
+    let blackouts = place
+        .blackouts
+        .as_ref()
+        .map(|blackouts| blackouts.iter().map(|tw| parse_time_window(tw)).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    if blackouts.is_empty() {
+        return parse_times(&place.times);
+    }
+
+    place
+        .times
+        .as_ref()
+        .map_or(vec![TimeWindow::max()], |tws| tws.iter().map(|tw| parse_time_window(tw)).collect())
+        .into_iter()
+        .flat_map(|tw| exclude_blackouts(tw, blackouts.as_slice(), place.duration))
+        .map(TimeSpan::Window)
+        .collect()
+}
+
+/// Splits time window into parts which allow to serve activity of given duration outside of blackout periods.
+fn exclude_blackouts(tw: TimeWindow, blackouts: &[TimeWindow], duration: Duration) -> Vec<TimeWindow> {
+    // NOTE service started at the end of time window lasts till `tw.end + duration`
+    let mut blackouts = blackouts
+        .iter()
+        .filter(|blackout| blackout.start < tw.end + duration && blackout.end >= tw.start)
+        .collect::<Vec<_>>();
+    blackouts.sort_by(|a, b| compare_floats(a.start, b.start));
+
+    let (mut parts, start) = blackouts.into_iter().fold((Vec::default(), tw.start), |(mut parts, start), blackout| {
+        // NOTE service has to be finished before blackout starts
+        let end = (blackout.start - duration).min(tw.end);
+        if start <= end {
+            parts.push(TimeWindow::new(start, end));
+        }
+
+        (parts, start.max(blackout.end))
+    });
+
+    if start <= tw.end {
+        parts.push(TimeWindow::new(start, tw.end));
+    }
+
+    parts
+}
+
+fn get_temperature(temperature: &Option<TemperatureRange>) -> Option<(f64, f64)> {
+    temperature.as_ref().map(|temperature| (temperature.min, temperature.max))
+}
@@ -12,6 +12,9 @@ pub(crate) type ApiProblem = Problem;
 mod model;
 pub use self::model::*;
 
+mod binary_matrix;
+pub use self::binary_matrix::*;
+
 #[cfg(test)]
 #[path = "../../../tests/unit/format/problem/reader_test.rs"]
 mod reader_test;
@@ -114,6 +117,19 @@ pub(crate) struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_tour_cost_limits: bool,
+    has_open_jobs_limits: bool,
+    has_late_activities_limits: bool,
+    has_shift_span_limits: bool,
+    has_interruption_limits: bool,
+    has_shift_job_types: bool,
+    has_overtime: bool,
+    has_detour_limits: bool,
+    has_wait_limits: bool,
+    has_lifo: bool,
+    has_visits: bool,
+    has_temperature: bool,
+    has_cordon: bool,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
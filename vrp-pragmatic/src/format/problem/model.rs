@@ -4,6 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
+use super::{deserialize_binary_matrix, is_binary_matrix};
 use crate::format::{FormatError, Location, MultiFormatError};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -51,6 +52,9 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle is preferred to have all of these skills defined. Used by `preferred-skills` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<Vec<String>>,
 }
 
 /// Specifies a place for sub job.
@@ -63,6 +67,10 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// A list of blackout periods with time specified in RFC3339 format. Job cannot be served
+    /// during blackout period even if it is inside of the place time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blackouts: Option<Vec<Vec<String>>>,
     /// A tag which will be propagated back within corresponding activity in solution.
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,6 +79,7 @@ pub struct JobPlace {
 
 /// Specifies a job task.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobTask {
     /// A list of possible places where given task can be performed.
     pub places: Vec<JobPlace>,
@@ -80,6 +89,10 @@ pub struct JobTask {
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
+    /// A preferred service start time in RFC3339 format. Unlike time windows, it is soft: deviation
+    /// from it is penalized by `minimize-target-deviation` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_time: Option<String>,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -115,6 +128,10 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 
+    /// Job priority tier, lower tier - higher priority, e.g. 1 is the highest. Used by `maximize-value` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<usize>,
+
     /// Job group: jobs of the same group are assigned to the same tour or unassigned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
@@ -122,6 +139,60 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A customer id: activities of jobs with the same customer id are kept together within the tour
+    /// by `minimize-customer-gaps` objective.
+    #[serde(rename = "customerId", skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+
+    /// A max detour time: a maximum route duration increase caused by insertion of job activity.
+    #[serde(rename = "maxDetourTime", skip_serializing_if = "Option::is_none")]
+    pub max_detour_time: Option<f64>,
+
+    /// A max wait time: a maximum time vehicle can wait at job activity location till its time window opens.
+    #[serde(rename = "maxWaitTime", skip_serializing_if = "Option::is_none")]
+    pub max_wait_time: Option<f64>,
+
+    /// An area (region) of the job. Used to decompose large problem into smaller ones solved separately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+
+    /// A visit of multi-visit job: visits are served in specified order, potentially by different vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visit: Option<JobVisit>,
+
+    /// A temperature range in which job's goods have to be kept, e.g. frozen or chilled goods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<TemperatureRange>,
+
+    /// A service level tier of the job, e.g. gold, silver or bronze. Used by `service-tiers` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+
+    /// Marks the job as a cleaning stop: it resets compatibility, so jobs of another compatibility class
+    /// can follow it without penalty. Used by `minimize-incompatibility` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleaning: Option<bool>,
+}
+
+/// Specifies a visit of multi-visit job, e.g. a complex install which requires differently skilled crews.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobVisit {
+    /// An id of multi-visit job: jobs with the same id are its visits.
+    pub id: String,
+
+    /// An order of the visit: visit can start only when the visit with the previous order is completed.
+    pub order: i32,
+}
+
+/// Specifies a temperature range.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TemperatureRange {
+    /// A min temperature.
+    pub min: f64,
+
+    /// A max temperature.
+    pub max: f64,
 }
 
 // region Clustering
@@ -145,6 +216,14 @@ pub enum Clustering {
         /// Specifies filtering policy.
         filtering: Option<VicinityFilteringPolicy>,
     },
+
+    /// Duplicates clustering: jobs which differ only by id are merged into one job with summed demand.
+    /// Merged jobs are reported as separate activities in the solution.
+    #[serde(rename(deserialize = "duplicates", serialize = "duplicates"))]
+    Duplicates {
+        /// Specifies a vehicle profile used to calculate commute info of merged jobs.
+        profile: VehicleProfile,
+    },
 }
 
 /// Defines a various thresholds to control cluster size.
@@ -225,6 +304,25 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// Specifies a cordon area with a limit of tour legs crossing it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cordon: Option<Cordon>,
+
+    /// When set to true, jobs of the same group are either all assigned or all unassigned.
+    #[serde(rename = "strictGroups", skip_serializing_if = "Option::is_none")]
+    pub strict_groups: Option<bool>,
+}
+
+/// Specifies a cordon area (e.g. toll zone) defined by the locations inside of it.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Cordon {
+    /// Locations inside the cordon.
+    pub locations: Vec<Location>,
+
+    /// Max amount of tour legs crossing the cordon boundary (entering or leaving it).
+    #[serde(rename = "maxCrossings")]
+    pub max_crossings: usize,
 }
 
 // endregion
@@ -243,6 +341,26 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Piecewise cost per distance unit: each tier overrides distance cost for the part of
+    /// tour distance above its threshold.
+    #[serde(rename = "distanceTiers", skip_serializing_if = "Option::is_none")]
+    pub distance_tiers: Option<Vec<VehicleDistanceCostTier>>,
+
+    /// A fixed handling cost applied for each stop: a job activity served at a location different
+    /// from the previous one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<f64>,
+}
+
+/// Specifies vehicle distance cost tier.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleDistanceCostTier {
+    /// A tour distance after which the tier's rate is applied.
+    pub threshold: f64,
+
+    /// Cost per distance unit.
+    pub rate: f64,
 }
 
 /// Specifies vehicle shift start.
@@ -300,6 +418,21 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleReload>>,
+
+    /// Job task types (`pickup`, `delivery`, `replacement`, `service`) which can be served within
+    /// the shift. All types are allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_types: Option<Vec<String>>,
+
+    /// An overtime penalty: when specified, the shift is considered as an overtime one and the penalty
+    /// is applied when the shift is used, so regular shifts are filled first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime_penalty: Option<f64>,
+
+    /// A minimum time between the end of one optional break and the start of the next one. When
+    /// specified, optional breaks are also scheduled in the order they are listed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub break_separation: Option<f64>,
 }
 
 /// Specifies a dispatch place where vehicle can load cargo and start the tour.
@@ -313,6 +446,11 @@ pub struct VehicleDispatch {
     /// A tag which will be propagated back within corresponding activity in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// A dispatch stage. Dispatch places of the same stage are alternatives to each other, while
+    /// different stages are visited one after another in ascending order at the tour start.
+    /// Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<usize>,
 }
 
 /// Specifies dispatch place limits to handle vehicles.
@@ -348,6 +486,11 @@ pub struct VehicleReload {
     /// A shared reload resource id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_id: Option<String>,
+
+    /// A minimum fraction of vehicle capacity which has to be used before reload can be visited.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_load_factor: Option<f64>,
 }
 
 /// Vehicle limits.
@@ -369,6 +512,38 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Max route cost per tour.
+    /// No cost restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cost: Option<f64>,
+
+    /// Max amount of simultaneously open jobs with multiple tasks (e.g. picked up, but not yet delivered).
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_open_jobs: Option<usize>,
+
+    /// Max amount of job activities served after their target time.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_late_activities: Option<usize>,
+
+    /// Max time vehicle can stay idle waiting for the next activity. When exceeded, vehicle
+    /// returns to one of its reload places and resumes the tour from there.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_idle_time: Option<f64>,
+
+    /// Max elapsed time between arrival at the first job activity and departure from the last one.
+    /// Unlike max duration, it doesn't include travel from and to vehicle's start and end locations.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_shift_span: Option<f64>,
+
+    /// Max total amount of tour interruptions, such as breaks and reloads, regardless of their type.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_interruptions: Option<usize>,
 }
 
 /// Vehicle optional break time variant.
@@ -445,7 +620,8 @@ pub struct VehicleType {
     /// Vehicle type id.
     pub type_id: String,
 
-    /// Concrete vehicle ids.
+    /// Concrete vehicle ids. Can be omitted when `count` is specified.
+    #[serde(default)]
     pub vehicle_ids: Vec<String>,
 
     /// Vehicle profile.
@@ -464,9 +640,43 @@ pub struct VehicleType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
 
+    /// Validity time windows of vehicle skills. Skills which are not listed here are valid at any time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_validity: Option<Vec<VehicleSkillValidity>>,
+
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Specifies whether vehicle is loaded in LIFO (last-in, first-out) order: a delivery is possible
+    /// only when all jobs picked up after it are already delivered. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifo: Option<bool>,
+
+    /// A size of homogeneous vehicle pool: when specified without vehicle ids, they are generated
+    /// automatically as `{typeId}_{index}` with index starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+
+    /// A temperature range which vehicle can keep: jobs with temperature range can be served only
+    /// when their range overlaps it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<TemperatureRange>,
+
+    /// Service duration scale factor applied to jobs served by the vehicle, e.g. unloading takes
+    /// longer with a large truck. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_scale: Option<f64>,
+}
+
+/// Specifies time windows when vehicle skill is valid, e.g. a period of driver's certification.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleSkillValidity {
+    /// A skill name.
+    pub skill: String,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    pub times: Vec<Vec<String>>,
 }
 
 /// Specifies a vehicle profile.
@@ -483,6 +693,7 @@ pub struct VehicleProfile {
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
@@ -491,6 +702,21 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Min travel values between two different locations: lower routing matrix values, including zero
+    /// or negative ones, are replaced by them. When omitted, negative values are reported as validation error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_travel: Option<MatrixMinTravel>,
+}
+
+/// Specifies min travel duration and distance between two different locations.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixMinTravel {
+    /// Min travel duration.
+    pub duration: i64,
+
+    /// Min travel distance.
+    pub distance: i64,
 }
 
 /// Specifies vehicle resource type.
@@ -507,6 +733,20 @@ pub enum VehicleResource {
     },
 }
 
+/// Specifies a travel gate: a directed edge between two locations which can be traversed
+/// only within given time windows (e.g. a bridge which opens at specific times).
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TravelGate {
+    /// Gate id.
+    pub id: String,
+    /// A location where the gated edge starts.
+    pub from: Location,
+    /// A location where the gated edge ends.
+    pub to: Location,
+    /// Time windows when traveling through the edge can be started.
+    pub times: Vec<Vec<String>>,
+}
+
 /// Specifies fleet.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Fleet {
@@ -519,6 +759,10 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies travel gates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gates: Option<Vec<TravelGate>>,
 }
 
 // endregion
@@ -541,6 +785,14 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-duration", serialize = "minimize-duration"))]
     MinimizeDuration,
 
+    /// An objective to maximize earnings: total pay for served jobs minus distance costs.
+    #[serde(rename(deserialize = "maximize-earnings", serialize = "maximize-earnings"))]
+    MaximizeEarnings {
+        /// A pay for each served job.
+        #[serde(rename = "jobPay")]
+        job_pay: f64,
+    },
+
     /// An objective to minimize total tour amount.
     #[serde(rename(deserialize = "minimize-tours", serialize = "minimize-tours"))]
     MinimizeTours,
@@ -549,12 +801,30 @@ pub enum Objective {
     #[serde(rename(deserialize = "maximize-tours", serialize = "maximize-tours"))]
     MaximizeTours,
 
+    /// An objective to minimize amount of distinct vehicle types used.
+    #[serde(rename(deserialize = "minimize-vehicle-types", serialize = "minimize-vehicle-types"))]
+    MinimizeVehicleTypes,
+
+    /// An objective to minimize deviation of tour amount from the target one.
+    #[serde(rename(deserialize = "minimize-tours-deviation", serialize = "minimize-tours-deviation"))]
+    MinimizeToursDeviation {
+        /// A target amount of tours, e.g. the one used in the previous day's plan.
+        target: usize,
+    },
+
     /// An objective to maximize value of served jobs.
     #[serde(rename(deserialize = "maximize-value", serialize = "maximize-value"))]
     MaximizeValue {
         /// Specifies a weight of skipped breaks.
         #[serde(skip_serializing_if = "Option::is_none")]
         breaks: Option<f64>,
+        /// Specifies a hard limit for total cost of all tours.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        budget: Option<f64>,
+        /// Specifies a base of job priority tier value: a job of tier `t` has value `base^(max_tier - t)`.
+        /// Default is 10.
+        #[serde(rename = "priorityBase", skip_serializing_if = "Option::is_none")]
+        priority_base: Option<f64>,
     },
 
     /// An objective to minimize amount of unassigned jobs.
@@ -566,6 +836,15 @@ pub enum Objective {
         breaks: Option<f64>,
     },
 
+    /// An objective to minimize total detour: extra distance caused by each job activity in
+    /// comparison to direct travel between its neighbours in the tour.
+    #[serde(rename(deserialize = "minimize-detour", serialize = "minimize-detour"))]
+    MinimizeDetour,
+
+    /// An objective to minimize total penalty of used overtime shifts.
+    #[serde(rename(deserialize = "minimize-overtime", serialize = "minimize-overtime"))]
+    MinimizeOvertime,
+
     /// An objective to minimize sum of arrival times from all routes.
     #[serde(rename(deserialize = "minimize-arrival-time", serialize = "minimize-arrival-time"))]
     MinimizeArrivalTime,
@@ -587,6 +866,15 @@ pub enum Objective {
         options: Option<BalanceOptions>,
     },
 
+    /// An objective to balance amount of stops (physical visits) across all tours.
+    #[serde(rename(deserialize = "balance-stops", serialize = "balance-stops"))]
+    BalanceStops {
+        /// An options which can be used to specify minimum stop amount in a tour before
+        /// it considered for balancing.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<BalanceOptions>,
+    },
+
     /// An objective to balance distance across all tours.
     #[serde(rename(deserialize = "balance-distance", serialize = "balance-distance"))]
     BalanceDistance {
@@ -605,6 +893,15 @@ pub enum Objective {
         options: Option<BalanceOptions>,
     },
 
+    /// An objective to balance total cost, including fixed one, across all tours.
+    #[serde(rename(deserialize = "balance-cost", serialize = "balance-cost"))]
+    BalanceCost {
+        /// An options which can be used to specify minimum cost of a tour before
+        /// it considered for balancing.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<BalanceOptions>,
+    },
+
     /// An objective to control how tours are built.
     #[serde(rename(deserialize = "compact-tour", serialize = "compact-tour"))]
     CompactTour {
@@ -615,6 +912,112 @@ pub enum Objective {
     /// An objective to control order of job activities in the tour.
     #[serde(rename(deserialize = "tour-order", serialize = "tour-order"))]
     TourOrder,
+
+    /// An objective to minimize mixing of jobs with different compatibility classes in the tour. When
+    /// specified, compatibility becomes a soft constraint.
+    #[serde(rename(deserialize = "minimize-incompatibility", serialize = "minimize-incompatibility"))]
+    MinimizeIncompatibility,
+
+    /// An objective to minimize deviation of service start from job's target time.
+    #[serde(rename(deserialize = "minimize-target-deviation", serialize = "minimize-target-deviation"))]
+    MinimizeTargetDeviation {
+        /// A penalty per time unit of service started before target time. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        earliness: Option<f64>,
+        /// A penalty per time unit of service started after target time. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lateness: Option<f64>,
+    },
+
+    /// An objective to minimize the maximum lateness of service start after job's target time.
+    #[serde(rename(deserialize = "minimize-max-lateness", serialize = "minimize-max-lateness"))]
+    MinimizeMaxLateness,
+
+    /// An objective to minimize cumulative lateness of service start after job's target time weighted
+    /// by job value.
+    #[serde(rename(deserialize = "minimize-weighted-lateness", serialize = "minimize-weighted-lateness"))]
+    MinimizeWeightedLateness,
+
+    /// An objective to keep activities of jobs with the same customer id next to each other
+    /// within the tour.
+    #[serde(rename(deserialize = "minimize-customer-gaps", serialize = "minimize-customer-gaps"))]
+    MinimizeCustomerGaps {
+        /// A penalty per foreign activity served between two activities of the same customer. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        penalty: Option<f64>,
+    },
+
+    /// An objective to minimize spatial overlap between tours. Requires locations to be
+    /// specified as geo coordinates.
+    #[serde(rename(deserialize = "minimize-overlap", serialize = "minimize-overlap"))]
+    MinimizeOverlap,
+
+    /// An objective to minimize amount of unique stops across the fleet: visits of the same location
+    /// by different vehicles are counted separately.
+    #[serde(rename(deserialize = "minimize-unique-stops", serialize = "minimize-unique-stops"))]
+    MinimizeUniqueStops,
+
+    /// An objective to prefer deliver-down load profiles: vehicle load should only decrease after
+    /// its peak within the tour.
+    #[serde(rename(deserialize = "deliver-down-load", serialize = "deliver-down-load"))]
+    DeliverDownLoad {
+        /// A penalty per load increase after the peak, measured as a ratio of vehicle capacity. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        penalty: Option<f64>,
+    },
+
+    /// An objective to provide proportionally better service for jobs of higher service tier:
+    /// they are less likely to be unassigned and are preferred to be served earlier.
+    #[serde(rename(deserialize = "service-tiers", serialize = "service-tiers"))]
+    ServiceTiers {
+        /// Service tiers definitions.
+        tiers: Vec<ServiceTierOptions>,
+    },
+
+    /// An objective to prefer vehicles which have all preferred skills of the job.
+    #[serde(rename(deserialize = "preferred-skills", serialize = "preferred-skills"))]
+    PreferredSkills {
+        /// A penalty per job served by a vehicle without all of job's preferred skills. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        penalty: Option<f64>,
+    },
+
+    /// An objective to control how tour departure times are spread: either staggered or kept
+    /// within a narrow band.
+    #[serde(rename(deserialize = "departure-spread", serialize = "departure-spread"))]
+    DepartureSpread {
+        /// Departure spread options.
+        options: DepartureSpreadOptions,
+    },
+}
+
+/// Specifies how tour departure times are spread.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum DepartureSpreadOptions {
+    /// Departures are staggered, e.g. to avoid congestion at depot with limited loading capacity.
+    #[serde(rename(deserialize = "stagger", serialize = "stagger"))]
+    Stagger {
+        /// A minimum time between two consecutive departures.
+        interval: f64,
+    },
+
+    /// Departures are kept within a narrow band.
+    #[serde(rename(deserialize = "band", serialize = "band"))]
+    Band,
+}
+
+/// Specifies service tier parameters.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ServiceTierOptions {
+    /// A tier name used in job's `tier` property.
+    pub name: String,
+    /// A penalty applied when job of the tier is not assigned. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty: Option<f64>,
+    /// A penalty per time unit passed since tour start till job's service start. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<f64>,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -625,6 +1028,9 @@ pub struct BalanceOptions {
     /// favor of another objective.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threshold: Option<f64>,
+    /// A tolerance specifies max difference of balancing values which are considered equal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<f64>,
 }
 
 /// Specifies tour compactness options to relax impact of objective.
@@ -684,7 +1090,7 @@ pub struct Matrix {
 
 /// Deserializes problem in json format from `BufReader`.
 pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, MultiFormatError> {
-    serde_json::from_reader(reader).map_err(|err| {
+    serde_json::from_reader(reader).map(expand_vehicle_pools).map_err(|err| {
         vec![FormatError::new(
             "E0000".to_string(),
             "cannot deserialize problem".to_string(),
@@ -694,8 +1100,24 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Mul
     })
 }
 
-/// Deserializes routing matrix in json format from `BufReader`.
-pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+/// Generates vehicle ids for vehicle types specified as a homogeneous pool by count.
+pub fn expand_vehicle_pools(mut problem: Problem) -> Problem {
+    problem.fleet.vehicles.iter_mut().filter(|vehicle| vehicle.vehicle_ids.is_empty()).for_each(|vehicle| {
+        if let Some(count) = vehicle.count {
+            vehicle.vehicle_ids = (1..=count).map(|idx| format!("{}_{}", vehicle.type_id, idx)).collect();
+        }
+    });
+
+    problem
+}
+
+/// Deserializes routing matrix from `BufReader`: binary format is detected automatically,
+/// otherwise json format is expected.
+pub fn deserialize_matrix<R: Read>(mut reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+    if is_binary_matrix(&mut reader) {
+        return deserialize_binary_matrix(reader);
+    }
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0001".to_string(),
@@ -2,7 +2,7 @@ use super::*;
 use crate::construction::enablers::{get_route_modifier, OnlyVehicleActivityCost, VehicleTie};
 use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
-use crate::format::problem::goal_reader::create_goal_context;
+use crate::format::problem::goal_reader::{create_goal_context, get_departure_spread};
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
 use crate::format::{FormatError, JobIndex};
 use crate::validation::ValidationContext;
@@ -10,9 +10,10 @@ use crate::{parse_time, CoordIndex};
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
 use vrp_core::models::problem::*;
 use vrp_core::models::{Extras, GoalContext};
-use vrp_core::solver::processing::VicinityDimension;
+use vrp_core::solver::processing::{DepartureSpreadDimension, VicinityDimension};
 
 pub fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
+    let problem = expand_vehicle_pools(problem);
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
     map_to_problem(problem, matrices, coord_index)
@@ -22,6 +23,7 @@ pub fn map_to_problem_with_matrices(
     problem: ApiProblem,
     matrices: Vec<Matrix>,
 ) -> Result<CoreProblem, MultiFormatError> {
+    let problem = expand_vehicle_pools(problem);
     let coord_index = CoordIndex::new(&problem);
     map_to_problem(problem, matrices, coord_index)
 }
@@ -46,7 +48,24 @@ pub fn map_to_problem(
             format!("check matrix routing data: '{err}'"),
         )]
     })?;
-    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(OnlyVehicleActivityCost::default());
+    let travel_gates_index = read_travel_gates_index(&api_problem, &coord_index);
+    let transport: Arc<dyn TransportCost + Send + Sync> = if travel_gates_index.is_empty() {
+        transport
+    } else {
+        Arc::new(GatedTransportCost::new(travel_gates_index, transport).map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check fleet gates definition: '{err}'"),
+            )]
+        })?)
+    };
+    let activity: Arc<dyn ActivityCost + Send + Sync> =
+        if api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.service_scale.is_some()) {
+            Arc::new(OnlyVehicleActivityCost::new_with_service_scale())
+        } else {
+            Arc::new(OnlyVehicleActivityCost::default())
+        };
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
@@ -87,6 +106,7 @@ pub fn map_to_problem(
         create_goal_context(
             &api_problem,
             &job_index,
+            coord_index.clone(),
             jobs.clone(),
             fleet.clone(),
             transport.clone(),
@@ -118,6 +138,16 @@ pub fn map_to_problem(
     Ok(CoreProblem { fleet, jobs, locks, goal, activity, transport, extras })
 }
 
+fn read_travel_gates_index(api_problem: &ApiProblem, coord_index: &CoordIndex) -> TravelGatesIndex {
+    api_problem.fleet.gates.iter().flatten().fold(TravelGatesIndex::default(), |mut acc, gate| {
+        if let Some(edge) = coord_index.get_by_loc(&gate.from).zip(coord_index.get_by_loc(&gate.to)) {
+            acc.entry(edge).or_default().extend(gate.times.iter().map(|tw| parse_time_window(tw)));
+        }
+
+        acc
+    })
+}
+
 fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> ReservedTimesIndex {
     let breaks_map = api_problem
         .fleet
@@ -188,11 +218,20 @@ fn create_extras(
         extras.set_cluster_config(config);
     }
 
+    let departure_spread = api_problem.objectives.iter().flatten().flatten().find_map(|objective| match objective {
+        Objective::DepartureSpread { options } => Some(get_departure_spread(options)),
+        _ => None,
+    });
+    if let Some(departure_spread) = departure_spread {
+        extras.set_departure_spread(departure_spread);
+    }
+
     Ok(extras)
 }
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
+    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some())
+        || api_problem.fleet.gates.as_ref().map_or(false, |gates| !gates.is_empty());
     let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem.plan.jobs.iter().any(|job| {
             job.pickups
@@ -230,7 +269,8 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|order| order > 0);
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
-    let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
+    let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.)
+        || api_problem.plan.jobs.iter().any(|job| job.priority.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
@@ -241,6 +281,34 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_tour_cost_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_cost.is_some()));
+
+    let has_open_jobs_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_open_jobs.is_some()));
+
+    let has_late_activities_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_late_activities.is_some()));
+
+    let has_shift_span_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_shift_span.is_some()));
+
+    let has_interruption_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_interruptions.is_some()));
+
+    let has_shift_job_types =
+        api_problem.fleet.vehicles.iter().flat_map(|v| v.shifts.iter()).any(|shift| shift.job_types.is_some());
+
+    let has_overtime =
+        api_problem.fleet.vehicles.iter().flat_map(|v| v.shifts.iter()).any(|shift| shift.overtime_penalty.is_some());
+
+    let has_detour_limits = api_problem.plan.jobs.iter().any(|job| job.max_detour_time.is_some());
+    let has_wait_limits = api_problem.plan.jobs.iter().any(|job| job.max_wait_time.is_some());
+    let has_lifo = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.lifo.unwrap_or(false));
+    let has_visits = api_problem.plan.jobs.iter().any(|job| job.visit.is_some());
+    let has_temperature = api_problem.plan.jobs.iter().any(|job| job.temperature.is_some());
+    let has_cordon = api_problem.plan.cordon.is_some();
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -254,5 +322,18 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_tour_cost_limits,
+        has_open_jobs_limits,
+        has_late_activities_limits,
+        has_shift_span_limits,
+        has_interruption_limits,
+        has_shift_job_types,
+        has_overtime,
+        has_detour_limits,
+        has_wait_limits,
+        has_lifo,
+        has_visits,
+        has_temperature,
+        has_cordon,
     }
 }
@@ -5,6 +5,7 @@ mod initial_reader_test;
 use crate::construction::enablers::VehicleTie;
 use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
 use crate::format::solution::Activity as FormatActivity;
+use crate::format::solution::Solution as FormatSolution;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::{deserialize_solution, map_reason_code};
@@ -13,7 +14,7 @@ use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
-use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::construction::heuristics::{InsertionContext, UnassignmentInfo};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Job, Single};
 use vrp_core::models::solution::Tour as CoreTour;
@@ -31,6 +32,29 @@ pub fn read_init_solution<R: Read>(
 ) -> Result<Solution, String> {
     let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
 
+    create_init_solution(&solution, problem, random, false)
+}
+
+/// Creates an insertion context from a previously calculated solution, so it can be used as initial
+/// solution (e.g. to re-optimize routes of the previous day). Jobs which are not present in the problem
+/// anymore are skipped, but all vehicles used in the solution have to be present.
+/// NOTE: Solution feasibility is not checked.
+pub fn create_init_insertion_context(
+    solution: &FormatSolution,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+) -> Result<InsertionContext, String> {
+    let solution = create_init_solution(solution, problem.clone(), environment.random.clone(), true)?;
+
+    Ok(InsertionContext::new_from_solution(problem, (solution, None), environment))
+}
+
+fn create_init_solution(
+    solution: &FormatSolution,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+    skip_unknown_jobs: bool,
+) -> Result<Solution, String> {
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
@@ -48,9 +72,20 @@ pub fn read_init_solution<R: Read>(
             let mut core_route = create_core_route(actor, tour)?;
 
             tour.stops.iter().try_for_each(|stop| {
-                stop.activities().iter().try_for_each::<_, Result<_, String>>(|activity| {
-                    try_insert_activity(&mut core_route, tour, stop, activity, job_index, coord_index, &mut added_jobs)
-                })
+                stop.activities()
+                    .iter()
+                    .filter(|activity| !skip_unknown_jobs || is_known_activity(activity, job_index))
+                    .try_for_each::<_, Result<_, String>>(|activity| {
+                        try_insert_activity(
+                            &mut core_route,
+                            tour,
+                            stop,
+                            activity,
+                            job_index,
+                            coord_index,
+                            &mut added_jobs,
+                        )
+                    })
             })?;
 
             routes.push(core_route);
@@ -58,9 +93,12 @@ pub fn read_init_solution<R: Read>(
             Ok(routes)
         })?;
 
-    let mut unassigned = solution.unassigned.unwrap_or_default().iter().try_fold::<Vec<_>, _, Result<_, String>>(
-        Default::default(),
-        |mut acc, unassigned_job| {
+    let mut unassigned = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .filter(|unassigned_job| !skip_unknown_jobs || job_index.contains_key(&unassigned_job.job_id))
+        .try_fold::<Vec<_>, _, Result<_, String>>(Default::default(), |mut acc, unassigned_job| {
             let job = job_index
                 .get(&unassigned_job.job_id)
                 .cloned()
@@ -76,8 +114,7 @@ pub fn read_init_solution<R: Read>(
             acc.push((job, code));
 
             Ok(acc)
-        },
-    )?;
+        })?;
 
     unassigned.extend(
         problem.jobs.all().filter(|job| added_jobs.get(job).is_none()).map(|job| (job, UnassignmentInfo::Unknown)),
@@ -115,6 +152,10 @@ fn try_insert_activity(
     Ok(())
 }
 
+fn is_known_activity(activity: &FormatActivity, job_index: &JobIndex) -> bool {
+    matches!(activity.activity_type.as_str(), "departure" | "arrival") || job_index.contains_key(&activity.job_id)
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
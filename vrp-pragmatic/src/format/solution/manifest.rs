@@ -0,0 +1,97 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/manifest_test.rs"]
+mod manifest_test;
+
+use crate::format::problem::{Job, JobTask, Problem};
+use crate::format::solution::{Activity, Stop, Tour};
+use std::collections::HashMap;
+
+/// Creates a human-readable route manifest of the tour: an ordered list of stops with arrival time,
+/// location, served jobs, demand loaded and unloaded at the stop and cumulative vehicle load after it.
+pub fn route_manifest(tour: &Tour, problem: &Problem) -> String {
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    let header = format!("Vehicle: {}, type: {}, shift: {}", tour.vehicle_id, tour.type_id, tour.shift_index);
+
+    let stops = tour.stops.iter().enumerate().map(|(idx, stop)| {
+        let (activities, location) = match stop {
+            Stop::Point(point) => (&point.activities, point.location.to_string()),
+            Stop::Transit(transit) => (&transit.activities, "transit".to_string()),
+        };
+
+        let (loaded, unloaded) =
+            activities.iter().fold((Vec::<i32>::default(), Vec::<i32>::default()), |(loaded, unloaded), activity| {
+                let demand = get_task(&jobs, activity).and_then(|task| task.demand.as_ref());
+                match (activity.activity_type.as_str(), demand) {
+                    ("pickup", Some(demand)) => (add_demand(loaded, demand), unloaded),
+                    ("delivery", Some(demand)) => (loaded, add_demand(unloaded, demand)),
+                    ("replacement", Some(demand)) => (add_demand(loaded, demand), add_demand(unloaded, demand)),
+                    _ => (loaded, unloaded),
+                }
+            });
+
+        let job_ids = activities
+            .iter()
+            .map(|activity| {
+                if activity.job_id == activity.activity_type {
+                    activity.job_id.clone()
+                } else {
+                    format!("{} ({})", activity.job_id, activity.activity_type)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "{}. arrival: {}, location: {}, jobs: {}, loaded: {}, unloaded: {}, load: {}",
+            idx + 1,
+            stop.schedule().arrival,
+            location,
+            job_ids,
+            format_demand(&loaded),
+            format_demand(&unloaded),
+            format_load(stop.load()),
+        )
+    });
+
+    std::iter::once(header).chain(stops).collect::<Vec<_>>().join("\n")
+}
+
+fn get_task<'a>(jobs: &HashMap<&str, &'a Job>, activity: &Activity) -> Option<&'a JobTask> {
+    let job = jobs.get(activity.job_id.as_str())?;
+    let tasks = match activity.activity_type.as_str() {
+        "pickup" => job.pickups.as_ref(),
+        "delivery" => job.deliveries.as_ref(),
+        "replacement" => job.replacements.as_ref(),
+        "service" => job.services.as_ref(),
+        _ => None,
+    }?;
+
+    // NOTE multi job activities are distinguished by tag
+    tasks
+        .iter()
+        .find(|task| activity.job_tag.is_some() && task.places.iter().any(|place| place.tag == activity.job_tag))
+        .or_else(|| tasks.first())
+}
+
+fn add_demand(mut total: Vec<i32>, demand: &[i32]) -> Vec<i32> {
+    if total.len() < demand.len() {
+        total.resize(demand.len(), 0);
+    }
+
+    total.iter_mut().zip(demand.iter()).for_each(|(total, value)| *total += value);
+
+    total
+}
+
+fn format_demand(demand: &[i32]) -> String {
+    if demand.iter().all(|value| *value == 0) {
+        "-".to_string()
+    } else {
+        format_load(demand)
+    }
+}
+
+fn format_load(load: &[i32]) -> String {
+    load.iter().map(|value| value.to_string()).collect::<Vec<_>>().join("/")
+}
@@ -9,13 +9,24 @@ mod geo_serializer;
 pub use self::geo_serializer::*;
 
 mod initial_reader;
-pub use self::initial_reader::read_init_solution;
+pub use self::initial_reader::{create_init_insertion_context, read_init_solution};
 
 mod extensions;
 
+mod manifest;
+pub use self::manifest::route_manifest;
+
+mod replay;
+pub use self::replay::*;
+
+mod stream_serializer;
+pub use self::stream_serializer::serialize_solution_streaming;
+
 mod writer;
 pub use self::writer::create_solution;
 pub use self::writer::PragmaticSolution;
+pub use self::writer::SolutionOptions;
+pub use self::writer::UnassignedReasonFn;
 
 use super::*;
 
@@ -46,6 +57,41 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         RELOAD_RESOURCE_CONSTRAINT_CODE => {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
+        COST_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_COST_CONSTRAINT", "cannot be assigned due to max cost constraint of vehicle")
+        }
+        OPEN_JOBS_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_OPEN_JOBS_CONSTRAINT", "cannot be assigned due to max open jobs constraint of vehicle")
+        }
+        JOB_TYPE_CONSTRAINT_CODE => {
+            ("JOB_TYPE_CONSTRAINT", "cannot be assigned due to job types allowed in vehicle shift")
+        }
+        MAX_DETOUR_CONSTRAINT_CODE => ("MAX_DETOUR_CONSTRAINT", "cannot be assigned due to max detour time constraint"),
+        LIFO_CONSTRAINT_CODE => ("LIFO_CONSTRAINT", "cannot be assigned due to LIFO loading order of vehicle"),
+        VISIT_ORDER_CONSTRAINT_CODE => {
+            ("VISIT_ORDER_CONSTRAINT", "cannot be assigned due to visit order of multi-visit job")
+        }
+        LATE_ACTIVITIES_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_LATE_ACTIVITIES_CONSTRAINT", "cannot be assigned due to max late activities constraint of vehicle")
+        }
+        MAX_WAIT_CONSTRAINT_CODE => ("MAX_WAIT_CONSTRAINT", "cannot be assigned due to max wait time constraint"),
+        TEMPERATURE_CONSTRAINT_CODE => {
+            ("TEMPERATURE_CONSTRAINT", "cannot be assigned due to temperature range of vehicle")
+        }
+        CORDON_CONSTRAINT_CODE => ("CORDON_CONSTRAINT", "cannot be assigned due to max cordon crossings of tour"),
+        SHIFT_SPAN_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_SHIFT_SPAN_CONSTRAINT", "cannot be assigned due to max shift span constraint of vehicle")
+        }
+        STRICT_GROUP_CONSTRAINT_CODE => {
+            ("STRICT_GROUP_CONSTRAINT", "cannot be assigned as other jobs of the group cannot be assigned")
+        }
+        INTERRUPTION_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_INTERRUPTIONS_CONSTRAINT", "cannot be assigned due to max interruptions constraint of vehicle")
+        }
+        FLEET_COST_BUDGET_CONSTRAINT_CODE => {
+            ("FLEET_COST_BUDGET_CONSTRAINT", "cannot be assigned due to cost budget of all tours")
+        }
+        MAX_IDLE_CONSTRAINT_CODE => ("MAX_IDLE_CONSTRAINT", "cannot be assigned due to max idle time of vehicle"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -67,6 +113,21 @@ fn map_reason_code(reason: &str) -> i32 {
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
+        "MAX_COST_CONSTRAINT" => COST_LIMIT_CONSTRAINT_CODE,
+        "MAX_OPEN_JOBS_CONSTRAINT" => OPEN_JOBS_LIMIT_CONSTRAINT_CODE,
+        "JOB_TYPE_CONSTRAINT" => JOB_TYPE_CONSTRAINT_CODE,
+        "MAX_DETOUR_CONSTRAINT" => MAX_DETOUR_CONSTRAINT_CODE,
+        "LIFO_CONSTRAINT" => LIFO_CONSTRAINT_CODE,
+        "VISIT_ORDER_CONSTRAINT" => VISIT_ORDER_CONSTRAINT_CODE,
+        "MAX_LATE_ACTIVITIES_CONSTRAINT" => LATE_ACTIVITIES_LIMIT_CONSTRAINT_CODE,
+        "MAX_WAIT_CONSTRAINT" => MAX_WAIT_CONSTRAINT_CODE,
+        "TEMPERATURE_CONSTRAINT" => TEMPERATURE_CONSTRAINT_CODE,
+        "CORDON_CONSTRAINT" => CORDON_CONSTRAINT_CODE,
+        "MAX_SHIFT_SPAN_CONSTRAINT" => SHIFT_SPAN_LIMIT_CONSTRAINT_CODE,
+        "STRICT_GROUP_CONSTRAINT" => STRICT_GROUP_CONSTRAINT_CODE,
+        "MAX_INTERRUPTIONS_CONSTRAINT" => INTERRUPTION_LIMIT_CONSTRAINT_CODE,
+        "FLEET_COST_BUDGET_CONSTRAINT" => FLEET_COST_BUDGET_CONSTRAINT_CODE,
+        "MAX_IDLE_CONSTRAINT" => MAX_IDLE_CONSTRAINT_CODE,
         _ => -1,
     }
 }
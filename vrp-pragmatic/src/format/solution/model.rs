@@ -1,5 +1,12 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/model_test.rs"]
+mod model_test;
+
 use crate::format::{CoordIndex, Location};
 use crate::{format_time, parse_time};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 use vrp_core::models::common::{Duration, Timestamp};
@@ -100,6 +107,12 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Cost of reaching and serving the activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+    /// Cumulative tour cost at the activity, including vehicle fixed cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cumulative_cost: Option<f64>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -258,6 +271,9 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// Diversity of the final population: a mean pairwise distance between its solutions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diversity: Option<f64>,
 }
 
 /// Represents information about generation.
@@ -276,6 +292,9 @@ pub struct Generation {
     pub is_improvement: bool,
     /// Population state.
     pub population: Population,
+    /// Fitness spread: a mean pairwise relative distance between individuals' fitness values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fitness_spread: Option<f64>,
 }
 
 /// Keeps essential information about particular individual in population.
@@ -299,6 +318,11 @@ pub struct Population {
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
+    /// A lower bound of the total cost required to serve the same jobs. Can be used to estimate
+    /// an optimality gap of the solution.
+    #[serde(rename = "lowerBound", skip_serializing_if = "Option::is_none")]
+    pub lower_bound: Option<f64>,
+
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
@@ -337,6 +361,20 @@ pub fn deserialize_solution<R: Read>(reader: BufReader<R>) -> Result<Solution, E
     serde_json::from_reader(reader).map_err(Error::from)
 }
 
+/// Serializes solution into gzip compressed json format.
+pub fn serialize_solution_gzip<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    let mut encoder = GzEncoder::new(writer, Compression::default());
+    serde_json::to_writer_pretty(&mut encoder, solution).map_err(Error::from)?;
+
+    encoder.finish().map(|_| ())
+}
+
+/// Deserializes solution from gzip compressed json format. Decompression errors are reported
+/// in the same way as deserialization ones.
+pub fn deserialize_solution_gzip<R: Read>(reader: BufReader<R>) -> Result<Solution, Error> {
+    deserialize_solution(BufReader::new(GzDecoder::new(reader)))
+}
+
 impl Interval {
     /// Returns interval's duration.
     pub fn duration(&self) -> Duration {
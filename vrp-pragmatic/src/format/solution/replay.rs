@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/replay_test.rs"]
+mod replay_test;
+
+use crate::format::solution::{create_solution, Solution as ApiSolution};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_core::models::Problem;
+use vrp_core::solver::ReplayFrame as DomainReplayFrame;
+
+/// A best known solution recorded at specific generation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ReplayFrame {
+    /// A generation number.
+    pub generation: usize,
+    /// A best known solution.
+    pub solution: ApiSolution,
+}
+
+/// An evolution replay: a sequence of best known solutions ordered by generation.
+/// The last frame is the solution returned by the solver.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Replay {
+    /// Recorded frames.
+    pub frames: Vec<ReplayFrame>,
+}
+
+/// Writes evolution replay frames in pragmatic json format.
+pub fn write_replay<W: Write>(
+    problem: &Problem,
+    frames: &[DomainReplayFrame],
+    writer: &mut BufWriter<W>,
+) -> Result<(), String> {
+    let replay = Replay {
+        frames: frames
+            .iter()
+            .map(|(generation, solution)| ReplayFrame {
+                generation: *generation,
+                solution: create_solution(problem, solution, &Default::default()),
+            })
+            .collect(),
+    };
+
+    serde_json::to_writer(writer, &replay).map_err(|err| format!("cannot write replay: {err}"))
+}
+
+/// Reads evolution replay from json format.
+pub fn read_replay<R: Read>(reader: BufReader<R>) -> Result<Replay, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot read replay: {err}"))
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/stream_serializer_test.rs"]
+mod stream_serializer_test;
+
+use super::writer::{create_extras, create_tours, create_unassigned, create_violations};
+use crate::format::solution::{SolutionOptions, Statistic, Tour};
+use serde::ser::{Error as SerError, SerializeSeq, SerializeStruct};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::io::{BufWriter, Error, Write};
+use vrp_core::models::{Problem as CoreProblem, Solution as CoreSolution};
+
+/// Serializes solution into json format creating and writing tours one by one, so there is no need
+/// to keep all tours in memory. The output is the same as `serialize_solution` produces for the
+/// solution created by `create_solution` with the same options.
+pub fn serialize_solution_streaming<W: Write>(
+    problem: &CoreProblem,
+    solution: &CoreSolution,
+    options: &SolutionOptions,
+    writer: &mut BufWriter<W>,
+) -> Result<(), Error> {
+    // NOTE statistic precedes tours in the output, so tours are created twice: first time only to
+    // aggregate their statistic
+    let statistic =
+        create_tours(problem, solution, options).fold(Statistic::default(), |acc, tour| acc + tour.statistic);
+    let unassigned = create_unassigned(solution, options.reason_fn.as_ref());
+    let violations = create_violations(solution);
+    let extras = create_extras(problem, solution, options.metrics);
+    let tours = create_tours(problem, solution, options);
+
+    let mut serializer = serde_json::Serializer::pretty(writer);
+
+    let fields =
+        2 + [unassigned.is_some(), violations.is_some(), extras.is_some()].iter().filter(|&&is_some| is_some).count();
+    let mut state = serializer.serialize_struct("Solution", fields)?;
+
+    state.serialize_field("statistic", &statistic)?;
+    state.serialize_field("tours", &TourSequence(RefCell::new(Some(tours))))?;
+
+    // NOTE keep the same order and skip policy as in derived implementation
+    match &unassigned {
+        Some(unassigned) => state.serialize_field("unassigned", unassigned)?,
+        None => state.skip_field("unassigned")?,
+    }
+    match &violations {
+        Some(violations) => state.serialize_field("violations", violations)?,
+        None => state.skip_field("violations")?,
+    }
+    match &extras {
+        Some(extras) => state.serialize_field("extras", extras)?,
+        None => state.skip_field("extras")?,
+    }
+
+    SerializeStruct::end(state).map_err(Error::from)
+}
+
+/// Serializes tours lazily: the iterator is consumed on serialization.
+struct TourSequence<I>(RefCell<Option<I>>);
+
+impl<I: Iterator<Item = Tour>> Serialize for TourSequence<I> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let tours = self.0.borrow_mut().take().ok_or_else(|| S::Error::custom("tours are already serialized"))?;
+
+        let mut seq = serializer.serialize_seq(None)?;
+        for tour in tours {
+            seq.serialize_element(&tour)?;
+        }
+
+        seq.end()
+    }
+}
@@ -11,10 +11,14 @@ use crate::format::*;
 use crate::{format_time, parse_time};
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 use vrp_core::construction::enablers::route_intervals;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{
+    get_cost_lower_bound, get_vehicle_distance_cost, DistanceCostTiersDimension, Multi, StopCostDimension, TravelTime,
+    Vehicle,
+};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::compare_floats;
@@ -68,13 +72,12 @@ fn write_pragmatic_json<W: Write>(
     metrics: Option<&TelemetryMetrics>,
     writer: &mut BufWriter<W>,
 ) -> Result<(), String> {
-    let solution = create_solution(problem, solution, metrics);
-    serialize_solution(&solution, writer).map_err(|err| err.to_string())?;
-    Ok(())
+    serialize_solution_streaming(problem, solution, &SolutionOptions { metrics, ..Default::default() }, writer)
+        .map_err(|err| err.to_string())
 }
 
 fn write_geo_json<W: Write>(problem: &Problem, solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), String> {
-    let solution = create_solution(problem, solution, None);
+    let solution = create_solution(problem, solution, &Default::default());
     serialize_solution_as_geojson(writer, problem, &solution).map_err(|err| err.to_string())?;
     Ok(())
 }
@@ -95,27 +98,54 @@ impl Leg {
     }
 }
 
-/// Creates solution.
-pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> ApiSolution {
-    let coord_index = get_coord_index(problem);
-    let reserved_times_index = get_reserved_times_index(problem);
+/// Specifies a function which returns a custom description of unassigned job reason.
+/// It accepts job id and reason code (e.g. `CAPACITY_CONSTRAINT`).
+pub type UnassignedReasonFn = Arc<dyn Fn(&str, &str) -> String + Send + Sync>;
+
+/// Specifies options to create pragmatic solution.
+#[derive(Clone, Default)]
+pub struct SolutionOptions<'a> {
+    /// Telemetry metrics to be written into solution's extras.
+    pub metrics: Option<&'a TelemetryMetrics>,
+    /// Specifies whether cost and cumulative tour cost should be specified for each activity.
+    pub activity_costs: bool,
+    /// Provides custom descriptions of unassigned job reasons.
+    pub reason_fn: Option<UnassignedReasonFn>,
+}
 
-    let tours = solution
-        .routes
-        .iter()
-        .map(|r| create_tour(problem, r, coord_index, reserved_times_index))
-        .collect::<Vec<Tour>>();
+/// Creates solution using given options.
+pub fn create_solution(problem: &Problem, solution: &Solution, options: &SolutionOptions) -> ApiSolution {
+    let tours = create_tours(problem, solution, options).collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
-    let unassigned = create_unassigned(solution);
+    let unassigned = create_unassigned(solution, options.reason_fn.as_ref());
     let violations = create_violations(solution);
 
-    let extras = create_extras(solution, metrics);
+    let extras = create_extras(problem, solution, options.metrics);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
 
+/// Creates tours from solution routes lazily, one by one.
+pub(super) fn create_tours<'a>(
+    problem: &'a Problem,
+    solution: &'a Solution,
+    options: &SolutionOptions,
+) -> impl Iterator<Item = Tour> + 'a {
+    let coord_index = get_coord_index(problem);
+    let reserved_times_index = get_reserved_times_index(problem);
+    let activity_costs = options.activity_costs;
+
+    solution.routes.iter().map(move |route| {
+        let mut tour = create_tour(problem, route, coord_index, reserved_times_index);
+        if !activity_costs {
+            remove_activity_costs(&mut tour);
+        }
+        tour
+    })
+}
+
 fn create_tour(
     problem: &Problem,
     route: &Route,
@@ -186,6 +216,8 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    cost: Some(vehicle.costs.fixed),
+                    cumulative_cost: None,
                 }],
                 parking: None,
             }));
@@ -247,23 +279,43 @@ fn create_tour(
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
                 let service_start = activity_arrival.max(act.place.time.start);
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
+                // NOTE service scale is applied only to plan jobs, conditional jobs are bound to the vehicle
+                let service_scale = match vehicle.dimens.get_vehicle_service_scale() {
+                    Some(scale)
+                        if act.job.as_ref().map_or(false, |single| single.dimens.get_vehicle_id().is_none()) =>
+                    {
+                        scale
+                    }
+                    _ => 1.,
+                };
+                let serving = act.place.duration * service_scale - parking;
                 let service_end = service_start + serving;
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let stop_cost = match vehicle.dimens.get_stop_cost() {
+                    Some(stop_cost) if act.job.is_some() && prev_location != act.place.location => stop_cost,
+                    _ => 0.,
+                };
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
                         as i64;
                 let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
 
+                // NOTE transport cost uses regular distance cost, so adjust it when distance cost tiers are used
+                let tiers_cost = get_distance_tiers_adjustment(vehicle, distance)
+                    - get_distance_tiers_adjustment(vehicle, leg.statistic.distance);
+
+                let total_cost =
+                    serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time + stop_cost + tiers_cost;
+
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
                     (Some(_), _) => false,
-                    (None, is_same_location) => !is_same_location,
+                    // NOTE same location activities share the stop only when they are served contiguously
+                    (None, is_same_location) => !is_same_location || waiting > 0.,
                 };
 
                 if is_new_stop {
@@ -308,6 +360,8 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    cost: Some(total_cost),
+                    cumulative_cost: None,
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -347,9 +401,11 @@ fn create_tour(
     });
 
     leg.statistic.cost += vehicle.costs.fixed;
+
     tour.statistic = leg.statistic;
 
     insert_reserved_times(route, &mut tour, reserved_times_index);
+    insert_cumulative_costs(&mut tour);
 
     // NOTE remove redundant info
     tour.stops
@@ -450,14 +506,15 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                         .unwrap_or(0);
 
                     // TODO costs may not match?
-                    let activities = match stop {
+                    let (activities, break_cost) = match stop {
                         Stop::Point(point) => {
-                            tour.statistic.cost += break_time as f64 * route.actor.vehicle.costs.per_service_time;
-                            &mut point.activities
+                            let break_cost = break_time as f64 * route.actor.vehicle.costs.per_service_time;
+                            tour.statistic.cost += break_cost;
+                            (&mut point.activities, break_cost)
                         }
                         Stop::Transit(transit) => {
                             tour.statistic.times.driving -= break_time;
-                            &mut transit.activities
+                            (&mut transit.activities, 0.)
                         }
                     };
 
@@ -473,6 +530,8 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                             }),
                             job_tag: None,
                             commute: None,
+                            cost: Some(break_cost),
+                            cumulative_cost: None,
                         },
                     );
 
@@ -497,6 +556,44 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         });
 }
 
+/// Returns a difference between tiered and regular distance cost of given total route distance.
+fn get_distance_tiers_adjustment(vehicle: &Vehicle, distance: i64) -> Cost {
+    if vehicle.dimens.get_distance_cost_tiers().is_some() {
+        let distance = distance as f64;
+        get_vehicle_distance_cost(vehicle, distance) - vehicle.costs.per_distance * distance
+    } else {
+        Cost::default()
+    }
+}
+
+fn insert_cumulative_costs(tour: &mut Tour) {
+    tour.stops
+        .iter_mut()
+        .flat_map(|stop| match stop {
+            Stop::Point(point) => point.activities.iter_mut(),
+            Stop::Transit(transit) => transit.activities.iter_mut(),
+        })
+        .fold(0., |acc, activity| {
+            let cumulative_cost = acc + activity.cost.unwrap_or_default();
+            activity.cumulative_cost = Some(cumulative_cost);
+
+            cumulative_cost
+        });
+}
+
+fn remove_activity_costs(tour: &mut Tour) {
+    tour.stops
+        .iter_mut()
+        .flat_map(|stop| match stop {
+            Stop::Point(point) => point.activities.iter_mut(),
+            Stop::Transit(transit) => transit.activities.iter_mut(),
+        })
+        .for_each(|activity| {
+            activity.cost = None;
+            activity.cumulative_cost = None;
+        });
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -507,10 +604,17 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
-    let create_simple_reasons = |code: i32| {
+pub(super) fn create_unassigned(
+    solution: &Solution,
+    reason_fn: Option<&UnassignedReasonFn>,
+) -> Option<Vec<UnassignedJob>> {
+    let get_description = |job_id: &str, code: &str, reason: &str| {
+        reason_fn.map_or_else(|| reason.to_string(), |reason_fn| (reason_fn)(job_id, code))
+    };
+    let create_simple_reasons = |job_id: &str, code: i32| {
         let (code, reason) = map_code_reason(code);
-        vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
+        let description = get_description(job_id, code, reason);
+        vec![UnassignedJobReason { code: code.to_string(), description, details: None }]
     };
 
     let unassigned = solution
@@ -521,7 +625,7 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
             let reasons = match code {
-                UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
+                UnassignmentInfo::Simple(code) => create_simple_reasons(&job_id, *code),
                 UnassignmentInfo::Detailed(details) if !details.is_empty() => details
                     .iter()
                     .collect_group_by_key(|(_, code)| *code)
@@ -548,11 +652,11 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
                                     .collect(),
                             ),
                             code: code.to_string(),
-                            description: reason.to_string(),
+                            description: get_description(&job_id, code, reason),
                         }
                     })
                     .collect(),
-                _ => create_simple_reasons(0),
+                _ => create_simple_reasons(&job_id, 0),
             };
 
             UnassignedJob { job_id, reasons }
@@ -566,7 +670,7 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
-fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
+pub(super) fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
         .unassigned
@@ -611,9 +715,16 @@ fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
-    metrics.map(|metrics| Extras {
-        metrics: Some(ApiMetrics {
+pub(super) fn create_extras(
+    problem: &Problem,
+    solution: &Solution,
+    metrics: Option<&TelemetryMetrics>,
+) -> Option<Extras> {
+    let jobs = solution.routes.iter().flat_map(|route| route.tour.jobs()).collect::<Vec<_>>();
+
+    Some(Extras {
+        lower_bound: Some(get_cost_lower_bound(problem, jobs.as_slice())),
+        metrics: metrics.map(|metrics| ApiMetrics {
             duration: metrics.duration,
             generations: metrics.generations,
             speed: metrics.speed,
@@ -634,8 +745,10 @@ fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Op
                             .map(|i| ApiIndividual { difference: i.difference, fitness: i.fitness.clone() })
                             .collect(),
                     },
+                    fitness_spread: g.fitness_spread,
                 })
                 .collect(),
+            diversity: metrics.diversity,
         }),
     })
 }
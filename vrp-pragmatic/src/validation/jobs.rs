@@ -3,7 +3,9 @@
 mod jobs_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -73,14 +75,14 @@ fn check_e1102_multiple_pickups_deliveries_demand(ctx: &ValidationContext) -> Re
     }
 }
 
-/// Checks that job's time windows are correct.
+/// Checks that job's time windows and blackout periods are correct.
 fn check_e1103_time_window_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
     let has_invalid_tws = |tasks: &Option<Vec<JobTask>>| {
         tasks.as_ref().map_or(false, |tasks| {
             tasks
                 .iter()
                 .flat_map(|task| task.places.iter())
-                .filter_map(|place| place.times.as_ref())
+                .flat_map(|place| place.times.iter().chain(place.blackouts.iter()))
                 .any(|tws| !check_raw_time_windows(tws, false))
         })
     };
@@ -179,6 +181,114 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has correctly specified target time.
+fn check_e1108_invalid_target_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .filter_map(|task| task.target_time.as_ref())
+                .any(|target_time| parse_time_safe(target_time).is_err())
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid target time in jobs".to_string(),
+            format!("use RFC3339 format for target time in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job has no negative max detour time.
+fn check_e1109_negative_max_detour_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.max_detour_time.map_or(false, |max_detour_time| max_detour_time < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job has negative max detour time".to_string(),
+            format!("fix negative max detour time in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that visits of multi-visit jobs have unique order and a single task.
+fn check_e1110_invalid_visits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let mut visit_orders = HashSet::new();
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.visit.as_ref().map_or(false, |visit| {
+                !visit_orders.insert((visit.id.as_str(), visit.order)) || get_job_tasks(job).count() != 1
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job visit".to_string(),
+            format!(
+                "ensure that visits of multi-visit job have unique order and jobs have a single task, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job has no negative max wait time.
+fn check_e1111_negative_max_wait_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.max_wait_time.map_or(false, |max_wait_time| max_wait_time < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "job has negative max wait time".to_string(),
+            format!("fix negative max wait time in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job has valid temperature range.
+fn check_e1112_invalid_temperature_range(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.temperature.as_ref().map_or(false, |temperature| temperature.min > temperature.max))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "job has invalid temperature range".to_string(),
+            format!("ensure that min temperature is not greater than max in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +300,11 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_target_time(ctx),
+        check_e1109_negative_max_detour_time(ctx),
+        check_e1110_invalid_visits(ctx),
+        check_e1111_negative_max_wait_time(ctx),
+        check_e1112_invalid_temperature_range(ctx),
     ])
     .map_err(|errors| errors.into())
 }
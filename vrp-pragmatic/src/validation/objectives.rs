@@ -28,17 +28,35 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeDistance => acc.entry("minimize-distance"),
                 MinimizeDuration => acc.entry("minimize-duration"),
+                MaximizeEarnings { .. } => acc.entry("maximize-earnings"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
+                MinimizeVehicleTypes => acc.entry("minimize-vehicle-types"),
+                MinimizeToursDeviation { .. } => acc.entry("minimize-tours-deviation"),
                 MaximizeValue { .. } => acc.entry("maximize-value"),
                 MinimizeUnassignedJobs { .. } => acc.entry("minimize-unassigned"),
+                MinimizeDetour => acc.entry("minimize-detour"),
+                MinimizeOvertime => acc.entry("minimize-overtime"),
                 MinimizeArrivalTime => acc.entry("minimize-arrival-time"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
+                BalanceStops { .. } => acc.entry("balance-stops"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                BalanceCost { .. } => acc.entry("balance-cost"),
                 CompactTour { .. } => acc.entry("compact-tour"),
                 TourOrder => acc.entry("tour-order"),
+                MinimizeIncompatibility => acc.entry("minimize-incompatibility"),
+                MinimizeTargetDeviation { .. } => acc.entry("minimize-target-deviation"),
+                MinimizeMaxLateness => acc.entry("minimize-max-lateness"),
+                MinimizeWeightedLateness => acc.entry("minimize-weighted-lateness"),
+                MinimizeCustomerGaps { .. } => acc.entry("minimize-customer-gaps"),
+                MinimizeOverlap => acc.entry("minimize-overlap"),
+                MinimizeUniqueStops => acc.entry("minimize-unique-stops"),
+                DeliverDownLoad { .. } => acc.entry("deliver-down-load"),
+                ServiceTiers { .. } => acc.entry("service-tiers"),
+                PreferredSkills { .. } => acc.entry("preferred-skills"),
+                DepartureSpread { .. } => acc.entry("departure-spread"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -64,14 +82,16 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
 
 /// Checks that cost objective is specified.
 fn check_e1602_no_cost_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
-    let no_min_cost =
-        !objectives.iter().any(|objective| matches!(objective, MinimizeCost | MinimizeDistance | MinimizeDuration));
+    let no_min_cost = !objectives.iter().any(|objective| {
+        matches!(objective, MinimizeCost | MinimizeDistance | MinimizeDuration | MaximizeEarnings { .. })
+    });
 
     if no_min_cost {
         Err(FormatError::new(
             "E1602".to_string(),
             "missing one of cost objectives".to_string(),
-            "specify 'minimize-cost', 'minimize-duration' or 'minimize-distance' objective".to_string(),
+            "specify 'minimize-cost', 'minimize-duration', 'minimize-distance' or 'maximize-earnings' objective"
+                .to_string(),
         ))
     } else {
         Ok(())
@@ -84,13 +104,14 @@ fn check_e1603_no_jobs_with_value_objective(
     objectives: &[&Objective],
 ) -> Result<(), FormatError> {
     let has_value_objective = objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. }));
-    let has_no_jobs_with_value = !ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.);
+    let has_no_jobs_with_value = !ctx.problem.plan.jobs.iter().any(|job| job.value.map_or(false, |value| value > 0.))
+        && !ctx.problem.plan.jobs.iter().any(|job| job.priority.is_some());
 
     if has_value_objective && has_no_jobs_with_value {
         Err(FormatError::new(
             "E1603".to_string(),
             "redundant value objective".to_string(),
-            "specify at least one non-zero valued job or delete 'maximize-value' objective".to_string(),
+            "specify at least one non-zero valued or prioritized job or delete 'maximize-value' objective".to_string(),
         ))
     } else {
         Ok(())
@@ -126,8 +147,9 @@ fn check_e1605_check_positive_value_and_order(ctx: &ValidationContext) -> Result
         .filter(|job| {
             let has_invalid_order = get_job_tasks(job).filter_map(|task| task.order).any(|value| value < 1);
             let has_invalid_value = job.value.map_or(false, |v| v < 1.);
+            let has_invalid_priority = job.priority.map_or(false, |p| p < 1);
 
-            has_invalid_order || has_invalid_value
+            has_invalid_order || has_invalid_value || has_invalid_priority
         })
         .map(|job| job.id.as_str())
         .collect::<Vec<_>>();
@@ -137,8 +159,8 @@ fn check_e1605_check_positive_value_and_order(ctx: &ValidationContext) -> Result
     } else {
         Err(FormatError::new(
             "E1605".to_string(),
-            "value or order of a job should be greater than zero".to_string(),
-            format!("change value or order of jobs to be greater than zero: '{}'", job_ids.join(", ")),
+            "value, order or priority of a job should be greater than zero".to_string(),
+            format!("change value, order or priority of jobs to be greater than zero: '{}'", job_ids.join(", ")),
         ))
     }
 }
@@ -147,7 +169,9 @@ fn check_e1605_check_positive_value_and_order(ctx: &ValidationContext) -> Result
 fn check_e1606_check_multiple_cost_objectives(objectives: &[&Objective]) -> Result<(), FormatError> {
     let cost_objectives = objectives
         .iter()
-        .filter(|objective| matches!(objective, MinimizeCost | MinimizeDistance | MinimizeDuration))
+        .filter(|objective| {
+            matches!(objective, MinimizeCost | MinimizeDistance | MinimizeDuration | MaximizeEarnings { .. })
+        })
         .count();
 
     if cost_objectives > 1 {
@@ -170,14 +194,17 @@ fn check_e1607_jobs_with_value_but_no_objective(
         return Ok(());
     }
 
-    let has_no_value_objective = !objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. }));
-    let has_jobs_with_vlue = ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.);
+    let has_no_value_objective =
+        !objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. } | MinimizeWeightedLateness));
+    let has_jobs_with_vlue = ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.)
+        || ctx.problem.plan.jobs.iter().any(|job| job.priority.is_some());
 
     if has_no_value_objective && has_jobs_with_vlue {
         Err(FormatError::new(
             "E1607".to_string(),
             "missing value objective".to_string(),
-            "specify 'maximize-value' objective, remove objectives property or remove value property from jobs"
+            "specify 'maximize-value' or 'minimize-weighted-lateness' objective, remove objectives property or \
+             remove value property from jobs"
                 .to_string(),
         ))
     } else {
@@ -185,6 +212,25 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that overlap objective is used only when locations are specified as geo coordinates.
+fn check_e1608_overlap_objective_without_coordinates(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_overlap_objective = objectives.iter().any(|objective| matches!(objective, MinimizeOverlap));
+    let has_no_coordinates = !ctx.coord_index.get_used_types().0;
+
+    if has_overlap_objective && has_no_coordinates {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "overlap objective requires geo coordinates".to_string(),
+            "specify locations as geo coordinates or delete 'minimize-overlap' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -200,6 +246,7 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_overlap_objective_without_coordinates(ctx, &objectives),
         ])
         .map_err(|errors| errors.into())
     } else {
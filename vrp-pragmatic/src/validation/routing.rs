@@ -103,7 +103,7 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
         .iter()
         .map(|vehicle| vehicle.profile.matrix.clone())
         .chain(ctx.problem.plan.clustering.iter().map(|clustering| match clustering {
-            Clustering::Vicinity { profile, .. } => profile.matrix.clone(),
+            Clustering::Vicinity { profile, .. } | Clustering::Duplicates { profile } => profile.matrix.clone(),
         }))
         .filter(|matrix| !known_matrix_profiles.contains(matrix))
         .collect::<HashSet<_>>();
@@ -120,6 +120,100 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that travel gates have proper time windows and known locations.
+fn check_e1506_invalid_travel_gates(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let gate_ids = ctx
+        .problem
+        .fleet
+        .gates
+        .iter()
+        .flatten()
+        .filter(|gate| {
+            let has_unknown_location =
+                [&gate.from, &gate.to].into_iter().any(|location| ctx.coord_index.get_by_loc(location).is_none());
+
+            has_unknown_location || !check_raw_time_windows(&gate.times, false)
+        })
+        .map(|gate| gate.id.clone())
+        .collect::<Vec<_>>();
+
+    if gate_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid travel gate".to_string(),
+            format!(
+                "ensure that gates have valid time windows and locations used by jobs or vehicles: '{}'",
+                gate_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that routing matrices have no negative values between different locations unless min travel is set.
+fn check_e1507_negative_matrix_values(ctx: &ValidationContext) -> Result<(), FormatError> {
+    const MAX_REPORTED_ENTRIES: usize = 5;
+
+    let profiles = &ctx.problem.fleet.profiles;
+    let matrix_entries = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter().enumerate())
+        .filter(|(idx, matrix)| {
+            let profile = matrix
+                .profile
+                .as_ref()
+                .and_then(|name| profiles.iter().find(|profile| profile.name == *name))
+                .or_else(|| profiles.get(*idx));
+
+            profile.map_or(true, |profile| profile.min_travel.is_none())
+        })
+        .filter_map(|(idx, matrix)| {
+            let size = (matrix.distances.len() as f64).sqrt().round() as usize;
+            let entries = matrix
+                .travel_times
+                .iter()
+                .zip(matrix.distances.iter())
+                .enumerate()
+                .filter(|(entry_idx, (duration, distance))| {
+                    let is_error = matrix
+                        .error_codes
+                        .as_ref()
+                        .and_then(|codes| codes.get(*entry_idx))
+                        .map_or(false, |code| *code > 0);
+
+                    entry_idx / size != entry_idx % size && !is_error && (**duration < 0 || **distance < 0)
+                })
+                .map(|(entry_idx, _)| format!("{}->{}", entry_idx / size, entry_idx % size))
+                .collect::<Vec<_>>();
+
+            if entries.is_empty() {
+                None
+            } else {
+                let name = matrix.profile.clone().unwrap_or_else(|| idx.to_string());
+                let total = entries.len();
+                let entries = entries.into_iter().take(MAX_REPORTED_ENTRIES).collect::<Vec<_>>().join(", ");
+
+                Some(format!("'{name}' ({total} in total): {entries}"))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if matrix_entries.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "negative values in routing matrix".to_string(),
+            format!(
+                "fix routing matrix or specify min travel on matrix profile, negative entries: {}",
+                matrix_entries.join("; ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = ctx.coord_index.get_used_types();
@@ -131,6 +225,8 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_invalid_travel_gates(ctx),
+        check_e1507_negative_matrix_values(ctx),
     ])
     .map_err(|errors| errors.into())
 }
@@ -4,7 +4,7 @@ mod vehicles_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use crate::validation::common::get_time_windows;
+use crate::validation::common::{get_time_window_from_vec, get_time_windows};
 use crate::{parse_time, parse_time_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
@@ -286,6 +286,228 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that reload min load factor is in the allowed range.
+fn check_e1309_vehicle_reload_min_load_factor(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift
+                .reloads
+                .iter()
+                .flatten()
+                .filter_map(|reload| reload.min_load_factor)
+                .all(|factor| (0. ..=1.).contains(&factor))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle reload min load factor".to_string(),
+            format!(
+                "ensure that reload min load factor is in the range [0, 1], vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that shift job types are known.
+fn check_e1310_vehicle_shift_job_types(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift
+                .job_types
+                .iter()
+                .flatten()
+                .all(|job_type| matches!(job_type.as_str(), "pickup" | "delivery" | "replacement" | "service"))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle shift job types".to_string(),
+            format!(
+                "ensure that shift job types are one of 'pickup', 'delivery', 'replacement' or 'service', \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle distance cost tiers are valid.
+fn check_e1311_vehicle_distance_cost_tiers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.distance_tiers.iter().flatten().any(|tier| tier.threshold < 0. || tier.rate < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle distance cost tiers".to_string(),
+            format!(
+                "ensure that distance cost tiers have non-negative threshold and rate, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle shift overtime penalty is not negative.
+fn check_e1312_vehicle_shift_overtime_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(ctx, Box::new(|_, shift, _| !shift.overtime_penalty.map_or(false, |p| p < 0.)));
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle shift overtime penalty".to_string(),
+            format!("ensure that shift overtime penalty is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle stop cost is not negative.
+fn check_e1313_vehicle_stop_cost(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.costs.stop.map_or(false, |stop| stop < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle stop cost".to_string(),
+            format!("ensure that vehicle stop cost is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle pool count matches amount of vehicle ids.
+fn check_e1314_vehicle_pool_count(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.count.map_or(false, |count| count == 0 || count != vehicle.vehicle_ids.len()))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle pool count".to_string(),
+            format!(
+                "ensure that count is positive and vehicle ids are either omitted or match the count, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle has valid temperature range.
+fn check_e1315_vehicle_temperature_range(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.temperature.as_ref().map_or(false, |temperature| temperature.min > temperature.max))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle temperature range".to_string(),
+            format!("ensure that min temperature is not greater than max, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle shift break separation is not negative.
+fn check_e1316_vehicle_shift_break_separation(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(ctx, Box::new(|_, shift, _| !shift.break_separation.map_or(false, |s| s < 0.)));
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle shift break separation".to_string(),
+            format!("ensure that shift break separation is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle service scale is positive and not combined with required breaks.
+fn check_e1317_vehicle_service_scale(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let has_required_break = vehicle
+                .shifts
+                .iter()
+                .any(|shift| shift.breaks.iter().flatten().any(|br| matches!(br, VehicleBreak::Required { .. })));
+
+            vehicle.service_scale.map_or(false, |scale| scale <= 0. || has_required_break)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle service scale".to_string(),
+            format!("ensure that vehicle service scale is positive and not used with required breaks, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+fn check_e1318_vehicle_skill_validity_times(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.skill_validity.iter().flatten().flat_map(|validity| validity.times.iter()).any(|tw| {
+                get_time_window_from_vec(tw).map_or(true, |tw| compare_floats(tw.start, tw.end) == Ordering::Greater)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle skill validity time".to_string(),
+            format!(
+                "ensure that skill validity times are valid time windows, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -334,6 +556,16 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_required_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_reload_min_load_factor(ctx),
+        check_e1310_vehicle_shift_job_types(ctx),
+        check_e1311_vehicle_distance_cost_tiers(ctx),
+        check_e1312_vehicle_shift_overtime_penalty(ctx),
+        check_e1313_vehicle_stop_cost(ctx),
+        check_e1314_vehicle_pool_count(ctx),
+        check_e1315_vehicle_temperature_range(ctx),
+        check_e1316_vehicle_shift_break_separation(ctx),
+        check_e1317_vehicle_service_scale(ctx),
+        check_e1318_vehicle_skill_validity_times(ctx),
     ])
     .map_err(|errors| errors.into())
 }
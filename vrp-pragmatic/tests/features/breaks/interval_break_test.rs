@@ -76,7 +76,9 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -87,7 +89,9 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             }
                         ],
                     }),
@@ -153,6 +157,9 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         ..create_default_reload()
                     }]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -207,7 +214,9 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -218,7 +227,9 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             }
                         ],
                     }),
@@ -5,3 +5,4 @@ mod multi_break_test;
 mod policy_break_test;
 mod relation_break_test;
 mod required_break;
+mod sequenced_break_test;
@@ -103,7 +103,9 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -114,7 +116,9 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             }
                         ],
                     }),
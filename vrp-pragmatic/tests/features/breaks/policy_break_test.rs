@@ -40,6 +40,9 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             policy,
                         }]),
                         reloads: None,
+                        job_types: None,
+                        overtime_penalty: None,
+                        break_separation: None,
                     }],
                     ..create_default_vehicle_type()
                 },
@@ -77,7 +77,9 @@ fn can_assign_break_during_travel() {
                             location: None,
                             time: None,
                             job_tag: None,
-                            commute: None
+                            commute: None,
+                            cost: None,
+                            cumulative_cost: None
                         }],
                     }),
                     create_stop_with_activity(
@@ -175,7 +177,9 @@ fn can_assign_break_during_activity() {
                                     end: "1970-01-01T00:00:10Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -186,7 +190,9 @@ fn can_assign_break_during_activity() {
                                     end: "1970-01-01T00:00:09Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                cost: None,
+                                cumulative_cost: None
                             }
                         ],
                     }),
@@ -0,0 +1,96 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_optional_break(start: f64, end: f64, duration: f64) -> VehicleBreak {
+    VehicleBreak::Optional {
+        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(start), format_time(end)]),
+        places: vec![VehicleOptionalBreakPlace { duration, location: None, tag: None }],
+        policy: None,
+    }
+}
+
+fn create_problem_with_breaks(breaks: Vec<VehicleBreak>, break_separation: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (15., 0.)),
+                create_delivery_job("job4", (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    breaks: Some(breaks),
+                    break_separation,
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_break_times(solution: &Solution) -> Vec<(f64, f64)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| {
+            let time = activity.time.clone().unwrap_or_else(|| Interval {
+                start: stop.schedule().arrival.clone(),
+                end: stop.schedule().departure.clone(),
+            });
+            (parse_time(&time.start), parse_time(&time.end))
+        })
+        .collect()
+}
+
+#[test]
+fn can_keep_minimum_separation_between_breaks() {
+    let problem = create_problem_with_breaks(
+        vec![create_optional_break(5., 10., 2.), create_optional_break(11., 40., 3.)],
+        Some(10.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let break_times = get_break_times(&solution);
+    assert_eq!(break_times.len(), 2);
+    let ((_, first_end), (_, second_end)) = (break_times[0], break_times[1]);
+    assert!(second_end - 3. >= first_end + 10., "breaks are too close: {break_times:?}");
+    assert!(solution.violations.is_none());
+}
+
+#[test]
+fn can_skip_break_which_cannot_be_separated_from_previous_one() {
+    let problem = create_problem_with_breaks(
+        vec![create_optional_break(5., 8., 2.), create_optional_break(9., 14., 2.)],
+        Some(10.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let break_times = get_break_times(&solution);
+    assert_eq!(break_times.len(), 1);
+    assert!(break_times[0].0 <= 8., "unexpected break time: {break_times:?}");
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+    );
+}
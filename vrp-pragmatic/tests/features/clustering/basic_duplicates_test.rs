@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn can_merge_duplicate_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (1., 0.)),
+                create_delivery_job("job3", (1., 0.)),
+                create_delivery_job_with_demand("job4", (1., 0.), vec![2]),
+                create_delivery_job("job5", (5., 0.)),
+            ],
+            clustering: Some(Clustering::Duplicates {
+                profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+            }),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![10])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| (activity.job_id.as_str(), activity.commute.is_some()))
+        .collect::<Vec<_>>();
+    assert_eq!(activities.len(), 5);
+    let mut merged =
+        activities.iter().filter(|(_, is_merged)| *is_merged).map(|(job_id, _)| *job_id).collect::<Vec<_>>();
+    merged.sort();
+    assert_eq!(merged, vec!["job1", "job2", "job3"]);
+    let merged_idx = activities.iter().position(|(_, is_merged)| *is_merged).unwrap();
+    assert!(activities[merged_idx..merged_idx + 3].iter().all(|(_, is_merged)| *is_merged));
+}
@@ -45,6 +45,8 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            cost: None,
+            cumulative_cost: None,
         }
     }
 }
@@ -129,6 +131,7 @@ fn create_test_problem(jobs_data: &[(f64, &str)], capacity: i32, clustering: Clu
     }
 }
 
+mod basic_duplicates_test;
 mod basic_vicinity_test;
 mod capacity_vicinity_test;
 mod profile_vicinity_test;
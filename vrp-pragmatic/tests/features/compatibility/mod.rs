@@ -1 +1,3 @@
 mod basic_compatibility;
+mod soft_compatibility;
+mod temperature_compatibility;
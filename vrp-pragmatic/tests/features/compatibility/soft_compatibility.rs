@@ -0,0 +1,68 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_objectives() -> Option<Vec<Vec<Objective>>> {
+    Some(vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizeTours],
+        vec![MinimizeIncompatibility],
+        vec![MinimizeCost],
+    ])
+}
+
+#[test]
+fn can_mix_incompatible_jobs_in_soft_mode() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_compatibility("food", (1., 0.), "food"),
+                create_delivery_job_with_compatibility("junk", (2., 0.), "junk"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        objectives: create_objectives(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
+
+#[test]
+fn can_use_cleaning_stop_to_separate_incompatible_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_compatibility("food1", (1., 0.), "food"),
+                create_delivery_job_with_compatibility("food2", (2., 0.), "food"),
+                create_delivery_job_with_compatibility("junk", (3., 0.), "junk"),
+                Job { cleaning: Some(true), ..create_delivery_job("cleaning", (4., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: create_objectives(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        vec!["departure", "food1", "food2", "cleaning", "junk"]
+    );
+}
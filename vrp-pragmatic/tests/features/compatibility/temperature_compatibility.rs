@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::format::solution::{UnassignedJobDetail, UnassignedJobReason};
+use crate::helpers::*;
+
+fn create_job_with_temperature(id: &str, location: (f64, f64), min: f64, max: f64) -> Job {
+    Job { temperature: Some(TemperatureRange { min, max }), ..create_delivery_job(id, location) }
+}
+
+fn create_vehicle_with_temperature(min: f64, max: f64) -> VehicleType {
+    VehicleType { temperature: Some(TemperatureRange { min, max }), capacity: vec![2], ..create_default_vehicle_type() }
+}
+
+#[test]
+fn can_unassign_frozen_job_on_ambient_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_temperature("frozen", (1., 0.), -25., -18.),
+                create_job_with_temperature("ambient", (2., 0.), 15., 25.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_temperature(10., 25.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).iter().flatten().filter(|id| *id == "ambient").count(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "frozen");
+    assert_eq!(
+        unassigned[0].reasons,
+        vec![UnassignedJobReason {
+            code: "TEMPERATURE_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to temperature range of vehicle".to_string(),
+            details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+        }]
+    );
+}
+
+#[test]
+fn can_serve_frozen_and_ambient_jobs_with_multi_temperature_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_temperature("frozen", (1., 0.), -25., -18.),
+                create_job_with_temperature("ambient", (2., 0.), 15., 25.),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![3], ..create_vehicle_with_temperature(-25., 25.) }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
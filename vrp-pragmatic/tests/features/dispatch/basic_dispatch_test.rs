@@ -26,6 +26,7 @@ fn can_assign_single_dispatch() {
         location: (7., 0.).to_loc(),
         limits: vec![VehicleDispatchLimit { max: 1, start: format_time(10.), end: format_time(12.) }],
         tag: None,
+        stage: None,
     }]));
     let matrix = create_matrix_from_problem(&problem);
 
@@ -104,6 +105,7 @@ fn can_assign_dispatch_at_start() {
         location: (0., 0.).to_loc(),
         limits: vec![VehicleDispatchLimit { max: 1, start: format_time(0.), end: format_time(2.) }],
         tag: None,
+        stage: None,
     }]));
     let matrix = create_matrix_from_problem(&problem);
 
@@ -126,6 +128,7 @@ fn can_handle_unassignable_dispatch() {
         location: (1001., 0.).to_loc(),
         limits: vec![VehicleDispatchLimit { max: 1, start: format_time(10.), end: format_time(12.) }],
         tag: None,
+        stage: None,
     }]));
     let matrix = create_matrix_from_problem(&problem);
 
@@ -160,6 +163,7 @@ fn can_handle_two_dispatch_impl(
                 end: format_time((first_dispatch.1).0),
             }],
             tag: None,
+            stage: None,
         },
         VehicleDispatch {
             location: second_dispatch.0.to_loc(),
@@ -169,6 +173,7 @@ fn can_handle_two_dispatch_impl(
                 end: format_time((second_dispatch.1).0),
             }],
             tag: None,
+            stage: None,
         },
     ]));
     let matrix = create_matrix_from_problem(&problem);
@@ -235,6 +240,7 @@ fn can_dispatch_multiple_vehicles_at_single_dispatch() {
                 VehicleDispatchLimit { max: 3, start: format_time(13.), end: format_time(16.) },
             ],
             tag: None,
+            stage: None,
         }]),
     );
     let matrix = create_matrix_from_problem(&problem);
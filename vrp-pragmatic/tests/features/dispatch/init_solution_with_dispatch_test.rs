@@ -31,6 +31,7 @@ fn can_use_init_solution_with_dispatch() {
                             VehicleDispatchLimit { max: 1, start: format_time(4.), end: format_time(6.) },
                         ],
                         tag: None,
+                        stage: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -74,6 +75,8 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                cost: None,
+                                cumulative_cost: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -85,6 +88,8 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                cost: None,
+                                cumulative_cost: None,
                             },
                         ],
                     }),
@@ -137,6 +142,8 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                cost: None,
+                                cumulative_cost: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -148,6 +155,8 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                cost: None,
+                                cumulative_cost: None,
                             },
                         ],
                     }),
@@ -195,7 +204,11 @@ fn can_use_init_solution_with_dispatch() {
             .unwrap_or_else(|err| panic!("cannot build solver: {err}"))
             .solve()
             .unwrap_or_else(|err| panic!("cannot solve the problem: {err}"));
-    let result_solution = create_solution(&core_problem, &core_solution, metrics.as_ref());
+    let result_solution = create_solution(
+        &core_problem,
+        &core_solution,
+        &SolutionOptions { metrics: metrics.as_ref(), ..Default::default() },
+    );
 
-    assert_vehicle_agnostic(result_solution, init_solution);
+    assert_vehicle_agnostic(assert_lower_bound(result_solution), init_solution);
 }
@@ -1,3 +1,4 @@
 mod basic_dispatch_test;
 mod init_solution_with_dispatch_test;
+mod multi_stage_dispatch_test;
 mod relation_dispatch_test;
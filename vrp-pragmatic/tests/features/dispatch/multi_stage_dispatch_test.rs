@@ -0,0 +1,151 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_dispatch_stage(location: (f64, f64), times: (f64, f64), stage: usize) -> VehicleDispatch {
+    VehicleDispatch {
+        location: location.to_loc(),
+        limits: vec![VehicleDispatchLimit { max: 1, start: format_time(times.0), end: format_time(times.1) }],
+        tag: None,
+        stage: Some(stage),
+    }
+}
+
+fn create_problem_with_dispatch(dispatch: Vec<VehicleDispatch>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (3., 0.)), create_delivery_job("job2", (5., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { dispatch: Some(dispatch), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_two_dispatch_stages() {
+    let problem = create_problem_with_dispatch(vec![
+        create_dispatch_stage((8., 0.), (12., 14.), 2),
+        create_dispatch_stage((7., 0.), (10., 11.), 1),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 47.,
+                distance: 16,
+                duration: 21,
+                times: Timing { driving: 16, serving: 5, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:03Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "dispatch",
+                        "dispatch",
+                        (7., 0.),
+                        2,
+                        ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
+                        7,
+                    ),
+                    create_stop_with_activity(
+                        "dispatch",
+                        "dispatch",
+                        (8., 0.),
+                        2,
+                        ("1970-01-01T00:00:12Z", "1970-01-01T00:00:14Z"),
+                        8,
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (5., 0.),
+                        1,
+                        ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
+                        11,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (3., 0.),
+                        0,
+                        ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                        13,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:24Z", "1970-01-01T00:00:24Z"),
+                        16,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 47.,
+                    distance: 16,
+                    duration: 21,
+                    times: Timing { driving: 16, serving: 5, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_select_dispatch_place_within_stage() {
+    let problem = create_problem_with_dispatch(vec![
+        create_dispatch_stage((7., 0.), (10., 11.), 1),
+        create_dispatch_stage((1001., 0.), (12., 14.), 2),
+        create_dispatch_stage((8., 0.), (12., 14.), 2),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let dispatch_locations = solution.tours[0]
+        .stops
+        .iter()
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "dispatch"))
+        .map(|stop| stop.as_point().unwrap().location.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(dispatch_locations, vec![(7., 0.).to_loc(), (8., 0.).to_loc()]);
+}
+
+#[test]
+fn can_handle_unassignable_dispatch_stage() {
+    let problem = create_problem_with_dispatch(vec![
+        create_dispatch_stage((7., 0.), (10., 11.), 1),
+        create_dispatch_stage((1001., 0.), (12., 14.), 2),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map_or(0, |u| u.len()), 2);
+}
@@ -26,6 +26,7 @@ fn can_use_dispatch_in_relation() {
                         location: (7., 0.).to_loc(),
                         limits: vec![VehicleDispatchLimit { max: 1, start: format_time(7.), end: format_time(9.) }],
                         tag: None,
+                        stage: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
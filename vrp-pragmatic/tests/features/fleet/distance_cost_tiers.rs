@@ -0,0 +1,40 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_distance_cost_tiers, (tiers, expected_cost), {
+    can_use_distance_cost_tiers_impl(tiers, expected_cost);
+}}
+
+can_use_distance_cost_tiers! {
+    case01_no_tiers: (None, 10. + 200. + 200. + 1.),
+    case02_single_tier: (Some(vec![(50., 0.5)]), 10. + (50. + 150. * 0.5) + 200. + 1.),
+    case03_several_tiers: (Some(vec![(50., 0.5), (150., 0.25)]), 10. + (50. + 100. * 0.5 + 50. * 0.25) + 200. + 1.),
+    case04_tier_above_distance: (Some(vec![(500., 0.5)]), 10. + 200. + 200. + 1.),
+}
+
+fn can_use_distance_cost_tiers_impl(tiers: Option<Vec<(f64, f64)>>, expected_cost: f64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    distance_tiers: tiers.map(|tiers| {
+                        tiers.into_iter().map(|(threshold, rate)| VehicleDistanceCostTier { threshold, rate }).collect()
+                    }),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 200);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
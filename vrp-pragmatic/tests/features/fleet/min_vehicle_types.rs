@@ -0,0 +1,56 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_vehicle_types, (objectives, expected_types), {
+    can_minimize_vehicle_types_impl(objectives, expected_types);
+}}
+
+can_minimize_vehicle_types! {
+    case01_minimize_tours: (
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeTours], vec![MinimizeCost]],
+        vec!["big", "small"]
+    ),
+    case02_minimize_vehicle_types: (
+        vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeVehicleTypes],
+            vec![MinimizeTours],
+            vec![MinimizeCost],
+        ],
+        vec!["small", "small", "small"]
+    ),
+}
+
+fn can_minimize_vehicle_types_impl(objectives: Vec<Vec<Objective>>, expected_types: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (10., 1.)),
+                create_delivery_job("job3", (10., 2.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    vehicle_ids: vec!["small_1".to_string(), "small_2".to_string(), "small_3".to_string()],
+                    ..create_vehicle_with_capacity("small", vec![1])
+                },
+                create_vehicle_with_capacity("big", vec![2]),
+            ],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut types = solution.tours.iter().map(|tour| tour.type_id.as_str()).collect::<Vec<_>>();
+    types.sort();
+    assert_eq!(types, expected_types);
+}
@@ -1,5 +1,13 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod distance_cost_tiers;
+mod min_vehicle_types;
 mod multi_dimens;
+mod overtime_shift;
 mod profile_variation;
+mod service_scale;
+mod shift_job_types;
+mod stop_cost;
+mod tours_deviation;
 mod unreachable_jobs;
+mod vehicle_pool;
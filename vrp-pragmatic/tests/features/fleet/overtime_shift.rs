@@ -0,0 +1,44 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_overtime_shift_only_when_regular_is_saturated, (jobs, expected_types), {
+    can_use_overtime_shift_only_when_regular_is_saturated_impl(jobs, expected_types);
+}}
+
+can_use_overtime_shift_only_when_regular_is_saturated! {
+    case01_regular_is_enough: (2, vec!["regular"]),
+    case02_regular_is_saturated: (3, vec!["overtime", "regular"]),
+}
+
+fn can_use_overtime_shift_only_when_regular_is_saturated_impl(jobs: usize, expected_types: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (0..jobs).map(|idx| create_delivery_job(&format!("job{}", idx + 1), (10., idx as f64))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("regular", vec![2]),
+                // NOTE overtime shift starts next to jobs, so it is cheaper to use without penalty
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        overtime_penalty: Some(1000.),
+                        break_separation: None,
+                        ..create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))
+                    }],
+                    ..create_vehicle_with_capacity("overtime", vec![2])
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut types = solution.tours.iter().map(|tour| tour.type_id.as_str()).collect::<Vec<_>>();
+    types.sort();
+    assert_eq!(types, expected_types);
+}
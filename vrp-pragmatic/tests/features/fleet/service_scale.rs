@@ -0,0 +1,55 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_use_vehicle_service_scale, (vehicles, expected_vehicle), {
+    can_use_vehicle_service_scale_impl(vehicles, expected_vehicle);
+}}
+
+can_use_vehicle_service_scale! {
+    case01_slow_only: (vec![("slow", 2.)], None),
+    case02_fast_only: (vec![("fast", 0.5)], Some("fast_1")),
+    case03_slow_and_fast: (vec![("slow", 2.), ("fast", 0.5)], Some("fast_1")),
+}
+
+fn can_use_vehicle_service_scale_impl(vehicles: Vec<(&str, f64)>, expected_vehicle: Option<&str>) {
+    // NOTE job1 has to be served first, so job2's tight time window is met only when job1's service is fast
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(5, 5)], 10.),
+                create_delivery_job_with_times("job2", (6., 0.), vec![(0, 12)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vehicles
+                .into_iter()
+                .map(|(id, service_scale)| VehicleType {
+                    service_scale: Some(service_scale),
+                    ..create_vehicle_with_capacity(id, vec![10])
+                })
+                .collect(),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    match expected_vehicle {
+        Some(vehicle_id) => {
+            assert!(solution.unassigned.is_none());
+            assert_eq!(solution.tours.len(), 1);
+            assert_eq!(solution.tours[0].vehicle_id, vehicle_id);
+            assert_eq!(solution.tours[0].stops[1].schedule().departure, format_time(10.));
+            assert_eq!(solution.tours[0].stops[2].schedule().arrival, format_time(11.));
+            assert_eq!(solution.statistic.times.serving, 5);
+        }
+        None => {
+            assert_eq!(solution.tours.len(), 1);
+            assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+        }
+    }
+}
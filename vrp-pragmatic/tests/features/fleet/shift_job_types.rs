@@ -0,0 +1,95 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_shift(start: f64, end: f64, job_types: &[&str]) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        job_types: Some(job_types.iter().map(|job_type| job_type.to_string()).collect()),
+        ..create_default_vehicle_shift()
+    }
+}
+
+#[test]
+fn can_serve_only_allowed_job_types_within_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_pickup_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_pickup_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_shift(0., 499., &["delivery"]), create_shift(500., 1000., &["pickup"])],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    solution.tours.iter().for_each(|tour| {
+        let expected_type = if tour.shift_index == 0 { "delivery" } else { "pickup" };
+        let job_activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter(|activity| activity.job_id.starts_with("job"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(job_activities.len(), 2);
+        assert!(job_activities.iter().all(|activity| activity.activity_type == expected_type));
+    });
+}
+
+#[test]
+fn can_assign_break_within_shift_with_job_types() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
+                        places: vec![VehicleOptionalBreakPlace {
+                            duration: 2.0,
+                            location: Some((6., 0.).to_loc()),
+                            tag: None,
+                        }],
+                        policy: None,
+                    }]),
+                    ..create_shift(0., 1000., &["delivery"])
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    assert!(activities.contains(&"break"));
+}
@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_consolidate_stops_with_stop_cost, (stop_cost, expected_consolidated, expected_distance), {
+    can_consolidate_stops_with_stop_cost_impl(stop_cost, expected_consolidated, expected_distance);
+}}
+
+can_consolidate_stops_with_stop_cost! {
+    case01_no_stop_cost: (None, false, 42),
+    case02_high_stop_cost: (Some(100.), true, 61),
+}
+
+fn can_consolidate_stops_with_stop_cost_impl(
+    stop_cost: Option<f64>,
+    expected_consolidated: bool,
+    expected_distance: i64,
+) {
+    let create_vehicle = |id: &str, location: (f64, f64)| VehicleType {
+        costs: VehicleCosts { stop: stop_cost, ..create_default_vehicle_costs() },
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        ..create_vehicle_with_capacity(id, vec![2])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (0., 1.)),
+                create_delivery_job("job4", (20., 1.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("left", (0., 0.)), create_vehicle("right", (20., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    // NOTE job1 and job2 share the same location
+    let is_consolidated = solution.tours.iter().any(|tour| {
+        let job_ids = get_ids_from_tour(tour).into_iter().flatten().collect::<Vec<_>>();
+        job_ids.contains(&"job1".to_string()) && job_ids.contains(&"job2".to_string())
+    });
+    assert_eq!(is_consolidated, expected_consolidated);
+    assert_eq!(solution.statistic.distance, expected_distance);
+}
@@ -0,0 +1,45 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_keep_tours_close_to_target, (target, expected_tours), {
+    can_keep_tours_close_to_target_impl(target, expected_tours);
+}}
+
+can_keep_tours_close_to_target! {
+    case01_one_tour: (1, 1),
+    case02_two_tours: (2, 2),
+    case03_three_tours: (3, 3),
+}
+
+fn can_keep_tours_close_to_target_impl(target: usize, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeToursDeviation { target }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
@@ -0,0 +1,42 @@
+use crate::construction::enablers::VehicleTie;
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::collections::HashSet;
+
+#[test]
+fn can_use_vehicle_pool_specified_by_count() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=100).map(|idx| create_delivery_job(&format!("job{idx}"), (idx as f64, 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec![],
+                count: Some(100),
+                ..create_vehicle_with_capacity("pool", vec![1])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = (problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap();
+
+    let vehicle_ids = core_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.dimens.get_vehicle_id().cloned())
+        .collect::<HashSet<_>>();
+    assert_eq!(core_problem.fleet.vehicles.len(), 100);
+    assert_eq!(vehicle_ids.len(), 100);
+    assert!(vehicle_ids.contains("pool_1"));
+    assert!(vehicle_ids.contains("pool_100"));
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 100);
+    assert_eq!(solution.tours.iter().map(|tour| tour.vehicle_id.clone()).collect::<HashSet<_>>().len(), 100);
+}
@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_solve_and_check_problem_with_min_travel_values() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 2 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                min_travel: Some(MatrixMinTravel { duration: 2, distance: 3 }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 0, -1, 0, 0, 0, -1, 0, 0],
+        distances: vec![0, 0, -1, 0, 0, 0, -1, 0, 0],
+        error_codes: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 6);
+    assert_eq!(solution.statistic.times.driving, 4);
+    assert_eq!(solution.statistic.times.serving, 2);
+}
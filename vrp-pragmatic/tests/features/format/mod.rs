@@ -1 +1,2 @@
 mod location_index;
+mod min_travel;
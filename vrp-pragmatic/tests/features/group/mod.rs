@@ -1 +1,2 @@
 mod basic_group;
+mod strict_group;
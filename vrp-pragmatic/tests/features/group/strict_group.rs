@@ -0,0 +1,38 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_assign_group_all_or_nothing, (strict_groups, expected_unassigned), {
+    can_assign_group_all_or_nothing_impl(strict_groups, expected_unassigned);
+}}
+
+can_assign_group_all_or_nothing! {
+    case01_strict: (Some(true), vec!["job1", "job2", "job3"]),
+    case02_default: (None, vec!["job3"]),
+}
+
+fn can_assign_group_all_or_nothing_impl(strict_groups: Option<bool>, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_group("job1", (1., 0.), "order"),
+                create_delivery_job_with_group("job2", (2., 0.), "order"),
+                Job {
+                    skills: Some(all_of_skills(vec!["unknown".to_string()])),
+                    ..create_delivery_job_with_group("job3", (3., 0.), "order")
+                },
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            strict_groups,
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, expected_unassigned);
+}
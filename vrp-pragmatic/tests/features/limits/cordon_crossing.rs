@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem_with_cordon(max_crossings: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("out1", (5., 0.)),
+                create_delivery_job("in1", (10., 0.)),
+                create_delivery_job("out2", (15., 0.)),
+                create_delivery_job("in2", (20., 0.)),
+                create_delivery_job("out3", (25., 0.)),
+            ],
+            cordon: Some(Cordon { locations: vec![(10., 0.).to_loc(), (20., 0.).to_loc()], max_crossings }),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn is_inside(job_id: &str) -> bool {
+    job_id.starts_with("in")
+}
+
+fn get_crossings(tour: &Tour) -> usize {
+    let ids = get_ids_from_tour(tour).into_iter().flatten().collect::<Vec<_>>();
+
+    ids.windows(2).filter(|pair| is_inside(pair[0].as_str()) != is_inside(pair[1].as_str())).count()
+}
+
+#[test]
+fn can_serve_jobs_inside_cordon_within_max_crossings() {
+    let problem = create_problem_with_cordon(2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.tours.iter().all(|tour| get_crossings(tour) <= 2));
+    let inside_tours = solution
+        .tours
+        .iter()
+        .filter(|tour| get_ids_from_tour(tour).into_iter().flatten().any(|id| is_inside(id.as_str())))
+        .collect::<Vec<_>>();
+    assert_eq!(inside_tours.len(), 1);
+}
+
+#[test]
+fn can_leave_jobs_inside_cordon_unassigned_without_crossings() {
+    let problem = create_problem_with_cordon(0);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["in1", "in2"]);
+    assert!(solution
+        .unassigned
+        .iter()
+        .flatten()
+        .flat_map(|job| job.reasons.iter())
+        .all(|reason| reason.code == "CORDON_CONSTRAINT"));
+}
@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem_with_cost_limit(vehicle_ids: Vec<&str>, max_cost: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (6., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: Some(max_cost),
+                    max_open_jobs: None,
+                    max_late_activities: None,
+                    max_idle_time: None,
+                    max_shift_span: None,
+                    max_interruptions: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_redistribute_jobs_due_to_max_cost() {
+    let problem = create_problem_with_cost_limit(vec!["v_1", "v_2"], 35.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.tours.iter().all(|tour| tour.statistic.cost <= 35.));
+    assert_eq!(solution.statistic.cost, 31. + 35.);
+}
+
+#[test]
+fn can_keep_single_tour_without_max_cost_violation() {
+    let problem = create_problem_with_cost_limit(vec!["v_1", "v_2"], 36.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.cost, 36.);
+}
+
+#[test]
+fn can_leave_surplus_job_unassigned_due_to_max_cost() {
+    let problem = create_problem_with_cost_limit(vec!["v_1"], 35.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_COST_CONSTRAINT");
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_job_by_max_detour_time, (max_detour_time, expected_unassigned), {
+    can_limit_job_by_max_detour_time_impl(max_detour_time, expected_unassigned);
+}}
+
+can_limit_job_by_max_detour_time! {
+    case01_exceeded: (50., Some("MAX_DETOUR_CONSTRAINT")),
+    case02_within_limit: (500., None),
+}
+
+fn can_limit_job_by_max_detour_time_impl(max_detour_time: f64, expected_unassigned: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_index("job1", 0),
+                Job { max_detour_time: Some(max_detour_time), ..create_delivery_job_with_index("job2", 1) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 2 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    // NOTE job2 is cheap to reach in terms of distance, but expensive in terms of time
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 100, 1, 100, 0, 100, 1, 100, 0],
+        distances: vec![0, 1, 1, 1, 0, 1, 1, 1, 0],
+        error_codes: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    match expected_unassigned {
+        Some(code) => {
+            let unassigned = solution.unassigned.expect("should have unassigned job");
+            assert_eq!(unassigned.len(), 1);
+            assert_eq!(unassigned[0].job_id, "job2");
+            assert_eq!(unassigned[0].reasons[0].code, code);
+            assert_eq!(get_ids_from_tour(&solution.tours[0]).concat(), vec!["departure", "job1"]);
+        }
+        None => assert!(solution.unassigned.is_none()),
+    }
+}
@@ -4,7 +4,17 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            max_cost: None,
+            max_open_jobs: None,
+            max_late_activities: None,
+            max_idle_time: None,
+            max_shift_span: None,
+            max_interruptions: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -0,0 +1,66 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_interruptions, (max_interruptions, expected_reloads, expected_unassigned), {
+    can_limit_interruptions_impl(max_interruptions, expected_reloads, expected_unassigned);
+}}
+
+can_limit_interruptions! {
+    case01_at_limit: (Some(1), 1, vec!["job3"]),
+    case02_above_limit: (Some(2), 2, vec![]),
+    case03_no_limit: (None, 2, vec![]),
+}
+
+fn can_limit_interruptions_impl(
+    max_interruptions: Option<usize>,
+    expected_reloads: usize,
+    expected_unassigned: Vec<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![create_default_reload(), create_default_reload()]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_open_jobs: None,
+                    max_late_activities: None,
+                    max_idle_time: None,
+                    max_shift_span: None,
+                    max_interruptions,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    let reloads = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "reload")
+        .count();
+    assert_eq!(unassigned, expected_unassigned);
+    assert_eq!(reloads, expected_reloads);
+}
@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_target_time(id: &str, location: (f64, f64)) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            target_time: Some("1970-01-01T00:00:10Z".to_string()),
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+parameterized_test! {can_limit_max_late_activities, (max_late_activities, expected_tours), {
+    can_limit_max_late_activities_impl(max_late_activities, expected_tours);
+}}
+
+can_limit_max_late_activities! {
+    case01_no_late_activities: (Some(0), 2),
+    case02_one_late_activity: (Some(1), 1),
+    case03_no_limit: (None, 1),
+}
+
+fn can_limit_max_late_activities_impl(max_late_activities: Option<usize>, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_target_time("job1", (10., 0.)),
+                create_delivery_job_with_target_time("job2", (-10., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_open_jobs: None,
+                    max_late_activities,
+                    max_idle_time: None,
+                    max_shift_span: None,
+                    max_interruptions: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
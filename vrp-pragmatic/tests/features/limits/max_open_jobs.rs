@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_open_jobs(tour: &Tour) -> Vec<usize> {
+    let job_ids = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "pickup" || activity.activity_type == "delivery")
+        .map(|activity| (activity.job_id.clone(), activity.activity_type == "pickup"))
+        .collect::<Vec<_>>();
+
+    job_ids
+        .iter()
+        .scan(0, |open, (_, is_pickup)| {
+            *open = if *is_pickup { *open + 1 } else { *open - 1 };
+            Some(*open)
+        })
+        .collect()
+}
+
+parameterized_test! {can_limit_max_open_jobs, (max_open_jobs, expected_max_open), {
+    can_limit_max_open_jobs_impl(max_open_jobs, expected_max_open);
+}}
+
+can_limit_max_open_jobs! {
+    case01_one_open_job: (Some(1), 1),
+    case02_two_open_jobs: (Some(2), 2),
+    case03_no_limit: (None, 3),
+}
+
+fn can_limit_max_open_jobs_impl(max_open_jobs: Option<usize>, expected_max_open: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (10., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (11., 0.)),
+                create_pickup_delivery_job("job3", (3., 0.), (12., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_open_jobs,
+                    max_late_activities: None,
+                    max_idle_time: None,
+                    max_shift_span: None,
+                    max_interruptions: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_open_jobs(&solution.tours[0]).into_iter().max(), Some(expected_max_open));
+}
@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_shift_span, (max_shift_span, expected_unassigned), {
+    can_limit_shift_span_impl(max_shift_span, expected_unassigned);
+}}
+
+can_limit_shift_span! {
+    case01_tight_span: (Some(10.), vec!["job4"]),
+    case02_span_ignores_depot_travel: (Some(44.), vec![]),
+    case03_no_limit: (None, vec![]),
+}
+
+fn can_limit_shift_span_impl(max_shift_span: Option<f64>, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (11., 0.)),
+                create_delivery_job("job3", (12., 0.)),
+                create_delivery_job("job4", (50., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_open_jobs: None,
+                    max_late_activities: None,
+                    max_idle_time: None,
+                    max_shift_span,
+                    max_interruptions: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned, expected_unassigned);
+    assert!(solution
+        .unassigned
+        .iter()
+        .flatten()
+        .flat_map(|job| job.reasons.iter())
+        .all(|reason| reason.code == "MAX_SHIFT_SPAN_CONSTRAINT"));
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_job_by_max_wait_time, (max_wait_time, expected), {
+    can_limit_job_by_max_wait_time_impl(max_wait_time, expected);
+}}
+
+can_limit_job_by_max_wait_time! {
+    case01_resequenced: (40., Ok(vec!["departure", "job2", "job1", "arrival"])),
+    case02_unassigned: (10., Err("MAX_WAIT_CONSTRAINT")),
+}
+
+fn can_limit_job_by_max_wait_time_impl(max_wait_time: f64, expected: Result<Vec<&str>, &str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    max_wait_time: Some(max_wait_time),
+                    ..create_delivery_job_with_times("job1", (5., 0.), vec![(50, 600)], 1.)
+                },
+                create_delivery_job("job2", (10., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                // NOTE departure time cannot be shifted to avoid waiting
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    match expected {
+        Ok(ids) => {
+            assert!(solution.unassigned.is_none());
+            assert_eq!(get_ids_from_tour(&solution.tours[0]).concat(), ids);
+        }
+        Err(code) => {
+            let unassigned = solution.unassigned.expect("should have unassigned job");
+            assert_eq!(unassigned.len(), 1);
+            assert_eq!(unassigned[0].job_id, "job1");
+            assert_eq!(unassigned[0].reasons[0].code, code);
+        }
+    }
+}
@@ -1,3 +1,11 @@
+mod cordon_crossing;
+mod max_cost;
+mod max_detour_time;
 mod max_distance;
 mod max_duration;
+mod max_interruptions;
+mod max_late_activities;
+mod max_open_jobs;
+mod max_shift_span;
+mod max_wait_time;
 mod tour_size;
@@ -2,5 +2,6 @@ mod basic_multi_job;
 mod basic_replacement;
 mod basic_service;
 mod limited_capacity;
+mod multi_visit_job;
 mod single_type_places;
 mod unassigned_multi_job;
@@ -0,0 +1,70 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_visit_job(id: &str, skill: &str, order: i32) -> Job {
+    Job {
+        visit: Some(JobVisit { id: "install".to_string(), order }),
+        ..create_delivery_job_with_skills(id, (10., 0.), all_of_skills(vec![skill.to_string()]))
+    }
+}
+
+fn create_crew_vehicle(skill: &str, start: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(start, start)],
+        skills: Some(vec![skill.to_string()]),
+        ..create_default_vehicle(skill)
+    }
+}
+
+parameterized_test! {can_serve_visits_in_order_by_different_crews, (electrician_start, expected), {
+    can_serve_visits_in_order_by_different_crews_impl(electrician_start, expected);
+}}
+
+can_serve_visits_in_order_by_different_crews! {
+    case01_electrician_arrives_after_plumber: ((-10., 0.), Some(20.)),
+    case02_electrician_arrives_before_plumber: ((5., 0.), None),
+}
+
+fn can_serve_visits_in_order_by_different_crews_impl(electrician_start: (f64, f64), expected: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_visit_job("electrics", "electrician", 2), create_visit_job("plumbing", "plumber", 1)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_crew_vehicle("plumber", (0., 0.)),
+                create_crew_vehicle("electrician", electrician_start),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let get_job_tour = |job_id: &str| {
+        solution.tours.iter().find(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter()).any(|activity| activity.job_id == job_id)
+        })
+    };
+    let plumber_tour = get_job_tour("plumbing").expect("plumbing should be assigned");
+    assert_eq!(plumber_tour.vehicle_id, "plumber_1");
+    assert_eq!(plumber_tour.stops[1].schedule().departure, format_time(11.));
+
+    match expected {
+        Some(arrival) => {
+            assert!(solution.unassigned.is_none());
+            let electrician_tour = get_job_tour("electrics").expect("electrics should be assigned");
+            assert_eq!(electrician_tour.vehicle_id, "electrician_1");
+            assert_eq!(electrician_tour.stops[1].schedule().arrival, format_time(arrival));
+        }
+        None => {
+            let unassigned = solution.unassigned.expect("should have unassigned job");
+            assert_eq!(unassigned.len(), 1);
+            assert_eq!(unassigned[0].job_id, "electrics");
+        }
+    }
+}
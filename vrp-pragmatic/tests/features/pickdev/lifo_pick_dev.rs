@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_deliver_jobs_in_lifo_order, (lifo, expected), {
+    can_deliver_jobs_in_lifo_order_impl(lifo, expected);
+}}
+
+can_deliver_jobs_in_lifo_order! {
+    case01_without_lifo: (None, vec!["departure", "job1", "job2", "job1", "job2"]),
+    case02_with_lifo: (Some(true), vec!["departure", "job1", "job2", "job2", "job1"]),
+}
+
+fn can_deliver_jobs_in_lifo_order_impl(lifo: Option<bool>, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                lifo,
+                ..create_vehicle_with_capacity("my_vehicle", vec![2])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
@@ -1,3 +1,4 @@
 mod basic_pick_dev;
+mod lifo_pick_dev;
 mod mixed_pick_dev_simple_jobs;
 mod relation_pick_dev;
@@ -0,0 +1,46 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_tier(id: &str, location: (f64, f64), tier: &str) -> Job {
+    Job { tier: Some(tier.to_string()), ..create_delivery_job(id, location) }
+}
+
+#[test]
+fn can_serve_gold_jobs_earlier_and_drop_bronze_ones() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_tier("bronze1", (0., 1.), "bronze"),
+                create_delivery_job_with_tier("bronze2", (0., 5.), "bronze"),
+                create_delivery_job_with_tier("gold", (3., 0.), "gold"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![ServiceTiers {
+                tiers: vec![
+                    ServiceTierOptions { name: "gold".to_string(), penalty: Some(10.), earliness: Some(1.) },
+                    ServiceTierOptions { name: "bronze".to_string(), penalty: Some(1.), earliness: None },
+                ],
+            }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned.iter().flatten().map(|job| job.job_id.clone()).collect::<Vec<_>>(),
+        vec!["bronze2".to_string()]
+    );
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        vec!["departure", "gold", "bronze1", "arrival"]
+    );
+}
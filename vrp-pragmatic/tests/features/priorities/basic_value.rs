@@ -10,7 +10,7 @@ parameterized_test! {can_prefer_jobs_with_more_value, objectives, {
 can_prefer_jobs_with_more_value! {
     case01: Some(vec![
         vec![MinimizeUnassignedJobs { breaks: None }],
-        vec![MaximizeValue { breaks: None }],
+        vec![MaximizeValue { breaks: None, budget: None, priority_base: None }],
         vec![MinimizeCost],
     ]),
     case02: None,
@@ -0,0 +1,50 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_select_most_valuable_jobs_within_fleet_cost_budget() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_value("job_low", (3., 0.), 1.),
+                create_delivery_job_with_value("job_high", (-4., 0.), 100.),
+                create_delivery_job_with_value("job_mid", (0., 5.), 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v_1".to_string(), "v_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MaximizeValue { breaks: None, budget: Some(30.), priority_base: None }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job_high"], vec!["arrival"]]);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.cost, 27.);
+    let mut unassigned = solution
+        .unassigned
+        .unwrap()
+        .into_iter()
+        .map(|job| (job.job_id, job.reasons[0].code.clone()))
+        .collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(
+        unassigned,
+        vec![
+            ("job_low".to_string(), "FLEET_COST_BUDGET_CONSTRAINT".to_string()),
+            ("job_mid".to_string(), "FLEET_COST_BUDGET_CONSTRAINT".to_string()),
+        ]
+    );
+}
@@ -1,2 +1,5 @@
 mod basic_order;
+mod basic_service_tiers;
 mod basic_value;
+mod budget_value;
+mod priority_value;
@@ -0,0 +1,50 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_jobs_with_higher_priority_tier, (priority_base, expected_served), {
+    can_prefer_jobs_with_higher_priority_tier_impl(priority_base, expected_served);
+}}
+
+can_prefer_jobs_with_higher_priority_tier! {
+    case01_default_base: (None, vec!["tier1"]),
+    case02_high_base: (Some(10.), vec!["tier1"]),
+    case03_low_base: (Some(2.), vec!["tier3_1", "tier3_2", "tier3_3", "tier3_4", "tier3_5"]),
+}
+
+fn can_prefer_jobs_with_higher_priority_tier_impl(priority_base: Option<f64>, expected_served: Vec<&str>) {
+    let create_job_with_priority = |id: &str, location: (f64, f64), demand: i32, priority: usize| Job {
+        priority: Some(priority),
+        ..create_delivery_job_with_demand(id, location, vec![demand])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: std::iter::once(create_job_with_priority("tier1", (6., 0.), 5, 1))
+                .chain((1..=5).map(|idx| create_job_with_priority(&format!("tier3_{idx}"), (idx as f64, 0.), 1, 3)))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![5], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives: priority_base.map(|priority_base| {
+            vec![
+                vec![MaximizeValue { breaks: None, budget: None, priority_base: Some(priority_base) }],
+                vec![MinimizeUnassignedJobs { breaks: None }],
+                vec![MinimizeCost],
+            ]
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let mut served = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    served.retain(|id| id != "departure" && id != "arrival");
+    served.sort();
+    assert_eq!(served, expected_served);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 6 - expected_served.len());
+}
@@ -37,6 +37,9 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -44,6 +44,9 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -36,6 +36,9 @@ fn can_use_reloads_with_different_locations() {
                             ..create_default_reload()
                         },
                     ]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_return_to_reload_place_instead_of_long_idle, (max_idle_time, expected_activities), {
+    can_return_to_reload_place_instead_of_long_idle_impl(max_idle_time, expected_activities);
+}}
+
+can_return_to_reload_place_instead_of_long_idle! {
+    case01_no_limit: (None, vec!["departure", "job1", "job2", "arrival"]),
+    case02_long_limit: (Some(100.), vec!["departure", "job1", "job2", "arrival"]),
+    case03_short_limit: (Some(10.), vec!["departure", "job1", "reload", "job2", "arrival"]),
+}
+
+fn can_return_to_reload_place_instead_of_long_idle_impl(max_idle_time: Option<f64>, expected_activities: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", (6., 0.), vec![(100, 200)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![create_default_reload()]),
+                    ..create_default_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_open_jobs: None,
+                    max_late_activities: None,
+                    max_idle_time,
+                    max_shift_span: None,
+                    max_interruptions: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    let activities = stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(activities, expected_activities);
+}
@@ -23,6 +23,9 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -1,2 +1,5 @@
 mod basic_skill;
+mod preferred_skills;
+mod skill_validity;
+mod skill_with_demand_and_duration;
 mod unassigned_due_to_skills;
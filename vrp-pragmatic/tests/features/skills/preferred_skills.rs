@@ -0,0 +1,57 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_vehicle_with_preferred_skills, (has_objective, expected_vehicle_id), {
+    can_prefer_vehicle_with_preferred_skills_impl(has_objective, expected_vehicle_id);
+}}
+
+can_prefer_vehicle_with_preferred_skills! {
+    case01_without_objective: (false, "junior_1"),
+    case02_with_objective: (true, "senior_1"),
+}
+
+fn can_prefer_vehicle_with_preferred_skills_impl(has_objective: bool, expected_vehicle_id: &str) {
+    let objectives = if has_objective {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![PreferredSkills { penalty: None }], vec![MinimizeCost]]
+    } else {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills(
+                "job1",
+                (5., 0.),
+                JobSkills { all_of: None, one_of: None, none_of: None, preferred: Some(vec!["senior".to_string()]) },
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "junior".to_string(),
+                    vehicle_ids: vec!["junior_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((4., 0.), (4., 0.))],
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "senior".to_string(),
+                    vehicle_ids: vec!["senior_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    skills: Some(vec!["senior".to_string()]),
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle_id);
+}
@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_reject_job_when_skill_lapsed, (validity_end, expected_unassigned), {
+    can_reject_job_when_skill_lapsed_impl(validity_end, expected_unassigned);
+}}
+
+can_reject_job_when_skill_lapsed! {
+    case01_valid_for_both: (100., vec![]),
+    case02_lapsed_for_far: (10., vec!["job2"]),
+    case03_lapsed_for_both: (2., vec!["job1", "job2"]),
+}
+
+fn can_reject_job_when_skill_lapsed_impl(validity_end: f64, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", (5., 0.), all_of_skills(vec!["certified".to_string()])),
+                create_delivery_job_with_skills("job2", (20., 0.), all_of_skills(vec!["certified".to_string()])),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: Some(vec!["certified".to_string()]),
+                skill_validity: Some(vec![VehicleSkillValidity {
+                    skill: "certified".to_string(),
+                    times: vec![vec![format_time(1.), format_time(validity_end)]],
+                }]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, expected_unassigned);
+    assert!(solution
+        .unassigned
+        .iter()
+        .flatten()
+        .flat_map(|job| job.reasons.iter())
+        .all(|reason| reason.code == "SKILL_CONSTRAINT"));
+}
@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_combined_job() -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { duration: 10., ..create_job_place((1., 0.), None) }],
+            demand: Some(vec![3]),
+            ..create_task((1., 0.), None)
+        }]),
+        skills: Some(all_of_skills(vec!["fridge".to_string()])),
+        ..create_job("job1")
+    }
+}
+
+parameterized_test! {can_enforce_demand_skill_and_duration_together, (capacity, skills, shift_end, expected), {
+    can_enforce_demand_skill_and_duration_together_impl(capacity, skills, shift_end, expected);
+}}
+
+can_enforce_demand_skill_and_duration_together! {
+    case01_all_satisfied: (5, Some("fridge"), 1000., Ok((3, 10))),
+    case02_not_enough_capacity: (2, Some("fridge"), 1000., Err("CAPACITY_CONSTRAINT")),
+    case03_missing_skill: (5, None, 1000., Err("SKILL_CONSTRAINT")),
+    case04_not_enough_time_for_service: (5, Some("fridge"), 11., Err("TIME_WINDOW_CONSTRAINT")),
+}
+
+fn can_enforce_demand_skill_and_duration_together_impl(
+    capacity: i32,
+    skills: Option<&str>,
+    shift_end: f64,
+    expected: Result<(i32, i64), &str>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_combined_job()], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(shift_end), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                skills: skills.map(|skill| vec![skill.to_string()]),
+                ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    match expected {
+        Ok((load, serving)) => {
+            assert!(solution.unassigned.is_none());
+            assert_eq!(solution.tours.len(), 1);
+            let tour = solution.tours.first().unwrap();
+            assert_eq!(tour.stops.first().unwrap().load(), &vec![load]);
+            let job_stop = tour.stops.get(1).unwrap();
+            assert_eq!(job_stop.load(), &vec![0]);
+            assert_eq!(job_stop.schedule().arrival, format_time(1.));
+            assert_eq!(job_stop.schedule().departure, format_time(1. + serving as f64));
+            assert_eq!(solution.statistic.times.serving, serving);
+            assert_eq!(solution.statistic.cost, 10. + 2. + 2. + serving as f64);
+        }
+        Err(code) => {
+            assert!(solution.tours.is_empty());
+            let unassigned = solution.unassigned.expect("should have unassigned job");
+            assert_eq!(unassigned.len(), 1);
+            assert_eq!(unassigned[0].reasons.iter().map(|reason| reason.code.as_str()).collect::<Vec<_>>(), vec![code]);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_target_time(id: &str, location: (f64, f64), target_time: Option<&str>) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            target_time: target_time.map(|time| time.to_string()),
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+parameterized_test! {can_serve_job_close_to_target_time, (target_time, expected), {
+    can_serve_job_close_to_target_time_impl(target_time, expected);
+}}
+
+can_serve_job_close_to_target_time! {
+    case01_early_target: ("1970-01-01T00:00:10Z", vec!["departure", "job1", "job2", "arrival"]),
+    case02_late_target: ("1970-01-01T00:00:30Z", vec!["departure", "job2", "job1", "arrival"]),
+}
+
+fn can_serve_job_close_to_target_time_impl(target_time: &str, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_target_time("job1", (10., 0.), Some(target_time)),
+                create_delivery_job_with_target_time("job2", (20., 0.), None),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeTours],
+            vec![MinimizeTargetDeviation { earliness: None, lateness: None }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_delivery_job_with_blackouts(id: &str, location: (f64, f64), blackouts: Vec<(f64, f64)>) -> Job {
+    let blackouts = blackouts.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect();
+
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                times: Some(vec![vec![format_time(0.), format_time(100.)]]),
+                blackouts: Some(blackouts),
+                ..create_job_place(location, None)
+            }],
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn get_service_start(tour: &Tour, job_id: &str) -> String {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.job_id == job_id)
+        .map(|(stop, activity)| {
+            activity.time.as_ref().map_or(stop.schedule().arrival.clone(), |time| time.start.clone())
+        })
+        .unwrap()
+}
+
+#[test]
+fn can_serve_job_outside_blackout_period() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_blackouts("job1", (10., 0.), vec![(5., 30.)]),
+                create_delivery_job("job2", (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour).into_iter().flatten().collect::<Vec<_>>(),
+        vec!["departure", "job2", "job1", "arrival"]
+    );
+    assert!(parse_time(&get_service_start(tour, "job1")) >= 30.);
+}
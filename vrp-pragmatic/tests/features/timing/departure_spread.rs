@@ -0,0 +1,62 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn get_departures(solution: &Solution) -> Vec<f64> {
+    let mut departures = solution
+        .tours
+        .iter()
+        .map(|tour| parse_time(&tour.stops.first().unwrap().schedule().departure))
+        .collect::<Vec<_>>();
+    departures.sort_by(|a, b| a.total_cmp(b));
+
+    departures
+}
+
+parameterized_test! {can_spread_departures, (options, expected), {
+    can_spread_departures_impl(options, expected);
+}}
+
+can_spread_departures! {
+    case01_default: (None, vec![0., 0., 0.]),
+    case02_stagger: (Some(DepartureSpreadOptions::Stagger { interval: 10. }), vec![0., 10., 20.]),
+}
+
+fn can_spread_departures_impl(options: Option<DepartureSpreadOptions>, expected: Vec<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (-10., 0.)),
+                create_delivery_job("job3", (0., 10.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: options.map(|options| {
+            vec![
+                vec![MinimizeUnassignedJobs { breaks: None }],
+                vec![MinimizeTours],
+                vec![MinimizeCost],
+                vec![DepartureSpread { options }],
+            ]
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 3);
+    assert_eq!(get_departures(&solution), expected);
+}
@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_time_travel_through_gate, (job_times, expected), {
+    can_time_travel_through_gate_impl(job_times, expected);
+}}
+
+can_time_travel_through_gate! {
+    case01_wait_for_gate_to_open: (vec![(0, 100)], Some(11.)),
+    case02_miss_job_time_window: (vec![(0, 5)], None),
+    case03_use_second_gate_window: (vec![(40, 100)], Some(40.)),
+}
+
+fn can_time_travel_through_gate_impl(job_times: Vec<(i32, i32)>, expected: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (1., 0.), job_times, 0.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            gates: Some(vec![TravelGate {
+                id: "bridge".to_string(),
+                from: (0., 0.).to_loc(),
+                to: (1., 0.).to_loc(),
+                times: vec![vec![format_time(10.), format_time(20.)], vec![format_time(30.), format_time(50.)]],
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    match expected {
+        Some(service_start) => {
+            assert!(solution.unassigned.is_none());
+            assert_eq!(solution.tours.len(), 1);
+            let job_stop = solution.tours[0].stops.get(1).unwrap();
+            assert_eq!(job_stop.schedule().departure, format_time(service_start));
+        }
+        None => {
+            assert!(solution.tours.is_empty());
+            assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+        }
+    }
+}
@@ -1,4 +1,10 @@
 mod basic_multiple_times;
+mod basic_target_time;
 mod basic_waiting_time;
+mod blackout_periods;
+mod departure_spread;
+mod gated_travel;
+mod same_location_stops;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod weighted_lateness;
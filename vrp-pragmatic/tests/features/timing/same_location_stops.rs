@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_split_same_location_jobs_into_stops, (job2_times, expected), {
+    can_split_same_location_jobs_into_stops_impl(job2_times, expected);
+}}
+
+can_split_same_location_jobs_into_stops! {
+    case01_contiguous: ((10, 100), vec![vec!["departure"], vec!["job1", "job2"], vec!["arrival"]]),
+    case02_disjoint: ((50, 100), vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["arrival"]]),
+}
+
+fn can_split_same_location_jobs_into_stops_impl(job2_times: (i32, i32), expected: Vec<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(10, 12)], 5.),
+                create_delivery_job_with_times("job2", (10., 0.), vec![job2_times], 5.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let expected_len = expected.len();
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        expected
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+    let locations = solution.tours[0].stops.iter().filter_map(|stop| stop.as_point()).map(|stop| &stop.location);
+    assert!(locations.skip(1).take(expected_len - 2).all(|location| *location == (10., 0.).to_loc()));
+}
@@ -0,0 +1,50 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_value_and_target(id: &str, location: (f64, f64), value: f64, target_time: &str) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask { target_time: Some(target_time.to_string()), ..create_task(location, None) }]),
+        value: Some(value),
+        ..create_job(id)
+    }
+}
+
+parameterized_test! {can_serve_high_value_job_on_time, (values, expected), {
+    can_serve_high_value_job_on_time_impl(values, expected);
+}}
+
+can_serve_high_value_job_on_time! {
+    case01_far_job_has_more_value: ((1., 100.), vec!["departure", "job2", "job1", "arrival"]),
+    case02_close_job_has_more_value: ((100., 1.), vec!["departure", "job1", "job2", "arrival"]),
+}
+
+fn can_serve_high_value_job_on_time_impl(values: (f64, f64), expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_value_and_target("job1", (10., 0.), values.0, "1970-01-01T00:00:10Z"),
+                create_delivery_job_with_value_and_target("job2", (11., 0.), values.1, "1970-01-01T00:00:11Z"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeTours],
+            vec![MinimizeWeightedLateness],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
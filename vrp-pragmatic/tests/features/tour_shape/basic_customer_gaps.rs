@@ -0,0 +1,58 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_customer(id: &str, location: (f64, f64), customer_id: Option<&str>) -> Job {
+    Job { customer_id: customer_id.map(|id| id.to_string()), ..create_delivery_job(id, location) }
+}
+
+parameterized_test! {can_serve_same_customer_jobs_together, (has_objective, expected), {
+    can_serve_same_customer_jobs_together_impl(has_objective, expected);
+}}
+
+can_serve_same_customer_jobs_together! {
+    case01_without_objective: (false, vec!["departure", "job1", "job2", "job3"]),
+    case02_with_objective: (true, vec!["departure", "job1", "job3", "job2"]),
+}
+
+fn can_serve_same_customer_jobs_together_impl(has_objective: bool, expected: Vec<&str>) {
+    let objectives = if has_objective {
+        vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeTours],
+            vec![MinimizeCustomerGaps { penalty: Some(100.) }],
+            vec![MinimizeCost],
+        ]
+    } else {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeTours], vec![MinimizeCost]]
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_customer("job1", (10., 0.), Some("customer1")),
+                create_delivery_job_with_customer("job2", (20., 0.), None),
+                create_delivery_job_with_customer("job3", (30., 0.), Some("customer1")),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(),
+        expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
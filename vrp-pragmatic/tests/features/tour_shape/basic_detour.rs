@@ -0,0 +1,43 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_total_detour, (has_objective, expected_tours), {
+    can_minimize_total_detour_impl(has_objective, expected_tours);
+}}
+
+can_minimize_total_detour! {
+    case01_without_objective: (false, 1),
+    case02_with_objective: (true, 2),
+}
+
+fn can_minimize_total_detour_impl(has_objective: bool, expected_tours: usize) {
+    let objectives = if has_objective {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeDetour], vec![MinimizeCost]]
+    } else {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                costs: VehicleCosts { fixed: Some(30.), ..create_default_vehicle_costs() },
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
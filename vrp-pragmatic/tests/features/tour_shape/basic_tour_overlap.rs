@@ -0,0 +1,75 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use hashbrown::HashMap;
+
+#[test]
+fn can_separate_tours_with_overlap_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job_n1", (1., 1.)),
+                create_delivery_job("job_n2", (2., 1.)),
+                create_delivery_job("job_n3", (3., 1.)),
+                create_delivery_job("job_n4", (4., 1.)),
+                create_delivery_job("job_s1", (1., -1.)),
+                create_delivery_job("job_s2", (2., -1.)),
+                create_delivery_job("job_s3", (3., -1.)),
+                create_delivery_job("job_s4", (4., -1.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![4])
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeTours],
+            vec![MinimizeOverlap],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let locations = problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| (job.id.clone(), get_job_tasks(job).next().unwrap().places[0].location.to_lat_lng()))
+        .collect::<HashMap<_, _>>();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    let tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            get_ids_from_tour(tour)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| locations.get(&id).cloned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    tours.iter().enumerate().for_each(|(idx, points)| {
+        let (min_lat, max_lat, min_lng, max_lng) =
+            points.iter().fold((f64::MAX, f64::MIN, f64::MAX, f64::MIN), |acc, &(lat, lng)| {
+                (acc.0.min(lat), acc.1.max(lat), acc.2.min(lng), acc.3.max(lng))
+            });
+
+        let overlap = tours
+            .iter()
+            .enumerate()
+            .filter(|(other_idx, _)| *other_idx != idx)
+            .flat_map(|(_, other)| other.iter())
+            .filter(|&&(lat, lng)| lat >= min_lat && lat <= max_lat && lng >= min_lng && lng <= max_lng)
+            .count();
+
+        assert_eq!(overlap, 0);
+    });
+}
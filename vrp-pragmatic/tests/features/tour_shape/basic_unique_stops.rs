@@ -0,0 +1,80 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type_at(id: &str, location: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(0.), latest: None, location: location.to_loc() },
+            ..create_default_open_vehicle_shift()
+        }],
+        ..create_vehicle_with_capacity(id, vec![2])
+    }
+}
+
+parameterized_test! {can_consolidate_visits_of_shared_location, (has_objective, expected), {
+    can_consolidate_visits_of_shared_location_impl(has_objective, expected);
+}}
+
+can_consolidate_visits_of_shared_location! {
+    case01_without_objective: (false, vec![
+        ("v1_1", vec!["departure", "job1", "shared"]),
+        ("v2_1", vec!["departure", "job2", "shared"]),
+    ]),
+    case02_with_objective: (true, vec![
+        ("v1_1", vec!["departure", "shared"]),
+        ("v2_1", vec!["departure", "job2", "job1"]),
+    ]),
+}
+
+fn can_consolidate_visits_of_shared_location_impl(has_objective: bool, expected: Vec<(&str, Vec<&str>)>) {
+    let objectives = if has_objective {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeUniqueStops], vec![MinimizeCost]]
+    } else {
+        vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (2., 0.)),
+                create_delivery_job("job2", (9., 0.)),
+                create_delivery_job("shared1", (5., 0.)),
+                create_delivery_job("shared2", (5., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_at("v1", (0., 0.)), create_vehicle_type_at("v2", (10., 0.))],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let ids = get_ids_from_tour(tour)
+                .into_iter()
+                .map(|stop| {
+                    stop.first().map_or("", |id| if id.starts_with("shared") { "shared" } else { id }).to_string()
+                })
+                .collect::<Vec<_>>();
+            (tour.vehicle_id.clone(), ids)
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(
+        tours,
+        expected
+            .into_iter()
+            .map(|(vehicle_id, ids)| (vehicle_id.to_string(), ids.into_iter().map(|id| id.to_string()).collect()))
+            .collect::<Vec<_>>()
+    );
+}
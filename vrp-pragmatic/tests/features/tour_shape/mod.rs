@@ -1 +1,5 @@
+mod basic_customer_gaps;
+mod basic_detour;
 mod basic_tour_compactness;
+mod basic_tour_overlap;
+mod basic_unique_stops;
@@ -75,7 +75,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone(), order, target_time: None }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -83,14 +83,23 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand, order: None }
+                ], demand, order: None, target_time: None }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            customer_id: None,
+            max_detour_time: None,
+            max_wait_time: None,
+            area: None,
+            visit: None,
+            temperature: None,
+            tier: None,
+            cleaning: None,
+            priority: None,
         }
     }
 }
@@ -137,6 +146,15 @@ prop_compose! {
             value,
             group,
             compatibility,
+            customer_id: None,
+            max_detour_time: None,
+            max_wait_time: None,
+            area: None,
+            visit: None,
+            temperature: None,
+            tier: None,
+            cleaning: None,
+            priority: None,
         }
     }
 }
@@ -152,7 +170,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand, order, target_time: None }
     }
 }
 
@@ -169,7 +187,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, blackouts: None }
     }
 }
 
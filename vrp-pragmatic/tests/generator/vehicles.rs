@@ -32,7 +32,12 @@ prop_compose! {
             shifts,
             capacity,
             skills,
+            skill_validity: None,
             limits,
+            lifo: None,
+            count: None,
+            temperature: None,
+            service_scale: None,
         }
     }
 }
@@ -56,6 +61,7 @@ prop_compose! {
           duration,
           tag,
           resource_id: None,
+          min_load_factor: None,
         }
     }
 }
@@ -86,7 +92,10 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
-          reloads
+          reloads,
+          job_types: None,
+          overtime_penalty: None,
+          break_separation: None,
         }
     }
 }
@@ -107,7 +116,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, gates: None }
     }
 }
 
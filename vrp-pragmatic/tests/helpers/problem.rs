@@ -5,11 +5,11 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, blackouts: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None, target_time: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -23,6 +23,15 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        customer_id: None,
+        max_detour_time: None,
+        max_wait_time: None,
+        area: None,
+        visit: None,
+        temperature: None,
+        tier: None,
+        cleaning: None,
+        priority: None,
     }
 }
 
@@ -36,6 +45,7 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: Some(order),
+            target_time: None,
         }]),
         ..create_job(id)
     }
@@ -47,6 +57,7 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            target_time: None,
         }]),
         group: Some(group.to_string()),
         ..create_job(id)
@@ -59,6 +70,7 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            target_time: None,
         }]),
         compatibility: Some(compatibility.to_string()),
         ..create_job(id)
@@ -79,6 +91,7 @@ pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duratio
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            target_time: None,
         }]),
         ..create_job(id)
     }
@@ -90,6 +103,7 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            target_time: None,
         }]),
         ..create_job(id)
     }
@@ -138,6 +152,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            target_time: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -147,6 +162,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand),
             order: None,
+            target_time: None,
         }]),
 
         ..create_job(id)
@@ -156,9 +172,16 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                blackouts: None,
+            }],
             demand: Some(vec![1]),
             order: None,
+            target_time: None,
         }]),
         ..create_job(id)
     }
@@ -180,6 +203,7 @@ pub fn create_multi_job(
                 }],
                 demand: Some(demand),
                 order: None,
+                target_time: None,
             })
             .collect::<Vec<_>>();
 
@@ -194,7 +218,14 @@ pub fn create_multi_job(
 }
 
 pub fn create_default_reload() -> VehicleReload {
-    VehicleReload { times: None, location: (0., 0.).to_loc(), duration: 2.0, tag: None, resource_id: None }
+    VehicleReload {
+        times: None,
+        location: (0., 0.).to_loc(),
+        duration: 2.0,
+        tag: None,
+        resource_id: None,
+        min_load_factor: None,
+    }
 }
 
 pub fn create_default_vehicle_shift() -> VehicleShift {
@@ -208,6 +239,9 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         dispatch: None,
         breaks: None,
         reloads: None,
+        job_types: None,
+        overtime_penalty: None,
+        break_separation: None,
     }
 }
 
@@ -218,11 +252,14 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         dispatch: None,
         breaks: None,
         reloads: None,
+        job_types: None,
+        overtime_penalty: None,
+        break_separation: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., distance_tiers: None, stop: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -250,16 +287,26 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         shifts: vec![create_default_vehicle_shift()],
         capacity,
         skills: None,
+        skill_validity: None,
         limits: None,
+        lifo: None,
+        count: None,
+        temperature: None,
+        service_scale: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        gates: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, min_travel: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
@@ -267,13 +314,13 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, cordon: None, strict_groups: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, gates: None },
         objectives: None,
     }
 }
@@ -315,7 +362,7 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, preferred: None }
 }
 
 fn convert_times(times: &Vec<(i32, i32)>) -> Option<Vec<Vec<String>>> {
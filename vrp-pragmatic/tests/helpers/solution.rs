@@ -63,6 +63,8 @@ fn create_stop_with_activity_impl(
             time: None,
             job_tag,
             commute: None,
+            cost: None,
+            cumulative_cost: None,
         }],
         parking: None,
     })
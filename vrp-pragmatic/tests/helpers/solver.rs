@@ -14,17 +14,20 @@ use vrp_core::utils::{Environment, Parallelism};
 
 /// Runs solver with cheapest insertion heuristic.
 pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    get_core_solution(problem, matrices, true, solve_core_with_cheapest_insertion)
+}
+
+/// Runs solver with cheapest insertion heuristic on core problem.
+pub fn solve_core_with_cheapest_insertion(problem: Arc<CoreProblem>) -> CoreSolution {
     let environment = Arc::new(Environment::default());
-    get_core_solution(problem, matrices, true, |problem: Arc<CoreProblem>| {
-        let population = create_elitism_population(problem.goal.clone(), environment.clone());
-        let refinement_ctx =
-            RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
-
-        RecreateWithCheapest::new(environment.random.clone())
-            .run(&refinement_ctx, InsertionContext::new(problem, environment))
-            .solution
-            .into()
-    })
+    let population = create_elitism_population(problem.goal.clone(), environment.clone());
+    let refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+        .solution
+        .into()
 }
 
 /// Runs solver with default metaheuristic and default amount of generations.
@@ -70,7 +73,7 @@ pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize
     })
 }
 
-fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
+pub fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
             .unwrap(),
@@ -90,7 +93,7 @@ fn get_core_solution<F: FnOnce(Arc<CoreProblem>) -> CoreSolution>(
 
     let core_solution = solve_func(core_problem.clone());
 
-    let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, None));
+    let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, &Default::default()));
 
     if perform_check {
         if let Some(err) =
@@ -107,7 +110,17 @@ fn get_core_solution<F: FnOnce(Arc<CoreProblem>) -> CoreSolution>(
         }
     }
 
-    sort_all_data(format_solution)
+    assert_lower_bound(format_solution)
+}
+
+/// Checks that lower bound doesn't exceed solution cost and removes extras to simplify test assertions.
+pub fn assert_lower_bound(solution: Solution) -> Solution {
+    let mut solution = sort_all_data(solution);
+
+    let lower_bound = solution.extras.take().and_then(|extras| extras.lower_bound).expect("no lower bound");
+    assert!(lower_bound <= solution.statistic.cost + 1E-6, "lower bound {lower_bound} exceeds solution cost");
+
+    solution
 }
 
 /// Sorts some solution properties in lexicographical order to simplify test assertions.
@@ -22,9 +22,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job1")
                     },
@@ -38,9 +40,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job2")
                     },
@@ -54,9 +58,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job3")
                     },
@@ -70,9 +76,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job4")
                     },
@@ -86,9 +94,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job5")
                     },
@@ -102,9 +112,11 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                blackouts: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            target_time: None,
                         }]),
                         ..create_job("job6")
                     },
@@ -116,7 +128,13 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts {
+                        fixed: Some(20.),
+                        distance: 0.002,
+                        time: 0.003,
+                        distance_tiers: None,
+                        stop: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -138,10 +156,18 @@ fn can_handle_properly_invalid_break_removal() {
                             policy: None,
                         }]),
                         reloads: None,
+                        job_types: None,
+                        overtime_penalty: None,
+                        break_separation: None,
                     }],
                     capacity: vec![5],
                     skills: None,
+                    skill_validity: None,
                     limits: None,
+                    lifo: None,
+                    count: None,
+                    temperature: None,
+                    service_scale: None,
                 }],
                 ..create_default_fleet()
             },
@@ -13,9 +13,11 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             duration: 0.,
             times: None,
             tag: Some(tag.to_string()),
+            blackouts: None,
         }],
         demand: Some(vec![1]),
         order: None,
+        target_time: None,
     };
 
     let problem = Problem {
@@ -36,7 +38,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0 },
+                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0, distance_tiers: None, stop: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: format_time(0.),
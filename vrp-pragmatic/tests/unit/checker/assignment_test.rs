@@ -133,9 +133,11 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    blackouts: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
+                target_time: None,
             })
             .collect()
     };
@@ -322,6 +324,7 @@ fn can_detect_dispatch_violations() {
                         location: (1., 0.).to_loc(),
                         limits: vec![VehicleDispatchLimit { max: 1, start: format_time(1.), end: format_time(2.) }],
                         tag: None,
+                        stage: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -432,3 +435,73 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".to_owned()));
 }
+
+parameterized_test! {can_detect_visit_order_violations, (first_time, second_time, expected), {
+    can_detect_visit_order_violations_impl(first_time, second_time, expected);
+}}
+
+can_detect_visit_order_violations! {
+    case01_in_order: (Some((1, 2)), Some((3, 4)), Ok(())),
+    case02_same_time: (Some((1, 2)), Some((2, 3)), Ok(())),
+    case03_overlap: (Some((2, 4)), Some((3, 5)), Err("job visit order is not respected: 'install'".to_owned())),
+    case04_wrong_order: (Some((5, 6)), Some((1, 2)), Err("job visit order is not respected: 'install'".to_owned())),
+    case05_only_second: (None, Some((1, 2)), Err("job visit order is not respected: 'install'".to_owned())),
+    case06_only_first: (Some((1, 2)), None, Ok(())),
+}
+
+fn can_detect_visit_order_violations_impl(
+    first_time: Option<(i32, i32)>,
+    second_time: Option<(i32, i32)>,
+    expected: Result<(), String>,
+) {
+    let create_visit_job = |job_id: &str, order: i32| Job {
+        visit: Some(JobVisit { id: "install".to_string(), order }),
+        ..create_delivery_job(job_id, (1., 0.))
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_visit_job("job1", 1), create_visit_job("job2", 2)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, job_id: &str, (start, end): (i32, i32)| Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                job_id,
+                "delivery",
+                (1., 0.),
+                0,
+                (format_time(start as f64).as_str(), format_time(end as f64).as_str()),
+                1,
+            ),
+        ],
+        ..create_empty_tour()
+    };
+    let tours = first_time
+        .map(|time| create_tour("v1", "job1", time))
+        .into_iter()
+        .chain(second_time.map(|time| create_tour("v2", "job2", time)))
+        .collect();
+    let solution = Solution { tours, ..create_empty_solution() };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_visits(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::format::problem::PragmaticProblem;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_report_capacity_as_binding, (capacity, expected), {
+    can_report_capacity_as_binding_impl(capacity, expected);
+}}
+
+can_report_capacity_as_binding! {
+    case01_saturated: (2, true),
+    case02_not_saturated: (3, false),
+}
+
+fn can_report_capacity_as_binding_impl(capacity: i32, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap();
+
+    let bindings = get_binding_constraints(&ctx).unwrap();
+
+    assert_eq!(bindings.len(), 1);
+    assert_eq!(bindings[0].constraints.contains(&BindingConstraint::Capacity), expected);
+}
+
+#[test]
+fn can_report_time_window_and_shift_time_as_binding() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 5)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(11.), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap();
+
+    let bindings = get_binding_constraints(&ctx).unwrap();
+
+    assert_eq!(bindings.len(), 1);
+    assert_eq!(
+        bindings[0].constraints,
+        vec![BindingConstraint::TimeWindow { job_id: "job1".to_string() }, BindingConstraint::ShiftTime]
+    );
+}
@@ -85,6 +85,9 @@ fn can_check_breaks_impl(
                         policy: None,
                     }]),
                     reloads: None,
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -101,6 +104,8 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        cost: None,
+        cumulative_cost: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -110,6 +115,8 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            cost: None,
+            cumulative_cost: None,
         });
     }
 
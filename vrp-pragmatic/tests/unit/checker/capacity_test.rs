@@ -45,6 +45,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -90,6 +93,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: None,
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -98,6 +103,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                     ],
                 }),
@@ -117,6 +124,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         time: None,
                         job_tag: None,
                         commute: None,
+                        cost: None,
+                        cumulative_cost: None,
                     }],
                 }),
                 Stop::Point(PointStop {
@@ -139,6 +148,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -150,6 +161,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                     ],
                 }),
@@ -232,6 +245,8 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: None,
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                         Activity {
                             job_id: "job1".to_string(),
@@ -240,6 +255,8 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            cost: None,
+                            cumulative_cost: None,
                         },
                     ],
                 }),
@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 use vrp_core::models::examples::create_example_problem;
 
 fn create_test_problem(limits: Option<VehicleLimits>) -> Problem {
@@ -60,7 +61,17 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        max_cost: None,
+        max_open_jobs: None,
+        max_late_activities: None,
+        max_idle_time: None,
+        max_shift_span: None,
+        max_interruptions: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -72,8 +83,17 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        max_cost: None,
+        max_open_jobs: None,
+        max_late_activities: None,
+        max_idle_time: None,
+        max_shift_span: None,
+        max_interruptions: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -203,3 +223,67 @@ fn can_check_shift_time() {
 
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".to_owned()));
 }
+
+parameterized_test! {can_check_lifo_order, (lifo, job_ids, expected), {
+    can_check_lifo_order_impl(lifo, job_ids, expected);
+}}
+
+can_check_lifo_order! {
+    case01_nested: (Some(true), &["job1", "job2", "job2", "job1"], Ok(())),
+    case02_crossed: (Some(true), &["job1", "job2", "job1", "job2"], Err(())),
+    case03_sequential: (Some(true), &["job1", "job1", "job2", "job2"], Ok(())),
+    case04_crossed_no_lifo: (None, &["job1", "job2", "job1", "job2"], Ok(())),
+}
+
+fn can_check_lifo_order_impl(lifo: Option<bool>, job_ids: &[&str], expected: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["some_real_vehicle".to_string()],
+                lifo,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let mut seen = HashSet::new();
+    let stops = once(create_stop_with_activity(
+        "departure",
+        "departure",
+        (0., 0.),
+        0,
+        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+        0,
+    ))
+    .chain(job_ids.iter().enumerate().map(|(idx, job_id)| {
+        let (activity_type, tag) = if seen.insert(*job_id) { ("pickup", "p1") } else { ("delivery", "d1") };
+        let time = format_time(idx as f64 + 1.);
+        create_stop_with_activity_with_tag(
+            job_id,
+            activity_type,
+            (idx as f64 + 1., 0.),
+            0,
+            (time.as_str(), time.as_str()),
+            idx as i64 + 1,
+            tag,
+        )
+    }))
+    .collect();
+    let solution = create_test_solution(Statistic::default(), stops);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(
+        result,
+        expected.map_err(|_| "lifo order violation, vehicle id 'some_real_vehicle', shift index: 0".to_string())
+    );
+}
@@ -100,10 +100,18 @@ mod single {
                             duration: 2.0,
                             ..create_default_reload()
                         }]),
+                        job_types: None,
+                        overtime_penalty: None,
+                        break_separation: None,
                     }],
                     capacity: vec![5],
                     skills: None,
+                    skill_validity: None,
                     limits: None,
+                    lifo: None,
+                    count: None,
+                    temperature: None,
+                    service_scale: None,
                 }],
                 ..create_default_fleet()
             },
@@ -155,6 +163,8 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    cost: None,
+                                    cumulative_cost: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -163,6 +173,8 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    cost: None,
+                                    cumulative_cost: None,
                                 },
                             ],
                         }),
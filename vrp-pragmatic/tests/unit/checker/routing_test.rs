@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 use vrp_core::models::examples::create_example_problem;
 
 fn create_test_problem() -> Problem {
@@ -48,6 +49,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        cost: None,
+                        cumulative_cost: None,
                     }],
                 }),
                 Stop::Point(PointStop {
@@ -63,6 +66,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        cost: None,
+                        cumulative_cost: None,
                     }],
                 }),
                 create_stop_with_activity(
@@ -168,3 +173,55 @@ fn can_check_solution_statistic() {
         )])
     );
 }
+
+parameterized_test! {can_correct_duration_for_dispatch_at_start, (dispatch_durations, duration, expected), {
+    can_correct_duration_for_dispatch_at_start_impl(dispatch_durations, duration, expected);
+}}
+
+can_correct_duration_for_dispatch_at_start! {
+    case01_no_dispatch: (vec![], 10, true),
+    case02_single_dispatch: (vec![2.], 12, true),
+    case03_two_dispatches: (vec![2., 3.], 15, true),
+    case04_two_dispatches_only_first: (vec![2., 3.], 12, false),
+}
+
+fn can_correct_duration_for_dispatch_at_start_impl(dispatch_durations: Vec<f64>, duration: i64, expected: bool) {
+    let departure = Activity {
+        job_id: "departure".to_string(),
+        activity_type: "departure".to_string(),
+        location: None,
+        time: None,
+        job_tag: None,
+        commute: None,
+        cost: None,
+        cumulative_cost: None,
+    };
+    let (dispatches, _) = dispatch_durations.iter().fold((vec![], 0.), |(mut acc, start), dispatch_duration| {
+        let end = start + dispatch_duration;
+        acc.push(Activity {
+            job_id: "dispatch".to_string(),
+            activity_type: "dispatch".to_string(),
+            time: Some(Interval { start: format_time(start), end: format_time(end) }),
+            ..departure.clone()
+        });
+        (acc, end)
+    });
+    let tour = Tour {
+        vehicle_id: "my_vehicle_1".to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops: vec![Stop::Point(PointStop {
+            location: (0., 0.).to_loc(),
+            time: Schedule { arrival: format_time(0.), departure: format_time(0.) },
+            distance: 0,
+            parking: None,
+            load: vec![0],
+            activities: once(departure).chain(dispatches).collect(),
+        })],
+        statistic: Statistic { duration, ..Statistic::default() },
+    };
+
+    let result = check_tour_statistic(10, 0, 0, &tour, false);
+
+    assert_eq!(result.is_ok(), expected);
+}
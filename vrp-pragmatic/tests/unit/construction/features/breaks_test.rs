@@ -90,3 +90,111 @@ fn can_skip_merge_breaks_impl(source: Job, candidate: Job, expected: Result<(),
 
     assert_eq!(result, expected);
 }
+
+fn create_sequenced_break(order: usize, separation: Duration) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single
+        .dimens
+        .set_job_id(format!("break{order}"))
+        .set_job_type("break".to_string())
+        .set_vehicle_id("v1".to_string())
+        .set_shift_index(0)
+        .set_break_sequence(BreakSequence { order, separation });
+
+    Arc::new(single)
+}
+
+fn create_activity_with_schedule(job: Arc<Single>, arrival: Timestamp, departure: Timestamp) -> Activity {
+    let mut activity = create_activity_with_job_at_location(job, DEFAULT_JOB_LOCATION);
+    activity.place.duration = departure - arrival;
+    activity.schedule = Schedule::new(arrival, departure);
+
+    activity
+}
+
+parameterized_test! {can_keep_breaks_sequence, (index, separation, expected), {
+    can_keep_breaks_sequence_impl(index, separation, expected);
+}}
+
+can_keep_breaks_sequence! {
+    case01_before_previous_break: (1, 0., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_right_after_previous_break: (2, 0., None),
+    case03_too_close_to_previous_break: (2, 5., ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_separated_from_previous_break: (3, 5., None),
+    case05_too_far_separation: (3, 25., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_keep_breaks_sequence_impl(index: usize, separation: Duration, expected: Option<ConstraintViolation>) {
+    let fleet = test_fleet();
+    let route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity_with_schedule(create_single("job1"), 10., 20.),
+                create_activity_with_schedule(create_sequenced_break(1, separation), 20., 30.),
+                create_activity_with_schedule(create_single("job2"), 40., 50.),
+            ],
+        ),
+        RouteState::default(),
+    );
+    let target = create_activity_with_schedule(create_sequenced_break(2, separation), 0., 10.);
+    let constraint = create_optional_break_feature("break", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_remove_out_of_sequence_break, (orders, second_arrival, separation, expected_removed), {
+    can_remove_out_of_sequence_break_impl(orders, second_arrival, separation, expected_removed);
+}}
+
+can_remove_out_of_sequence_break! {
+    case01_ordered_and_separated: ((1, 2), 35., 5., None),
+    case02_wrong_order: ((2, 1), 35., 5., Some("break1")),
+    case03_too_close: ((1, 2), 32., 5., Some("break2")),
+}
+
+fn can_remove_out_of_sequence_break_impl(
+    orders: (usize, usize),
+    second_arrival: Timestamp,
+    separation: Duration,
+    expected_removed: Option<&str>,
+) {
+    let fleet = test_fleet();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![RouteContext::new_with_state(
+            create_route_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    create_activity_with_schedule(create_single("job1"), 10., 20.),
+                    create_activity_with_schedule(create_sequenced_break(orders.0, separation), 20., 30.),
+                    create_activity_with_schedule(
+                        create_sequenced_break(orders.1, separation),
+                        second_arrival,
+                        second_arrival + 10.,
+                    ),
+                ],
+            ),
+            RouteState::default(),
+        )],
+        ..create_solution_context_for_fleet(&fleet)
+    };
+    let feature = create_optional_break_feature("break", VIOLATION_CODE).unwrap();
+
+    feature.state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    let removed =
+        solution_ctx.unassigned.keys().map(|job| job.dimens().get_job_id().unwrap().clone()).collect::<Vec<_>>();
+    assert_eq!(removed, expected_removed.map(|id| id.to_string()).into_iter().collect::<Vec<_>>());
+}
@@ -8,7 +8,7 @@ const VIOLATION_CODE: i32 = 1;
 const STATE_KEY: i32 = 2;
 
 fn create_feature() -> Feature {
-    create_compatibility_feature("compatibility", VIOLATION_CODE, STATE_KEY).unwrap()
+    create_compatibility_feature("compatibility", VIOLATION_CODE, STATE_KEY, CompatibilityMode::Hard).unwrap()
 }
 
 fn create_test_single(compatibility: Option<String>) -> Arc<Single> {
@@ -102,3 +102,65 @@ fn can_merge_jobs_impl(
         (Ok(result), Ok(expected)) => assert_eq!(result, expected),
     }
 }
+
+fn create_soft_feature() -> Feature {
+    create_compatibility_feature("compatibility", VIOLATION_CODE, STATE_KEY, CompatibilityMode::Soft).unwrap()
+}
+
+fn create_soft_test_single(compatibility: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    match compatibility {
+        Some("cleaning") => single.dimens.set_job_cleaning(Some(true)),
+        compatibility => single.dimens.set_job_compatibility(compatibility.map(|v| v.to_string())),
+    };
+
+    Arc::new(single)
+}
+
+fn create_soft_test_route_ctx(compatibilities: Vec<Option<&str>>) -> RouteContext {
+    let activities = compatibilities
+        .into_iter()
+        .enumerate()
+        .map(|(idx, compatibility)| create_activity_with_job_at_location(create_soft_test_single(compatibility), idx))
+        .collect();
+    let mut route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(&test_fleet(), "v1", activities),
+        RouteState::default(),
+    );
+    create_soft_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_estimate_soft_compatibility, (index, target_compat, expected), {
+    can_estimate_soft_compatibility_impl(index, target_compat, expected);
+}}
+
+can_estimate_soft_compatibility! {
+    case_01_same_before_change: (1, Some("food"), 0.),
+    case_02_change_moved_earlier: (1, Some("junk"), 0.),
+    case_03_cleaning_before_change: (1, Some("cleaning"), -1.),
+    case_04_after_cleaning: (3, Some("food"), 0.),
+    case_05_new_change: (2, Some("food"), 1.),
+    case_06_no_compatibility: (1, None, 0.),
+}
+
+fn can_estimate_soft_compatibility_impl(index: usize, target_compat: Option<&str>, expected: Cost) {
+    let route_ctx = create_soft_test_route_ctx(vec![Some("food"), Some("junk"), Some("cleaning")]);
+    let target = create_activity_with_job_at_location(create_soft_test_single(target_compat), 10);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = create_soft_feature().objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_hard_constraint_in_soft_mode() {
+    assert!(create_soft_feature().constraint.is_none());
+}
@@ -11,7 +11,7 @@ const VIOLATION_CODE: ViolationCode = 1;
 const STATE_KEY: StateKey = 2;
 
 fn create_test_group_feature(total_jobs: usize) -> Feature {
-    create_group_feature("group", total_jobs, VIOLATION_CODE, STATE_KEY).unwrap()
+    create_group_feature("group", total_jobs, VIOLATION_CODE, STATE_KEY, None).unwrap()
 }
 
 fn get_total_jobs(routes: &[(&str, Vec<Option<&str>>)]) -> usize {
@@ -200,3 +200,46 @@ fn can_merge_groups_impl(source: Job, candidate: Job, expected: Result<(), i32>)
 
     assert_eq!(result, expected);
 }
+
+const ALL_OR_NOTHING_CODE: ViolationCode = 3;
+
+parameterized_test! {can_unassign_partial_groups, (is_required, expected_groups), {
+    can_unassign_partial_groups_impl(is_required, expected_groups);
+}}
+
+can_unassign_partial_groups! {
+    case_01_partial_group: (false, vec![Some("g2")]),
+    case_02_job_still_required: (true, vec![Some("g1"), Some("g1"), Some("g2")]),
+}
+
+fn can_unassign_partial_groups_impl(is_required: bool, expected_groups: Vec<Option<&str>>) {
+    let fleet = create_test_fleet();
+    let routes = vec![("v1", vec![Some("g1"), Some("g1"), Some("g2")])];
+    let mut solution_ctx = create_test_solution_context(0, &fleet, routes);
+    let unassigned_job = Job::Single(create_test_single(Some("g1")));
+    solution_ctx.unassigned.insert(unassigned_job.clone(), UnassignmentInfo::Simple(VIOLATION_CODE));
+    if is_required {
+        solution_ctx.required.push(unassigned_job);
+    }
+    let state =
+        create_group_feature("group", 4, VIOLATION_CODE, STATE_KEY, Some(ALL_OR_NOTHING_CODE)).unwrap().state.unwrap();
+
+    // NOTE second call checks that state is stable
+    state.accept_solution_state(&mut solution_ctx);
+    state.accept_solution_state(&mut solution_ctx);
+
+    let groups = solution_ctx.routes[0]
+        .route()
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .map(|single| single.dimens.get_job_group().cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(groups, expected_groups.iter().map(|group| group.map(|g| g.to_string())).collect::<Vec<_>>());
+    let all_or_nothing_count = solution_ctx
+        .unassigned
+        .values()
+        .filter(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == ALL_OR_NOTHING_CODE))
+        .count();
+    assert_eq!(all_or_nothing_count, 3 - expected_groups.len());
+}
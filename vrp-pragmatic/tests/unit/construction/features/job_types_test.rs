@@ -0,0 +1,77 @@
+use super::*;
+use crate::construction::enablers::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{MoveContext, RouteContext, RouteState};
+use vrp_core::models::problem::Fleet;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_job_with_type(job_type: &str) -> Job {
+    create_job_with_type_and_vehicle(job_type, None)
+}
+
+fn create_job_with_type_and_vehicle(job_type: &str, vehicle_id: Option<&str>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_type(job_type.to_string());
+    if let Some(vehicle_id) = vehicle_id {
+        single.dimens.set_vehicle_id(vehicle_id.to_string());
+    }
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_check_shift_job_types, (job_type, shift_job_types, expected), {
+    can_check_shift_job_types_impl(create_job_with_type(job_type), shift_job_types, expected);
+}}
+
+can_check_shift_job_types! {
+    case01_no_restrictions: ("delivery", None, None),
+    case02_allowed: ("delivery", Some(vec!["delivery"]), None),
+    case03_allowed_one_of: ("pickup", Some(vec!["delivery", "pickup"]), None),
+    case04_not_allowed: ("pickup", Some(vec!["delivery"]), ConstraintViolation::fail(VIOLATION_CODE)),
+    case05_empty: ("pickup", Some(vec![]), ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+parameterized_test! {can_skip_vehicle_bound_jobs, (job_type, shift_job_types), {
+    let job = create_job_with_type_and_vehicle(job_type, Some("v1"));
+
+    can_check_shift_job_types_impl(job, shift_job_types, None);
+}}
+
+can_skip_vehicle_bound_jobs! {
+    case01_break: ("break", Some(vec!["delivery"])),
+    case02_reload: ("reload", Some(vec!["delivery"])),
+    case03_dispatch: ("dispatch", Some(vec![])),
+}
+
+fn can_check_shift_job_types_impl(job: Job, shift_job_types: Option<Vec<&str>>, expected: Option<ConstraintViolation>) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(job_types) = shift_job_types {
+        vehicle.dimens.set_shift_job_types(job_types.iter().map(|job_type| job_type.to_string()).collect());
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let constraint = create_shift_job_types_feature("shift_job_types", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(&create_solution_context_for_fleet(&fleet), &route_ctx, &job));
+
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_merge_job_types, (source, candidate, expected), {
+    let constraint = create_shift_job_types_feature("shift_job_types", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job_with_type(source), create_job_with_type(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}}
+
+can_merge_job_types! {
+    case01_same: ("delivery", "delivery", Ok(())),
+    case02_different: ("delivery", "pickup", Err(VIOLATION_CODE)),
+}
@@ -0,0 +1,32 @@
+use super::*;
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
+
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    create_matrix_transport_cost(vec![MatrixData::new(
+        0,
+        None,
+        vec![0., -1., 10., 10., 0., -1., 10., 10., 0.],
+        vec![0., -1., 5., 5., 0., -1., 5., 5., 0.],
+    )])
+    .unwrap()
+}
+
+parameterized_test! {can_check_reachability, (from, to, expected), {
+    can_check_reachability_impl(from, to, expected);
+}}
+
+can_check_reachability! {
+    case01_unreachable: (0, 1, false),
+    case02_reachable_backward: (1, 0, true),
+    case03_reachable: (0, 2, true),
+    case04_unreachable: (1, 2, false),
+    case05_same_location: (1, 1, true),
+}
+
+fn can_check_reachability_impl(from: Location, to: Location, expected: bool) {
+    let transport = create_transport();
+
+    let result = is_reachable(transport.as_ref(), &Profile::default(), from, to);
+
+    assert_eq!(result, expected);
+}
@@ -8,6 +8,15 @@ use vrp_core::models::problem::*;
 
 const VIOLATION_CODE: ViolationCode = 1;
 
+fn create_test_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    // NOTE locations are placed on a line, so travel duration is equal to difference between them
+    let size = 16;
+    let values =
+        (0..size).flat_map(|from| (0..size).map(move |to| (from as f64 - to as f64).abs())).collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, values.clone(), values)]).unwrap()
+}
+
 fn create_activity_with_demand(
     job_id: &str,
     pickup: (i32, i32),
@@ -55,6 +64,7 @@ fn can_handle_reload_jobs_with_merge() {
         "reload",
         Box::new(|name, multi_trip| create_capacity_limit_with_multi_trip_feature(name, VIOLATION_CODE, multi_trip)),
         Box::new(|_| SingleDimLoad::default()),
+        create_test_transport(),
     );
     let constraint = feature.unwrap().constraint.unwrap();
 
@@ -191,6 +201,7 @@ fn can_remove_trivial_reloads_when_used_from_capacity_constraint_impl(
         "reload",
         Box::new(|name, multi_trip| create_capacity_limit_with_multi_trip_feature(name, VIOLATION_CODE, multi_trip)),
         Box::new(move |capacity| *capacity * threshold),
+        create_test_transport(),
     )
     .unwrap();
     let variant = GoalContext::new(&[feature], &[], &[]).unwrap();
@@ -230,7 +241,11 @@ fn can_handle_multi_trip_needed_for_multi_dim_load_impl(
     expected: bool,
 ) {
     let threshold = 1.;
-    let multi_trip = create_reload_multi_trip::<MultiDimLoad>(Box::new(move |capacity| *capacity * threshold), None);
+    let multi_trip = create_reload_multi_trip::<MultiDimLoad>(
+        Box::new(move |capacity| *capacity * threshold),
+        create_test_transport(),
+        None,
+    );
     let (mut route_ctx, _) = create_route_context_with_fleet(vehicle_capacity, Vec::default());
     let (route, state) = route_ctx.as_mut();
     state.put_activity_state(MAX_PAST_CAPACITY_KEY, route.tour.end().unwrap(), MultiDimLoad::new(current_capacity));
@@ -239,3 +254,70 @@ fn can_handle_multi_trip_needed_for_multi_dim_load_impl(
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_restrict_reload_by_min_load_factor, (delivery_demand, min_load_factor, expected), {
+    can_restrict_reload_by_min_load_factor_impl(delivery_demand, min_load_factor, expected);
+}}
+
+can_restrict_reload_by_min_load_factor! {
+    case01_lightly_loaded: (2, Some(0.5), Some(ConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+    case02_fully_loaded: (10, Some(0.5), None),
+    case03_exactly_loaded: (5, Some(0.5), None),
+    case04_no_factor: (2, None, None),
+}
+
+fn can_restrict_reload_by_min_load_factor_impl(
+    delivery_demand: i32,
+    min_load_factor: Option<f64>,
+    expected: Option<ConstraintViolation>,
+) {
+    let (mut route_ctx, _) = create_route_context_with_fleet(vec![10], vec![delivery("d1", (delivery_demand, 0))]);
+    let capacity_feature = create_simple_reload_multi_trip_feature::<MultiDimLoad>(
+        "reload",
+        Box::new(|name, multi_trip| create_capacity_limit_with_multi_trip_feature(name, VIOLATION_CODE, multi_trip)),
+        Box::new(|capacity| *capacity * 0.9),
+        create_test_transport(),
+    )
+    .unwrap();
+    capacity_feature.state.unwrap().accept_route_state(&mut route_ctx);
+    let mut target = reload("r1");
+    Arc::get_mut(target.job.as_mut().unwrap()).unwrap().dimens.set_reload_min_load_factor(min_load_factor);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let feature = create_reload_min_load_feature::<MultiDimLoad>("reload_min_load", VIOLATION_CODE).unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_idle_reload, (next_location, expected), {
+    can_detect_idle_reload_impl(next_location, expected);
+}}
+
+can_detect_idle_reload! {
+    case01_waiting_after_reload: (0, true),
+    case02_travelling_after_reload: (10, false),
+}
+
+fn can_detect_idle_reload_impl(next_location: Location, expected: bool) {
+    let transport = create_test_transport();
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_capacity(MultiDimLoad::new(vec![10])).set_max_idle_time(8.);
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(vehicle)]);
+    let mut next = delivery("d2", (1, 0));
+    next.place.location = next_location;
+    next.place.time = TimeWindow::new(15., 100.);
+    let route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(&fleet, "v1", vec![delivery("d1", (1, 0)), reload("r1"), next]),
+        RouteState::default(),
+    );
+
+    let result = is_idle_reload(&route_ctx, transport.as_ref(), 2);
+
+    assert_eq!(result, expected);
+}
@@ -1,23 +1,30 @@
 use crate::construction::enablers::create_typed_actor_groups;
-use crate::construction::enablers::{JobTie, VehicleTie};
-use crate::construction::features::skills::create_skills_feature;
+use crate::construction::enablers::{JobTie, OnlyVehicleActivityCost, VehicleTie};
+use crate::construction::features::skills::{create_preferred_skills_feature, create_skills_feature};
 use crate::construction::features::JobSkills;
 use crate::helpers::*;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::iter::FromIterator;
 use std::sync::Arc;
-use vrp_core::construction::heuristics::{MoveContext, RouteContext, RouteState};
-use vrp_core::models::problem::{Fleet, Job, Vehicle};
+use vrp_core::construction::heuristics::{ActivityContext, MoveContext, RouteContext, RouteState};
+use vrp_core::models::common::{Location, Schedule, TimeWindow};
+use vrp_core::models::problem::*;
+use vrp_core::models::solution::Activity;
 use vrp_core::models::{ConstraintViolation, ViolationCode};
 
 const VIOLATION_CODE: ViolationCode = 1;
 
+fn create_test_activity_cost() -> Arc<dyn ActivityCost + Send + Sync> {
+    Arc::new(OnlyVehicleActivityCost::new_with_service_scale())
+}
+
 fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>, none_of: Option<Vec<&str>>) -> Job {
     let mut single = create_single_with_location(None);
     single.dimens.set_job_skills(Some(JobSkills {
         all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+        preferred: None,
     }));
 
     Job::Single(Arc::new(single))
@@ -33,6 +40,15 @@ fn create_vehicle_with_skills(skills: Option<Vec<&str>>) -> Vehicle {
     vehicle
 }
 
+fn create_test_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    // NOTE locations are placed on a line, so travel duration is equal to difference between them
+    let size = 16;
+    let values =
+        (0..size).flat_map(|from| (0..size).map(move |to| (from as f64 - to as f64).abs())).collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, values.clone(), values)]).unwrap()
+}
+
 fn failure() -> Option<ConstraintViolation> {
     ConstraintViolation::fail(VIOLATION_CODE)
 }
@@ -88,7 +104,11 @@ fn can_check_skills_impl(
     );
     let route_ctx =
         RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
-    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+    let constraint =
+        create_skills_feature("skills", VIOLATION_CODE, create_test_transport(), create_test_activity_cost())
+            .unwrap()
+            .constraint
+            .unwrap();
 
     let actual = constraint.evaluate(&MoveContext::route(
         &create_solution_context_for_fleet(&fleet),
@@ -121,9 +141,168 @@ can_merge_skills! {
 }
 
 fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), i32>) {
-    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+    let constraint =
+        create_skills_feature("skills", VIOLATION_CODE, create_test_transport(), create_test_activity_cost())
+            .unwrap()
+            .constraint
+            .unwrap();
 
     let result = constraint.merge(source, candidate).map(|_| ());
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_check_skill_validity, (location, all_of, one_of, existing, expected), {
+    can_check_skill_validity_impl(location, all_of, one_of, existing, expected);
+}}
+
+can_check_skill_validity! {
+    case01_valid: (3, Some(vec!["s1"]), None, None, None),
+    case02_lapsed: (10, Some(vec!["s1"]), None, None, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_without_validity: (10, Some(vec!["s2"]), None, None, None),
+    case04_no_skills: (10, None, None, None, None),
+    case05_one_of_valid: (10, None, Some(vec!["s1", "s2"]), None, None),
+    case06_one_of_lapsed: (10, None, Some(vec!["s1", "s3"]), None, ConstraintViolation::skip(VIOLATION_CODE)),
+    case07_next_valid: (2, None, None, Some(4), None),
+    case08_next_lapsed: (8, None, None, Some(4), ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_check_skill_validity_impl(
+    location: Location,
+    all_of: Option<Vec<&str>>,
+    one_of: Option<Vec<&str>>,
+    existing: Option<Location>,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle = create_vehicle_with_skills(Some(vec!["s1", "s2"]));
+    vehicle
+        .dimens
+        .set_vehicle_skill_validity(HashMap::from_iter(vec![("s1".to_string(), vec![TimeWindow::new(0., 5.)])]));
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let activities = existing
+        .map(|existing| {
+            let job = create_job_with_skills(Some(vec!["s1"]), None, None).to_single().clone();
+            vec![Activity {
+                schedule: Schedule::new(existing as f64, existing as f64),
+                ..create_activity_with_job_at_location(job, existing)
+            }]
+        })
+        .unwrap_or_default();
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", activities), RouteState::default());
+    let target = create_activity_with_job_at_location(
+        create_job_with_skills(all_of, one_of, None).to_single().clone(),
+        location,
+    );
+    let constraint =
+        create_skills_feature("skills", VIOLATION_CODE, create_test_transport(), create_test_activity_cost())
+            .unwrap()
+            .constraint
+            .unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        },
+    ));
+
+    assert_eq!(actual, expected)
+}
+
+parameterized_test! {can_check_skill_validity_with_scaled_service, (service_scale, expected), {
+    can_check_skill_validity_with_scaled_service_impl(service_scale, expected);
+}}
+
+can_check_skill_validity_with_scaled_service! {
+    case01_not_scaled: (None, None),
+    case02_scaled_next_lapsed: (Some(3.), ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_check_skill_validity_with_scaled_service_impl(
+    service_scale: Option<f64>,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle = create_vehicle_with_skills(Some(vec!["s1"]));
+    vehicle
+        .dimens
+        .set_vehicle_skill_validity(HashMap::from_iter(vec![("s1".to_string(), vec![TimeWindow::new(0., 5.)])]));
+    if let Some(service_scale) = service_scale {
+        vehicle.dimens.set_vehicle_service_scale(service_scale);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let job = create_job_with_skills(Some(vec!["s1"]), None, None).to_single().clone();
+    let existing = Activity { schedule: Schedule::new(4., 4.), ..create_activity_with_job_at_location(job, 4) };
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![existing]), RouteState::default());
+    let mut target =
+        create_activity_with_job_at_location(create_job_with_skills(None, None, None).to_single().clone(), 2);
+    target.place.duration = 0.5;
+    let constraint =
+        create_skills_feature("skills", VIOLATION_CODE, create_test_transport(), create_test_activity_cost())
+            .unwrap()
+            .constraint
+            .unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        },
+    ));
+
+    // NOTE next activity starts at 4.5 without scale and at 5.5 when target's service is scaled
+    assert_eq!(actual, expected)
+}
+
+parameterized_test! {can_estimate_preferred_skills, (preferred, vehicle_skills, expected), {
+    can_estimate_preferred_skills_impl(preferred, vehicle_skills, expected);
+}}
+
+can_estimate_preferred_skills! {
+    case01_no_preferred: (None, Some(vec!["s1"]), 0.),
+    case02_all_matched: (Some(vec!["s1", "s2"]), Some(vec!["s1", "s2", "s3"]), 0.),
+    case03_partially_matched: (Some(vec!["s1", "s2"]), Some(vec!["s1"]), 10.),
+    case04_no_vehicle_skills: (Some(vec!["s1"]), None, 10.),
+    case05_empty_preferred: (Some(vec![]), None, 0.),
+}
+
+fn can_estimate_preferred_skills_impl(preferred: Option<Vec<&str>>, vehicle_skills: Option<Vec<&str>>, expected: f64) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_skills(vehicle_skills))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_skills(Some(JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: preferred.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+    }));
+    let objective = create_preferred_skills_feature("preferred_skills", 10.).unwrap().objective.unwrap();
+
+    let actual = objective.estimate(&MoveContext::route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &Job::Single(Arc::new(single)),
+    ));
+
+    assert_eq!(actual, expected);
+}
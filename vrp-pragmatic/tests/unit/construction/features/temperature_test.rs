@@ -0,0 +1,70 @@
+use super::*;
+use crate::construction::enablers::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{MoveContext, RouteContext, RouteState};
+use vrp_core::models::problem::Fleet;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_job_with_temperature(temperature: Option<(f64, f64)>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_temperature(temperature);
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_check_temperature, (job_temperature, vehicle_temperature, expected), {
+    can_check_temperature_impl(job_temperature, vehicle_temperature, expected);
+}}
+
+can_check_temperature! {
+    case01_no_job_temperature: (None, Some((2., 8.)), None),
+    case02_no_temperatures: (None, None, None),
+    case03_no_vehicle_temperature: (Some((-25., -18.)), None, ConstraintViolation::fail(VIOLATION_CODE)),
+    case04_frozen_on_ambient: (Some((-25., -18.)), Some((10., 25.)), ConstraintViolation::fail(VIOLATION_CODE)),
+    case05_frozen_on_multi: (Some((-25., -18.)), Some((-25., 25.)), None),
+    case06_ambient_on_multi: (Some((15., 25.)), Some((-25., 25.)), None),
+    case07_touching: (Some((0., 4.)), Some((4., 8.)), None),
+}
+
+fn can_check_temperature_impl(
+    job_temperature: Option<(f64, f64)>,
+    vehicle_temperature: Option<(f64, f64)>,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(temperature) = vehicle_temperature {
+        vehicle.dimens.set_vehicle_temperature(temperature);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let constraint = create_temperature_feature("temperature", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job_with_temperature(job_temperature),
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_merge_temperature, (source, candidate, expected), {
+    let constraint = create_temperature_feature("temperature", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result =
+        constraint.merge(create_job_with_temperature(source), create_job_with_temperature(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}}
+
+can_merge_temperature! {
+    case01_same: (Some((-25., -18.)), Some((-25., -18.)), Ok(())),
+    case02_different: (Some((-25., -18.)), Some((2., 8.)), Err(VIOLATION_CODE)),
+    case03_none: (None, None, Ok(())),
+}
@@ -0,0 +1,104 @@
+use super::*;
+use crate::format::problem::deserialize_matrix;
+use crate::helpers::SIMPLE_MATRIX;
+
+fn to_binary(matrix: &Matrix) -> Vec<u8> {
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_binary_matrix(matrix, &mut writer).expect("cannot serialize matrix");
+
+    writer.into_inner().expect("cannot get bytes")
+}
+
+fn assert_matrices(actual: &Matrix, expected: &Matrix) {
+    assert_eq!(actual.profile, expected.profile);
+    assert_eq!(actual.timestamp, expected.timestamp);
+    assert_eq!(actual.travel_times, expected.travel_times);
+    assert_eq!(actual.distances, expected.distances);
+    assert_eq!(actual.error_codes, expected.error_codes);
+}
+
+#[test]
+fn can_read_binary_matrix_identical_to_json_one() {
+    let json_matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).expect("cannot read json matrix");
+    let bytes = to_binary(&json_matrix);
+
+    let binary_matrix = deserialize_binary_matrix(BufReader::new(bytes.as_slice())).expect("cannot read binary matrix");
+    assert_matrices(&binary_matrix, &json_matrix);
+
+    let detected_matrix = deserialize_matrix(BufReader::new(bytes.as_slice())).expect("cannot detect binary matrix");
+    assert_matrices(&detected_matrix, &json_matrix);
+}
+
+parameterized_test! {can_round_trip_optional_fields, (profile, timestamp, error_codes), {
+    can_round_trip_optional_fields_impl(profile, timestamp, error_codes);
+}}
+
+can_round_trip_optional_fields! {
+    case01_all_empty: (None, None, None),
+    case02_all_present: (Some("car"), Some("2020-07-04T00:00:00Z"), Some(vec![0, 1, 1, 0])),
+    case03_empty_profile: (Some(""), None, None),
+}
+
+fn can_round_trip_optional_fields_impl(profile: Option<&str>, timestamp: Option<&str>, error_codes: Option<Vec<i64>>) {
+    let matrix = Matrix {
+        profile: profile.map(|profile| profile.to_string()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: vec![0, 10, i64::MAX, -1],
+        distances: vec![0, 20, 30, i64::MIN],
+        error_codes,
+    };
+
+    let result = deserialize_binary_matrix(BufReader::new(to_binary(&matrix).as_slice())).expect("cannot read matrix");
+
+    assert_matrices(&result, &matrix);
+}
+
+#[test]
+fn can_detect_invalid_binary_matrix() {
+    let mut bytes = to_binary(&Matrix {
+        profile: None,
+        timestamp: None,
+        travel_times: vec![1, 2],
+        distances: vec![3, 4],
+        error_codes: None,
+    });
+    bytes.truncate(bytes.len() - 1);
+
+    let result = deserialize_binary_matrix(BufReader::new(bytes.as_slice()));
+
+    assert_eq!(result.err().map(|errs| errs.into_iter().next().unwrap().code), Some("E0001".to_string()));
+}
+
+fn create_header(profile_length: u32, size: u64) -> Vec<u8> {
+    MAGIC
+        .iter()
+        .cloned()
+        .chain(VERSION.to_le_bytes())
+        .chain(0_u32.to_le_bytes())
+        .chain(profile_length.to_le_bytes())
+        .chain(if profile_length == NO_VALUE { vec![] } else { vec![b'a'; 4] })
+        .chain(NO_VALUE.to_le_bytes())
+        .chain(size.to_le_bytes())
+        .chain(0_i64.to_le_bytes())
+        .collect()
+}
+
+parameterized_test! {can_reject_header_with_invalid_sizes, (profile_length, size), {
+    can_reject_header_with_invalid_sizes_impl(profile_length, size);
+}}
+
+can_reject_header_with_invalid_sizes! {
+    case01_truncated_values: (NO_VALUE, 2),
+    case02_oversized_values: (NO_VALUE, u64::MAX / 4),
+    case03_overflow_values: (NO_VALUE, u64::MAX),
+    case04_truncated_string: (5, 0),
+    case05_oversized_string: (u32::MAX - 1, 0),
+}
+
+fn can_reject_header_with_invalid_sizes_impl(profile_length: u32, size: u64) {
+    let bytes = create_header(profile_length, size);
+
+    let result = deserialize_binary_matrix(BufReader::new(bytes.as_slice()));
+
+    assert_eq!(result.err().map(|errs| errs.into_iter().next().unwrap().code), Some("E0001".to_string()));
+}
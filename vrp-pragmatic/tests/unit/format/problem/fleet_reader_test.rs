@@ -31,7 +31,10 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, min_travel: None })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -171,3 +174,29 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+#[test]
+fn can_apply_min_travel_to_non_positive_matrix_values() {
+    let mut problem = create_problem(&["car"]);
+    problem.fleet.profiles[0].min_travel = Some(MatrixMinTravel { duration: 3, distance: 5 });
+    let matrices =
+        vec![Matrix { travel_times: vec![0, 0, -1, 0], distances: vec![0, 0, 7, 0], ..matrix(None, None, 0, 4) }];
+    let route = Route {
+        actor: Arc::new(Actor {
+            vehicle: Arc::new(Vehicle { profile: CoreProfile::new(0, None), ..test_vehicle("v1") }),
+            driver: Arc::new(test_driver()),
+            detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.) },
+        }),
+        tour: Default::default(),
+    };
+
+    let transport = create_transport_costs(&problem, &matrices).unwrap();
+
+    let departure = TravelTime::Departure(0.);
+    assert_eq!(transport.duration(&route, 0, 1, departure), 3.);
+    assert_eq!(transport.distance(&route, 0, 1, departure), 5.);
+    assert_eq!(transport.duration(&route, 1, 0, departure), 3.);
+    assert_eq!(transport.distance(&route, 1, 0, departure), 7.);
+    assert_eq!(transport.duration(&route, 0, 0, departure), 0.);
+    assert_eq!(transport.distance(&route, 1, 1, departure), 0.);
+}
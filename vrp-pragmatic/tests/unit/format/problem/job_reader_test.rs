@@ -0,0 +1,22 @@
+use super::*;
+
+parameterized_test! {can_exclude_blackouts, (blackouts, duration, expected), {
+    can_exclude_blackouts_impl(blackouts, duration, expected);
+}}
+
+can_exclude_blackouts! {
+    case01_no_blackouts: (vec![], 10., vec![(0., 100.)]),
+    case02_blackout_inside: (vec![(50., 60.)], 10., vec![(0., 40.), (60., 100.)]),
+    case03_blackout_after_end_within_duration: (vec![(105., 200.)], 10., vec![(0., 95.)]),
+    case04_blackout_after_end_at_duration: (vec![(110., 200.)], 10., vec![(0., 100.)]),
+    case05_blackout_before_start: (vec![(0., 20.)], 10., vec![(20., 100.)]),
+    case06_blackout_covers_all: (vec![(0., 200.)], 10., vec![]),
+}
+
+fn can_exclude_blackouts_impl(blackouts: Vec<(f64, f64)>, duration: Duration, expected: Vec<(f64, f64)>) {
+    let blackouts = blackouts.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
+
+    let result = exclude_blackouts(TimeWindow::new(0., 100.), blackouts.as_slice(), duration);
+
+    assert_eq!(result, expected.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>());
+}
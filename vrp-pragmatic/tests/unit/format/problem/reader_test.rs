@@ -77,9 +77,11 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            blackouts: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
+                        target_time: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                     ..create_job("delivery_job")
@@ -94,9 +96,11 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            blackouts: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        target_time: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -107,9 +111,11 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            blackouts: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        target_time: None,
                     }]),
                     ..create_job("pickup_delivery_job")
                 },
@@ -123,9 +129,11 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            blackouts: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
+                        target_time: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                     ..create_job("pickup_job")
@@ -138,7 +146,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., distance_tiers: None, stop: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -164,10 +172,28 @@ fn can_read_complex_problem() {
                         policy: None,
                     }]),
                     reloads: None,
+                    job_types: None,
+                    overtime_penalty: None,
+                    break_separation: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                skill_validity: None,
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    max_cost: None,
+                    max_open_jobs: None,
+                    max_late_activities: None,
+                    max_idle_time: None,
+                    max_shift_span: None,
+                    max_interruptions: None,
+                }),
+                lifo: None,
+                count: None,
+                temperature: None,
+                service_scale: None,
             }],
             ..create_default_fleet()
         },
@@ -292,10 +318,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), min_travel: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), min_travel: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), min_travel: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, min_travel: None },
             ],
             ..create_default_fleet()
         },
@@ -74,6 +74,8 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                cost: None,
+                cumulative_cost: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,6 +95,8 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                cost: None,
+                cumulative_cost: None,
             },
         ],
     };
@@ -0,0 +1,92 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_create_route_manifest_with_cumulative_load() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (1., 0.), vec![2]),
+                create_pickup_job("job2", (2., 0.)),
+                create_pickup_delivery_job("job3", (3., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let tour = Tour {
+        vehicle_id: "my_vehicle_1".to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                2,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "pickup",
+                (2., 0.),
+                1,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                2,
+            ),
+            create_stop_with_activity_with_tag(
+                "job3",
+                "pickup",
+                (3., 0.),
+                2,
+                ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                3,
+                "p1",
+            ),
+            create_stop_with_activity_with_tag(
+                "job3",
+                "delivery",
+                (4., 0.),
+                1,
+                ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
+                4,
+                "d1",
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
+                8,
+            ),
+        ],
+        statistic: Default::default(),
+    };
+
+    let manifest = route_manifest(&tour, &problem);
+
+    assert_eq!(
+        manifest.lines().collect::<Vec<_>>(),
+        vec![
+            "Vehicle: my_vehicle_1, type: my_vehicle, shift: 0",
+            "1. arrival: 1970-01-01T00:00:00Z, location: lat=0, lng=0, jobs: departure, loaded: -, unloaded: -, load: 2",
+            "2. arrival: 1970-01-01T00:00:01Z, location: lat=1, lng=0, jobs: job1 (delivery), loaded: -, unloaded: 2, load: 0",
+            "3. arrival: 1970-01-01T00:00:03Z, location: lat=2, lng=0, jobs: job2 (pickup), loaded: 1, unloaded: -, load: 1",
+            "4. arrival: 1970-01-01T00:00:05Z, location: lat=3, lng=0, jobs: job3 (pickup), loaded: 1, unloaded: -, load: 2",
+            "5. arrival: 1970-01-01T00:00:07Z, location: lat=4, lng=0, jobs: job3 (delivery), loaded: -, unloaded: 1, load: 1",
+            "6. arrival: 1970-01-01T00:00:12Z, location: lat=0, lng=0, jobs: arrival, loaded: -, unloaded: -, load: 1",
+        ]
+    );
+}
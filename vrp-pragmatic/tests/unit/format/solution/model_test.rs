@@ -0,0 +1,38 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_serialize_and_deserialize_gzip_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+    let mut writer = BufWriter::new(Vec::new());
+
+    serialize_solution_gzip(&solution, &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+    let result = deserialize_solution_gzip(BufReader::new(bytes.as_slice())).unwrap();
+
+    assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+    assert_eq!(result.tours.len(), 1);
+    assert_eq!(result.statistic, solution.statistic);
+    assert_eq!(get_ids_from_tour(&result.tours[0]), get_ids_from_tour(&solution.tours[0]));
+}
+
+#[test]
+fn can_return_error_when_deserializing_non_gzip_solution() {
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution(&create_empty_solution(), &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+
+    let result = deserialize_solution_gzip(BufReader::new(bytes.as_slice()));
+
+    assert!(result.is_err());
+}
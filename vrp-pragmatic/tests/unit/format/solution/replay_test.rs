@@ -0,0 +1,45 @@
+use super::{read_replay, write_replay};
+use crate::format::problem::*;
+use crate::format::solution::create_solution;
+use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+use vrp_core::prelude::{create_default_config_builder, Environment, Solver};
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::EvolutionReplay;
+
+#[test]
+fn can_write_and_read_replay() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let replay = EvolutionReplay::new(2);
+    let config = create_default_config_builder(core_problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+    let (solution, _, _) = Solver::new(core_problem.clone(), config).with_replay(replay.clone()).solve().unwrap();
+    let mut buffer = Vec::new();
+
+    write_replay(core_problem.as_ref(), replay.take_frames().as_slice(), &mut BufWriter::new(&mut buffer)).unwrap();
+    let result = read_replay(BufReader::new(buffer.as_slice())).unwrap();
+
+    assert!(result.frames.len() > 1);
+    assert!(result.frames.windows(2).all(|frames| frames[0].generation < frames[1].generation));
+    assert_eq!(
+        result.frames.last().unwrap().solution,
+        create_solution(core_problem.as_ref(), &solution, &Default::default())
+    );
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::{create_solution, serialize_solution};
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::models::solution::Registry;
+use vrp_core::utils::DefaultRandom;
+
+fn serialize_to_string(serialize: impl FnOnce(&mut BufWriter<Vec<u8>>) -> Result<(), Error>) -> String {
+    let mut writer = BufWriter::new(Vec::new());
+    serialize(&mut writer).unwrap();
+
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+#[test]
+fn can_serialize_solution_same_as_default_serializer() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=30)
+                .map(|idx| create_delivery_job(&format!("job{idx}"), ((idx % 7) as f64, (idx / 7) as f64)))
+                .chain(std::iter::once(create_delivery_job_with_skills(
+                    "job_with_skill",
+                    (1., 1.),
+                    all_of_skills(vec!["unknown".to_string()]),
+                )))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = get_core_problem(problem, Some(vec![matrix]));
+    let core_solution = solve_core_with_cheapest_insertion(core_problem.clone());
+    let options = SolutionOptions { activity_costs: true, ..Default::default() };
+    let solution = create_solution(&core_problem, &core_solution, &options);
+    assert_eq!(solution.tours.len(), 3);
+    assert!(solution.unassigned.is_some());
+
+    let expected = serialize_to_string(|writer| serialize_solution(&solution, writer));
+    let actual =
+        serialize_to_string(|writer| serialize_solution_streaming(&core_problem, &core_solution, &options, writer));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_serialize_solution_without_tours() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let core_problem = get_core_problem(problem, None);
+    let core_solution = CoreSolution {
+        registry: Registry::new(&core_problem.fleet, Arc::new(DefaultRandom::default())),
+        routes: vec![],
+        unassigned: vec![],
+    };
+    let options = SolutionOptions::default();
+
+    let expected = serialize_to_string(|writer| {
+        serialize_solution(&create_solution(&core_problem, &core_solution, &options), writer)
+    });
+    let actual =
+        serialize_to_string(|writer| serialize_solution_streaming(&core_problem, &core_solution, &options, writer));
+
+    assert_eq!(actual, expected);
+}
@@ -4,9 +4,12 @@ use crate::format::solution::*;
 use crate::helpers::*;
 use std::cmp::Ordering;
 use std::sync::Arc;
+use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
-use vrp_core::utils::compare_floats;
+use vrp_core::models::problem::{DistanceCostTiers, DistanceCostTiersDimension};
+use vrp_core::models::solution::Registry;
+use vrp_core::utils::{compare_floats, DefaultRandom};
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
@@ -227,3 +230,80 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+parameterized_test! {can_calculate_cumulative_activity_costs, distance_tiers, {
+    can_calculate_cumulative_activity_costs_impl(distance_tiers);
+}}
+
+can_calculate_cumulative_activity_costs! {
+    case01_no_distance_tiers: None,
+    case02_distance_tiers: Some(vec![(1., 0.5), (2., 3.)]),
+}
+
+fn can_calculate_cumulative_activity_costs_impl(distance_tiers: Option<Vec<(f64, f64)>>) {
+    let (mut problem, mut coord_index) = create_test_problem_and_coord_index();
+    if let Some(distance_tiers) = distance_tiers {
+        let mut vehicle = test_vehicle("v1");
+        vehicle.dimens.set_distance_cost_tiers(DistanceCostTiers::new(distance_tiers));
+        problem.fleet = Arc::new(test_fleet_with_vehicles(vec![Arc::new(vehicle)]));
+    }
+    coord_index.add(&Location::Reference { index: 1 });
+    coord_index.add(&Location::Reference { index: 2 });
+    let activities = vec![
+        DomainActivity {
+            schedule: DomainSchedule { arrival: 1., departure: 2. },
+            ..create_activity_with_job_at_location(create_single("job1"), 1)
+        },
+        DomainActivity {
+            schedule: DomainSchedule { arrival: 3., departure: 4. },
+            ..create_activity_with_job_at_location(create_single("job2"), 2)
+        },
+    ];
+    let route = create_route_with_activities(&problem.fleet, "v1", activities);
+    let reserved_times_index =
+        vec![(route.actor.clone(), vec![TimeSpan::Window(TimeWindow::new(1., 2.))])].into_iter().collect();
+
+    let tour = create_tour(&problem, &route, &coord_index, &reserved_times_index);
+
+    let activities = tour.stops.iter().flat_map(|stop| stop.activities().iter()).collect::<Vec<_>>();
+    assert!(activities.iter().all(|activity| activity.cost.is_some()));
+    assert!(activities.windows(2).all(|pair| {
+        let (prev, next) = (pair[0].cumulative_cost.unwrap(), pair[1].cumulative_cost.unwrap());
+        compare_floats(next - prev, pair[1].cost.unwrap()) == Ordering::Equal
+    }));
+    let last_cumulative_cost = activities.last().and_then(|activity| activity.cumulative_cost).unwrap();
+    assert_eq!(compare_floats(last_cumulative_cost, tour.statistic.cost), Ordering::Equal);
+}
+
+#[test]
+fn can_use_custom_unassigned_reason_description() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![2])], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1])], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+    let job = core_problem.jobs.all().next().unwrap();
+    let core_solution = vrp_core::models::Solution {
+        registry: Registry::new(&core_problem.fleet, Arc::new(DefaultRandom::default())),
+        routes: vec![],
+        unassigned: vec![(job, UnassignmentInfo::Simple(4))],
+    };
+    let reason_fn: UnassignedReasonFn = Arc::new(|job_id, code| match code {
+        "CAPACITY_CONSTRAINT" => format!("Auftrag {job_id} passt nicht in das Fahrzeug"),
+        _ => code.to_string(),
+    });
+
+    let solution = create_solution(
+        &core_problem,
+        &core_solution,
+        &SolutionOptions { reason_fn: Some(reason_fn), ..Default::default() },
+    );
+
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "CAPACITY_CONSTRAINT");
+    assert_eq!(unassigned[0].reasons[0].description, "Auftrag job1 passt nicht in das Fahrzeug");
+}
@@ -74,3 +74,149 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_invalid_target_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![JobTask {
+                        target_time: Some("1970-01-01T00:00:10Z".to_string()),
+                        ..create_task((1., 0.), None)
+                    }]),
+                    ..create_job("job1")
+                },
+                Job {
+                    deliveries: Some(vec![JobTask {
+                        target_time: Some("not a time".to_string()),
+                        ..create_task((2., 0.), None)
+                    }]),
+                    ..create_job("job2")
+                },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_invalid_target_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1108", "job2", result);
+}
+
+#[test]
+fn can_detect_negative_max_detour_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { max_detour_time: Some(10.), ..create_delivery_job("job1", (1., 0.)) },
+                Job { max_detour_time: Some(-1.), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_negative_max_detour_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1109", "job2", result);
+}
+
+parameterized_test! {can_detect_invalid_visits, (visits, expected), {
+    can_detect_invalid_visits_impl(visits, expected);
+}}
+
+can_detect_invalid_visits! {
+    case01_unique_orders: (vec![("a", 1), ("a", 2), ("b", 1)], None),
+    case02_same_orders: (vec![("a", 1), ("a", 1)], Some("job2")),
+}
+
+fn can_detect_invalid_visits_impl(visits: Vec<(&str, i32)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: visits
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (id, order))| Job {
+                    visit: Some(JobVisit { id: id.to_string(), order }),
+                    ..create_delivery_job(format!("job{}", idx + 1).as_str(), (1., 0.))
+                })
+                .collect(),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_invalid_visits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    match expected {
+        Some(job_id) => assert_result("E1110", job_id, result),
+        None => assert!(result.is_none()),
+    }
+}
+
+#[test]
+fn can_detect_visit_with_multiple_tasks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                visit: Some(JobVisit { id: "a".to_string(), order: 1 }),
+                ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_invalid_visits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1110", "job1", result);
+}
+
+#[test]
+fn can_detect_negative_max_wait_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { max_wait_time: Some(10.), ..create_delivery_job("job1", (1., 0.)) },
+                Job { max_wait_time: Some(-1.), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_negative_max_wait_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1111", "job2", result);
+}
+
+#[test]
+fn can_detect_invalid_temperature_range() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    temperature: Some(TemperatureRange { min: -25., max: -18. }),
+                    ..create_delivery_job("job1", (1., 0.))
+                },
+                Job {
+                    temperature: Some(TemperatureRange { min: 8., max: 2. }),
+                    ..create_delivery_job("job2", (2., 0.))
+                },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_invalid_temperature_range(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_result("E1112", "job2", result);
+}
@@ -79,6 +79,7 @@ can_detect_missing_cost_objective! {
     case03: (Some(vec![vec![MinimizeDistance]]), None),
     case04: (Some(vec![vec![balance_dist()]]), Some(())),
     case05: (Some(vec![vec![], vec![balance_dist()]]), Some(())),
+    case06: (Some(vec![vec![MaximizeEarnings { job_pay: 10. }]]), None),
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<()>) {
@@ -97,7 +98,7 @@ fn can_detect_missing_value_jobs() {
     let problem = Problem {
         objectives: Some(vec![
             vec![MinimizeUnassignedJobs { breaks: None }],
-            vec![MaximizeValue { breaks: None }],
+            vec![MaximizeValue { breaks: None, budget: None, priority_base: None }],
             vec![MinimizeCost],
         ]),
         ..create_empty_problem()
@@ -167,6 +168,7 @@ can_detect_multiple_cost_objective! {
     case03: (Some(vec![vec![min_cost(), MinimizeDuration]]), Some(())),
     case04: (Some(vec![vec![min_cost(), MinimizeDistance]]), Some(())),
     case05: (Some(vec![vec![MinimizeDuration, MinimizeDistance]]), Some(())),
+    case06: (Some(vec![vec![min_cost(), MaximizeEarnings { job_pay: 10. }]]), Some(())),
 }
 
 fn can_detect_multiple_cost_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<()>) {
@@ -191,7 +193,7 @@ can_detect_missing_value_objective! {
             ]), Some("E1607".to_string())),
     case02: (Some(vec![
                 vec![MinimizeUnassignedJobs { breaks: None }],
-                vec![MaximizeValue { breaks: None }],
+                vec![MaximizeValue { breaks: None, budget: None, priority_base: None }],
                 vec![MinimizeCost],
             ]), None),
     case03: (None, None),
@@ -218,3 +220,27 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_overlap_objective_without_coordinates, (job, expected), {
+    can_detect_overlap_objective_without_coordinates_impl(job, expected);
+}}
+
+can_detect_overlap_objective_without_coordinates! {
+    case01: (create_delivery_job("job1", (1., 0.)), None),
+    case02: (create_delivery_job_with_index("job1", 0), Some("E1608".to_string())),
+}
+
+fn can_detect_overlap_objective_without_coordinates_impl(job: Job, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        objectives: Some(vec![vec![MinimizeOverlap], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_overlap_objective_without_coordinates(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -6,8 +7,8 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, min_travel: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, min_travel: None },
             ],
             ..create_default_fleet()
         },
@@ -100,7 +101,7 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, min_travel: None }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -112,3 +113,75 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_travel_gates, (to, times, expected), {
+    can_detect_invalid_travel_gates_impl(to, times, expected);
+}}
+
+can_detect_invalid_travel_gates! {
+    case01_valid: ((1., 0.), vec![(0., 10.), (20., 30.)], None),
+    case02_unknown_location: ((2., 0.), vec![(0., 10.)], Some("E1506")),
+    case03_empty_times: ((1., 0.), vec![], Some("E1506")),
+    case04_invalid_time: ((1., 0.), vec![(10., 0.)], Some("E1506")),
+    case05_intersecting_times: ((1., 0.), vec![(0., 10.), (5., 15.)], Some("E1506")),
+}
+
+fn can_detect_invalid_travel_gates_impl(to: (f64, f64), times: Vec<(f64, f64)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            gates: Some(vec![TravelGate {
+                id: "gate1".to_string(),
+                from: (0., 0.).to_loc(),
+                to: to.to_loc(),
+                times: times.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect(),
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1506_invalid_travel_gates(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_negative_matrix_values, (travel_times, min_travel, expected), {
+    can_detect_negative_matrix_values_impl(travel_times, min_travel, expected);
+}}
+
+can_detect_negative_matrix_values! {
+    case01_positive: (vec![0, 1, 1, 0], None, None),
+    case02_zero: (vec![0, 0, 1, 0], None, None),
+    case03_negative: (vec![0, -1, 1, 0], None, Some("E1507")),
+    case04_negative_with_min_travel: (vec![0, -1, 1, 0], Some(MatrixMinTravel { duration: 1, distance: 1 }), None),
+}
+
+fn can_detect_negative_matrix_values_impl(
+    travel_times: Vec<i64>,
+    min_travel: Option<MatrixMinTravel>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, min_travel }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times,
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_negative_matrix_values(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -53,6 +53,7 @@ fn can_detect_invalid_dispatch_impl(dispatch: &[(f64, (f64, f64))], expected: Op
                 location: Location::Coordinate { lat, lng: 0. },
                 limits: vec![VehicleDispatchLimit { max: 1, start: format_time(times.0), end: format_time(times.1) }],
                 tag: None,
+                stage: None,
             })
             .collect(),
     );
@@ -89,7 +90,7 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, distance_tiers: None, stop: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -181,3 +182,309 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_reload_min_load_factor, (min_load_factor, expected), {
+    can_handle_reload_min_load_factor_impl(min_load_factor, expected);
+}}
+
+can_handle_reload_min_load_factor! {
+    case01: (None, None),
+    case02: (Some(0.5), None),
+    case03: (Some(1.), None),
+    case04: (Some(1.5), Some("E1309".to_string())),
+    case05: (Some(-0.5), Some("E1309".to_string())),
+}
+
+fn can_handle_reload_min_load_factor_impl(min_load_factor: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload { min_load_factor, ..create_default_reload() }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_reload_min_load_factor(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_shift_job_types, (job_types, expected), {
+    can_handle_shift_job_types_impl(job_types, expected);
+}}
+
+can_handle_shift_job_types! {
+    case01: (None, None),
+    case02: (Some(vec!["delivery", "pickup"]), None),
+    case03: (Some(vec!["replacement", "service"]), None),
+    case04: (Some(vec!["delivery", "reload"]), Some("E1310".to_string())),
+}
+
+fn can_handle_shift_job_types_impl(job_types: Option<Vec<&str>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    job_types: job_types.map(|types| types.iter().map(|job_type| job_type.to_string()).collect()),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1310_vehicle_shift_job_types(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_distance_cost_tiers, (tiers, expected), {
+    can_handle_distance_cost_tiers_impl(tiers, expected);
+}}
+
+can_handle_distance_cost_tiers! {
+    case01: (None, None),
+    case02: (Some(vec![(100., 0.5), (200., 0.)]), None),
+    case03: (Some(vec![(-100., 0.5)]), Some("E1311".to_string())),
+    case04: (Some(vec![(100., -0.5)]), Some("E1311".to_string())),
+}
+
+fn can_handle_distance_cost_tiers_impl(tiers: Option<Vec<(f64, f64)>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    distance_tiers: tiers.map(|tiers| {
+                        tiers.into_iter().map(|(threshold, rate)| VehicleDistanceCostTier { threshold, rate }).collect()
+                    }),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_distance_cost_tiers(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_shift_overtime_penalty, (penalty, expected), {
+    can_handle_shift_overtime_penalty_impl(penalty, expected);
+}}
+
+can_handle_shift_overtime_penalty! {
+    case01: (None, None),
+    case02: (Some(100.), None),
+    case03: (Some(-1.), Some("E1312".to_string())),
+}
+
+fn can_handle_shift_overtime_penalty_impl(penalty: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { overtime_penalty: penalty, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_shift_overtime_penalty(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_stop_cost, (stop, expected), {
+    can_handle_stop_cost_impl(stop, expected);
+}}
+
+can_handle_stop_cost! {
+    case01: (None, None),
+    case02: (Some(10.), None),
+    case03: (Some(-1.), Some("E1313".to_string())),
+}
+
+fn can_handle_stop_cost_impl(stop: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { stop, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1313_vehicle_stop_cost(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_pool_count, (count, vehicle_ids, expected), {
+    can_handle_vehicle_pool_count_impl(count, vehicle_ids, expected);
+}}
+
+can_handle_vehicle_pool_count! {
+    case01: (None, vec!["v1"], None),
+    case02: (Some(1), vec!["v1"], None),
+    case03: (Some(2), vec!["v1"], Some("E1314".to_string())),
+    case04: (Some(0), vec![], Some("E1314".to_string())),
+}
+
+fn can_handle_vehicle_pool_count_impl(count: Option<usize>, vehicle_ids: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                count,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_pool_count(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_temperature_range, (temperature, expected), {
+    can_handle_vehicle_temperature_range_impl(temperature, expected);
+}}
+
+can_handle_vehicle_temperature_range! {
+    case01: (None, None),
+    case02: (Some((-25., 25.)), None),
+    case03: (Some((8., 2.)), Some("E1315".to_string())),
+}
+
+fn can_handle_vehicle_temperature_range_impl(temperature: Option<(f64, f64)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                temperature: temperature.map(|(min, max)| TemperatureRange { min, max }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1315_vehicle_temperature_range(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_shift_break_separation, (separation, expected), {
+    can_handle_shift_break_separation_impl(separation, expected);
+}}
+
+can_handle_shift_break_separation! {
+    case01: (None, None),
+    case02: (Some(3600.), None),
+    case03: (Some(-1.), Some("E1316".to_string())),
+}
+
+fn can_handle_shift_break_separation_impl(separation: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { break_separation: separation, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1316_vehicle_shift_break_separation(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_service_scale, (service_scale, has_required_break, expected), {
+    can_handle_service_scale_impl(service_scale, has_required_break, expected);
+}}
+
+can_handle_service_scale! {
+    case01: (None, false, None),
+    case02: (Some(1.5), false, None),
+    case03: (Some(0.), false, Some("E1317".to_string())),
+    case04: (Some(-1.), false, Some("E1317".to_string())),
+    case05: (None, true, None),
+    case06: (Some(1.5), true, Some("E1317".to_string())),
+}
+
+fn can_handle_service_scale_impl(service_scale: Option<f64>, has_required_break: bool, expected: Option<String>) {
+    let breaks = if has_required_break {
+        Some(vec![VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime(10.), duration: 2. }])
+    } else {
+        None
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks, ..create_default_vehicle_shift() }],
+                service_scale,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1317_vehicle_service_scale(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_skill_validity_times, (times, expected), {
+    can_handle_skill_validity_times_impl(times, expected);
+}}
+
+can_handle_skill_validity_times! {
+    case01: (vec![vec!["1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"]], None),
+    case02: (vec![vec!["1970-01-01T00:00:00Z"]], Some("E1318".to_string())),
+    case03: (vec![vec!["1970-01-01T00:00:00Z", "invalid"]], Some("E1318".to_string())),
+    case04: (vec![vec!["1970-01-01T00:01:40Z", "1970-01-01T00:00:00Z"]], Some("E1318".to_string())),
+    case05: (vec![vec!["1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"], vec!["1970-01-01T00:03:20Z"]], Some("E1318".to_string())),
+}
+
+fn can_handle_skill_validity_times_impl(times: Vec<Vec<&str>>, expected: Option<String>) {
+    let times = times.into_iter().map(|tw| tw.into_iter().map(|time| time.to_string()).collect()).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skill_validity: Some(vec![VehicleSkillValidity { skill: "skill".to_string(), times }]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1318_vehicle_skill_validity_times(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}